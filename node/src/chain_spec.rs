@@ -1,9 +1,12 @@
-use node_template_runtime::{AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
+use node_template_runtime::{AccountId, RuntimeGenesisConfig, Signature, DAYS, WASM_BINARY};
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::{
+	traits::{IdentifyAccount, Verify},
+	Perbill, Permill,
+};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -111,7 +114,46 @@ fn testnet_genesis(
 		},
 		"sudo": {
 			// Assign network admin rights.
-			"key": Some(root_key),
+			"key": Some(root_key.clone()),
+		},
+		"veles": {
+			// Assign Veles authority rights (fees, time values, penalty levels...).
+			"authorityAccounts": vec![root_key],
+			// Registration, proposal, voting and complaint fees.
+			"feeValues": {
+				"traderAccountFee": 100,
+				"projectValidatorAccountFee": 100,
+				"projectOwnerAccountFee": 100,
+				"carbonFootprintReportFee": 300,
+				"projectProposalFee": 100,
+				"carbonCreditBatchFee": 50,
+				"votingFee": 100,
+				"complaintFee": 100,
+			},
+			// Timeouts in blocks (the pallet base time must be left at 0).
+			"timeValues": {
+				"numberOfBlocksPerYear": 365 * DAYS,
+				"palletBaseTime": 0,
+				"penaltyTimeout": 31 * DAYS,
+				"votingTimeout": 7 * DAYS,
+				"salesTimeout": 7 * DAYS,
+			},
+			// Two thirds of all votes (rounded up, see has_vote_passed).
+			"votePassRatio": Perbill::from_parts(666_666_667),
+			// Share of a payment or gain that is withheld at each penalty level.
+			"penaltyLevels": {
+				"0": Perbill::zero(),
+				"1": Perbill::from_rational(6_700u32, 16_700u32),
+				"2": Perbill::from_rational(13_000u32, 23_000u32),
+				"3": Perbill::from_rational(17_550u32, 27_550u32),
+				"4": Perbill::from_rational(22_000u32, 32_000u32),
+			},
+			// Validator and project owner shares of every sale.
+			"beneficiarySplits": {
+				"PrimarySaleValidators": Permill::from_percent(45),
+				"SecondarySaleValidators": Permill::from_percent(35),
+				"SecondarySaleOwner": Permill::from_percent(10),
+			},
 		},
 	})
 }
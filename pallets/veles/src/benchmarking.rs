@@ -4,7 +4,7 @@ use super::*;
 
 use crate::Pallet as Veles;
 use codec::Decode;
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{benchmarks, BenchmarkError};
 use frame_support::traits::Time;
 use frame_system::{EventRecord, RawOrigin};
 
@@ -67,6 +67,219 @@ pub fn generate_hash<T: Config>(user: AccountIdOf<T>) -> H256 {
 	hash
 }
 
+// Insert carbon credit batches for a project together with the project indexes
+fn insert_project_batches<T: Config>(project_owner: AccountIdOf<T>, project_hash: H256, b: u32) {
+	for i in 0..b {
+		let batch_hash = H256::from_low_u64_be(i as u64 + 1);
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch_documentation"),
+			project_hash,
+			creation_date: T::Time::now(),
+			credit_amount: BalanceOf::<T>::from(100u32),
+			penalty_repay_price: BalanceOf::<T>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<T>::new(),
+		};
+
+		CarbonCreditBatches::<T>::insert(batch_hash, batch);
+
+		BatchesByProject::<T>::mutate(project_hash, |batches| {
+			batches.get_or_insert_with(BoundedBTreeSet::new).try_insert(batch_hash).unwrap();
+		});
+	}
+
+	ProjectsByOwner::<T>::mutate(project_owner, |projects| {
+		projects.get_or_insert_with(BoundedBTreeSet::new).try_insert(project_hash).unwrap();
+	});
+}
+
+// Insert an active carbon credit buy order (10 credits for 5 each) and hold the buyer funds
+fn insert_buy_order<T: Config>(
+	buyer: AccountIdOf<T>,
+	target: BuyOrderTarget,
+	timeout_block: BlockNumber<T>,
+) -> H256 {
+	let credit_amount = BalanceOf::<T>::from(10u32);
+	let credit_price = BalanceOf::<T>::from(5u32);
+
+	let buy_order = CarbonCreditBuyOrderInfo {
+		target,
+		credit_amount,
+		credit_price,
+		buyer: buyer.clone(),
+		buy_active: true,
+		buy_timeout: timeout_block,
+	};
+
+	let buy_hash = generate_hash::<T>(buyer.clone());
+
+	CarbonCreditBuyOrders::<T>::insert(buy_hash, buy_order);
+
+	let mut buy_events = TimeoutsOf::<T, H256>::new();
+	buy_events.try_insert(buy_hash).unwrap();
+
+	BuyOrderTimeouts::<T>::insert(timeout_block, buy_events);
+
+	T::Currency::set_balance(&buyer, BalanceOf::<T>::from(1000u32));
+	T::Currency::hold(&HoldReason::BuyOrderEscrow.into(), &buyer, credit_amount * credit_price)
+		.unwrap();
+
+	buy_hash
+}
+
+// Insert an active carbon credit batch (owned by alice, validated by dave and fred) and give bob 50
+// available credits from it
+fn insert_auction_batch<T: Config>() -> H256 {
+	let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+		documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_1"),
+		penalty_level: 0,
+		penalty_timeout: BlockNumber::<T>::from(0u32),
+	};
+
+	let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+		documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_2"),
+		penalty_level: 0,
+		penalty_timeout: BlockNumber::<T>::from(0u32),
+	};
+
+	Validators::<T>::insert(dave::<T>(), validator_1);
+	Validators::<T>::insert(fred::<T>(), validator_2);
+
+	let owner = ProjectValidatorOrProjectOwnerInfo {
+		documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("owner"),
+		penalty_level: 0,
+		penalty_timeout: BlockNumber::<T>::from(0u32),
+	};
+
+	ProjectOwners::<T>::insert(alice::<T>(), owner);
+
+	let project = ProjectInfo {
+		documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project"),
+		project_owner: alice::<T>(),
+		creation_date: T::Time::now(),
+		penalty_level: 0,
+		penalty_timeout: BlockNumber::<T>::from(0u32),
+	};
+
+	let project_hash = generate_hash::<T>(alice::<T>());
+
+	Projects::<T>::insert(project_hash, project);
+	insert_project_beneficiary_splits::<T>(project_hash);
+
+	let mut validator_benefactors = VotesOf::<T>::new();
+	validator_benefactors.try_insert(dave::<T>()).unwrap();
+	validator_benefactors.try_insert(fred::<T>()).unwrap();
+
+	let credit_batch = CarbonCreditBatchInfo {
+		documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
+		project_hash,
+		creation_date: T::Time::now(),
+		credit_amount: BalanceOf::<T>::from(100u32),
+		penalty_repay_price: BalanceOf::<T>::from(10u32),
+		status: CarbonCreditBatchStatus::Active,
+		validator_benefactors,
+	};
+
+	let batch_hash = generate_hash::<T>(bob::<T>());
+
+	CarbonCreditBatches::<T>::insert(batch_hash, credit_batch);
+
+	let credit_holdings = CarbonCreditHoldingsInfo {
+		available_amount: BalanceOf::<T>::from(50u32),
+		unavailable_amount: BalanceOf::<T>::from(0u32),
+	};
+
+	CarbonCreditHoldings::<T>::insert(batch_hash, bob::<T>(), credit_holdings);
+	TraderAccounts::<T>::insert(bob::<T>(), TraderAccountInfo::default());
+	T::Currency::set_balance(&bob::<T>(), BalanceOf::<T>::from(1000u32));
+
+	batch_hash
+}
+
+// Insert an open carbon credit auction of bob (10 credits) and hold the funds of the highest bid
+fn insert_auction<T: Config>(
+	batch_hash: H256,
+	auction_type: AuctionType<BalanceOf<T>>,
+	highest_bid: Option<(AccountIdOf<T>, BalanceOf<T>)>,
+	end_block: BlockNumber<T>,
+) -> H256 {
+	let credit_amount = BalanceOf::<T>::from(10u32);
+
+	CarbonCreditHoldings::<T>::mutate(batch_hash, bob::<T>(), |holdings| {
+		let holdings = holdings.as_mut().unwrap();
+
+		holdings.available_amount -= credit_amount;
+		holdings.unavailable_amount += credit_amount;
+	});
+
+	if let Some((bidder, credit_price)) = highest_bid.clone() {
+		T::Currency::set_balance(&bidder, BalanceOf::<T>::from(1000u32));
+		let amount_to_hold = credit_amount * credit_price;
+
+		T::Currency::hold(&HoldReason::AuctionBidEscrow.into(), &bidder, amount_to_hold).unwrap();
+	}
+
+	let auction = CarbonCreditAuctionInfo {
+		batch_hash,
+		credit_amount,
+		seller: bob::<T>(),
+		auction_type,
+		highest_bid,
+		start_block: BlockNumber::<T>::from(0u32),
+		end_block,
+		status: AuctionStatus::Open,
+	};
+
+	let auction_hash = generate_hash::<T>(bob::<T>());
+
+	CarbonCreditAuctions::<T>::insert(auction_hash, auction);
+
+	let mut auction_events = TimeoutsOf::<T, H256>::new();
+	auction_events.try_insert(auction_hash).unwrap();
+
+	AuctionTimeouts::<T>::insert(end_block, auction_events);
+
+	auction_hash
+}
+
+// Maximum number of allowed buyers of a private sale order (including the buyer)
+fn max_allowed_buyers<T: Config>(buyer: AccountIdOf<T>) -> AllowedBuyersOf<T> {
+	let mut allowed_buyers = AllowedBuyersOf::<T>::new();
+	allowed_buyers.try_insert(buyer).unwrap();
+
+	for i in 1 .. T::MaxAllowedBuyersPerSaleOrder::get() {
+		let allowed_buyer: AccountIdOf<T> = frame_benchmarking::account("buyer", i, 0);
+
+		allowed_buyers.try_insert(allowed_buyer).unwrap();
+	}
+
+	allowed_buyers
+}
+
+// Maximum number of stakeholders of a project (each with a share of 0.01%)
+fn max_stakeholders<T: Config>() -> BTreeMap<AccountIdOf<T>, Permill> {
+	let mut stakeholders = BTreeMap::<AccountIdOf<T>, Permill>::new();
+
+	for i in 0 .. T::MaxStakeholdersPerProject::get() {
+		let stakeholder: AccountIdOf<T> = frame_benchmarking::account("stakeholder", i, 0);
+
+		stakeholders.insert(stakeholder, Permill::from_parts(100));
+	}
+
+	stakeholders
+}
+
+// Insert approved project beneficiary splits with the maximum number of stakeholders
+fn insert_project_beneficiary_splits<T: Config>(project_hash: H256) {
+	let project_splits = ProjectBeneficiarySplitsInfo {
+		beneficiary_splits: BeneficiarySplits::<T>::get(),
+		stakeholders: StakeholderSharesOf::<T>::try_from(max_stakeholders::<T>()).unwrap(),
+	};
+
+	ProjectBeneficiarySplits::<T>::insert(project_hash, project_splits);
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -78,55 +291,52 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 
 benchmarks! {
 	update_vote_pass_ratio {
-		let user = alice::<T>();
-		let new_proportion_part = 2u16;
-		let	new_upper_limit_part = 3u16;
+		let new_vote_pass_ratio = Perbill::from_percent(75);
 
-		let mut authorities = AuthorityAccounts::<T>::get();
-		authorities.insert(user.clone());
-		AuthorityAccounts::<T>::set(authorities);
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 	} : {
 		Veles::<T>::update_vote_pass_ratio(
-			RawOrigin::Signed(user.clone()).into(),
-			new_proportion_part,
-			new_upper_limit_part,
+			origin,
+			new_vote_pass_ratio,
 		).unwrap();
 	} verify {
-		assert_last_event::<T>(Event::<T>::VotePassRatioUpdated(new_proportion_part, new_upper_limit_part).into());
+		assert_last_event::<T>(Event::<T>::VotePassRatioUpdated(new_vote_pass_ratio).into());
 	}
 
 	update_penalty_levels {
-		let user = alice::<T>();
-		let new_proportion_part = 2u16;
-		let	new_upper_limit_part = 3u16;
-
-		let mut authorities = AuthorityAccounts::<T>::get();
-		authorities.insert(user.clone());
-		AuthorityAccounts::<T>::set(authorities);
+		let mut new_penalty_levels = BTreeMap::<u8, Perbill>::new();
+		new_penalty_levels.insert(0, Perbill::zero());
+		new_penalty_levels.insert(1, Perbill::from_percent(40));
+		new_penalty_levels.insert(2, Perbill::from_percent(50));
+		new_penalty_levels.insert(3, Perbill::from_percent(60));
+		new_penalty_levels.insert(4, Perbill::from_percent(70));
+
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 	} : {
-		Veles::<T>::update_vote_pass_ratio(
-			RawOrigin::Signed(user.clone()).into(),
-			new_proportion_part,
-			new_upper_limit_part,
+		Veles::<T>::update_penalty_levels(
+			origin,
+			new_penalty_levels.clone(),
 		).unwrap();
 	} verify {
-		assert_last_event::<T>(Event::<T>::VotePassRatioUpdated(new_proportion_part, new_upper_limit_part).into());
+		assert_last_event::<T>(Event::<T>::PenaltyLevelsUpdated(new_penalty_levels).into());
 	}
 
 	update_beneficiary_splits {
-		let user = alice::<T>();
-
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<T>>::new();
-		new_beneficiary_splits.insert(0, 4500u32.into());
-		new_beneficiary_splits.insert(1, 1500u32.into());
-		new_beneficiary_splits.insert(2, 3000u32.into());
-
-		let mut authorities = AuthorityAccounts::<T>::get();
-		authorities.insert(user.clone());
-		AuthorityAccounts::<T>::set(authorities);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(45));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(15));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_percent(30));
+
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 	} : {
 		Veles::<T>::update_beneficiary_splits(
-			RawOrigin::Signed(user.clone()).into(),
+			origin,
 			new_beneficiary_splits.clone(),
 		).unwrap();
 	} verify {
@@ -134,17 +344,14 @@ benchmarks! {
 	}
 
 	update_time_value {
-		let user = alice::<T>();
-
 		let time_type = TimeType::SalesTimeout;
 		let new_time_value = BlockNumber::<T>::from(1u32);
 
-		let mut authorities = AuthorityAccounts::<T>::get();
-		authorities.insert(user.clone());
-		AuthorityAccounts::<T>::set(authorities);
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 	} : {
 		Veles::<T>::update_time_value(
-			RawOrigin::Signed(user.clone()).into(),
+			origin,
 			time_type.clone(),
 			new_time_value,
 		).unwrap();
@@ -153,17 +360,14 @@ benchmarks! {
 	}
 
 	update_fee_value {
-		let user = alice::<T>();
-
 		let fee_type = FeeType::ProjectValidatorAccountFee;
 		let new_fee_value = BalanceOf::<T>::from(1u32);
 
-		let mut authorities = AuthorityAccounts::<T>::get();
-		authorities.insert(user.clone());
-		AuthorityAccounts::<T>::set(authorities);
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 	} : {
 		Veles::<T>::update_fee_value(
-			RawOrigin::Signed(user.clone()).into(),
+			origin,
 			fee_type.clone(),
 			new_fee_value,
 		).unwrap();
@@ -276,8 +480,8 @@ benchmarks! {
 			complaint_type: ComplaintType::ValidatorComplaint,
 			complaint_for: charlie::<T>(),
 			creation_date: T::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_for: VotesOf::<T>::new(),
+			votes_against: VotesOf::<T>::new(),
 			complaint_active: true,
 		};
 		ComplaintsForAccounts::<T>::insert(complaint_ipfs.clone(), complaint_info);
@@ -372,10 +576,7 @@ benchmarks! {
 	}
 
 	create_sale_order {
-		let mut traders = BTreeSet::<AccountIdOf<T>>::new();
-		traders.insert(alice::<T>());
-
-		TraderAccounts::<T>::set(traders);
+		TraderAccounts::<T>::insert(alice::<T>(), TraderAccountInfo::default());
 
 		let batch_ipfs = BoundedString::<T::IPFSLength>::truncate_from("batch_ipfs");
 
@@ -388,7 +589,7 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(100u32),
 			penalty_repay_price: BalanceOf::<T>::from(1u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		let batch_hash = generate_hash::<T>(bob::<T>());
@@ -402,6 +603,9 @@ benchmarks! {
 
 		CarbonCreditHoldings::<T>::insert(batch_hash, alice::<T>(), credit_holdings.clone());
 
+		// Worst case: private sale order with the maximum number of allowed buyers
+		let allowed_buyers = max_allowed_buyers::<T>(charlie::<T>());
+
 		let user = alice::<T>();
 		let credit_price = BalanceOf::<T>::from(10u32);
 		let credit_amount = BalanceOf::<T>::from(5u32);
@@ -411,6 +615,9 @@ benchmarks! {
 			batch_hash,
 			credit_price,
 			credit_amount,
+			None,
+			Some(allowed_buyers),
+			None,
 		).unwrap();
 	} verify {
 		assert_last_event::<T>(Event::<T>::CarbonCreditSaleOrderCreated(
@@ -422,10 +629,7 @@ benchmarks! {
 	}
 
 	complete_sale_order {
-		let mut traders = BTreeSet::<AccountIdOf<T>>::new();
-		traders.insert(charlie::<T>());
-
-		TraderAccounts::<T>::set(traders);
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
 
 		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
 			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_1"),
@@ -462,9 +666,13 @@ benchmarks! {
 
 		Projects::<T>::insert(project_hash, project);
 
-		let mut validator_benefactors = BTreeSet::<AccountIdOf<T>>::new();
-		validator_benefactors.insert(dave::<T>());
-		validator_benefactors.insert(fred::<T>());
+		// Worst case: the project has approved beneficiary splits with the maximum number of
+		// stakeholders
+		insert_project_beneficiary_splits::<T>(project_hash);
+
+		let mut validator_benefactors = VotesOf::<T>::new();
+		validator_benefactors.try_insert(dave::<T>()).unwrap();
+		validator_benefactors.try_insert(fred::<T>()).unwrap();
 
 		let credit_batch = CarbonCreditBatchInfo {
 			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
@@ -492,39 +700,55 @@ benchmarks! {
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash,
 			credit_amount: BalanceOf::<T>::from(10u32),
+			min_fill_amount: BalanceOf::<T>::from(1u32),
 			credit_price: BalanceOf::<T>::from(5u32),
 			seller: bob::<T>(),
-			buyer: bob::<T>(),
-			sale_active: true,
-			sale_timeout: timeout_block,
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
 		let sale_hash = generate_hash::<T>(bob::<T>());
 
 		CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
 
-		let mut sale_timeouts = BTreeSet::<H256>::new();
-		sale_timeouts.insert(sale_hash);
+		let mut sale_timeouts = TimeoutsOf::<T, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
 
 		SaleOrderTimeouts::<T>::insert(timeout_block, sale_timeouts);
 
-		let _ = T::Currency::deposit_creating(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+		// Worst case: private sale order with the maximum number of allowed buyers
+		SaleOrderAllowedBuyers::<T>::insert(sale_hash, max_allowed_buyers::<T>(charlie::<T>()));
+
+		// Worst case: maker and taker trading fees are paid
+		TradingFees::<T>::set(TradingFeeValues {
+			maker_fee: Permill::from_percent(2),
+			taker_fee: Permill::from_percent(2),
+		});
+
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
 
 		let user = charlie::<T>();
 	} : {
 		Veles::<T>::complete_sale_order(
 			RawOrigin::Signed(user.clone()).into(),
 			sale_hash,
+			None,
 		).unwrap();
 	} verify {
-		assert_last_event::<T>(Event::<T>::CarbonCreditSaleOrderCompleted(user, sale_hash).into());
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditSaleOrderCompleted(
+				user,
+				sale_hash,
+				BalanceOf::<T>::from(1u32),
+				BalanceOf::<T>::from(1u32),
+			)
+			.into(),
+		);
 	}
 
 	close_sale_order {
-		let mut traders = BTreeSet::<AccountIdOf<T>>::new();
-		traders.insert(bob::<T>());
-
-		TraderAccounts::<T>::set(traders);
+		TraderAccounts::<T>::insert(bob::<T>(), TraderAccountInfo::default());
 
 		let batch = CarbonCreditBatchInfo {
 			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from(
@@ -535,7 +759,7 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(100u32),
 			penalty_repay_price: BalanceOf::<T>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		let batch_hash = generate_hash::<T>(alice::<T>());
@@ -554,19 +778,20 @@ benchmarks! {
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash,
 			credit_amount: BalanceOf::<T>::from(10u32),
+			min_fill_amount: BalanceOf::<T>::from(1u32),
 			credit_price: BalanceOf::<T>::from(5u32),
 			seller: bob::<T>(),
-			buyer: bob::<T>(),
-			sale_active: true,
-			sale_timeout: timeout_block,
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
 		let sale_hash = generate_hash::<T>(bob::<T>());
 
 		CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
 
-		let mut sale_timeouts = BTreeSet::<H256>::new();
-		sale_timeouts.insert(sale_hash);
+		let mut sale_timeouts = TimeoutsOf::<T, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
 
 		SaleOrderTimeouts::<T>::insert(timeout_block, sale_timeouts);
 
@@ -581,6 +806,8 @@ benchmarks! {
 	}
 
 	open_account_complaint {
+		let b in 1 .. 100;
+
 		let mut pallet_fees = PalletFeeValues::<T>::get();
 		pallet_fees = FeeValues {
 			complaint_fee: BalanceOf::<T>::from(0u32),
@@ -633,13 +860,15 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(100u32),
 			penalty_repay_price: BalanceOf::<T>::from(5u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		let batch_hash = generate_hash::<T>(bob::<T>());
 
 		CarbonCreditBatches::<T>::insert(batch_hash, batch);
 
+		insert_project_batches::<T>(bob::<T>(), project_hash, b);
+
 		let complaint_documentation =
 			BoundedString::<T::IPFSLength>::truncate_from("complaint_documentation");
 		let complaint_for = bob::<T>();
@@ -663,6 +892,8 @@ benchmarks! {
 	}
 
 	open_hash_complaint {
+		let b in 1 .. 100;
+
 		let mut pallet_fees = PalletFeeValues::<T>::get();
 		pallet_fees = FeeValues {
 			complaint_fee: BalanceOf::<T>::from(0u32),
@@ -715,13 +946,15 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(100u32),
 			penalty_repay_price: BalanceOf::<T>::from(5u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		let batch_hash = generate_hash::<T>(bob::<T>());
 
 		CarbonCreditBatches::<T>::insert(batch_hash, batch);
 
+		insert_project_batches::<T>(bob::<T>(), project_hash, b);
+
 		let complaint_documentation =
 			BoundedString::<T::IPFSLength>::truncate_from("complaint_documentation");
 		let complaint_for = project_hash;
@@ -745,11 +978,12 @@ benchmarks! {
 	}
 
 	retire_carbon_credits {
-		let mut documentation_ipfses = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+		let mut documentation_ipfses =
+		BoundedBTreeSet::<BoundedString<T::IPFSLength>, T::MaxDocumentsPerCfa>::new();
 		let footprint_account_ipfs =
 			BoundedString::<T::IPFSLength>::truncate_from("footprint_account_ipfs");
 
-		documentation_ipfses.insert(footprint_account_ipfs);
+		documentation_ipfses.try_insert(footprint_account_ipfs).unwrap();
 
 		let carbon_footprint_account = CarbonFootprintAccountInfo {
 			documentation_ipfses: documentation_ipfses.clone(),
@@ -770,7 +1004,7 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(200u32),
 			penalty_repay_price: BalanceOf::<T>::from(20u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		let batch_hash = generate_hash::<T>(alice::<T>());
@@ -812,14 +1046,14 @@ benchmarks! {
 
 		ProjectOwners::<T>::insert(charlie::<T>(), project_owner);
 
-		let mut debts = BTreeMap::<AccountIdOf<T>, BalanceOf<T>>::new();
+		let mut debts = DebtsOf::<T>::new();
 
-		debts.insert(alice::<T>(), BalanceOf::<T>::from(20u32));
-		debts.insert(bob::<T>(), BalanceOf::<T>::from(30u32));
+		debts.try_insert(alice::<T>(), BalanceOf::<T>::from(20u32)).unwrap();
+		debts.try_insert(bob::<T>(), BalanceOf::<T>::from(30u32)).unwrap();
 
 		ProjectOwnerDebts::<T>::insert(charlie::<T>(), debts);
 
-		let _ = T::Currency::deposit_creating(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
 
 		let user = charlie::<T>();
 	} : {
@@ -832,9 +1066,11 @@ benchmarks! {
 
     update_pallet_base_time {
         let new_pallet_base_time = BlockNumber::<T>::from(100u32);
+
+		frame_system::Pallet::<T>::set_block_number(new_pallet_base_time);
     } : {
         Veles::<T>::update_pallet_base_time(
-            RawOrigin::Signed(alice::<T>()).into(),
+            RawOrigin::None.into(),
 			new_pallet_base_time
 		).unwrap();
     } verify {
@@ -842,9 +1078,10 @@ benchmarks! {
     }
 
     update_carbon_footprint_report {
-		let mut documentation_ipfses = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+		let mut documentation_ipfses =
+		BoundedBTreeSet::<BoundedString<T::IPFSLength>, T::MaxDocumentsPerCfa>::new();
 		let report_1_ipfs = BoundedString::<T::IPFSLength>::truncate_from("report_1_ipfs");
-		documentation_ipfses.insert(report_1_ipfs.clone());
+		documentation_ipfses.try_insert(report_1_ipfs.clone()).unwrap();
 
 		let cf_account = CarbonFootprintAccountInfo {
 			documentation_ipfses: documentation_ipfses,
@@ -855,34 +1092,44 @@ benchmarks! {
 
 		CarbonFootprintAccounts::<T>::insert(alice::<T>(), cf_account);
 
-		let mut votes_for = BTreeSet::<AccountIdOf<T>>::new();
-		votes_for.insert(bob::<T>());
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(bob::<T>()).unwrap();
 
 		let report_info = CarbonFootprintReportInfo {
 			cf_account: alice::<T>(),
 			creation_date: T::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<T>::from(100u32),
-			votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_for: VotesOf::<T>::new(),
+			votes_against: VotesOf::<T>::new(),
 			voting_active: true,
 		};
 
 		let report_2_ipfs = BoundedString::<T::IPFSLength>::truncate_from("report_2_ipfs");
 
 		CarbonFootprintReports::<T>::insert(report_2_ipfs.clone(), report_info);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		timeout_events.try_insert(report_2_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<T>::insert(timeout_block, timeout_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
     } : {
         Veles::<T>::update_carbon_footprint_report(
-            RawOrigin::Signed(alice::<T>()).into(),
-			report_2_ipfs.clone()
+            RawOrigin::None.into(),
+			report_2_ipfs.clone(),
+			timeout_block
 		).unwrap();
     } verify {
         assert_last_event::<T>(Event::<T>::CarbonFootprintReportUpdated(report_2_ipfs).into());
     }
 
     update_project_proposal {
-		let mut votes_for = BTreeSet::<AccountIdOf<T>>::new();
-		votes_for.insert(bob::<T>());
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(bob::<T>()).unwrap();
 
 		let project_hash = generate_hash::<T>(alice::<T>());
 
@@ -891,17 +1138,27 @@ benchmarks! {
 			creation_date: T::Time::now(),
 			project_hash: project_hash,
 			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_against: VotesOf::<T>::new(),
 			voting_active: true,
 		};
 
 		let proposal_ipfs = BoundedString::<T::IPFSLength>::truncate_from("proposal_ipfs");
 
 		ProjectProposals::<T>::insert(proposal_ipfs.clone(), proposal);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<T>::insert(timeout_block, timeout_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
     } : {
         Veles::<T>::update_project_proposal(
-            RawOrigin::Signed(alice::<T>()).into(),
-			proposal_ipfs.clone()
+            RawOrigin::None.into(),
+			proposal_ipfs.clone(),
+			timeout_block
 		).unwrap();
     } verify {
         assert_last_event::<T>(Event::<T>::ProjectProposalUpdated(proposal_ipfs).into());
@@ -935,8 +1192,8 @@ benchmarks! {
 
 		let batch_hash = generate_hash::<T>(charlie::<T>());
 
-		let mut votes_for = BTreeSet::<AccountIdOf<T>>::new();
-		votes_for.insert(bob::<T>());
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(bob::<T>()).unwrap();
 
 		let proposal = CarbonCreditBatchProposalInfo {
 			project_hash,
@@ -945,24 +1202,32 @@ benchmarks! {
 			credit_amount: 100u32.into(),
 			penalty_repay_price: 5u32.into(),
 			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_against: VotesOf::<T>::new(),
 			voting_active: true,
 		};
 
 		CarbonCreditBatchProposals::<T>::insert(proposal_ipfs.clone(), proposal);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<T>::insert(timeout_block, timeout_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
     } : {
         Veles::<T>::update_carbon_credit_batch_proposal(
-            RawOrigin::Signed(alice::<T>()).into(),
-			proposal_ipfs.clone()
+            RawOrigin::None.into(),
+			proposal_ipfs.clone(),
+			timeout_block
 		).unwrap();
     } verify {
         assert_last_event::<T>(Event::<T>::CarbonCreditBatchProposalUpdated(proposal_ipfs).into());
     }
 
     update_carbon_credit_sale_order {
-		let mut new_traders = TraderAccounts::<T>::get();
-		new_traders.insert(alice::<T>());
-		TraderAccounts::<T>::set(new_traders);
+		TraderAccounts::<T>::insert(alice::<T>(), TraderAccountInfo::default());
 
 		let batch_hash = generate_hash::<T>(alice::<T>());
 
@@ -976,19 +1241,27 @@ benchmarks! {
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash: batch_hash,
 			credit_amount: BalanceOf::<T>::from(10u32),
+			min_fill_amount: BalanceOf::<T>::from(1u32),
 			credit_price: BalanceOf::<T>::from(5u32),
 			seller: alice::<T>(),
-			buyer: alice::<T>(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<T>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<T>::from(10u32)),
 		};
 
 		let sale_hash = generate_hash::<T>(bob::<T>());
 
 		CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
+
+		let mut sale_events = TimeoutsOf::<T, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<T>::insert(BlockNumber::<T>::from(10u32), sale_events);
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumber::<T>::from(10u32));
     } : {
         Veles::<T>::update_carbon_credit_sale_order(
-            RawOrigin::Signed(alice::<T>()).into(),
+            RawOrigin::None.into(),
 			sale_hash
 		).unwrap();
     } verify {
@@ -996,6 +1269,8 @@ benchmarks! {
     }
 
     update_complaint_for_account {
+		let b in 1 .. 100;
+
 		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
 			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("documentation_ipfs"),
 			penalty_level: 1,
@@ -1004,10 +1279,24 @@ benchmarks! {
 
 		ProjectOwners::<T>::insert(alice::<T>(), project_owner_info);
 
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project_documentation"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		Projects::<T>::insert(project_hash, project);
+
+		insert_project_batches::<T>(alice::<T>(), project_hash, b);
+
 		let complaint_ipfs = BoundedString::<T::IPFSLength>::truncate_from("complaint_ipfs");
 
-		let mut votes_for = BTreeSet::<AccountIdOf<T>>::new();
-		votes_for.insert(charlie::<T>());
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(charlie::<T>()).unwrap();
 
 		let complaint = ComplaintAccountBasedInfo {
 			complaint_proposer: bob::<T>(),
@@ -1015,26 +1304,38 @@ benchmarks! {
 			complaint_for: alice::<T>(),
 			creation_date: T::Time::now(),
 			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_against: VotesOf::<T>::new(),
 			complaint_active: true,
 		};
 
 		ComplaintsForAccounts::<T>::insert(complaint_ipfs.clone(), complaint);
 
-		let mut penalty_accounts = BTreeSet::<AccountIdOf<T>>::new();
-		penalty_accounts.insert(alice::<T>());
+		let mut penalty_accounts = TimeoutsOf::<T, AccountIdOf<T>>::new();
+		penalty_accounts.try_insert(alice::<T>()).unwrap();
 
 		PenaltyTimeoutsAccounts::<T>::insert(BlockNumber::<T>::from(120u32), penalty_accounts);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut complaint_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<T>::insert(timeout_block, complaint_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
 	} : {
 		Veles::<T>::update_complaint_for_account(
-            RawOrigin::Signed(alice::<T>()).into(),
-			complaint_ipfs.clone()
+            RawOrigin::None.into(),
+			complaint_ipfs.clone(),
+			timeout_block
 		).unwrap();
 	} verify {
 		assert_last_event::<T>(Event::<T>::AccountComplaintUpdated(complaint_ipfs.clone()).into());
 	}
 
     update_complaint_for_hash {
+		let b in 1 .. 100;
+
 		let project_hash = generate_hash::<T>(alice::<T>());
 		let batch_hash = generate_hash::<T>(bob::<T>());
 
@@ -1072,11 +1373,13 @@ benchmarks! {
 			credit_amount: BalanceOf::<T>::from(10000u32),
 			penalty_repay_price: BalanceOf::<T>::from(2u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<T>>::new(),
+			validator_benefactors: VotesOf::<T>::new(),
 		};
 
 		CarbonCreditBatches::<T>::insert(batch_hash, batch);
 
+		insert_project_batches::<T>(alice::<T>(), project_hash, b);
+
 		let credit_holdings = CarbonCreditHoldingsInfo {
 			available_amount: BalanceOf::<T>::from(120u32),
 			unavailable_amount: BalanceOf::<T>::from(10u32),
@@ -1084,10 +1387,11 @@ benchmarks! {
 
 		CarbonCreditHoldings::<T>::insert(batch_hash, alice::<T>(), credit_holdings);
 
-		let mut documentation_ipfses_1 = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+		let mut documentation_ipfses_1 =
+		BoundedBTreeSet::<BoundedString<T::IPFSLength>, T::MaxDocumentsPerCfa>::new();
 		let documentation_ipfs_1 = BoundedString::<T::IPFSLength>::truncate_from("documentation_ipfs_1");
 
-		documentation_ipfses_1.insert(documentation_ipfs_1);
+		documentation_ipfses_1.try_insert(documentation_ipfs_1).unwrap();
 
 		let carbon_footprint_account_1 = CarbonFootprintAccountInfo {
 			documentation_ipfses: documentation_ipfses_1,
@@ -1098,10 +1402,11 @@ benchmarks! {
 
 		CarbonFootprintAccounts::<T>::insert(bob::<T>(), carbon_footprint_account_1);
 
-		let mut documentation_ipfses_2 = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+		let mut documentation_ipfses_2 =
+		BoundedBTreeSet::<BoundedString<T::IPFSLength>, T::MaxDocumentsPerCfa>::new();
 		let documentation_ipfs_2 = BoundedString::<T::IPFSLength>::truncate_from("documentation_ipfs_2");
 
-		documentation_ipfses_2.insert(documentation_ipfs_2);
+		documentation_ipfses_2.try_insert(documentation_ipfs_2).unwrap();
 
 		let carbon_footprint_account_2 = CarbonFootprintAccountInfo {
 			documentation_ipfses: documentation_ipfses_2,
@@ -1157,8 +1462,8 @@ benchmarks! {
 
 		let complaint_ipfs = BoundedString::<T::IPFSLength>::truncate_from("complaint_ipfs");
 
-		let mut votes_for = BTreeSet::<AccountIdOf<T>>::new();
-		votes_for.insert(hank::<T>());
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(hank::<T>()).unwrap();
 
 		let complaint = ComplaintHashBasedInfo {
 			complaint_proposer: ian::<T>(),
@@ -1166,15 +1471,25 @@ benchmarks! {
 			complaint_for: batch_hash,
 			creation_date: T::Time::now(),
 			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+			votes_against: VotesOf::<T>::new(),
 			complaint_active: true,
 		};
 
 		ComplaintsForHashes::<T>::insert(complaint_ipfs.clone(), complaint);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut complaint_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<T>::insert(timeout_block, complaint_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
 	} : {
 		Veles::<T>::update_complaint_for_hash(
-            RawOrigin::Signed(alice::<T>()).into(),
-			complaint_ipfs.clone()
+            RawOrigin::None.into(),
+			complaint_ipfs.clone(),
+			timeout_block
 		).unwrap();
 	} verify {
 		assert_last_event::<T>(Event::<T>::HashComplaintUpdated(complaint_ipfs).into());
@@ -1188,9 +1503,16 @@ benchmarks! {
 		};
 
 		ProjectOwners::<T>::insert(alice::<T>(), project_owner_info);
+
+		let mut penalty_accounts = TimeoutsOf::<T, AccountIdOf<T>>::new();
+		penalty_accounts.try_insert(alice::<T>()).unwrap();
+
+		PenaltyTimeoutsAccounts::<T>::insert(BlockNumber::<T>::from(120u32), penalty_accounts);
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumber::<T>::from(120u32));
     } : {
         Veles::<T>::update_project_owner_penalty_level(
-            RawOrigin::Signed(alice::<T>()).into(),
+            RawOrigin::None.into(),
 			alice::<T>()
 		).unwrap();
     } verify {
@@ -1205,9 +1527,16 @@ benchmarks! {
 		};
 
 		Validators::<T>::insert(alice::<T>(), validator_info);
+
+		let mut penalty_accounts = TimeoutsOf::<T, AccountIdOf<T>>::new();
+		penalty_accounts.try_insert(alice::<T>()).unwrap();
+
+		PenaltyTimeoutsAccounts::<T>::insert(BlockNumber::<T>::from(120u32), penalty_accounts);
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumber::<T>::from(120u32));
     } : {
         Veles::<T>::update_validator_penalty_level(
-            RawOrigin::Signed(alice::<T>()).into(),
+            RawOrigin::None.into(),
 			alice::<T>()
 		).unwrap();
     } verify {
@@ -1226,15 +1555,768 @@ benchmarks! {
 		};
 
 		Projects::<T>::insert(project_hash, project_info);
+
+		let mut penalty_hashes = TimeoutsOf::<T, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
+
+		PenaltyTimeoutsHashes::<T>::insert(BlockNumber::<T>::from(120u32), penalty_hashes);
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumber::<T>::from(120u32));
     } : {
         Veles::<T>::update_project_penalty_level(
-            RawOrigin::Signed(alice::<T>()).into(),
+            RawOrigin::None.into(),
 			project_hash
 		).unwrap();
     } verify {
         assert_last_event::<T>(Event::<T>::ProjectPenaltyLevelUpdated(project_hash).into());
     }
 
+	add_authority {
+		let origin = T::AuthorityManagerOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	} : {
+		Veles::<T>::add_authority(
+			origin,
+			bob::<T>(),
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::AuthorityAccountAdded(bob::<T>()).into());
+	}
+
+	remove_authority {
+		let origin = T::AuthorityManagerOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		AuthorityAccounts::<T>::insert(bob::<T>(), AuthorityAccountInfo::default());
+	} : {
+		Veles::<T>::remove_authority(
+			origin,
+			bob::<T>(),
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::AuthorityAccountRemoved(bob::<T>()).into());
+	}
+
+	update_transfer_royalties {
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	} : {
+		Veles::<T>::update_transfer_royalties(
+			origin,
+			true,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::TransferRoyaltiesUpdated(true).into());
+	}
+
+	transfer_carbon_credits {
+		TraderAccounts::<T>::insert(bob::<T>(), TraderAccountInfo::default());
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		Validators::<T>::insert(dave::<T>(), validator_1);
+		Validators::<T>::insert(fred::<T>(), validator_2);
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		ProjectOwners::<T>::insert(alice::<T>(), owner);
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		Projects::<T>::insert(project_hash, project);
+
+		// Worst case: the project has approved beneficiary splits with the maximum number of
+		// stakeholders
+		insert_project_beneficiary_splits::<T>(project_hash);
+
+		let mut validator_benefactors = VotesOf::<T>::new();
+		validator_benefactors.try_insert(dave::<T>()).unwrap();
+		validator_benefactors.try_insert(fred::<T>()).unwrap();
+
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: T::Time::now(),
+			credit_amount: BalanceOf::<T>::from(100u32),
+			penalty_repay_price: BalanceOf::<T>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash::<T>(bob::<T>());
+
+		CarbonCreditBatches::<T>::insert(batch_hash, credit_batch);
+
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<T>::from(50u32),
+			unavailable_amount: BalanceOf::<T>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<T>::insert(batch_hash, bob::<T>(), credit_holdings);
+
+		// Worst case: the transfer triggers the secondary beneficiary split
+		TransferRoyaltiesEnabled::<T>::set(true);
+
+		T::Currency::set_balance(&bob::<T>(), BalanceOf::<T>::from(1000u32));
+
+		let amount = BalanceOf::<T>::from(10u32);
+	} : {
+		Veles::<T>::transfer_carbon_credits(
+			RawOrigin::Signed(bob::<T>()).into(),
+			batch_hash,
+			charlie::<T>(),
+			amount,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditsTransferred(batch_hash, bob::<T>(), charlie::<T>(), amount).into()
+		);
+	}
+
+	create_buy_order {
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash::<T>(alice::<T>()),
+			creation_date: T::Time::now(),
+			credit_amount: BalanceOf::<T>::from(100u32),
+			penalty_repay_price: BalanceOf::<T>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<T>::new(),
+		};
+
+		let batch_hash = generate_hash::<T>(bob::<T>());
+
+		CarbonCreditBatches::<T>::insert(batch_hash, batch);
+
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+
+		let credit_amount = BalanceOf::<T>::from(10u32);
+		let credit_price = BalanceOf::<T>::from(5u32);
+		let buy_hash = generate_hash::<T>(charlie::<T>());
+	} : {
+		Veles::<T>::create_buy_order(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			BuyOrderTarget::Batch(batch_hash),
+			credit_price,
+			credit_amount,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditBuyOrderCreated(
+				charlie::<T>(),
+				buy_hash,
+				credit_amount,
+				credit_price,
+			).into()
+		);
+	}
+
+	cancel_buy_order {
+		let buy_hash = insert_buy_order::<T>(
+			charlie::<T>(),
+			BuyOrderTarget::Batch(generate_hash::<T>(bob::<T>())),
+			BlockNumber::<T>::from(100u32),
+		);
+	} : {
+		Veles::<T>::cancel_buy_order(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			buy_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditBuyOrderCancelled(charlie::<T>(), buy_hash).into()
+		);
+	}
+
+	fill_buy_order {
+		TraderAccounts::<T>::insert(bob::<T>(), TraderAccountInfo::default());
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		Validators::<T>::insert(dave::<T>(), validator_1);
+		Validators::<T>::insert(fred::<T>(), validator_2);
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		ProjectOwners::<T>::insert(alice::<T>(), owner);
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		Projects::<T>::insert(project_hash, project);
+
+		// Worst case: the project has approved beneficiary splits with the maximum number of
+		// stakeholders
+		insert_project_beneficiary_splits::<T>(project_hash);
+
+		let mut validator_benefactors = VotesOf::<T>::new();
+		validator_benefactors.try_insert(dave::<T>()).unwrap();
+		validator_benefactors.try_insert(fred::<T>()).unwrap();
+
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: T::Time::now(),
+			credit_amount: BalanceOf::<T>::from(100u32),
+			penalty_repay_price: BalanceOf::<T>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash::<T>(bob::<T>());
+
+		CarbonCreditBatches::<T>::insert(batch_hash, credit_batch);
+
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<T>::from(50u32),
+			unavailable_amount: BalanceOf::<T>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<T>::insert(batch_hash, bob::<T>(), credit_holdings);
+
+		// Worst case: the buy order targets a project and is fully filled with a secondary sale
+		let buy_hash = insert_buy_order::<T>(
+			charlie::<T>(),
+			BuyOrderTarget::Project(project_hash),
+			BlockNumber::<T>::from(100u32),
+		);
+
+		// Worst case: maker and taker trading fees are paid
+		TradingFees::<T>::set(TradingFeeValues {
+			maker_fee: Permill::from_percent(2),
+			taker_fee: Permill::from_percent(2),
+		});
+	} : {
+		Veles::<T>::fill_buy_order(
+			RawOrigin::Signed(bob::<T>()).into(),
+			buy_hash,
+			batch_hash,
+			None,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditBuyOrderCompleted(bob::<T>(), buy_hash).into()
+		);
+	}
+
+	update_carbon_credit_buy_order {
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let buy_hash = insert_buy_order::<T>(
+			charlie::<T>(),
+			BuyOrderTarget::Batch(generate_hash::<T>(bob::<T>())),
+			timeout_block,
+		);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
+	} : {
+		Veles::<T>::update_carbon_credit_buy_order(
+			RawOrigin::None.into(),
+			buy_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::CarbonCreditBuyOrderUpdated(buy_hash).into());
+	}
+
+	place_limit_order {
+		let m in 0 .. T::MaxOrdersPerBook::get() - 1;
+
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		Validators::<T>::insert(dave::<T>(), validator_1);
+		Validators::<T>::insert(fred::<T>(), validator_2);
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		ProjectOwners::<T>::insert(alice::<T>(), owner);
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		Projects::<T>::insert(project_hash, project);
+
+		// Worst case: the project has approved beneficiary splits with the maximum number of
+		// stakeholders
+		insert_project_beneficiary_splits::<T>(project_hash);
+
+		let mut validator_benefactors = VotesOf::<T>::new();
+		validator_benefactors.try_insert(dave::<T>()).unwrap();
+		validator_benefactors.try_insert(fred::<T>()).unwrap();
+
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: T::Time::now(),
+			credit_amount: BalanceOf::<T>::from(T::MaxOrdersPerBook::get()),
+			penalty_repay_price: BalanceOf::<T>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash::<T>(bob::<T>());
+
+		CarbonCreditBatches::<T>::insert(batch_hash, credit_batch);
+
+		// Fill the ask side of the order book with m orders (one credit each)
+		let mut order_book = OrderBookOf::<T>::new();
+
+		for i in 0 .. m {
+			let maker: AccountIdOf<T> = frame_benchmarking::account("maker", i, 0);
+
+			T::Currency::set_balance(&maker, BalanceOf::<T>::from(1000u32));
+
+			let credit_holdings = CarbonCreditHoldingsInfo {
+				available_amount: BalanceOf::<T>::from(0u32),
+				unavailable_amount: BalanceOf::<T>::from(1u32),
+			};
+
+			CarbonCreditHoldings::<T>::insert(batch_hash, maker.clone(), credit_holdings);
+
+			let limit_order = LimitOrderInfo {
+				batch_hash,
+				side: OrderSide::Ask,
+				owner: maker.clone(),
+				credit_price: BalanceOf::<T>::from(5u32),
+				credit_amount: BalanceOf::<T>::from(1u32),
+				creation_block: BlockNumber::<T>::from(0u32),
+			};
+
+			let order_hash = generate_hash::<T>(maker);
+
+			LimitOrders::<T>::insert(order_hash, limit_order);
+			order_book.try_push((BalanceOf::<T>::from(5u32), order_hash)).unwrap();
+		}
+
+		if !order_book.is_empty() {
+			OrderBooks::<T>::insert(batch_hash, OrderSide::Ask, order_book);
+		}
+
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+
+		// Worst case: the bid matches all asks and the remaining amount is added to the order book
+		let credit_amount = BalanceOf::<T>::from(m + 1);
+	} : {
+		Veles::<T>::place_limit_order(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<T>::from(5u32),
+			credit_amount,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::LimitOrderPlaced(
+				charlie::<T>(),
+				generate_hash::<T>(charlie::<T>()),
+				batch_hash,
+				OrderSide::Bid,
+				BalanceOf::<T>::from(5u32),
+				BalanceOf::<T>::from(1u32),
+			).into()
+		);
+	}
+
+	cancel_limit_order {
+		let batch_hash = generate_hash::<T>(bob::<T>());
+		let credit_amount = BalanceOf::<T>::from(10u32);
+		let credit_price = BalanceOf::<T>::from(5u32);
+
+		let limit_order = LimitOrderInfo {
+			batch_hash,
+			side: OrderSide::Bid,
+			owner: charlie::<T>(),
+			credit_price,
+			credit_amount,
+			creation_block: BlockNumber::<T>::from(0u32),
+		};
+
+		let order_hash = generate_hash::<T>(charlie::<T>());
+
+		LimitOrders::<T>::insert(order_hash, limit_order);
+
+		let mut order_book = OrderBookOf::<T>::new();
+		order_book.try_push((credit_price, order_hash)).unwrap();
+
+		OrderBooks::<T>::insert(batch_hash, OrderSide::Bid, order_book);
+
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+		T::Currency::hold(
+			&HoldReason::OrderBookEscrow.into(),
+			&charlie::<T>(),
+			credit_amount * credit_price,
+		).unwrap();
+	} : {
+		Veles::<T>::cancel_limit_order(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			order_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::LimitOrderCancelled(charlie::<T>(), order_hash).into()
+		);
+	}
+
+	create_auction {
+		let batch_hash = insert_auction_batch::<T>();
+
+		let auction_type = AuctionType::English { reserve_price: BalanceOf::<T>::from(5u32) };
+		let duration = BlockNumber::<T>::from(10u32);
+	} : {
+		Veles::<T>::create_auction(
+			RawOrigin::Signed(bob::<T>()).into(),
+			batch_hash,
+			BalanceOf::<T>::from(10u32),
+			auction_type,
+			duration,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditAuctionCreated(
+				bob::<T>(),
+				generate_hash::<T>(bob::<T>()),
+				batch_hash,
+				BalanceOf::<T>::from(10u32),
+			).into()
+		);
+	}
+
+	place_auction_bid {
+		let batch_hash = insert_auction_batch::<T>();
+
+		// Worst case: the previous highest bid is released and the auction is extended
+		let end_block = BlockNumber::<T>::from(10u32);
+
+		let auction_hash = insert_auction::<T>(
+			batch_hash,
+			AuctionType::English { reserve_price: BalanceOf::<T>::from(5u32) },
+			Some((george::<T>(), BalanceOf::<T>::from(5u32))),
+			end_block,
+		);
+
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+
+		frame_system::Pallet::<T>::set_block_number(end_block - BlockNumber::<T>::from(1u32));
+	} : {
+		Veles::<T>::place_auction_bid(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			auction_hash,
+			BalanceOf::<T>::from(6u32),
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditAuctionExtended(
+				auction_hash,
+				end_block - BlockNumber::<T>::from(1u32) + T::AuctionExtensionPeriod::get(),
+			).into()
+		);
+	}
+
+	buy_auction_lot {
+		let batch_hash = insert_auction_batch::<T>();
+
+		let auction_type = AuctionType::Dutch {
+			start_price: BalanceOf::<T>::from(10u32),
+			floor_price: BalanceOf::<T>::from(5u32),
+			price_decrement: BalanceOf::<T>::from(1u32),
+		};
+
+		let auction_hash =
+			insert_auction::<T>(batch_hash, auction_type, None, BlockNumber::<T>::from(10u32));
+
+		TraderAccounts::<T>::insert(charlie::<T>(), TraderAccountInfo::default());
+		T::Currency::set_balance(&charlie::<T>(), BalanceOf::<T>::from(1000u32));
+
+		// Worst case: maker and taker trading fees are paid
+		TradingFees::<T>::set(TradingFeeValues {
+			maker_fee: Permill::from_percent(2),
+			taker_fee: Permill::from_percent(2),
+		});
+
+		frame_system::Pallet::<T>::set_block_number(BlockNumber::<T>::from(2u32));
+	} : {
+		Veles::<T>::buy_auction_lot(
+			RawOrigin::Signed(charlie::<T>()).into(),
+			auction_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditAuctionSold(
+				charlie::<T>(),
+				auction_hash,
+				BalanceOf::<T>::from(8u32),
+				BalanceOf::<T>::from(1u32),
+				BalanceOf::<T>::from(1u32),
+			).into()
+		);
+	}
+
+	cancel_auction {
+		let batch_hash = insert_auction_batch::<T>();
+
+		let auction_hash = insert_auction::<T>(
+			batch_hash,
+			AuctionType::English { reserve_price: BalanceOf::<T>::from(5u32) },
+			None,
+			BlockNumber::<T>::from(10u32),
+		);
+	} : {
+		Veles::<T>::cancel_auction(
+			RawOrigin::Signed(bob::<T>()).into(),
+			auction_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditAuctionCancelled(bob::<T>(), auction_hash).into()
+		);
+	}
+
+	update_carbon_credit_auction {
+		let batch_hash = insert_auction_batch::<T>();
+
+		// Worst case: the auction is sold to the highest bid
+		let end_block = BlockNumber::<T>::from(10u32);
+
+		let auction_hash = insert_auction::<T>(
+			batch_hash,
+			AuctionType::English { reserve_price: BalanceOf::<T>::from(5u32) },
+			Some((charlie::<T>(), BalanceOf::<T>::from(5u32))),
+			end_block,
+		);
+
+		// Worst case: maker and taker trading fees are paid
+		TradingFees::<T>::set(TradingFeeValues {
+			maker_fee: Permill::from_percent(2),
+			taker_fee: Permill::from_percent(2),
+		});
+
+		frame_system::Pallet::<T>::set_block_number(end_block);
+	} : {
+		Veles::<T>::update_carbon_credit_auction(
+			RawOrigin::None.into(),
+			auction_hash,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::CarbonCreditAuctionUpdated(auction_hash).into());
+	}
+
+	amend_sale_order {
+		let batch_hash = insert_auction_batch::<T>();
+
+		CarbonCreditHoldings::<T>::insert(batch_hash, bob::<T>(), CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<T>::from(40u32),
+			unavailable_amount: BalanceOf::<T>::from(10u32),
+		});
+
+		let timeout_block = BlockNumber::<T>::from(100u32);
+
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash,
+			credit_amount: BalanceOf::<T>::from(10u32),
+			min_fill_amount: BalanceOf::<T>::from(1u32),
+			credit_price: BalanceOf::<T>::from(5u32),
+			seller: bob::<T>(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
+		};
+
+		let sale_hash = generate_hash::<T>(bob::<T>());
+
+		CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
+
+		let mut sale_timeouts = TimeoutsOf::<T, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<T>::insert(timeout_block, sale_timeouts);
+
+		// Worst case: the sale order is resized up and its timeout is moved
+		let credit_price = BalanceOf::<T>::from(6u32);
+		let credit_amount = BalanceOf::<T>::from(20u32);
+	} : {
+		Veles::<T>::amend_sale_order(
+			RawOrigin::Signed(bob::<T>()).into(),
+			sale_hash,
+			credit_price,
+			credit_amount,
+			Some(SaleOrderExpiry::AfterBlocks(BlockNumber::<T>::from(50u32))),
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::CarbonCreditSaleOrderAmended(
+				bob::<T>(),
+				sale_hash,
+				credit_amount,
+				credit_price,
+			).into()
+		);
+	}
+
+	propose_beneficiary_splits {
+		let mut pallet_fees = PalletFeeValues::<T>::get();
+		pallet_fees = FeeValues {
+			project_proposal_fee: BalanceOf::<T>::from(0u32),
+			..pallet_fees
+		};
+		PalletFeeValues::<T>::set(pallet_fees);
+
+		let owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("owner_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		ProjectOwners::<T>::insert(alice::<T>(), owner_info);
+
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		Projects::<T>::insert(project_hash, project_info);
+
+		let user = alice::<T>();
+		let beneficiary_splits = BeneficiarySplits::<T>::get().into_inner();
+		let proposal_ipfs = BoundedString::<T::IPFSLength>::truncate_from("proposal_ipfs");
+	} : {
+		// Worst case: the proposal has the maximum number of stakeholders
+		Veles::<T>::propose_beneficiary_splits(
+			RawOrigin::Signed(user.clone()).into(),
+			project_hash,
+			beneficiary_splits,
+			max_stakeholders::<T>(),
+			proposal_ipfs.clone(),
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(
+			Event::<T>::BeneficiarySplitProposalCreated(user, proposal_ipfs).into()
+		);
+	}
+
+	update_beneficiary_split_proposal {
+		let project_hash = generate_hash::<T>(alice::<T>());
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice::<T>(),
+			creation_date: T::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<T>::from(0u32),
+		};
+
+		Projects::<T>::insert(project_hash, project_info);
+
+		// Worst case: the vote passes and the project beneficiary splits are applied
+		let mut votes_for = VotesOf::<T>::new();
+		votes_for.try_insert(dave::<T>()).unwrap();
+
+		let proposal_info = BeneficiarySplitProposalInfo {
+			project_hash,
+			creation_date: T::Time::now(),
+			beneficiary_splits: BeneficiarySplits::<T>::get(),
+			stakeholders: StakeholderSharesOf::<T>::try_from(max_stakeholders::<T>()).unwrap(),
+			votes_for,
+			votes_against: VotesOf::<T>::new(),
+			voting_active: true,
+		};
+
+		let proposal_ipfs = BoundedString::<T::IPFSLength>::truncate_from("proposal_ipfs");
+
+		BeneficiarySplitProposals::<T>::insert(proposal_ipfs.clone(), proposal_info);
+
+		let timeout_block = BlockNumber::<T>::from(10u32);
+
+		let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<T>::insert(timeout_block, timeout_events);
+
+		frame_system::Pallet::<T>::set_block_number(timeout_block);
+	} : {
+		Veles::<T>::update_beneficiary_split_proposal(
+			RawOrigin::None.into(),
+			proposal_ipfs.clone(),
+			timeout_block,
+		).unwrap();
+	} verify {
+		assert_last_event::<T>(Event::<T>::BeneficiarySplitProposalUpdated(proposal_ipfs).into());
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::ExtBuilder::default().build(),
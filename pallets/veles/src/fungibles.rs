@@ -0,0 +1,196 @@
+// Fungibles trait implementation for carbon credit holdings
+// Note: The asset ID is the carbon credit batch hash, the available amount is the free balance and
+// the unavailable amount (credits in a sales cycle) is the balance on hold
+use super::*;
+use frame_support::{
+	sp_runtime::{traits::CheckedAdd, DispatchError},
+	traits::{
+		fungibles::{self, Inspect as _, InspectHold as _},
+		tokens::{DepositConsequence, Provenance, WithdrawConsequence},
+	},
+};
+
+impl<T: Config> fungibles::Inspect<AccountIdOf<T>> for Pallet<T> {
+	type AssetId = H256;
+	type Balance = BalanceOf<T>;
+
+	// Note: Retired carbon credits are included (same as in the carbon credit batch)
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance {
+		CarbonCreditBatches::<T>::get(asset)
+			.map(|batch| batch.credit_amount)
+			.unwrap_or_default()
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		BalanceOf::<T>::from(0u32)
+	}
+
+	fn total_balance(asset: Self::AssetId, who: &AccountIdOf<T>) -> Self::Balance {
+		CarbonCreditHoldings::<T>::get(asset, who)
+			.map(|holdings| holdings.available_amount + holdings.unavailable_amount)
+			.unwrap_or_default()
+	}
+
+	fn balance(asset: Self::AssetId, who: &AccountIdOf<T>) -> Self::Balance {
+		CarbonCreditHoldings::<T>::get(asset, who)
+			.map(|holdings| holdings.available_amount)
+			.unwrap_or_default()
+	}
+
+	// Note: Nothing is reducible if the account can't transact carbon credits from the batch
+	fn reducible_balance(
+		asset: Self::AssetId,
+		who: &AccountIdOf<T>,
+		_preservation: Preservation,
+		_force: Fortitude,
+	) -> Self::Balance {
+		match Self::ensure_can_transact_carbon_credits(who, asset) {
+			Ok(()) => Self::balance(asset, who),
+			Err(_) => BalanceOf::<T>::from(0u32),
+		}
+	}
+
+	fn can_deposit(
+		asset: Self::AssetId,
+		who: &AccountIdOf<T>,
+		amount: Self::Balance,
+		_provenance: Provenance,
+	) -> DepositConsequence {
+		match Self::ensure_can_transact_carbon_credits(who, asset) {
+			Ok(()) => (),
+			Err(Error::<T>::CarbonCreditBatchDoesNotExist) => {
+				return DepositConsequence::UnknownAsset
+			},
+			Err(_) => return DepositConsequence::Blocked,
+		}
+
+		match Self::total_balance(asset, who).checked_add(&amount) {
+			Some(_) => DepositConsequence::Success,
+			None => DepositConsequence::Overflow,
+		}
+	}
+
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &AccountIdOf<T>,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		match Self::ensure_can_transact_carbon_credits(who, asset) {
+			Ok(()) => (),
+			Err(Error::<T>::CarbonCreditBatchDoesNotExist) => {
+				return WithdrawConsequence::UnknownAsset
+			},
+			Err(_) => return WithdrawConsequence::Frozen,
+		}
+
+		if amount > Self::balance(asset, who) {
+			return WithdrawConsequence::BalanceLow;
+		}
+
+		WithdrawConsequence::Success
+	}
+
+	fn asset_exists(asset: Self::AssetId) -> bool {
+		CarbonCreditBatches::<T>::contains_key(asset)
+	}
+}
+
+impl<T: Config> fungibles::Unbalanced<AccountIdOf<T>> for Pallet<T> {
+	// Note: There is no minimum balance, so there is never any dust
+	fn handle_dust(_dust: fungibles::Dust<AccountIdOf<T>, Self>) {}
+
+	fn write_balance(
+		asset: Self::AssetId,
+		who: &AccountIdOf<T>,
+		amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		Self::ensure_can_transact_carbon_credits(who, asset)?;
+
+		CarbonCreditHoldings::<T>::mutate_exists(asset, who, |holdings| {
+			let mut new_holdings = holdings.take().unwrap_or_default();
+			new_holdings.available_amount = amount;
+
+			// Remove empty holdings
+			if new_holdings != CarbonCreditHoldingsInfo::default() {
+				*holdings = Some(new_holdings);
+			}
+		});
+
+		Ok(None)
+	}
+
+	// Note: The carbon credit batch amount is only set by batch proposals, so the issuance can't be
+	// changed through the fungibles traits
+	fn set_total_issuance(_asset: Self::AssetId, _amount: Self::Balance) {}
+}
+
+// Note: Carbon credits can only be issued by batch proposals and destroyed by retirements, so
+// minting, burning, shelving and restoring are unavailable (only transfers are supported)
+impl<T: Config> fungibles::Mutate<AccountIdOf<T>> for Pallet<T> {
+	fn mint_into(
+		_asset: Self::AssetId,
+		_who: &AccountIdOf<T>,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn burn_from(
+		_asset: Self::AssetId,
+		_who: &AccountIdOf<T>,
+		_amount: Self::Balance,
+		_precision: Precision,
+		_force: Fortitude,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn shelve(
+		_asset: Self::AssetId,
+		_who: &AccountIdOf<T>,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn restore(
+		_asset: Self::AssetId,
+		_who: &AccountIdOf<T>,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+}
+
+impl<T: Config> fungibles::InspectHold<AccountIdOf<T>> for Pallet<T> {
+	type Reason = CarbonCreditHoldReason;
+
+	fn total_balance_on_hold(asset: Self::AssetId, who: &AccountIdOf<T>) -> Self::Balance {
+		CarbonCreditHoldings::<T>::get(asset, who)
+			.map(|holdings| holdings.unavailable_amount)
+			.unwrap_or_default()
+	}
+
+	// Note: Credits in a sales cycle can only be released by the sale order
+	fn reducible_total_balance_on_hold(
+		_asset: Self::AssetId,
+		_who: &AccountIdOf<T>,
+		_force: Fortitude,
+	) -> Self::Balance {
+		BalanceOf::<T>::from(0u32)
+	}
+
+	fn balance_on_hold(
+		asset: Self::AssetId,
+		reason: &Self::Reason,
+		who: &AccountIdOf<T>,
+	) -> Self::Balance {
+		match reason {
+			CarbonCreditHoldReason::SaleOrder => Self::total_balance_on_hold(asset, who),
+		}
+	}
+
+	fn hold_available(asset: Self::AssetId, _reason: &Self::Reason, who: &AccountIdOf<T>) -> bool {
+		Self::ensure_can_transact_carbon_credits(who, asset).is_ok()
+	}
+}
@@ -4,12 +4,18 @@ pub use codec::{Decode, Encode, MaxEncodedLen};
 pub use common::BoundedString;
 pub use frame_support::pallet_prelude::Get;
 pub use frame_support::sp_runtime::traits::AccountIdConversion;
-pub use frame_support::traits::Currency;
-pub use frame_support::traits::ExistenceRequirement;
+pub use frame_support::sp_runtime::{Perbill, Permill};
+pub use frame_support::traits::fungible::{Inspect, Mutate, MutateHold};
+pub use frame_support::traits::tokens::{Fortitude, Precision, Preservation, Restriction};
+use frame_support::traits::{ConstU32, EnsureOrigin};
+pub use frame_support::{BoundedBTreeMap, BoundedBTreeSet};
+use frame_support::{CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound};
 pub use pallet::*;
+use serde::{Deserialize, Serialize};
 pub use sp_core::{blake2_256, H256};
 pub use sp_std::collections::btree_map::BTreeMap;
 pub use sp_std::collections::btree_set::BTreeSet;
+use sp_std::fmt::Debug;
 
 #[cfg(test)]
 mod mock;
@@ -19,12 +25,16 @@ mod tests;
 
 mod benchmarking;
 
+mod fungibles;
+
+pub mod migrations;
+
 pub mod weights;
 pub use weights::WeightInfo;
 
 /// Global data structures
 // Project Validator / Project Owner data structure
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 #[scale_info(skip_type_params(IPFSLength))]
 pub struct ProjectValidatorOrProjectOwnerInfo<IPFSLength: Get<u32>, BlockNumber> {
@@ -36,13 +46,42 @@ pub struct ProjectValidatorOrProjectOwnerInfo<IPFSLength: Get<u32>, BlockNumber>
 	penalty_timeout: BlockNumber,
 }
 
-// Carbon Footprint account data structure
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+// Trader account data structure
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
-#[scale_info(skip_type_params(IPFSLength))]
-pub struct CarbonFootprintAccountInfo<MomentOf, IPFSLength: Get<u32>, BalanceOf> {
+pub struct TraderAccountInfo<MomentOf> {
+	// Registration date
+	registration_date: MomentOf,
+}
+
+// Authority account data structure
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AuthorityAccountInfo<MomentOf> {
+	// Date the account was added as an authority
+	added_date: MomentOf,
+}
+
+// Carbon Footprint account data structure
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(IPFSLength, MaxDocuments))]
+pub struct CarbonFootprintAccountInfo<
+	MomentOf: Clone + Eq + Debug,
+	IPFSLength: Get<u32>,
+	BalanceOf: Clone + Eq + Debug,
+	MaxDocuments: Get<u32>,
+> {
 	// IPFS links to CFA documentation
-	documentation_ipfses: BTreeSet<BoundedString<IPFSLength>>,
+	documentation_ipfses: BoundedBTreeSet<BoundedString<IPFSLength>, MaxDocuments>,
 	// Carbon footprint surplus
 	carbon_footprint_surplus: BalanceOf,
 	// Carbon footprint deficit
@@ -52,9 +91,23 @@ pub struct CarbonFootprintAccountInfo<MomentOf, IPFSLength: Get<u32>, BalanceOf>
 }
 
 // Carbon Footprint report data structure
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct CarbonFootprintReportInfo<AccountIdOf, MomentOf, BalanceOf> {
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes))]
+pub struct CarbonFootprintReportInfo<
+	AccountIdOf: Clone + Ord + Debug,
+	MomentOf: Clone + Eq + Debug,
+	BalanceOf: Clone + Eq + Debug,
+	MaxVotes: Get<u32>,
+> {
 	// Carbon footprint account
 	cf_account: AccountIdOf,
 	// Creation date
@@ -64,17 +117,30 @@ pub struct CarbonFootprintReportInfo<AccountIdOf, MomentOf, BalanceOf> {
 	// Carbon footprint deficit
 	carbon_footprint_deficit: BalanceOf,
 	// Votes for
-	votes_for: BTreeSet<AccountIdOf>,
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Votes against
-	votes_against: BTreeSet<AccountIdOf>,
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Voting status
 	voting_active: bool,
 }
 
 // Project Proposal info structure
-#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, scale_info::TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct ProjectProposalInfo<AccountIdOf, MomentOf> {
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes))]
+pub struct ProjectProposalInfo<
+	AccountIdOf: Clone + Ord + Debug,
+	MomentOf: Clone + Eq + Debug,
+	MaxVotes: Get<u32>,
+> {
 	// Project owner
 	project_owner: AccountIdOf,
 	// Creation date
@@ -82,17 +148,31 @@ pub struct ProjectProposalInfo<AccountIdOf, MomentOf> {
 	// Project hash
 	project_hash: H256,
 	// Votes for
-	votes_for: BTreeSet<AccountIdOf>,
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Votes against
-	votes_against: BTreeSet<AccountIdOf>,
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Voting status
 	voting_active: bool,
 }
 
 // Carbon Credit Batch Proposal info structure
-#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, scale_info::TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct CarbonCreditBatchProposalInfo<MomentOf, BalanceOf, AccountIdOf> {
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes))]
+pub struct CarbonCreditBatchProposalInfo<
+	MomentOf: Clone + Eq + Debug,
+	BalanceOf: Clone + Eq + Debug,
+	AccountIdOf: Clone + Ord + Debug,
+	MaxVotes: Get<u32>,
+> {
 	// Project hash
 	project_hash: H256,
 	// Carbon credit batch hash
@@ -104,15 +184,69 @@ pub struct CarbonCreditBatchProposalInfo<MomentOf, BalanceOf, AccountIdOf> {
 	// Penalty repay price (per credit)
 	penalty_repay_price: BalanceOf,
 	// Votes for
-	votes_for: BTreeSet<AccountIdOf>,
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+	// Votes against
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+	// Voting status
+	voting_active: bool,
+}
+
+// Beneficiary split proposal info structure
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes, MaxStakeholders))]
+pub struct BeneficiarySplitProposalInfo<
+	MomentOf: Clone + Eq + Debug,
+	AccountIdOf: Clone + Ord + Debug,
+	MaxVotes: Get<u32>,
+	MaxStakeholders: Get<u32>,
+> {
+	// Project hash
+	project_hash: H256,
+	// Creation date
+	creation_date: MomentOf,
+	// Beneficiary splits (replace the pallet beneficiary splits for the project)
+	beneficiary_splits: BoundedBTreeMap<BeneficiarySplitType, Permill, ConstU32<3>>,
+	// Stakeholder shares (of every primary and secondary sale)
+	stakeholders: BoundedBTreeMap<AccountIdOf, Permill, MaxStakeholders>,
+	// Votes for
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Votes against
-	votes_against: BTreeSet<AccountIdOf>,
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	// Voting status
 	voting_active: bool,
 }
 
+// Project beneficiary splits info structure
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxStakeholders))]
+pub struct ProjectBeneficiarySplitsInfo<AccountIdOf: Clone + Ord + Debug, MaxStakeholders: Get<u32>>
+{
+	// Beneficiary splits (replace the pallet beneficiary splits for the project)
+	beneficiary_splits: BoundedBTreeMap<BeneficiarySplitType, Permill, ConstU32<3>>,
+	// Stakeholder shares (of every primary and secondary sale)
+	stakeholders: BoundedBTreeMap<AccountIdOf, Permill, MaxStakeholders>,
+}
+
 // Projects info structure
-#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 #[scale_info(skip_type_params(IPFSLength))]
 pub struct ProjectInfo<IPFSLength: Get<u32>, AccountIdOf, MomentOf, BlockNumber> {
@@ -129,15 +263,24 @@ pub struct ProjectInfo<IPFSLength: Get<u32>, AccountIdOf, MomentOf, BlockNumber>
 }
 
 // Carbon credit batch info structure
-#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
-#[scale_info(skip_type_params(IPFSLength))]
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(IPFSLength, MaxBenefactors))]
 pub struct CarbonCreditBatchInfo<
 	IPFSLength: Get<u32>,
-	MomentOf,
-	BalanceOf,
-	CarbonCreditBatchStatus,
-	AccountId,
+	MomentOf: Clone + Eq + Debug,
+	BalanceOf: Clone + Eq + Debug,
+	CarbonCreditBatchStatus: Clone + Eq + Debug,
+	AccountId: Clone + Ord + Debug,
+	MaxBenefactors: Get<u32>,
 > {
 	// IPFS link to CFA documentation
 	documentation_ipfs: BoundedString<IPFSLength>,
@@ -152,11 +295,11 @@ pub struct CarbonCreditBatchInfo<
 	// Batch status
 	status: CarbonCreditBatchStatus,
 	// Validator benefactors
-	validator_benefactors: BTreeSet<AccountId>,
+	validator_benefactors: BoundedBTreeSet<AccountId, MaxBenefactors>,
 }
 
 // Carbon credit holding info structure
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct CarbonCreditHoldingsInfo<BalanceOf> {
 	// Amount of available tokens for sale and retirment
@@ -166,72 +309,237 @@ pub struct CarbonCreditHoldingsInfo<BalanceOf> {
 }
 
 // Carbon credit sale info structure
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct CarbonCreditSaleOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
 	// Carbon credit batch hash
 	batch_hash: H256,
-	// Amount of credit being sold
+	// Amount of credit being sold (reduced with every fill)
 	credit_amount: BalanceOf,
+	// Minimum amount of credit per fill (except for the last fill)
+	min_fill_amount: BalanceOf,
 	// Price of 1 credit
 	credit_price: BalanceOf,
 	// Seller account ID
 	seller: AccountIdOf,
+	// Buyer account ID (set once the sale order has been fully filled)
+	buyer: Option<AccountIdOf>,
+	// Sale status
+	status: SaleOrderStatus,
+	// Sale timeout (None for good-til-cancelled sale orders)
+	sale_timeout: Option<BlockNumber>,
+}
+
+// Carbon credit sale order status
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum SaleOrderStatus {
+	Open,      // Credits can be bought
+	Filled,    // All credits have been bought
+	Cancelled, // Sale order has been closed by the seller
+	Expired,   // Sale order has timed out
+}
+
+// Carbon credit sale order expiry
+// Note: Sale orders without an expiry time out after the sales timeout (which is also the longest
+// expiry a seller can choose)
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum SaleOrderExpiry<BlockNumber> {
+	AfterBlocks(BlockNumber), // Sale order times out after the number of blocks
+	GoodTilCancelled,         // Sale order stays open until it's filled or closed
+}
+
+// Carbon credit sale order fill info structure
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SaleOrderFillInfo<AccountIdOf, BalanceOf, MomentOf> {
 	// Buyer account ID
 	buyer: AccountIdOf,
-	// Sale status
-	sale_active: bool,
-	// Sale timeout
-	sale_timeout: BlockNumber,
+	// Amount of credit bought
+	credit_amount: BalanceOf,
+	// Price of 1 credit
+	credit_price: BalanceOf,
+	// Fill date
+	fill_date: MomentOf,
+	// Trading fee paid for the seller (maker)
+	maker_fee: BalanceOf,
+	// Trading fee paid for the buyer (taker)
+	taker_fee: BalanceOf,
+}
+
+// Carbon credit buy order target
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum BuyOrderTarget {
+	Batch(H256),   // Carbon credits from the given carbon credit batch
+	Project(H256), // Carbon credits from any carbon credit batch of the given project
+}
+
+// Carbon credit buy order info structure
+// Note: The buyer funds for the remaining amount are held until the buy order is finished
+#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CarbonCreditBuyOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
+	// Carbon credit batch or project the credits are bought from
+	target: BuyOrderTarget,
+	// Amount of credit being bought (reduced with every fill)
+	credit_amount: BalanceOf,
+	// Price of 1 credit
+	credit_price: BalanceOf,
+	// Buyer account ID
+	buyer: AccountIdOf,
+	// Buy order status
+	buy_active: bool,
+	// Buy order timeout
+	buy_timeout: BlockNumber,
+}
+
+// Order book side
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum OrderSide {
+	Bid, // Buying carbon credits
+	Ask, // Selling carbon credits
+}
+
+// Order book limit order info structure
+// Note: Bids hold the buyer funds and asks hold the seller credits for the remaining amount
+#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct LimitOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
+	// Carbon credit batch hash
+	batch_hash: H256,
+	// Order book side
+	side: OrderSide,
+	// Trader account ID
+	owner: AccountIdOf,
+	// Price of 1 credit
+	credit_price: BalanceOf,
+	// Amount of credit left in the order book (reduced with every fill)
+	credit_amount: BalanceOf,
+	// Block the order was placed in
+	creation_block: BlockNumber,
+}
+
+// Carbon credit auction type
+// Note: English auctions sell the lot to the highest bid once the auction ends, Dutch auctions
+// sell the lot to the first buyer at the current price (lowered every block down to the floor)
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum AuctionType<BalanceOf> {
+	English { reserve_price: BalanceOf },
+	Dutch { start_price: BalanceOf, floor_price: BalanceOf, price_decrement: BalanceOf },
+}
+
+// Carbon credit auction status
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum AuctionStatus {
+	Open,      // Lot can be bid on (English) or bought (Dutch)
+	Sold,      // Lot has been sold
+	Unsold,    // Auction has ended without a sale
+	Cancelled, // Auction has been cancelled by the seller
+}
+
+// Carbon credit auction info structure
+// Note: All prices are credit prices (the whole lot is sold at the credit price)
+#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CarbonCreditAuctionInfo<BalanceOf, AccountIdOf, BlockNumber> {
+	// Carbon credit batch hash
+	batch_hash: H256,
+	// Amount of credit in the lot
+	credit_amount: BalanceOf,
+	// Seller account ID
+	seller: AccountIdOf,
+	// Auction type and prices
+	auction_type: AuctionType<BalanceOf>,
+	// Highest bid (English auctions) or winning bid (bidder and credit price)
+	highest_bid: Option<(AccountIdOf, BalanceOf)>,
+	// Block the auction was created in
+	start_block: BlockNumber,
+	// Auction end (extended by late bids in English auctions)
+	end_block: BlockNumber,
+	// Auction status
+	status: AuctionStatus,
+}
+
+// Deposit info structure
+// Note: Held in deposit mode and settled once the related vote finishes
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct DepositInfo<AccountIdOf, BalanceOf> {
+	// Account that made the deposit
+	depositor: AccountIdOf,
+	// Held amount
+	amount: BalanceOf,
 }
 
 // Penalty level structure for carbon footprint
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct PenaltyLevelConfig<BalanceOf> {
 	pub level: u8,       // Penalty level
 	pub base: BalanceOf, // Balance
 }
 
-// Proportion structure (used for vote ratio calculations)
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct ProportionStructure {
-	// Explanation:
-	// Let's consider that we have the 3/4 proportion, we will say that the upper limit part of this
-	// proportion is the number 4 while the proportion part is the number 3.
-	//
-	// Example:
-	// Let's say that we need 3/4 of the total number of votes in order for a proposal to pass, this means
-	// that we need 75% (0.75) vote in order for our vote to pass. Let's now say that there was a total of a 100
-	// votes. In order to calculate this proportion without using a float point number for the proportion, we can
-	// first multiply the total votes by the proportion part of our proportion (100 * 3 = 300) and then devide it by
-	// the upper limit part of out proportion (300 / 4 = 75) which will give us the exact proportion that we need
-	pub proportion_part: u16,
-	pub upper_limit_part: u16,
-}
-
 // Vote type enum
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum VoteType {
 	CarbonFootprintReportVote,
 	ProjectProposalVote,
 	CarbonCreditBatchVote,
 	ComplaintVote,
+	BeneficiarySplitVote,
 }
 
-// Carbon credit batch status
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+// Beneficiary split types
+// Note: Splits are expressed as a Permill share of the sale price and encoded the same way as the
+// former 0, 1 and 2 beneficiary split keys
+#[derive(
+	Encode,
+	Decode,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	Serialize,
+	Deserialize,
+)]
 #[cfg_attr(feature = "std", derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub enum BeneficiarySplitType {
+	PrimarySaleValidators,   // Validator split during the initial sale
+	SecondarySaleValidators, // Validator split during the secondary sale
+	SecondarySaleOwner,      // Project owner split during the secondary sale
+}
+
+// Carbon credit batch status
+// Note: Debug is always derived since the status is a carbon credit batch type parameter
+#[derive(
+	Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, RuntimeDebug,
+)]
 pub enum CarbonCreditBatchStatus {
 	Active,   // Tokens can be traded and retired
 	Frozen,   // Tokens can't be traded or retired
 	Redacted, // Tokens have been removed from circulation
 }
 
+// Carbon credit hold reasons (used by the fungibles trait implementation)
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum CarbonCreditHoldReason {
+	SaleOrder, // Tokens are unavailable while they are in a sales cycle (sale orders and asks)
+}
+
 // Fee types
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum FeeType {
 	TraderAccountFee,           // Trader acccount registration fee
@@ -242,11 +550,43 @@ pub enum FeeType {
 	CarbonCreditBatchFee,       // Carbon credit batch proposition fee
 	VotingFee,                  // Voting fee
 	ComplaintFee,               // Complaint proposal fee
+	TradingFee(TradingFeeRole), // Carbon credit trading fee (in parts per million)
+}
+
+// Trading fee roles
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum TradingFeeRole {
+	Maker, // Sale order creator (seller)
+	Taker, // Sale order filler (buyer)
+}
+
+// Trading fee values
+// Note: Fees are expressed as a share of the fill price
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TradingFeeValues {
+	// Trading fee paid for the seller (maker)
+	maker_fee: Permill,
+	// Trading fee paid for the buyer (taker)
+	taker_fee: Permill,
 }
 
 // Fee values
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(
+	Encode,
+	Decode,
+	Default,
+	PartialEq,
+	Eq,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	Clone,
+	Serialize,
+	Deserialize,
+)]
 #[cfg_attr(feature = "std", derive(Debug))]
+#[serde(rename_all = "camelCase")]
 pub struct FeeValues<BalanceOf> {
 	// Trader acccount registration fee
 	trader_account_fee: BalanceOf,
@@ -267,7 +607,7 @@ pub struct FeeValues<BalanceOf> {
 }
 
 // Time type
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum TimeType {
 	NumberOfBlocksYearly,
@@ -278,8 +618,20 @@ pub enum TimeType {
 }
 
 // Time values (in blocks)
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(
+	Encode,
+	Decode,
+	Default,
+	PartialEq,
+	Eq,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+	Clone,
+	Serialize,
+	Deserialize,
+)]
 #[cfg_attr(feature = "std", derive(Debug))]
+#[serde(rename_all = "camelCase")]
 pub struct TimeValues<BlockNumber> {
 	number_of_blocks_per_year: BlockNumber,
 	pallet_base_time: BlockNumber,
@@ -289,7 +641,7 @@ pub struct TimeValues<BlockNumber> {
 }
 
 // Complaint type
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub enum ComplaintType {
 	ProjectComplaint,           // Complaint made for a project
@@ -299,33 +651,91 @@ pub enum ComplaintType {
 }
 
 // Complaint info structure (for AccountID based entities)
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct ComplaintAccountBasedInfo<AccountIdOf, MomentOf> {
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes))]
+pub struct ComplaintAccountBasedInfo<
+	AccountIdOf: Clone + Ord + Debug,
+	MomentOf: Clone + Eq + Debug,
+	MaxVotes: Get<u32>,
+> {
 	complaint_proposer: AccountIdOf,
 	complaint_type: ComplaintType,
 	complaint_for: AccountIdOf,
 	creation_date: MomentOf,
-	votes_for: BTreeSet<AccountIdOf>,
-	votes_against: BTreeSet<AccountIdOf>,
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	complaint_active: bool,
 }
 
 // Complaint info structure (for hash based entities)
-#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, Clone)]
-#[cfg_attr(feature = "std", derive(Debug))]
-pub struct ComplaintHashBasedInfo<AccountIdOf, MomentOf> {
+#[derive(
+	Encode,
+	Decode,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+	scale_info::TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxVotes))]
+pub struct ComplaintHashBasedInfo<
+	AccountIdOf: Clone + Ord + Debug,
+	MomentOf: Clone + Eq + Debug,
+	MaxVotes: Get<u32>,
+> {
 	complaint_proposer: AccountIdOf,
 	complaint_type: ComplaintType,
 	complaint_for: H256,
 	creation_date: MomentOf,
-	votes_for: BTreeSet<AccountIdOf>,
-	votes_against: BTreeSet<AccountIdOf>,
+	votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+	votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
 	complaint_active: bool,
 }
 
+// Documentation owner (entity type and key that used a documentation IPFS link)
+#[derive(Encode, Decode, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum DocumentationOwner<AccountIdOf> {
+	Validator(AccountIdOf),             // Project validator account
+	ProjectOwner(AccountIdOf),          // Project owner account
+	CarbonFootprintReport(AccountIdOf), // Carbon footprint report (for a carbon footprint account)
+	ProjectProposal(H256),              // Project proposal (for a project hash)
+	CarbonCreditBatchProposal(H256),    // Carbon credit batch proposal (for a batch hash)
+	AccountComplaint(AccountIdOf),      // Complaint made for a validator or project owner
+	HashComplaint(H256),                // Complaint made for a project or carbon credit batch
+	BeneficiarySplitProposal(H256),     // Beneficiary split proposal (for a project hash)
+}
+
+// Finished record (record type and key of a finished report, proposal, order or complaint)
+// Note: Records are keyed by their IPFS link, sale and buy orders and auctions by their hash
+#[derive(
+	Encode, Decode, PartialEq, Eq, PartialOrd, Ord, scale_info::TypeInfo, MaxEncodedLen, Clone,
+)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum FinishedRecord<Ipfs> {
+	CarbonFootprintReport(Ipfs),     // Carbon footprint report
+	ProjectProposal(Ipfs),           // Project proposal
+	CarbonCreditBatchProposal(Ipfs), // Carbon credit batch proposal
+	CarbonCreditSaleOrder(H256),     // Carbon credit sale order
+	AccountComplaint(Ipfs),          // Complaint made for a validator or project owner
+	HashComplaint(Ipfs),             // Complaint made for a project or carbon credit batch
+	CarbonCreditBuyOrder(H256),      // Carbon credit buy order
+	CarbonCreditAuction(H256),       // Carbon credit auction
+	BeneficiarySplitProposal(Ipfs),  // Beneficiary split proposal
+}
+
 // Carbon credit retirement info structure (only for CFAs)
-#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, Clone)]
+#[derive(Encode, Decode, Default, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen, Clone)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct CarbonCreditRetirementInfo<AccountIdOf, BalanceOf, MomentOf> {
 	carbon_footprint_account: AccountIdOf,
@@ -334,22 +744,48 @@ pub struct CarbonCreditRetirementInfo<AccountIdOf, BalanceOf, MomentOf> {
 	retirement_date: MomentOf,
 }
 
+// Carbon credit batch redaction progress structure
+// Note: Batch retirements are processed first, batch holdings after that
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RedactionProgress<AccountIdOf> {
+	// Last processed retirement
+	last_retirement: Option<H256>,
+	// Last processed holdings account
+	last_holding: Option<AccountIdOf>,
+	// All batch retirements have been processed
+	retirements_processed: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::ReservableCurrency;
+	use frame_support::sp_runtime::traits::{
+		CheckedAdd, CheckedMul, SaturatedConversion, Saturating,
+	};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	#[cfg(any(feature = "try-runtime", test))]
+	use frame_support::traits::fungible::InspectHold;
 	use frame_support::traits::Time;
+	use frame_support::weights::WeightMeter;
 	use frame_support::PalletId;
 	use frame_system::offchain::{SendTransactionTypes, SubmitTransaction};
 	use frame_system::pallet_prelude::*;
 	use log::{info, warn};
 	use sp_std::collections::btree_set::BTreeSet;
+	use sp_std::ops::Bound;
+	use sp_std::vec::Vec;
 
 	const PALLET_ID: PalletId = PalletId(*b"velesplt");
 
+	// Current storage version
+	// Note: Bump the version together with a new versioned migration (see the migrations module)
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Pallet configuration
@@ -359,37 +795,129 @@ pub mod pallet {
 		type IPFSLength: Get<u32>;
 		type BlockFinalizationTime: Get<u32>;
 		type Time: Time;
-		type Currency: ReservableCurrency<Self::AccountId>;
+		type RuntimeHoldReason: From<HoldReason>;
+		// Note: Only holds are used, all funds locked by the pallet (deposits and escrows) must be
+		// released or moved, which freezes don't support, so there is no pallet FreezeReason
+		type Currency: Inspect<Self::AccountId>
+			+ Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
 		type WeightInfo: WeightInfo;
+		// Origin allowed to update pallet values
+		// Note: Use EnsureAuthority to keep the authority account set model
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		// Origin allowed to add and remove authority accounts
+		// Note: Shouldn't include EnsureAuthority, otherwise a single authority could take over
+		// the authority account set
+		type AuthorityManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		#[pallet::constant]
 		type UnsignedPriority: Get<TransactionPriority>;
 		type UnsignedLongevity: Get<u64>;
+		// Maximum weight used for processing timeout events in a single block
+		// Note: Timeout events that don't fit are carried over to the next block
+		#[pallet::constant]
+		type MaxTimeoutsWeight: Get<Weight>;
+		// Submit carried over timeout events as unsigned transactions from the offchain worker
+		#[pallet::constant]
+		type OffchainWorkerFallback: Get<bool>;
+		// Maximum number of blocks with carried over timeout events visited per offchain worker
+		// run
+		#[pallet::constant]
+		type MaxOffchainWorkerTimeoutBlocks: Get<u32>;
+		// Hold fees for reports, proposals and complaints as refundable deposits
+		// Note: Deposits are returned if the vote passes and moved to the pallet otherwise
+		#[pallet::constant]
+		type DepositMode: Get<bool>;
+		// Maximum number of votes (for or against) and validator benefactors per vote
+		#[pallet::constant]
+		type MaxValidatorsPerVote: Get<u32>;
+		// Maximum number of documentation IPFS links per carbon footprint account
+		#[pallet::constant]
+		type MaxDocumentsPerCfa: Get<u32>;
+		// Maximum number of timeout events (of a single type) per block
+		#[pallet::constant]
+		type MaxTimeoutsPerBlock: Get<u32>;
+		// Maximum number of creditors per project owner
+		// Note: Debts to additional creditors are redirected to the pallet
+		#[pallet::constant]
+		type MaxDebtsPerProjectOwner: Get<u32>;
+		// Maximum number of projects per project owner
+		#[pallet::constant]
+		type MaxProjectsPerOwner: Get<u32>;
+		// Maximum number of carbon credit batches per project
+		#[pallet::constant]
+		type MaxBatchesPerProject: Get<u32>;
+		// Maximum number of carbon credit retirements per carbon credit batch
+		#[pallet::constant]
+		type MaxRetirementsPerBatch: Get<u32>;
+		// Maximum number of fills per carbon credit sale order
+		#[pallet::constant]
+		type MaxFillsPerSaleOrder: Get<u32>;
+		// Maximum number of allowed buyers per (private) carbon credit sale order
+		#[pallet::constant]
+		type MaxAllowedBuyersPerSaleOrder: Get<u32>;
+		// Maximum number of limit orders per order book side (of a carbon credit batch)
+		#[pallet::constant]
+		type MaxOrdersPerBook: Get<u32>;
+		// Maximum number of stakeholders per project beneficiary split override
+		#[pallet::constant]
+		type MaxStakeholdersPerProject: Get<u32>;
+		// Number of blocks before the end of an English auction in which a bid extends the auction
+		// Note: The auction is extended to this number of blocks after the bid (anti-sniping)
+		#[pallet::constant]
+		type AuctionExtensionPeriod: Get<BlockNumberFor<Self>>;
+		// Maximum trading fee (share of the fill price) for both the maker and the taker
+		#[pallet::constant]
+		type MaxTradingFee: Get<Permill>;
+		// Trading fee beneficiary
+		// Note: Trading fees are paid to the pallet if no beneficiary is set
+		type TradingFeeBeneficiary: Get<Option<AccountIdOf<Self>>>;
+		// Number of blocks finished reports, proposals, sale orders and complaints are kept for
+		// Note: Finished records are removed on idle once the retention period is over
+		#[pallet::constant]
+		type RetentionPeriod: Get<BlockNumberFor<Self>>;
+	}
+
+	/// Hold reasons
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Refundable deposit for a carbon footprint report, project or carbon credit batch proposal
+		ProposalDeposit,
+		/// Refundable deposit for a complaint
+		ComplaintDeposit,
+		/// Escrowed buyer funds for a carbon credit buy order
+		BuyOrderEscrow,
+		/// Escrowed buyer funds for an order book bid
+		OrderBookEscrow,
+		/// Escrowed bidder funds for the highest bid of an English auction
+		AuctionBidEscrow,
 	}
 
 	/// Pallet types and constants
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
 	pub type BlockNumber<T> = BlockNumberFor<T>;
-	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+	pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
+	pub type PenaltyLevelsOf<T> = BoundedBTreeMap<u8, Perbill, ConstU32<5>>;
+	pub type BeneficiarySplitsOf<T> = BoundedBTreeMap<BeneficiarySplitType, Permill, ConstU32<3>>;
+	pub type StakeholderSharesOf<T> =
+		BoundedBTreeMap<AccountIdOf<T>, Permill, <T as Config>::MaxStakeholdersPerProject>;
+	pub type ProjectBeneficiarySplitsOf<T> =
+		ProjectBeneficiarySplitsInfo<AccountIdOf<T>, <T as Config>::MaxStakeholdersPerProject>;
+	pub type VotesOf<T> = BoundedBTreeSet<AccountIdOf<T>, <T as Config>::MaxValidatorsPerVote>;
+	pub type TimeoutsOf<T, V> = BoundedBTreeSet<V, <T as Config>::MaxTimeoutsPerBlock>;
+	pub type DebtsOf<T> =
+		BoundedBTreeMap<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxDebtsPerProjectOwner>;
+	pub type SaleOrderFillsOf<T> = BoundedVec<
+		SaleOrderFillInfo<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>,
+		<T as Config>::MaxFillsPerSaleOrder,
+	>;
+	pub type AllowedBuyersOf<T> =
+		BoundedBTreeSet<AccountIdOf<T>, <T as Config>::MaxAllowedBuyersPerSaleOrder>;
+	pub type OrderBookOf<T> = BoundedVec<(BalanceOf<T>, H256), <T as Config>::MaxOrdersPerBook>;
+	pub type FinishedRecordOf<T> = FinishedRecord<BoundedString<<T as Config>::IPFSLength>>;
 
 	/// Helper functions
-	// Default authority accounts
-	#[pallet::type_value]
-	pub fn DefaultForAuthorityAccounts<T: Config>() -> BTreeSet<AccountIdOf<T>> {
-		let set: BTreeSet<AccountIdOf<T>> = BTreeSet::<AccountIdOf<T>>::new();
-
-		set
-	}
-
-	// Default trade accounts
-	#[pallet::type_value]
-	pub fn DefaultForTraderAccounts<T: Config>() -> BTreeSet<AccountIdOf<T>> {
-		let set: BTreeSet<AccountIdOf<T>> = BTreeSet::<AccountIdOf<T>>::new();
-
-		set
-	}
-
 	// Default values for all fees
 	#[pallet::type_value]
 	pub fn DefaultForPalletFeeValues<T: Config>() -> FeeValues<BalanceOf<T>> {
@@ -432,53 +960,49 @@ pub mod pallet {
 	}
 
 	// Default value for voting ratio needed for a vote to pass
-	// Note: If the upper_limit_part is set to 0 then we will consider that we only need a
-	// 1 vote difference to decide what is the outcome of the vote. If we set the proportion_part to
-	// a number that is equal to the upper_limit_part or that is greater then it then we will need
-	// a 100% of votes in order to made a passing/non passing decision
+	// Note: If the ratio is set to 0 then we will consider that we only need a 1 vote difference
+	// to decide what is the outcome of the vote. If we set the ratio to 100% then we will need
+	// all of the votes in order to made a passing decision
+	// Note: Two thirds rounded up, so that the votes needed aren't rounded down one vote too far
+	// (see has_vote_passed)
 	#[pallet::type_value]
-	pub fn DefaultForVotePassRatio<T: Config>() -> ProportionStructure {
-		let mut proportion_part: u16 = 2;
-		let upper_limit_part: u16 = 3;
-
-		if upper_limit_part == 0 {
-			proportion_part = 0;
-		} else if proportion_part >= upper_limit_part {
-			proportion_part = upper_limit_part;
-		}
-
-		let pass_voting_ratio = ProportionStructure { proportion_part, upper_limit_part };
-
-		pass_voting_ratio
+	pub fn DefaultForVotePassRatio<T: Config>() -> Perbill {
+		Perbill::from_parts(666_666_667)
 	}
 
 	// Default value for penalty levels
+	// Note: A penalty level is the share by which the payments and gains of an account (or
+	// project) at that level are reduced, the defaults reduce them by a factor of 1, 1.67, 2.3,
+	// 2.755 and 3.2
 	#[pallet::type_value]
-	pub fn DefaultForPenaltyLevels<T: Config>() -> BTreeMap<u8, BalanceOf<T>> {
-		let mut penalty_levels = BTreeMap::<u8, BalanceOf<T>>::new();
+	pub fn DefaultForPenaltyLevels<T: Config>() -> PenaltyLevelsOf<T> {
+		let mut penalty_levels = BTreeMap::<u8, Perbill>::new();
 
-		penalty_levels.insert(0u8, BalanceOf::<T>::from(10000u32));
-		penalty_levels.insert(1u8, BalanceOf::<T>::from(16700u32));
-		penalty_levels.insert(2u8, BalanceOf::<T>::from(23000u32));
-		penalty_levels.insert(3u8, BalanceOf::<T>::from(27550u32));
-		penalty_levels.insert(4u8, BalanceOf::<T>::from(32000u32));
+		penalty_levels.insert(0u8, Perbill::zero());
+		penalty_levels.insert(1u8, Perbill::from_rational(6_700u32, 16_700u32));
+		penalty_levels.insert(2u8, Perbill::from_rational(13_000u32, 23_000u32));
+		penalty_levels.insert(3u8, Perbill::from_rational(17_550u32, 27_550u32));
+		penalty_levels.insert(4u8, Perbill::from_rational(22_000u32, 32_000u32));
 
-		penalty_levels
+		PenaltyLevelsOf::<T>::try_from(penalty_levels).unwrap_or_default()
 	}
 
 	// Default value for penalty levels
 	#[pallet::type_value]
-	pub fn DefaultForBeneficiarySplits<T: Config>() -> BTreeMap<u8, BalanceOf<T>> {
-		let mut beneficiary_splits = BTreeMap::<u8, BalanceOf<T>>::new();
+	pub fn DefaultForBeneficiarySplits<T: Config>() -> BeneficiarySplitsOf<T> {
+		let mut beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
 
 		// Add beneficiary percentage for validators (only initial sale)
-		beneficiary_splits.insert(0u8, BalanceOf::<T>::from(4500u32));
+		beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(45));
 		// Add beneficiary percentage for validators (only secondary sale)
-		beneficiary_splits.insert(1u8, BalanceOf::<T>::from(3500u32));
+		beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(35));
 		// Add beneficiary percentage for project owner (only secondary sale)
-		beneficiary_splits.insert(2u8, BalanceOf::<T>::from(1000u32));
-
 		beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_percent(10));
+
+		BeneficiarySplitsOf::<T>::try_from(beneficiary_splits).unwrap_or_default()
 	}
 
 	/// Pallet storages
@@ -498,25 +1022,36 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn vote_pass_ratio)]
 	pub type VotePassRatio<T: Config> =
-		StorageValue<_, ProportionStructure, ValueQuery, DefaultForVotePassRatio<T>>;
+		StorageValue<_, Perbill, ValueQuery, DefaultForVotePassRatio<T>>;
 
 	// Penalty levels
 	#[pallet::storage]
 	#[pallet::getter(fn penalty_levels)]
 	pub type PenaltyLevels<T: Config> =
-		StorageValue<_, BTreeMap<u8, BalanceOf<T>>, ValueQuery, DefaultForPenaltyLevels<T>>;
+		StorageValue<_, PenaltyLevelsOf<T>, ValueQuery, DefaultForPenaltyLevels<T>>;
 
 	// Beneficiary splits
 	#[pallet::storage]
 	#[pallet::getter(fn beneficiary_splits)]
 	pub type BeneficiarySplits<T: Config> =
-		StorageValue<_, BTreeMap<u8, BalanceOf<T>>, ValueQuery, DefaultForBeneficiarySplits<T>>;
+		StorageValue<_, BeneficiarySplitsOf<T>, ValueQuery, DefaultForBeneficiarySplits<T>>;
+
+	// Trading fees
+	#[pallet::storage]
+	#[pallet::getter(fn trading_fees)]
+	pub(super) type TradingFees<T: Config> = StorageValue<_, TradingFeeValues, ValueQuery>;
+
+	// Transfer royalties flag
+	// Note: If enabled, direct carbon credit transfers trigger beneficiary splits
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_royalties_enabled)]
+	pub(super) type TransferRoyaltiesEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
 
 	// Authority accounts
 	#[pallet::storage]
 	#[pallet::getter(fn authority_accounts)]
 	pub type AuthorityAccounts<T: Config> =
-		StorageValue<_, BTreeSet<AccountIdOf<T>>, ValueQuery, DefaultForAuthorityAccounts<T>>;
+		StorageMap<_, Identity, AccountIdOf<T>, AuthorityAccountInfo<MomentOf<T>>, OptionQuery>;
 
 	// Carbon Footprint accounts
 	#[pallet::storage]
@@ -525,7 +1060,7 @@ pub mod pallet {
 		_,
 		Identity,
 		AccountIdOf<T>,
-		CarbonFootprintAccountInfo<MomentOf<T>, T::IPFSLength, BalanceOf<T>>,
+		CarbonFootprintAccountInfo<MomentOf<T>, T::IPFSLength, BalanceOf<T>, T::MaxDocumentsPerCfa>,
 		OptionQuery,
 	>;
 
@@ -533,7 +1068,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn trader_accounts)]
 	pub type TraderAccounts<T: Config> =
-		StorageValue<_, BTreeSet<AccountIdOf<T>>, ValueQuery, DefaultForTraderAccounts<T>>;
+		StorageMap<_, Identity, AccountIdOf<T>, TraderAccountInfo<MomentOf<T>>, OptionQuery>;
 
 	// Validator accounts
 	#[pallet::storage]
@@ -581,10 +1116,28 @@ pub mod pallet {
 			BalanceOf<T>,
 			CarbonCreditBatchStatus,
 			AccountIdOf<T>,
+			T::MaxValidatorsPerVote,
 		>,
 		OptionQuery,
 	>;
 
+	// Projects by project owner
+	#[pallet::storage]
+	#[pallet::getter(fn projects_by_owner)]
+	pub(super) type ProjectsByOwner<T: Config> = StorageMap<
+		_,
+		Identity,
+		AccountIdOf<T>,
+		BoundedBTreeSet<H256, T::MaxProjectsPerOwner>,
+		OptionQuery,
+	>;
+
+	// Carbon credit batches by project
+	#[pallet::storage]
+	#[pallet::getter(fn batches_by_project)]
+	pub(super) type BatchesByProject<T: Config> =
+		StorageMap<_, Identity, H256, BoundedBTreeSet<H256, T::MaxBatchesPerProject>, OptionQuery>;
+
 	// Carbon credit holdings
 	#[pallet::storage]
 	#[pallet::getter(fn carbon_credit_holdings)]
@@ -609,6 +1162,17 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	// Carbon credit buy orders
+	#[pallet::storage]
+	#[pallet::getter(fn carbon_credit_buy_orders)]
+	pub(super) type CarbonCreditBuyOrders<T: Config> = StorageMap<
+		_,
+		Identity,
+		H256,
+		CarbonCreditBuyOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+		OptionQuery,
+	>;
+
 	// Complaints (for AccountID based entities)
 	#[pallet::storage]
 	#[pallet::getter(fn complaints_for_accounts)]
@@ -616,7 +1180,7 @@ pub mod pallet {
 		_,
 		Identity,
 		BoundedString<T::IPFSLength>,
-		ComplaintAccountBasedInfo<AccountIdOf<T>, MomentOf<T>>,
+		ComplaintAccountBasedInfo<AccountIdOf<T>, MomentOf<T>, T::MaxValidatorsPerVote>,
 		OptionQuery,
 	>;
 
@@ -627,7 +1191,7 @@ pub mod pallet {
 		_,
 		Identity,
 		BoundedString<T::IPFSLength>,
-		ComplaintHashBasedInfo<AccountIdOf<T>, MomentOf<T>>,
+		ComplaintHashBasedInfo<AccountIdOf<T>, MomentOf<T>, T::MaxValidatorsPerVote>,
 		OptionQuery,
 	>;
 
@@ -635,19 +1199,19 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn project_owner_debts)]
 	pub(super) type ProjectOwnerDebts<T: Config> =
-		StorageMap<_, Identity, AccountIdOf<T>, BTreeMap<AccountIdOf<T>, BalanceOf<T>>, ValueQuery>;
+		StorageMap<_, Identity, AccountIdOf<T>, DebtsOf<T>, ValueQuery>;
 
 	// Penalty timeouts (for AccountID's)
 	#[pallet::storage]
 	#[pallet::getter(fn penalty_timeouts_accounts)]
 	pub(super) type PenaltyTimeoutsAccounts<T: Config> =
-		StorageMap<_, Identity, BlockNumber<T>, BTreeSet<AccountIdOf<T>>, OptionQuery>;
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, AccountIdOf<T>>, OptionQuery>;
 
 	// Penalty timeouts (for hashes)
 	#[pallet::storage]
 	#[pallet::getter(fn penalty_timeouts_hashes)]
 	pub(super) type PenaltyTimeoutsHashes<T: Config> =
-		StorageMap<_, Identity, BlockNumber<T>, BTreeSet<H256>, OptionQuery>;
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, H256>, OptionQuery>;
 
 	// Voting timeouts
 	#[pallet::storage]
@@ -656,7 +1220,7 @@ pub mod pallet {
 		_,
 		Identity,
 		BlockNumber<T>,
-		BTreeSet<BoundedString<T::IPFSLength>>,
+		TimeoutsOf<T, BoundedString<T::IPFSLength>>,
 		OptionQuery,
 	>;
 
@@ -664,7 +1228,13 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn sales_timeouts)]
 	pub(super) type SaleOrderTimeouts<T: Config> =
-		StorageMap<_, Identity, BlockNumber<T>, BTreeSet<H256>, OptionQuery>;
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, H256>, OptionQuery>;
+
+	// Buy order timeouts
+	#[pallet::storage]
+	#[pallet::getter(fn buy_order_timeouts)]
+	pub(super) type BuyOrderTimeouts<T: Config> =
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, H256>, OptionQuery>;
 
 	// Complaint timeouts
 	#[pallet::storage]
@@ -673,10 +1243,28 @@ pub mod pallet {
 		_,
 		Identity,
 		BlockNumber<T>,
-		BTreeSet<BoundedString<T::IPFSLength>>,
+		TimeoutsOf<T, BoundedString<T::IPFSLength>>,
 		OptionQuery,
 	>;
 
+	// Timeouts cursor
+	// Note: First block with timeout events that haven't all been processed
+	#[pallet::storage]
+	#[pallet::getter(fn timeouts_cursor)]
+	pub(super) type TimeoutsCursor<T: Config> = StorageValue<_, BlockNumber<T>, ValueQuery>;
+
+	// Pruning timeouts (for finished records)
+	#[pallet::storage]
+	#[pallet::getter(fn pruning_timeouts)]
+	pub(super) type PruningTimeouts<T: Config> =
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, FinishedRecordOf<T>>, OptionQuery>;
+
+	// Pruning cursor
+	// Note: First block with pruning timeout events that haven't all been processed
+	#[pallet::storage]
+	#[pallet::getter(fn pruning_cursor)]
+	pub(super) type PruningCursor<T: Config> = StorageValue<_, BlockNumber<T>, ValueQuery>;
+
 	// Carbon footprint reports
 	#[pallet::storage]
 	#[pallet::getter(fn carbon_footprint_reports)]
@@ -684,7 +1272,12 @@ pub mod pallet {
 		_,
 		Identity,
 		BoundedString<T::IPFSLength>,
-		CarbonFootprintReportInfo<AccountIdOf<T>, MomentOf<T>, BalanceOf<T>>,
+		CarbonFootprintReportInfo<
+			AccountIdOf<T>,
+			MomentOf<T>,
+			BalanceOf<T>,
+			T::MaxValidatorsPerVote,
+		>,
 		OptionQuery,
 	>;
 
@@ -695,7 +1288,7 @@ pub mod pallet {
 		_,
 		Identity,
 		BoundedString<T::IPFSLength>,
-		ProjectProposalInfo<AccountIdOf<T>, MomentOf<T>>,
+		ProjectProposalInfo<AccountIdOf<T>, MomentOf<T>, T::MaxValidatorsPerVote>,
 		OptionQuery,
 	>;
 
@@ -706,10 +1299,37 @@ pub mod pallet {
 		_,
 		Identity,
 		BoundedString<T::IPFSLength>,
-		CarbonCreditBatchProposalInfo<MomentOf<T>, BalanceOf<T>, AccountIdOf<T>>,
+		CarbonCreditBatchProposalInfo<
+			MomentOf<T>,
+			BalanceOf<T>,
+			AccountIdOf<T>,
+			T::MaxValidatorsPerVote,
+		>,
+		OptionQuery,
+	>;
+
+	// Beneficiary split proposals
+	#[pallet::storage]
+	#[pallet::getter(fn beneficiary_split_proposals)]
+	pub(super) type BeneficiarySplitProposals<T: Config> = StorageMap<
+		_,
+		Identity,
+		BoundedString<T::IPFSLength>,
+		BeneficiarySplitProposalInfo<
+			MomentOf<T>,
+			AccountIdOf<T>,
+			T::MaxValidatorsPerVote,
+			T::MaxStakeholdersPerProject,
+		>,
 		OptionQuery,
 	>;
 
+	// Project beneficiary splits (approved per-project beneficiary split overrides)
+	#[pallet::storage]
+	#[pallet::getter(fn project_beneficiary_splits)]
+	pub(super) type ProjectBeneficiarySplits<T: Config> =
+		StorageMap<_, Identity, H256, ProjectBeneficiarySplitsOf<T>, OptionQuery>;
+
 	// Carbon credit retirements
 	#[pallet::storage]
 	#[pallet::getter(fn carbon_credit_retirements)]
@@ -721,72 +1341,379 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub (super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// Time Value Updated
-		TimeValueUpdated(TimeType, BlockNumber<T>),
-		/// Fee Value Updated
-		FeeValueUpdated(FeeType, BalanceOf<T>),
-		/// Vote Pass Ration Updated
-		VotePassRatioUpdated(u16, u16),
-		/// Trader Account Registered
-		TraderAccountRegistered(AccountIdOf<T>),
-		/// Project Validator Account Registered
-		ProjectValidatorAccountRegistered(AccountIdOf<T>, BoundedString<T::IPFSLength>),
-		/// Project Owner Account Registered
-		ProjectOwnerAccountRegistered(AccountIdOf<T>, BoundedString<T::IPFSLength>),
-		/// Successful Vote Cast
-		SuccessfulVote(AccountIdOf<T>, BoundedString<T::IPFSLength>, VoteType, bool),
-		/// Carbon Footprint Report Submitted
-		CarbonFootprintReportSubmitted(AccountIdOf<T>, BoundedString<T::IPFSLength>),
-		/// Successful Project Proposal Created
-		ProjectProposalCreated(AccountIdOf<T>, BoundedString<T::IPFSLength>),
-		/// Carbon Credit Batch Proposal Created
-		CarbonCreditBatchProposalCreated(AccountIdOf<T>, BoundedString<T::IPFSLength>),
-		/// Carbon Credit Sale Order Created
-		CarbonCreditSaleOrderCreated(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>),
-		/// Carbon Credit Sale Order Completed
-		CarbonCreditSaleOrderCompleted(AccountIdOf<T>, H256),
-		/// Carbon Credit Sale Order Closed
-		CarbonCreditSaleOrderClosed(AccountIdOf<T>, H256),
-		// Account Complaint Opened
-		AccountComplaintOpened(
-			AccountIdOf<T>,
-			AccountIdOf<T>,
-			ComplaintType,
-			BoundedString<T::IPFSLength>,
-		),
-		// Hash Complaint Opened
-		HashComplaintOpened(AccountIdOf<T>, H256, ComplaintType, BoundedString<T::IPFSLength>),
-		/// Carbon Credits Have Been Retired
-		CarbonCreditsHaveBeenRetired(AccountIdOf<T>, H256, BalanceOf<T>),
-		/// Project Owner Debts Have Been Repaid
-		ProjectOwnerDebtsHaveBeenRepaid(AccountIdOf<T>),
-		/// Penalty Levels Updated
-		PenaltyLevelsUpdated(BTreeMap<u8, BalanceOf<T>>),
-		/// Beneficiary Split Updated
-		BeneficiarySplitsUpdated(BTreeMap<u8, BalanceOf<T>>),
-		/// Base Pallet Time Updated
-		BasePalletTimeUpdated(BlockNumber<T>),
-		/// Carbon Footprint Report Updated
-		CarbonFootprintReportUpdated(BoundedString<T::IPFSLength>),
-		/// Project Proposal Updated
-		ProjectProposalUpdated(BoundedString<T::IPFSLength>),
-		/// Carbon Credit Batch Proposal Updated
-		CarbonCreditBatchProposalUpdated(BoundedString<T::IPFSLength>),
-		/// Carbon Credit Sale Order Updated
-		CarbonCreditSaleOrderUpdated(H256),
-		/// Account Complaint Updated
-		AccountComplaintUpdated(BoundedString<T::IPFSLength>),
-		/// Hash Complaint Updated
-		HashComplaintUpdated(BoundedString<T::IPFSLength>),
+	// Carbon credit retirements by carbon credit batch
+	#[pallet::storage]
+	#[pallet::getter(fn retirements_by_batch)]
+	pub(super) type RetirementsByBatch<T: Config> = StorageMap<
+		_,
+		Identity,
+		H256,
+		BoundedBTreeSet<H256, T::MaxRetirementsPerBatch>,
+		OptionQuery,
+	>;
+
+	// Carbon credit sale order fills
+	#[pallet::storage]
+	#[pallet::getter(fn sale_order_fills)]
+	pub(super) type SaleOrderFills<T: Config> =
+		StorageMap<_, Identity, H256, SaleOrderFillsOf<T>, OptionQuery>;
+
+	// Allowed buyers of private carbon credit sale orders
+	// Note: Sale orders without allowed buyers can be filled by any eligible account
+	#[pallet::storage]
+	#[pallet::getter(fn sale_order_allowed_buyers)]
+	pub(super) type SaleOrderAllowedBuyers<T: Config> =
+		StorageMap<_, Identity, H256, AllowedBuyersOf<T>, OptionQuery>;
+
+	// Order book limit orders
+	#[pallet::storage]
+	#[pallet::getter(fn limit_orders)]
+	pub(super) type LimitOrders<T: Config> = StorageMap<
+		_,
+		Identity,
+		H256,
+		LimitOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+		OptionQuery,
+	>;
+
+	// Order books (per carbon credit batch and side)
+	// Note: Entries (price and limit order hash) are sorted by price-time priority, bids from the
+	// highest and asks from the lowest price
+	#[pallet::storage]
+	#[pallet::getter(fn order_books)]
+	pub(super) type OrderBooks<T: Config> =
+		StorageDoubleMap<_, Identity, H256, Identity, OrderSide, OrderBookOf<T>, OptionQuery>;
+
+	// Carbon credit auctions
+	#[pallet::storage]
+	#[pallet::getter(fn carbon_credit_auctions)]
+	pub(super) type CarbonCreditAuctions<T: Config> = StorageMap<
+		_,
+		Identity,
+		H256,
+		CarbonCreditAuctionInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+		OptionQuery,
+	>;
+
+	// Auction timeouts (auction ends)
+	#[pallet::storage]
+	#[pallet::getter(fn auction_timeouts)]
+	pub(super) type AuctionTimeouts<T: Config> =
+		StorageMap<_, Identity, BlockNumber<T>, TimeoutsOf<T, H256>, OptionQuery>;
+
+	// Pending carbon credit batch redactions
+	// Note: Processed on initialize within the timeouts weight limit
+	#[pallet::storage]
+	#[pallet::getter(fn pending_redactions)]
+	pub(super) type PendingRedactions<T: Config> =
+		StorageMap<_, Identity, H256, RedactionProgress<AccountIdOf<T>>, OptionQuery>;
+
+	// Held deposits (for reports, proposals and complaints)
+	#[pallet::storage]
+	#[pallet::getter(fn deposits)]
+	pub(super) type Deposits<T: Config> = StorageMap<
+		_,
+		Identity,
+		BoundedString<T::IPFSLength>,
+		DepositInfo<AccountIdOf<T>, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	// Used documentation (IPFS links)
+	// Note: Records the entity type and key that used each documentation IPFS link
+	#[pallet::storage]
+	#[pallet::getter(fn used_documentation)]
+	pub(super) type UsedDocumentation<T: Config> = StorageMap<
+		_,
+		Identity,
+		BoundedString<T::IPFSLength>,
+		DocumentationOwner<AccountIdOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Genesis configuration
+	// Note: All optional values fall back to the pallet defaults if they are not set
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		// Authority accounts
+		pub authority_accounts: Vec<AccountIdOf<T>>,
+		// Fee values
+		pub fee_values: Option<FeeValues<BalanceOf<T>>>,
+		// Time values (the pallet base time must be left at 0)
+		pub time_values: Option<TimeValues<BlockNumber<T>>>,
+		// Pass voting ratio
+		pub vote_pass_ratio: Option<Perbill>,
+		// Penalty levels
+		pub penalty_levels: Option<BTreeMap<u8, Perbill>>,
+		// Beneficiary splits
+		pub beneficiary_splits: Option<BTreeMap<BeneficiarySplitType, Permill>>,
+		// Pre-registered validators (account ID, IPFS link to documentation)
+		pub validators: Vec<(AccountIdOf<T>, Vec<u8>)>,
+		// Pre-registered project owners (account ID, IPFS link to documentation)
+		pub project_owners: Vec<(AccountIdOf<T>, Vec<u8>)>,
+		// Pre-registered trader accounts
+		pub traders: Vec<AccountIdOf<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			// Authority accounts
+			for account_id in self.authority_accounts.iter() {
+				AuthorityAccounts::<T>::insert(
+					account_id.clone(),
+					AuthorityAccountInfo { added_date: T::Time::now() },
+				);
+			}
+
+			// Fee values
+			if let Some(fee_values) = &self.fee_values {
+				PalletFeeValues::<T>::set(fee_values.clone());
+			}
+
+			// Time values
+			if let Some(time_values) = &self.time_values {
+				assert!(
+					time_values.pallet_base_time == BlockNumber::<T>::from(0u32),
+					"Pallet base time can't be set at genesis"
+				);
+
+				for (time_type, time_value) in [
+					(TimeType::NumberOfBlocksYearly, time_values.number_of_blocks_per_year),
+					(TimeType::PenaltyTimeout, time_values.penalty_timeout),
+					(TimeType::VotingTimeout, time_values.voting_timeout),
+					(TimeType::SalesTimeout, time_values.sales_timeout),
+				] {
+					Pallet::<T>::validate_time_value(&time_type, time_value)
+						.expect("Invalid genesis time value");
+				}
+
+				PalletTimeValues::<T>::set(time_values.clone());
+			}
+
+			// Pass voting ratio
+			if let Some(vote_pass_ratio) = self.vote_pass_ratio {
+				VotePassRatio::<T>::set(vote_pass_ratio);
+			}
+
+			// Penalty levels
+			if let Some(penalty_levels) = &self.penalty_levels {
+				Pallet::<T>::validate_penalty_levels(penalty_levels)
+					.expect("Invalid genesis penalty levels");
+
+				PenaltyLevels::<T>::set(
+					PenaltyLevelsOf::<T>::try_from(penalty_levels.clone())
+						.expect("Invalid genesis penalty levels"),
+				);
+			}
+
+			// Beneficiary splits
+			if let Some(beneficiary_splits) = &self.beneficiary_splits {
+				Pallet::<T>::validate_beneficiary_splits(beneficiary_splits, Permill::zero())
+					.expect("Invalid genesis beneficiary splits");
+
+				BeneficiarySplits::<T>::set(
+					BeneficiarySplitsOf::<T>::try_from(beneficiary_splits.clone())
+						.expect("Invalid genesis beneficiary splits"),
+				);
+			}
+
+			// Validator accounts
+			for (account_id, documentation) in self.validators.iter() {
+				let documentation_ipfs =
+					Pallet::<T>::genesis_documentation(account_id.clone(), documentation);
+
+				UsedDocumentation::<T>::insert(
+					documentation_ipfs.clone(),
+					DocumentationOwner::Validator(account_id.clone()),
+				);
+
+				Validators::<T>::insert(
+					account_id.clone(),
+					ProjectValidatorOrProjectOwnerInfo {
+						documentation_ipfs,
+						penalty_level: 0,
+						penalty_timeout: BlockNumber::<T>::from(0u32),
+					},
+				);
+			}
+
+			// Project owner accounts
+			for (account_id, documentation) in self.project_owners.iter() {
+				let documentation_ipfs =
+					Pallet::<T>::genesis_documentation(account_id.clone(), documentation);
+
+				UsedDocumentation::<T>::insert(
+					documentation_ipfs.clone(),
+					DocumentationOwner::ProjectOwner(account_id.clone()),
+				);
+
+				ProjectOwners::<T>::insert(
+					account_id.clone(),
+					ProjectValidatorOrProjectOwnerInfo {
+						documentation_ipfs,
+						penalty_level: 0,
+						penalty_timeout: BlockNumber::<T>::from(0u32),
+					},
+				);
+			}
+
+			// Trader accounts
+			for account_id in self.traders.iter() {
+				assert!(
+					Pallet::<T>::is_account_id_available(account_id.clone()),
+					"Genesis account ID already in use"
+				);
+
+				TraderAccounts::<T>::insert(
+					account_id.clone(),
+					TraderAccountInfo { registration_date: T::Time::now() },
+				);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Time Value Updated
+		TimeValueUpdated(TimeType, BlockNumber<T>),
+		/// Fee Value Updated
+		FeeValueUpdated(FeeType, BalanceOf<T>),
+		/// Vote Pass Ration Updated
+		VotePassRatioUpdated(Perbill),
+		/// Trader Account Registered
+		TraderAccountRegistered(AccountIdOf<T>),
+		/// Project Validator Account Registered
+		ProjectValidatorAccountRegistered(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Project Owner Account Registered
+		ProjectOwnerAccountRegistered(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Successful Vote Cast
+		SuccessfulVote(AccountIdOf<T>, BoundedString<T::IPFSLength>, VoteType, bool),
+		/// Carbon Footprint Report Submitted
+		CarbonFootprintReportSubmitted(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Successful Project Proposal Created
+		ProjectProposalCreated(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Carbon Credit Batch Proposal Created
+		CarbonCreditBatchProposalCreated(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Carbon Credit Sale Order Created
+		CarbonCreditSaleOrderCreated(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>),
+		/// Carbon Credit Sale Order Completed (with the total maker and taker trading fees)
+		CarbonCreditSaleOrderCompleted(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>),
+		/// Carbon Credit Sale Order Closed
+		CarbonCreditSaleOrderClosed(AccountIdOf<T>, H256),
+		// Account Complaint Opened
+		AccountComplaintOpened(
+			AccountIdOf<T>,
+			AccountIdOf<T>,
+			ComplaintType,
+			BoundedString<T::IPFSLength>,
+		),
+		// Hash Complaint Opened
+		HashComplaintOpened(AccountIdOf<T>, H256, ComplaintType, BoundedString<T::IPFSLength>),
+		/// Carbon Credits Have Been Retired
+		CarbonCreditsHaveBeenRetired(AccountIdOf<T>, H256, BalanceOf<T>),
+		/// Project Owner Debts Have Been Repaid
+		ProjectOwnerDebtsHaveBeenRepaid(AccountIdOf<T>),
+		/// Penalty Levels Updated
+		PenaltyLevelsUpdated(BTreeMap<u8, Perbill>),
+		/// Beneficiary Split Updated
+		BeneficiarySplitsUpdated(BTreeMap<BeneficiarySplitType, Permill>),
+		/// Base Pallet Time Updated
+		BasePalletTimeUpdated(BlockNumber<T>),
+		/// Carbon Footprint Report Updated
+		CarbonFootprintReportUpdated(BoundedString<T::IPFSLength>),
+		/// Project Proposal Updated
+		ProjectProposalUpdated(BoundedString<T::IPFSLength>),
+		/// Carbon Credit Batch Proposal Updated
+		CarbonCreditBatchProposalUpdated(BoundedString<T::IPFSLength>),
+		/// Carbon Credit Sale Order Updated
+		CarbonCreditSaleOrderUpdated(H256),
+		/// Account Complaint Updated
+		AccountComplaintUpdated(BoundedString<T::IPFSLength>),
+		/// Hash Complaint Updated
+		HashComplaintUpdated(BoundedString<T::IPFSLength>),
 		/// Project Owner Penalty Level Updated
 		ProjectOwnerPenaltyLevelUpdated(AccountIdOf<T>),
 		/// Validator Penalty Level Updated
 		ValidatorPenaltyLevelUpdated(AccountIdOf<T>),
 		/// Project Penalty Level Updated
 		ProjectPenaltyLevelUpdated(H256),
+		/// Authority Account Added
+		AuthorityAccountAdded(AccountIdOf<T>),
+		/// Authority Account Removed
+		AuthorityAccountRemoved(AccountIdOf<T>),
+		/// Deposit Held
+		DepositHeld(AccountIdOf<T>, BoundedString<T::IPFSLength>, BalanceOf<T>),
+		/// Deposit Released
+		DepositReleased(AccountIdOf<T>, BoundedString<T::IPFSLength>, BalanceOf<T>),
+		/// Deposit Slashed
+		DepositSlashed(AccountIdOf<T>, BoundedString<T::IPFSLength>, BalanceOf<T>),
+		/// Carbon Credit Batch Redaction Processed
+		CarbonCreditBatchRedactionProcessed(H256),
+		/// Finished Record Pruned
+		FinishedRecordPruned(FinishedRecordOf<T>),
+		/// Carbon Credit Sale Order Filled
+		CarbonCreditSaleOrderFilled(AccountIdOf<T>, H256, BalanceOf<T>),
+		/// Carbon Credits Transferred
+		CarbonCreditsTransferred(H256, AccountIdOf<T>, AccountIdOf<T>, BalanceOf<T>),
+		/// Transfer Royalties Updated
+		TransferRoyaltiesUpdated(bool),
+		/// Carbon Credit Buy Order Created
+		CarbonCreditBuyOrderCreated(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>),
+		/// Carbon Credit Buy Order Filled (seller, buy order, batch, amount, maker fee, taker fee)
+		CarbonCreditBuyOrderFilled(
+			AccountIdOf<T>,
+			H256,
+			H256,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// Carbon Credit Buy Order Completed
+		CarbonCreditBuyOrderCompleted(AccountIdOf<T>, H256),
+		/// Carbon Credit Buy Order Cancelled
+		CarbonCreditBuyOrderCancelled(AccountIdOf<T>, H256),
+		/// Carbon Credit Buy Order Updated
+		CarbonCreditBuyOrderUpdated(H256),
+		/// Limit Order Placed (remaining amount added to the order book)
+		LimitOrderPlaced(AccountIdOf<T>, H256, H256, OrderSide, BalanceOf<T>, BalanceOf<T>),
+		/// Limit Order Matched (batch, maker order, maker, taker, amount, price, maker fee, taker fee)
+		LimitOrderMatched(
+			H256,
+			H256,
+			AccountIdOf<T>,
+			AccountIdOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+			BalanceOf<T>,
+		),
+		/// Limit Order Cancelled
+		LimitOrderCancelled(AccountIdOf<T>, H256),
+		/// Carbon Credit Auction Created (seller, auction, batch, amount)
+		CarbonCreditAuctionCreated(AccountIdOf<T>, H256, H256, BalanceOf<T>),
+		/// Carbon Credit Auction Bid Placed
+		CarbonCreditAuctionBidPlaced(AccountIdOf<T>, H256, BalanceOf<T>),
+		/// Carbon Credit Auction Extended (new end block)
+		CarbonCreditAuctionExtended(H256, BlockNumber<T>),
+		/// Carbon Credit Auction Sold (buyer, auction, price, maker fee, taker fee)
+		CarbonCreditAuctionSold(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// Carbon Credit Auction Cancelled
+		CarbonCreditAuctionCancelled(AccountIdOf<T>, H256),
+		/// Carbon Credit Auction Updated
+		CarbonCreditAuctionUpdated(H256),
+		/// Carbon Credit Sale Order Amended (seller, sale order, amount, price)
+		CarbonCreditSaleOrderAmended(AccountIdOf<T>, H256, BalanceOf<T>, BalanceOf<T>),
+		/// Beneficiary Split Proposal Created
+		BeneficiarySplitProposalCreated(AccountIdOf<T>, BoundedString<T::IPFSLength>),
+		/// Beneficiary Split Proposal Updated
+		BeneficiarySplitProposalUpdated(BoundedString<T::IPFSLength>),
+		/// Project Beneficiary Splits Updated (approved override for a project)
+		ProjectBeneficiarySplitsUpdated(H256),
+		/// Escrow Release Failed (account, buy order or auction, amount still on hold)
+		EscrowReleaseFailed(AccountIdOf<T>, H256, BalanceOf<T>),
 	}
 
 	#[pallet::error]
@@ -881,6 +1808,88 @@ pub mod pallet {
 		InvalidPrimarySaleBeneficiarySplit,
 		/// Invalid secondary sale beneficiary split
 		InvalidSecondarySaleBeneficiarySplit,
+		/// Authority account already exists
+		AuthorityAccountAlreadyExists,
+		/// Authority account doesnt exist
+		AuthorityAccountDoesntExist,
+		/// Update is not due yet
+		UpdateIsNotDue,
+		/// Maximum number of votes reached
+		TooManyVotes,
+		/// Maximum number of carbon footprint account documents reached
+		TooManyDocuments,
+		/// Maximum number of timeout events for the block reached
+		TooManyTimeouts,
+		/// Maximum number of projects for the project owner reached
+		TooManyProjects,
+		/// Maximum number of carbon credit batches for the project reached
+		TooManyBatches,
+		/// Maximum number of retirements for the carbon credit batch reached
+		TooManyRetirements,
+		/// Carbon credits can't be transferred to the same account
+		CantTransferCarbonCreditsToSelf,
+		/// Minimum fill amount is zero or exceeds the sale order amount
+		InvalidMinFillAmount,
+		/// Fill amount is zero or exceeds the remaining sale order amount
+		InvalidFillAmount,
+		/// Fill amount is below the sale order minimum fill amount
+		FillAmountBelowMinimum,
+		/// Maximum number of fills for the sale order reached
+		TooManyFills,
+		/// Carbon credit buy order doesnt exist
+		CarbonCreditBuyOrderDoesntExist,
+		/// Buy order is not active
+		BuyOrderIsNotActive,
+		/// User didn't create the buy order
+		UserDidntCreateTheBuyOrder,
+		/// The seller can't fill his own buy order
+		SellerCantFillHisOwnBuyOrder,
+		/// Carbon credit batch doesn't match the buy order target
+		CarbonCreditBatchDoesntMatchBuyOrder,
+		/// Maximum number of limit orders for the order book side reached
+		OrderBookIsFull,
+		/// Limit order doesnt exist
+		LimitOrderDoesntExist,
+		/// User didn't create the limit order
+		UserDidntCreateTheLimitOrder,
+		/// Buyer isn't allowed to fill the private sale order
+		BuyerIsNotAllowedToFillTheSaleOrder,
+		/// Allowed buyers are empty or contain the seller
+		InvalidAllowedBuyers,
+		/// Carbon credit auction doesnt exist
+		CarbonCreditAuctionDoesntExist,
+		/// Auction is not active
+		AuctionIsNotActive,
+		/// Auction duration is zero or longer than the sales timeout
+		InvalidAuctionDuration,
+		/// Dutch auction start price is below the floor price
+		InvalidAuctionPrices,
+		/// Bid is below the reserve price or not above the highest bid
+		BidIsTooLow,
+		/// Action isn't supported by the auction type
+		WrongAuctionType,
+		/// English auction with bids can't be cancelled
+		AuctionHasBids,
+		/// User didn't create the auction
+		UserDidntCreateTheAuction,
+		/// Sale order expiry is zero or longer than the sales timeout
+		InvalidSaleOrderExpiry,
+		/// Trading fee is higher than the maximum trading fee
+		TradingFeeTooHigh,
+		/// Beneficiary split proposal not found
+		BeneficiarySplitProposalNotFound,
+		/// Too many stakeholders for a project
+		TooManyStakeholders,
+		/// Stakeholder share can't be zero
+		InvalidStakeholderShare,
+		/// Arithmetic overflow
+		ArithmeticOverflow,
+		/// Seller trading fee is higher than the seller proceeds (after the beneficiary splits)
+		TradingFeeExceedsSaleProceeds,
+		/// Project owner doesnt exist
+		ProjectOwnerDoesntExist,
+		/// Penalty level doesnt exist
+		PenaltyLevelDoesntExist,
 	}
 
 	#[pallet::call]
@@ -890,36 +1899,14 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::update_vote_pass_ratio())]
 		pub fn update_vote_pass_ratio(
 			origin: OriginFor<T>,
-			new_proportion_part: u16,
-			new_upper_limit_part: u16,
+			new_vote_pass_ratio: Perbill,
 		) -> DispatchResultWithPostInfo {
-			let user = ensure_signed(origin)?;
-
-			// Check if caller is a Authority account
-			ensure!(
-				AuthorityAccounts::<T>::get().contains(&user.clone()),
-				Error::<T>::Unauthorized
-			);
-
-			let mut temp_proportion_part = new_proportion_part;
-
-			if new_upper_limit_part == 0 {
-				temp_proportion_part = 0;
-			} else if new_proportion_part >= new_upper_limit_part {
-				temp_proportion_part = new_upper_limit_part;
-			}
-
-			let new_pass_voting_ratio = ProportionStructure {
-				proportion_part: temp_proportion_part,
-				upper_limit_part: new_upper_limit_part,
-			};
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			VotePassRatio::<T>::set(new_pass_voting_ratio);
+			VotePassRatio::<T>::set(new_vote_pass_ratio);
 
-			Self::deposit_event(Event::VotePassRatioUpdated(
-				temp_proportion_part,
-				new_upper_limit_part,
-			));
+			Self::deposit_event(Event::VotePassRatioUpdated(new_vote_pass_ratio));
 
 			Ok(().into())
 		}
@@ -929,43 +1916,19 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::update_penalty_levels())]
 		pub fn update_penalty_levels(
 			origin: OriginFor<T>,
-			new_penalty_levels: BTreeMap<u8, BalanceOf<T>>,
+			new_penalty_levels: BTreeMap<u8, Perbill>,
 		) -> DispatchResultWithPostInfo {
-			let user = ensure_signed(origin)?;
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Check if caller is a Authority account
-			ensure!(
-				AuthorityAccounts::<T>::get().contains(&user.clone()),
-				Error::<T>::Unauthorized
-			);
+			// Check if the penalty levels are valid
+			Self::validate_penalty_levels(&new_penalty_levels)?;
 
-			// Check if all penalty levels have been submitted
-			ensure!(
-				new_penalty_levels.len() == 5,
-				Error::<T>::NotAllPenaltyLevelsHaveBeenSubmitted
+			PenaltyLevels::<T>::set(
+				PenaltyLevelsOf::<T>::try_from(new_penalty_levels.clone())
+					.map_err(|_| Error::<T>::NotAllPenaltyLevelsHaveBeenSubmitted)?,
 			);
 
-			// Check if all levels are given as a 5 number value
-			for (level, value) in new_penalty_levels.iter() {
-				ensure!(
-					(*value / BalanceOf::<T>::from(10000u32) > BalanceOf::<T>::from(0u32)
-						&& *value / BalanceOf::<T>::from(10000u32) < BalanceOf::<T>::from(9u32)),
-					Error::<T>::InvalidPenaltyLevelValue
-				);
-
-				if *level == 4 {
-					continue;
-				}
-
-				// Check if levels are of a increasing order
-				ensure!(
-					new_penalty_levels[&level] < new_penalty_levels[&(level + 1)],
-					Error::<T>::InvalidPenaltyLevelValue
-				)
-			}
-
-			PenaltyLevels::<T>::set(new_penalty_levels.clone());
-
 			Self::deposit_event(Event::PenaltyLevelsUpdated(new_penalty_levels));
 
 			Ok(().into())
@@ -976,41 +1939,21 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::update_beneficiary_splits())]
 		pub fn update_beneficiary_splits(
 			origin: OriginFor<T>,
-			new_beneficiary_splits: BTreeMap<u8, BalanceOf<T>>,
+			new_beneficiary_splits: BTreeMap<BeneficiarySplitType, Permill>,
 		) -> DispatchResultWithPostInfo {
-			// Note: The structure of the map is as follows:
-			// 0 -> beneficiary split for validator during the initial sale
-			// 1 -> beneficiary split for validator during the secondary sale
-			// 2 -> beneficiary split for owners during the secondary sale
-
 			// Note: Beneficiary splits can't exceed 50% (either inital or secondary sale)
 
-			let user = ensure_signed(origin)?;
-
-			// Check if caller is a Authority account
-			ensure!(
-				AuthorityAccounts::<T>::get().contains(&user.clone()),
-				Error::<T>::Unauthorized
-			);
-
-			// Check if the user submitted an adequate beneficiary split map
-			ensure!(new_beneficiary_splits.len() == 3, Error::<T>::InvalidBeneficiarySplitValues);
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Check if the primary sale beneficiary split (for validators) exceeds 50%
-			ensure!(
-				new_beneficiary_splits[&0] <= BalanceOf::<T>::from(5000u32),
-				Error::<T>::InvalidPrimarySaleBeneficiarySplit
-			);
+			// Check if the beneficiary splits are valid
+			Self::validate_beneficiary_splits(&new_beneficiary_splits, Permill::zero())?;
 
-			// Check if the secondary sale beneficiary split (for validators and the project owner) exceeds 50%
-			ensure!(
-				new_beneficiary_splits[&1] + new_beneficiary_splits[&2]
-					<= BalanceOf::<T>::from(5000u32),
-				Error::<T>::InvalidSecondarySaleBeneficiarySplit
+			BeneficiarySplits::<T>::set(
+				BeneficiarySplitsOf::<T>::try_from(new_beneficiary_splits.clone())
+					.map_err(|_| Error::<T>::InvalidBeneficiarySplitValues)?,
 			);
 
-			BeneficiarySplits::<T>::set(new_beneficiary_splits.clone());
-
 			Self::deposit_event(Event::BeneficiarySplitsUpdated(new_beneficiary_splits));
 
 			Ok(().into())
@@ -1024,25 +1967,11 @@ pub mod pallet {
 			time_type: TimeType,
 			new_time_value: BlockNumber<T>,
 		) -> DispatchResultWithPostInfo {
-			let user = ensure_signed(origin)?;
-
-			// Check if caller is a Authority account
-			ensure!(
-				AuthorityAccounts::<T>::get().contains(&user.clone()),
-				Error::<T>::Unauthorized
-			);
-
-			// Check if the user is trying to update the pallet base time
-			ensure!(
-				time_type != TimeType::PalletBaseTime,
-				Error::<T>::UnableToChangePalletBaseTime
-			);
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			// Check if the new time value is not 0
-			ensure!(
-				new_time_value != BlockNumber::<T>::from(0u32),
-				Error::<T>::InvalidTimeoutValue
-			);
+			// Check if the time value is valid
+			Self::validate_time_value(&time_type, new_time_value)?;
 
 			let mut pallet_times = PalletTimeValues::<T>::get();
 
@@ -1098,13 +2027,8 @@ pub mod pallet {
 			fee_type: FeeType,
 			new_fee_value: BalanceOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let user = ensure_signed(origin)?;
-
-			// Check if caller is a Authority account
-			ensure!(
-				AuthorityAccounts::<T>::get().contains(&user.clone()),
-				Error::<T>::Unauthorized
-			);
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
 
 			let mut pallet_fees = PalletFeeValues::<T>::get();
 
@@ -1177,6 +2101,33 @@ pub mod pallet {
 
 					pallet_fees = FeeValues { complaint_fee: new_fee_value, ..pallet_fees };
 				},
+				FeeType::TradingFee(role) => {
+					// Note: Trading fees are set in parts per million of the fill price
+					ensure!(
+						new_fee_value
+							<= BalanceOf::<T>::from(T::MaxTradingFee::get().deconstruct()),
+						Error::<T>::TradingFeeTooHigh,
+					);
+
+					let new_trading_fee =
+						Permill::from_parts(new_fee_value.try_into().unwrap_or(u32::MAX));
+
+					let mut trading_fees = TradingFees::<T>::get();
+
+					let current_fee_value = match role {
+						TradingFeeRole::Maker => &mut trading_fees.maker_fee,
+						TradingFeeRole::Taker => &mut trading_fees.taker_fee,
+					};
+
+					ensure!(
+						new_trading_fee != *current_fee_value,
+						Error::<T>::UpdatingToCurrentValue,
+					);
+
+					*current_fee_value = new_trading_fee;
+
+					TradingFees::<T>::set(trading_fees);
+				},
 			}
 
 			PalletFeeValues::<T>::set(pallet_fees);
@@ -1203,22 +2154,22 @@ pub mod pallet {
 
 			// Check if caller has sufficient funds
 			ensure!(
-				PalletFeeValues::<T>::get().trader_account_fee
-					<= T::Currency::free_balance(&user.clone()),
+				PalletFeeValues::<T>::get().trader_account_fee <= T::Currency::balance(&user),
 				Error::<T>::InsufficientFunds
 			);
 
 			// Insert trader account
-			let mut new_traders = TraderAccounts::<T>::get();
-			new_traders.insert(user.clone());
-			TraderAccounts::<T>::set(new_traders);
+			TraderAccounts::<T>::insert(
+				user.clone(),
+				TraderAccountInfo { registration_date: T::Time::now() },
+			);
 
 			// Transfer funds
 			T::Currency::transfer(
 				&user,
 				&Self::pallet_id(),
 				PalletFeeValues::<T>::get().trader_account_fee,
-				ExistenceRequirement::KeepAlive,
+				Preservation::Preserve,
 			)?;
 
 			// Deposit event
@@ -1254,7 +2205,7 @@ pub mod pallet {
 			// Check if caller has sufficient funds
 			ensure!(
 				PalletFeeValues::<T>::get().project_validator_account_fee
-					<= T::Currency::free_balance(&user.clone()),
+					<= T::Currency::balance(&user),
 				Error::<T>::InsufficientFunds
 			);
 
@@ -1267,12 +2218,18 @@ pub mod pallet {
 				};
 			Validators::<T>::insert(user.clone(), validator_info);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				documentation_ipfs.clone(),
+				DocumentationOwner::Validator(user.clone()),
+			);
+
 			// Transfer funds
 			T::Currency::transfer(
 				&user,
 				&Self::pallet_id(),
 				PalletFeeValues::<T>::get().project_validator_account_fee,
-				ExistenceRequirement::KeepAlive,
+				Preservation::Preserve,
 			)?;
 
 			// Deposit event
@@ -1311,7 +2268,7 @@ pub mod pallet {
 			// Check if caller has sufficient funds
 			ensure!(
 				PalletFeeValues::<T>::get().project_owner_account_fee
-					<= T::Currency::free_balance(&user.clone()),
+					<= T::Currency::balance(&user),
 				Error::<T>::InsufficientFunds
 			);
 
@@ -1324,12 +2281,18 @@ pub mod pallet {
 				};
 			ProjectOwners::<T>::insert(user.clone(), owner_info);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				documentation_ipfs.clone(),
+				DocumentationOwner::ProjectOwner(user.clone()),
+			);
+
 			// Transfer funds
 			T::Currency::transfer(
 				&user,
 				&Self::pallet_id(),
 				PalletFeeValues::<T>::get().project_owner_account_fee,
-				ExistenceRequirement::KeepAlive,
+				Preservation::Preserve,
 			)?;
 
 			// Deposit event
@@ -1370,6 +2333,12 @@ pub mod pallet {
 				Error::<T>::CarbonFootprintReportAlreadySubmitted
 			);
 
+			// Check if the carbon footprint account can hold another report documentation
+			ensure!(
+				Self::has_room_for_cfa_documentation(user.clone()),
+				Error::<T>::TooManyDocuments
+			);
+
 			// Check if the documentation (IPFS link) has been used previously
 			ensure!(
 				Self::is_ipfs_available(ipfs.clone()),
@@ -1379,7 +2348,7 @@ pub mod pallet {
 			// Check if caller has sufficient funds
 			ensure!(
 				PalletFeeValues::<T>::get().carbon_footprint_report_fee
-					<= T::Currency::free_balance(&user.clone()),
+					<= T::Currency::balance(&user),
 				Error::<T>::InsufficientFunds
 			);
 
@@ -1392,34 +2361,42 @@ pub mod pallet {
 				creation_date,
 				carbon_footprint_deficit,
 				carbon_footprint_surplus,
-				votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-				votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
 				voting_active: true,
 			};
 
 			// Write to info storage
 			CarbonFootprintReports::<T>::insert(ipfs.clone(), report_info);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				ipfs.clone(),
+				DocumentationOwner::CarbonFootprintReport(user.clone()),
+			);
+
 			// Set voting timeout
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
 
-			let mut timeout_events = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+			let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
 
 			if VotingTimeouts::<T>::contains_key(timeout_block) {
 				timeout_events = VotingTimeouts::<T>::get(timeout_block).unwrap();
 			}
 
-			timeout_events.insert(ipfs.clone());
+			timeout_events
+				.try_insert(ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
 
 			VotingTimeouts::<T>::insert(timeout_block, timeout_events);
 
-			// Transfer funds
-			T::Currency::transfer(
-				&user,
-				&Self::pallet_id(),
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				user.clone(),
+				ipfs.clone(),
 				PalletFeeValues::<T>::get().carbon_footprint_report_fee,
-				ExistenceRequirement::KeepAlive,
+				HoldReason::ProposalDeposit,
 			)?;
 
 			// Deposit event
@@ -1445,13 +2422,10 @@ pub mod pallet {
 			let amount_to_pay = Self::calculate_basic_payment_made_to_pallet(
 				user.clone(),
 				PalletFeeValues::<T>::get().voting_fee,
-			);
+			)?;
 
 			// Check if caller has sufficient funds
-			ensure!(
-				amount_to_pay <= T::Currency::free_balance(&user.clone()),
-				Error::<T>::InsufficientFunds
-			);
+			ensure!(amount_to_pay <= T::Currency::balance(&user), Error::<T>::InsufficientFunds);
 
 			match vote_type {
 				VoteType::CarbonFootprintReportVote => {
@@ -1469,9 +2443,15 @@ pub mod pallet {
 					);
 
 					if vote {
-						report.votes_for.insert(user.clone());
+						report
+							.votes_for
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					} else {
-						report.votes_against.insert(user.clone());
+						report
+							.votes_against
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					};
 
 					CarbonFootprintReports::<T>::insert(ipfs.clone(), report);
@@ -1492,9 +2472,15 @@ pub mod pallet {
 					);
 
 					if vote {
-						proposal.votes_for.insert(user.clone());
+						proposal
+							.votes_for
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					} else {
-						proposal.votes_against.insert(user.clone());
+						proposal
+							.votes_against
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					};
 
 					ProjectProposals::<T>::insert(ipfs.clone(), proposal);
@@ -1514,9 +2500,15 @@ pub mod pallet {
 					);
 
 					if vote {
-						batch.votes_for.insert(user.clone());
+						batch
+							.votes_for
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					} else {
-						batch.votes_against.insert(user.clone());
+						batch
+							.votes_against
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
 					};
 
 					CarbonCreditBatchProposals::<T>::insert(ipfs.clone(), batch);
@@ -1541,9 +2533,15 @@ pub mod pallet {
 						);
 
 						if vote {
-							complaint.votes_for.insert(user.clone());
+							complaint
+								.votes_for
+								.try_insert(user.clone())
+								.map_err(|_| Error::<T>::TooManyVotes)?;
 						} else {
-							complaint.votes_against.insert(user.clone());
+							complaint
+								.votes_against
+								.try_insert(user.clone())
+								.map_err(|_| Error::<T>::TooManyVotes)?;
 						};
 
 						ComplaintsForAccounts::<T>::insert(ipfs.clone(), complaint);
@@ -1563,9 +2561,15 @@ pub mod pallet {
 						);
 
 						if vote {
-							complaint.votes_for.insert(user.clone());
+							complaint
+								.votes_for
+								.try_insert(user.clone())
+								.map_err(|_| Error::<T>::TooManyVotes)?;
 						} else {
-							complaint.votes_against.insert(user.clone());
+							complaint
+								.votes_against
+								.try_insert(user.clone())
+								.map_err(|_| Error::<T>::TooManyVotes)?;
 						};
 
 						ComplaintsForHashes::<T>::insert(ipfs.clone(), complaint);
@@ -1573,14 +2577,43 @@ pub mod pallet {
 
 					ensure!(complaint_exists, Error::<T>::ComplaintNotFound);
 				},
-			}
+				VoteType::BeneficiarySplitVote => {
+					// Get beneficiary split proposal info or return error if it does not exist
+					let mut proposal = BeneficiarySplitProposals::<T>::get(ipfs.clone())
+						.ok_or(Error::<T>::BeneficiarySplitProposalNotFound)?;
 
-			// Transfer funds
-			T::Currency::transfer(
+					// Check if the voting cycle is over
+					ensure!(proposal.voting_active, Error::<T>::VotingCycleIsOver);
+
+					// Check if vote already exists
+					ensure!(
+						!proposal.votes_for.contains(&user)
+							&& !proposal.votes_against.contains(&user),
+						Error::<T>::VoteAlreadySubmitted
+					);
+
+					if vote {
+						proposal
+							.votes_for
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
+					} else {
+						proposal
+							.votes_against
+							.try_insert(user.clone())
+							.map_err(|_| Error::<T>::TooManyVotes)?;
+					};
+
+					BeneficiarySplitProposals::<T>::insert(ipfs.clone(), proposal);
+				},
+			}
+
+			// Transfer funds
+			T::Currency::transfer(
 				&user,
 				&Self::pallet_id(),
 				amount_to_pay,
-				ExistenceRequirement::KeepAlive,
+				Preservation::Preserve,
 			)?;
 
 			Self::deposit_event(Event::SuccessfulVote(user.clone(), ipfs.clone(), vote_type, vote));
@@ -1612,6 +2645,9 @@ pub mod pallet {
 				Error::<T>::ProjectProposalAlreadyExists
 			);
 
+			// Check if the project owner can hold another project
+			ensure!(Self::has_room_for_project(user.clone()), Error::<T>::TooManyProjects);
+
 			// Check if the documentation (IPFS link) has been used previously
 			ensure!(
 				Self::is_ipfs_available(ipfs.clone()),
@@ -1621,13 +2657,10 @@ pub mod pallet {
 			let amount_to_pay = Self::calculate_basic_payment_made_to_pallet(
 				user.clone(),
 				PalletFeeValues::<T>::get().project_proposal_fee,
-			);
+			)?;
 
 			// Check if caller has sufficient funds
-			ensure!(
-				amount_to_pay <= T::Currency::free_balance(&user.clone()),
-				Error::<T>::InsufficientFunds
-			);
+			ensure!(amount_to_pay <= T::Currency::balance(&user), Error::<T>::InsufficientFunds);
 
 			// Get time
 			let creation_date = T::Time::now();
@@ -1640,34 +2673,42 @@ pub mod pallet {
 				project_owner: user.clone(),
 				creation_date,
 				project_hash,
-				votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-				votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
 				voting_active: true,
 			};
 
 			// Write to info storage
 			ProjectProposals::<T>::insert(ipfs.clone(), proposal_info);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				ipfs.clone(),
+				DocumentationOwner::ProjectProposal(project_hash),
+			);
+
 			// Set for voting timeout
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
 
-			let mut timeout_events = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+			let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
 
 			if VotingTimeouts::<T>::contains_key(timeout_block) {
 				timeout_events = VotingTimeouts::<T>::get(timeout_block).unwrap();
 			}
 
-			timeout_events.insert(ipfs.clone());
+			timeout_events
+				.try_insert(ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
 
 			VotingTimeouts::<T>::insert(timeout_block, timeout_events);
 
-			// Transfer funds
-			T::Currency::transfer(
-				&user,
-				&Self::pallet_id(),
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				user.clone(),
+				ipfs.clone(),
 				amount_to_pay,
-				ExistenceRequirement::KeepAlive,
+				HoldReason::ProposalDeposit,
 			)?;
 
 			// Deposit event
@@ -1709,6 +2750,9 @@ pub mod pallet {
 			// Check if the owner owns the mentioned project
 			ensure!(project.project_owner == user, Error::<T>::Unauthorized);
 
+			// Check if the project can hold another carbon credit batch
+			ensure!(Self::has_room_for_batch(project_hash), Error::<T>::TooManyBatches);
+
 			// Check if the documentation (IPFS link) has been used previously
 			ensure!(
 				Self::is_ipfs_available(ipfs.clone()),
@@ -1719,13 +2763,10 @@ pub mod pallet {
 				project.project_owner,
 				project_hash,
 				PalletFeeValues::<T>::get().carbon_credit_batch_fee,
-			);
+			)?;
 
 			// Check if caller has sufficient funds
-			ensure!(
-				amount_to_pay <= T::Currency::free_balance(&user.clone()),
-				Error::<T>::InsufficientFunds
-			);
+			ensure!(amount_to_pay <= T::Currency::balance(&user), Error::<T>::InsufficientFunds);
 
 			// Create batch hash
 			let batch_hash = Self::generate_hash(user.clone());
@@ -1740,34 +2781,42 @@ pub mod pallet {
 				creation_date,
 				credit_amount,
 				penalty_repay_price,
-				votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-				votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
 				voting_active: true,
 			};
 
 			// Write to info storage
 			CarbonCreditBatchProposals::<T>::insert(ipfs.clone(), proposal_info);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				ipfs.clone(),
+				DocumentationOwner::CarbonCreditBatchProposal(batch_hash),
+			);
+
 			// Set for voting timeout
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
 
-			let mut timeout_events = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+			let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
 
 			if VotingTimeouts::<T>::contains_key(timeout_block) {
 				timeout_events = VotingTimeouts::<T>::get(timeout_block).unwrap();
 			}
 
-			timeout_events.insert(ipfs.clone());
+			timeout_events
+				.try_insert(ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
 
 			VotingTimeouts::<T>::insert(timeout_block, timeout_events);
 
-			// Transfer funds
-			T::Currency::transfer(
-				&user,
-				&Self::pallet_id(),
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				user.clone(),
+				ipfs.clone(),
 				PalletFeeValues::<T>::get().carbon_credit_batch_fee,
-				ExistenceRequirement::KeepAlive,
+				HoldReason::ProposalDeposit,
 			)?;
 
 			// Deposit event
@@ -1777,6 +2826,8 @@ pub mod pallet {
 		}
 
 		// Create carbon credit sale order
+		// Note: Without a minimum fill amount the sale order can be filled in any amount
+		// Note: With allowed buyers (private sale order) only they can fill the sale order
 		#[pallet::call_index(12)]
 		#[pallet::weight(<T as Config>::WeightInfo::create_sale_order())]
 		pub fn create_sale_order(
@@ -1784,6 +2835,9 @@ pub mod pallet {
 			batch_hash: H256,
 			credit_price: BalanceOf<T>,
 			credit_amount: BalanceOf<T>,
+			min_fill_amount: Option<BalanceOf<T>>,
+			allowed_buyers: Option<AllowedBuyersOf<T>>,
+			expiry: Option<SaleOrderExpiry<BlockNumber<T>>>,
 		) -> DispatchResultWithPostInfo {
 			let seller = ensure_signed(origin)?;
 
@@ -1807,6 +2861,22 @@ pub mod pallet {
 				Error::<T>::InvalidCarbonCreditAmount
 			);
 
+			// Check if the minimum fill amount is valid
+			let min_fill_amount = min_fill_amount.unwrap_or(BalanceOf::<T>::from(1u32));
+
+			ensure!(
+				min_fill_amount > BalanceOf::<T>::from(0u32) && min_fill_amount <= credit_amount,
+				Error::<T>::InvalidMinFillAmount
+			);
+
+			// Check if the allowed buyers are valid
+			if let Some(allowed_buyers) = &allowed_buyers {
+				ensure!(
+					!allowed_buyers.is_empty() && !allowed_buyers.contains(&seller),
+					Error::<T>::InvalidAllowedBuyers
+				);
+			}
+
 			// Check if carbon credit batch exists
 			ensure!(
 				CarbonCreditBatches::<T>::contains_key(batch_hash),
@@ -1840,34 +2910,30 @@ pub mod pallet {
 			let sale_hash = Self::generate_hash(seller.clone());
 
 			// Set sale timeout
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
+			let timeout_block = Self::sale_order_timeout(expiry)?;
 
 			// Create a carbon credit sale order
-			// Note: If the buyer ID is the same as the seller we know that
-			// the sale has not been finalized
 			let sale_order = CarbonCreditSaleOrderInfo {
 				batch_hash,
 				credit_amount,
+				min_fill_amount,
 				credit_price,
 				seller: seller.clone(),
-				buyer: seller.clone(),
-				sale_active: true,
+				buyer: None,
+				status: SaleOrderStatus::Open,
 				sale_timeout: timeout_block,
 			};
 
 			CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
 
-			// Create a carbon credit sale order timeout event
-			let mut sale_timeouts = BTreeSet::<H256>::new();
-
-			if SaleOrderTimeouts::<T>::contains_key(timeout_block) {
-				sale_timeouts = SaleOrderTimeouts::<T>::get(current_block).unwrap();
+			if let Some(allowed_buyers) = allowed_buyers {
+				SaleOrderAllowedBuyers::<T>::insert(sale_hash, allowed_buyers);
 			}
 
-			sale_timeouts.insert(sale_hash);
-
-			SaleOrderTimeouts::<T>::insert(timeout_block, sale_timeouts);
+			// Create a carbon credit sale order timeout event
+			if let Some(timeout_block) = timeout_block {
+				Self::insert_sale_order_timeout(timeout_block, sale_hash)?;
+			}
 
 			// Update seller carbon credit holdings
 			seller_holdings = CarbonCreditHoldingsInfo {
@@ -1894,6 +2960,7 @@ pub mod pallet {
 		pub fn complete_sale_order(
 			origin: OriginFor<T>,
 			sale_hash: H256,
+			amount: Option<BalanceOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			let buyer = ensure_signed(origin)?;
 
@@ -1919,9 +2986,20 @@ pub mod pallet {
 
 			let mut sale_order = CarbonCreditSaleOrders::<T>::get(sale_hash).unwrap();
 
+			// Check if the sale order is active
+			ensure!(sale_order.status == SaleOrderStatus::Open, Error::<T>::SaleOrderIsNotActive);
+
 			// Check if the buyer isn't the seller
 			ensure!(buyer != sale_order.seller, Error::<T>::BuyerCantBuyHisOwnTokens);
 
+			// Check if the buyer is allowed to fill the sale order (private sale orders)
+			if let Some(allowed_buyers) = SaleOrderAllowedBuyers::<T>::get(sale_hash) {
+				ensure!(
+					allowed_buyers.contains(&buyer),
+					Error::<T>::BuyerIsNotAllowedToFillTheSaleOrder
+				);
+			}
+
 			// Check if carbon credit batch exists
 			ensure!(
 				CarbonCreditBatches::<T>::contains_key(sale_order.batch_hash),
@@ -1936,63 +3014,115 @@ pub mod pallet {
 				Error::<T>::CarbonCreditBatchIsNotActive,
 			);
 
-			// Check if the buyer has enough assets
-			let mut amount_to_pay = sale_order.credit_amount * sale_order.credit_price;
+			// Check if the fill amount is valid
+			// Note: Without an amount the whole remaining sale order amount is bought
+			let fill_amount = amount.unwrap_or(sale_order.credit_amount);
 
 			ensure!(
-				amount_to_pay <= T::Currency::free_balance(&buyer.clone()),
-				Error::<T>::InsufficientFunds
+				fill_amount > BalanceOf::<T>::from(0u32) && fill_amount <= sale_order.credit_amount,
+				Error::<T>::InvalidFillAmount
 			);
 
-			let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
+			let is_last_fill = fill_amount == sale_order.credit_amount;
 
-			let beneficiary_splits = BeneficiarySplits::<T>::get();
+			// Check if the fill amount reaches the minimum fill amount
+			// Note: The last fill can be smaller, so that the sale order can always be filled
+			ensure!(
+				is_last_fill || fill_amount >= sale_order.min_fill_amount,
+				Error::<T>::FillAmountBelowMinimum
+			);
 
-			if project.project_owner == sale_order.seller {
-				// Do primary beneficiary split
-				let validator_gains =
-					amount_to_pay * beneficiary_splits[&0] / BalanceOf::<T>::from(10000u32);
+			// Check if the fill can be recorded
+			// Note: The last fill that fits into the fill history has to take the remaining amount
+			let mut fills = SaleOrderFills::<T>::get(sale_hash).unwrap_or_default();
 
-				Self::process_validator_gains(
-					buyer.clone(),
-					carbon_credit_batch.validator_benefactors,
-					validator_gains,
-				);
+			ensure!(
+				is_last_fill || (fills.len() as u32) + 1 < T::MaxFillsPerSaleOrder::get(),
+				Error::<T>::TooManyFills
+			);
 
-				amount_to_pay -= validator_gains;
-			} else {
-				// Do secondary beneficiary split
-				let validator_gains =
-					amount_to_pay * beneficiary_splits[&1] / BalanceOf::<T>::from(10000u32);
-				let owner_gains =
-					amount_to_pay * beneficiary_splits[&2] / BalanceOf::<T>::from(10000u32);
+			let mut amount_to_pay = fill_amount
+				.checked_mul(&sale_order.credit_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let (maker_fee, taker_fee) = Self::calculate_trading_fees(amount_to_pay);
+
+			fills
+				.try_push(SaleOrderFillInfo {
+					buyer: buyer.clone(),
+					credit_amount: fill_amount,
+					credit_price: sale_order.credit_price,
+					fill_date: T::Time::now(),
+					maker_fee,
+					taker_fee,
+				})
+				.map_err(|_| Error::<T>::TooManyFills)?;
 
-				Self::process_validator_gains(
-					buyer.clone(),
-					carbon_credit_batch.validator_benefactors,
-					validator_gains,
-				);
+			// Check if the buyer has enough assets
+			// Note: The buyer pays the taker fee on top of the fill price
+			ensure!(
+				amount_to_pay.checked_add(&taker_fee).ok_or(Error::<T>::ArithmeticOverflow)?
+					<= T::Currency::balance(&buyer),
+				Error::<T>::InsufficientFunds
+			);
 
-				Self::process_owner_gains(buyer.clone(), project.project_owner, owner_gains);
+			let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
 
-				amount_to_pay -= validator_gains;
-				amount_to_pay -= owner_gains;
-			}
+			// Do beneficiary splits (for every fill)
+			let split_gains = Self::process_beneficiary_splits(
+				buyer.clone(),
+				&sale_order.seller,
+				carbon_credit_batch.project_hash,
+				project.project_owner,
+				carbon_credit_batch.validator_benefactors,
+				amount_to_pay,
+			)?;
+
+			amount_to_pay =
+				amount_to_pay.checked_sub(&split_gains).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Pay trading fees
+			// Note: The seller is the maker and the buyer is the taker
+			amount_to_pay =
+				Self::process_trading_fees(&buyer, amount_to_pay, taker_fee, maker_fee)?;
 
 			// Transfer funds
 			T::Currency::transfer(
 				&buyer,
 				&sale_order.seller,
 				amount_to_pay,
-				ExistenceRequirement::KeepAlive,
+				Preservation::Preserve,
 			)?;
 
+			// Total trading fees of the sale order
+			let (total_maker_fee, total_taker_fee) = fills.iter().fold(
+				(BalanceOf::<T>::from(0u32), BalanceOf::<T>::from(0u32)),
+				|(maker_fees, taker_fees), fill| {
+					(
+						maker_fees.saturating_add(fill.maker_fee),
+						taker_fees.saturating_add(fill.taker_fee),
+					)
+				},
+			);
+
+			SaleOrderFills::<T>::insert(sale_hash, fills);
+
 			// Update sell order
-			sale_order = CarbonCreditSaleOrderInfo {
-				buyer: buyer.clone(),
-				sale_active: false,
-				..sale_order
-			};
+			// Note: The sale order is closed once it has been fully filled
+			sale_order.credit_amount -= fill_amount;
+
+			if is_last_fill {
+				sale_order = CarbonCreditSaleOrderInfo {
+					buyer: Some(buyer.clone()),
+					status: SaleOrderStatus::Filled,
+					..sale_order
+				};
+
+				if let Some(sale_timeout) = sale_order.sale_timeout {
+					Self::remove_sale_order_timeout(sale_timeout, sale_hash);
+				}
+
+				Self::insert_pruning_timeout(FinishedRecord::CarbonCreditSaleOrder(sale_hash));
+			}
 
 			CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order.clone());
 
@@ -2002,7 +3132,7 @@ pub mod pallet {
 					.unwrap();
 
 			seller_holdings = CarbonCreditHoldingsInfo {
-				unavailable_amount: seller_holdings.unavailable_amount - sale_order.credit_amount,
+				unavailable_amount: seller_holdings.unavailable_amount - fill_amount,
 				..seller_holdings
 			};
 
@@ -2025,7 +3155,7 @@ pub mod pallet {
 			// Note: First we will check if the buyer has some preexisting holdings and if that's the case we will update them
 			//		 If that is not the case we will create a new holdings entity
 			let mut buyer_holdings = CarbonCreditHoldingsInfo {
-				available_amount: sale_order.credit_amount,
+				available_amount: fill_amount,
 				unavailable_amount: BalanceOf::<T>::from(0u32),
 			};
 
@@ -2034,27 +3164,29 @@ pub mod pallet {
 					CarbonCreditHoldings::<T>::get(sale_order.batch_hash, buyer.clone()).unwrap();
 
 				buyer_holdings = CarbonCreditHoldingsInfo {
-					available_amount: buyer_holdings.available_amount + sale_order.credit_amount,
+					available_amount: buyer_holdings.available_amount + fill_amount,
 					..buyer_holdings
 				};
 			}
 
 			CarbonCreditHoldings::<T>::insert(sale_order.batch_hash, buyer.clone(), buyer_holdings);
 
-			// Remove sale timeout event
-			let mut sale_timeouts = SaleOrderTimeouts::<T>::get(sale_order.sale_timeout).unwrap();
-
-			sale_timeouts.remove(&sale_hash);
+			// Deposit events
+			Self::deposit_event(Event::CarbonCreditSaleOrderFilled(
+				buyer.clone(),
+				sale_hash,
+				fill_amount,
+			));
 
-			if sale_timeouts.len() == 0 {
-				SaleOrderTimeouts::<T>::remove(sale_order.sale_timeout);
-			} else {
-				SaleOrderTimeouts::<T>::insert(sale_order.sale_timeout, sale_timeouts);
+			if is_last_fill {
+				Self::deposit_event(Event::CarbonCreditSaleOrderCompleted(
+					buyer,
+					sale_hash,
+					total_maker_fee,
+					total_taker_fee,
+				));
 			}
 
-			// Deposit event
-			Self::deposit_event(Event::CarbonCreditSaleOrderCompleted(buyer, sale_hash));
-
 			Ok(().into())
 		}
 
@@ -2090,7 +3222,7 @@ pub mod pallet {
 			let mut sale_order = CarbonCreditSaleOrders::<T>::get(sale_hash).unwrap();
 
 			// Check if the sale order is still active
-			ensure!(sale_order.sale_active, Error::<T>::SaleOrderIsNotActive);
+			ensure!(sale_order.status == SaleOrderStatus::Open, Error::<T>::SaleOrderIsNotActive);
 
 			// Check if the seller created the sale order
 			ensure!(seller == sale_order.seller, Error::<T>::UserDidntCreateTheSaleOrder);
@@ -2117,19 +3249,16 @@ pub mod pallet {
 			);
 
 			// Update sale order
-			sale_order = CarbonCreditSaleOrderInfo { sale_active: false, ..sale_order };
+			sale_order =
+				CarbonCreditSaleOrderInfo { status: SaleOrderStatus::Cancelled, ..sale_order };
 
 			CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order.clone());
 
-			// Remove sale order timeout
-			let mut sale_timeouts = SaleOrderTimeouts::<T>::get(sale_order.sale_timeout).unwrap();
-
-			sale_timeouts.remove(&sale_hash);
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditSaleOrder(sale_hash));
 
-			if sale_timeouts.len() == 0 {
-				SaleOrderTimeouts::<T>::remove(sale_order.sale_timeout);
-			} else {
-				SaleOrderTimeouts::<T>::insert(sale_order.sale_timeout, sale_timeouts);
+			// Remove sale order timeout
+			if let Some(sale_timeout) = sale_order.sale_timeout {
+				Self::remove_sale_order_timeout(sale_timeout, sale_hash);
 			}
 
 			// Deposit event
@@ -2140,7 +3269,9 @@ pub mod pallet {
 
 		// Open complaint (for AccountId entity)
 		#[pallet::call_index(15)]
-		#[pallet::weight(<T as Config>::WeightInfo::open_account_complaint())]
+		#[pallet::weight(<T as Config>::WeightInfo::open_account_complaint(
+			Self::owner_batch_count(complaint_for)
+		))]
 		pub fn open_account_complaint(
 			origin: OriginFor<T>,
 			documentation_ipfs: BoundedString<T::IPFSLength>,
@@ -2164,11 +3295,11 @@ pub mod pallet {
 			let amount_to_pay = Self::calculate_basic_payment_made_to_pallet(
 				validator.clone(),
 				PalletFeeValues::<T>::get().complaint_fee,
-			);
+			)?;
 
 			// Check if the proposer has enough credits
 			ensure!(
-				amount_to_pay <= T::Currency::free_balance(&validator.clone()),
+				amount_to_pay <= T::Currency::balance(&validator),
 				Error::<T>::InsufficientFunds
 			);
 
@@ -2206,33 +3337,41 @@ pub mod pallet {
 				complaint_type: complaint_type.clone(),
 				complaint_proposer: validator.clone(),
 				creation_date: T::Time::now(),
-				votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-				votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
 				complaint_active: true,
 			};
 
 			ComplaintsForAccounts::<T>::insert(documentation_ipfs.clone(), complaint);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				documentation_ipfs.clone(),
+				DocumentationOwner::AccountComplaint(complaint_for.clone()),
+			);
+
 			// Save complaint timeout event
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
 
-			let mut timeout_events = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+			let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
 
 			if ComplaintTimeouts::<T>::contains_key(timeout_block) {
 				timeout_events = ComplaintTimeouts::<T>::get(timeout_block).unwrap();
 			}
 
-			timeout_events.insert(documentation_ipfs.clone());
+			timeout_events
+				.try_insert(documentation_ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
 
 			ComplaintTimeouts::<T>::insert(timeout_block, timeout_events);
 
-			// Transfer funds
-			T::Currency::transfer(
-				&validator,
-				&Self::pallet_id(),
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				validator.clone(),
+				documentation_ipfs.clone(),
 				amount_to_pay,
-				ExistenceRequirement::KeepAlive,
+				HoldReason::ComplaintDeposit,
 			)?;
 
 			// Deposit event
@@ -2248,7 +3387,9 @@ pub mod pallet {
 
 		// Open complaint (for hash entity)
 		#[pallet::call_index(16)]
-		#[pallet::weight(<T as Config>::WeightInfo::open_hash_complaint())]
+		#[pallet::weight(<T as Config>::WeightInfo::open_hash_complaint(
+			Self::project_batch_count(*complaint_for)
+		))]
 		pub fn open_hash_complaint(
 			origin: OriginFor<T>,
 			documentation_ipfs: BoundedString<T::IPFSLength>,
@@ -2272,8 +3413,7 @@ pub mod pallet {
 			// Check if the proposer has enough credits
 			// TODO: Implement penalty tax
 			ensure!(
-				PalletFeeValues::<T>::get().complaint_fee
-					<= T::Currency::free_balance(&validator.clone()),
+				PalletFeeValues::<T>::get().complaint_fee <= T::Currency::balance(&validator),
 				Error::<T>::InsufficientFunds
 			);
 
@@ -2333,33 +3473,41 @@ pub mod pallet {
 				complaint_type: complaint_type.clone(),
 				complaint_proposer: validator.clone(),
 				creation_date: T::Time::now(),
-				votes_for: BTreeSet::<AccountIdOf<T>>::new(),
-				votes_against: BTreeSet::<AccountIdOf<T>>::new(),
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
 				complaint_active: true,
 			};
 
 			ComplaintsForHashes::<T>::insert(documentation_ipfs.clone(), complaint);
 
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				documentation_ipfs.clone(),
+				DocumentationOwner::HashComplaint(complaint_for),
+			);
+
 			// Save complaint timeout event
 			let current_block = frame_system::Pallet::<T>::block_number();
 			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
 
-			let mut timeout_events = BTreeSet::<BoundedString<T::IPFSLength>>::new();
+			let mut timeout_events = TimeoutsOf::<T, BoundedString<T::IPFSLength>>::new();
 
 			if ComplaintTimeouts::<T>::contains_key(timeout_block) {
 				timeout_events = ComplaintTimeouts::<T>::get(timeout_block).unwrap();
 			}
 
-			timeout_events.insert(documentation_ipfs.clone());
+			timeout_events
+				.try_insert(documentation_ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
 
 			ComplaintTimeouts::<T>::insert(timeout_block, timeout_events);
 
-			// Transfer funds
-			T::Currency::transfer(
-				&validator,
-				&Self::pallet_id(),
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				validator.clone(),
+				documentation_ipfs.clone(),
 				PalletFeeValues::<T>::get().complaint_fee,
-				ExistenceRequirement::KeepAlive,
+				HoldReason::ComplaintDeposit,
 			)?;
 
 			// Deposit event
@@ -2468,6 +3616,13 @@ pub mod pallet {
 
 			CarbonCreditRetirements::<T>::insert(retirement_hash, retirement_info);
 
+			RetirementsByBatch::<T>::try_mutate(batch_hash, |retirements| {
+				retirements
+					.get_or_insert_with(BoundedBTreeSet::new)
+					.try_insert(retirement_hash)
+					.map_err(|_| Error::<T>::TooManyRetirements)
+			})?;
+
 			// Deposit event
 			Self::deposit_event(Event::CarbonCreditsHaveBeenRetired(
 				footprint_account,
@@ -2508,19 +3663,14 @@ pub mod pallet {
 
 			// Check to see if user has enough assets
 			ensure!(
-				total_debt <= T::Currency::free_balance(&project_owner),
+				total_debt <= T::Currency::balance(&project_owner),
 				Error::<T>::InsufficientFunds
 			);
 
 			// Repay each debt collector
 			for debt in debts.iter() {
 				// Transfer funds
-				T::Currency::transfer(
-					&project_owner,
-					&debt.0,
-					*debt.1,
-					ExistenceRequirement::KeepAlive,
-				)?;
+				T::Currency::transfer(&project_owner, &debt.0, *debt.1, Preservation::Preserve)?;
 			}
 
 			// Remove project owner debts
@@ -2536,22 +3686,19 @@ pub mod pallet {
 		#[pallet::call_index(19)]
 		#[pallet::weight(<T as Config>::WeightInfo::update_pallet_base_time())]
 		pub fn update_pallet_base_time(
-			_origin: OriginFor<T>,
+			origin: OriginFor<T>,
 			new_pallet_base_time: BlockNumber<T>,
 		) -> DispatchResult {
-			let mut pallet_times = PalletTimeValues::<T>::get();
+			// Check if the call is unsigned
+			ensure_none(origin)?;
 
-			if pallet_times.pallet_base_time == BlockNumber::<T>::from(0u32)
-				|| new_pallet_base_time
-					== pallet_times.pallet_base_time + pallet_times.number_of_blocks_per_year
-			{
-				pallet_times =
-					TimeValues { pallet_base_time: new_pallet_base_time, ..pallet_times };
-
-				PalletTimeValues::<T>::set(pallet_times);
+			// Check if the base pallet time update is due
+			ensure!(
+				Self::is_pallet_base_time_update_due(new_pallet_base_time),
+				Error::<T>::UpdateIsNotDue
+			);
 
-				Self::deposit_event(Event::BasePalletTimeUpdated(new_pallet_base_time));
-			}
+			Self::do_update_pallet_base_time(new_pallet_base_time);
 
 			Ok(())
 		}
@@ -2559,120 +3706,2824 @@ pub mod pallet {
 		#[pallet::call_index(20)]
 		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_footprint_report())]
 		pub fn update_carbon_footprint_report(
-			_origin: OriginFor<T>,
+			origin: OriginFor<T>,
 			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
 		) -> DispatchResult {
-			let report = CarbonFootprintReports::<T>::get(ipfs.clone()).unwrap();
-
-			// Get the votes that were made for the report
-			let votes_for: u16 = report.votes_for.len().try_into().unwrap();
-			let votes_against: u16 = report.votes_against.len().try_into().unwrap();
-			let votes_total: u16 = votes_for + votes_against;
-
-			// Check if the vote has passed
-			if Self::has_vote_passed(votes_total, votes_for) {
-				let mut documentation_ipfses = BTreeSet::<BoundedString<T::IPFSLength>>::new();
-				documentation_ipfses.insert(ipfs.clone());
-
-				// Create an empty carbon footprint account
-				let mut new_account = CarbonFootprintAccountInfo {
-					documentation_ipfses,
-					carbon_footprint_surplus: report.carbon_footprint_surplus,
-					carbon_footprint_deficit: report.carbon_footprint_deficit,
-					creation_date: T::Time::now(),
-				};
-
-				// Check to see if a carbon footprint account with the given accountID exists
-				if CarbonFootprintAccounts::<T>::contains_key(report.cf_account.clone()) {
-					let old_account =
-						CarbonFootprintAccounts::<T>::get(report.cf_account.clone()).unwrap();
+			// Check if the call is unsigned
+			ensure_none(origin)?;
 
-					// Update documentation related to the carbon footprint account
-					let mut new_documentation = old_account.documentation_ipfses;
-					new_documentation.insert(ipfs.clone());
+			// Check if the voting timeout event is due
+			ensure!(
+				Self::is_carbon_footprint_report_update_due(&ipfs, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
 
-					// Update the carbon footprint account structure
-					if report.carbon_footprint_surplus == BalanceOf::<T>::from(0u32) {
-						if report.carbon_footprint_deficit >= old_account.carbon_footprint_surplus {
-							let change_amount = report.carbon_footprint_deficit
-								- old_account.carbon_footprint_surplus;
+			Self::do_update_carbon_footprint_report(ipfs, timeout_block);
 
-							new_account = CarbonFootprintAccountInfo {
-								documentation_ipfses: new_documentation,
-								carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
-								carbon_footprint_deficit: old_account.carbon_footprint_deficit
-									+ change_amount,
-								creation_date: old_account.creation_date,
-							};
-						} else {
-							let change_amount = report.carbon_footprint_surplus
-								- new_account.carbon_footprint_deficit;
+			Ok(())
+		}
 
-							new_account = CarbonFootprintAccountInfo {
-								documentation_ipfses: new_documentation,
-								carbon_footprint_surplus: old_account.carbon_footprint_surplus
-									+ change_amount,
-								carbon_footprint_deficit: BalanceOf::<T>::from(0u32),
-								creation_date: old_account.creation_date,
-							};
-						}
-					} else {
-						if report.carbon_footprint_surplus >= old_account.carbon_footprint_deficit {
-							let change_amount = report.carbon_footprint_surplus
-								- old_account.carbon_footprint_deficit;
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_project_proposal())]
+		pub fn update_project_proposal(
+			origin: OriginFor<T>,
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
 
-							new_account = CarbonFootprintAccountInfo {
-								documentation_ipfses: new_documentation,
-								carbon_footprint_surplus: old_account.carbon_footprint_surplus
-									+ change_amount,
-								carbon_footprint_deficit: BalanceOf::<T>::from(0u32),
-								creation_date: old_account.creation_date,
-							};
-						} else {
-							let change_amount =
-								report.carbon_footprint_deficit - report.carbon_footprint_surplus;
+			// Check if the voting timeout event is due
+			ensure!(
+				Self::is_project_proposal_update_due(&ipfs, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
 
-							new_account = CarbonFootprintAccountInfo {
-								documentation_ipfses: new_documentation,
-								carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
-								carbon_footprint_deficit: old_account.carbon_footprint_deficit
-									+ change_amount,
-								creation_date: old_account.creation_date,
-							};
-						}
-					}
-				}
+			Self::do_update_project_proposal(ipfs, timeout_block);
 
-				// Save the changes made to the carbon footprint account
-				CarbonFootprintAccounts::<T>::insert(report.cf_account.clone(), new_account);
-			}
+			Ok(())
+		}
 
-			// Create new report
-			// Note: Only change is made to the voting_active cycle status
-			let new_report = CarbonFootprintReportInfo {
-				cf_account: report.cf_account.clone(),
-				creation_date: report.creation_date,
-				carbon_footprint_surplus: report.carbon_footprint_surplus,
-				carbon_footprint_deficit: report.carbon_footprint_deficit,
-				votes_for: report.votes_for,
-				votes_against: report.votes_against,
-				voting_active: false,
-			};
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_batch_proposal())]
+		pub fn update_carbon_credit_batch_proposal(
+			origin: OriginFor<T>,
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
 
-			// Save new report
-			CarbonFootprintReports::<T>::insert(ipfs.clone(), new_report);
+			// Check if the voting timeout event is due
+			ensure!(
+				Self::is_carbon_credit_batch_proposal_update_due(&ipfs, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
 
-			Self::deposit_event(Event::CarbonFootprintReportUpdated(ipfs));
+			Self::do_update_carbon_credit_batch_proposal(ipfs, timeout_block);
 
 			Ok(())
 		}
 
-		#[pallet::call_index(21)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_project_proposal())]
-		pub fn update_project_proposal(
-			_origin: OriginFor<T>,
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_sale_order())]
+		pub fn update_carbon_credit_sale_order(
+			origin: OriginFor<T>,
+			sale_hash: H256,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the sale order timeout event is due
+			ensure!(
+				Self::is_carbon_credit_sale_order_update_due(sale_hash),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_carbon_credit_sale_order(sale_hash);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_complaint_for_account(
+			Self::complaint_batch_count(complaint)
+		))]
+		pub fn update_complaint_for_account(
+			origin: OriginFor<T>,
+			complaint: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the complaint timeout event is due
+			ensure!(
+				Self::is_complaint_for_account_update_due(&complaint, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_complaint_for_account(complaint, timeout_block);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_complaint_for_hash(
+			Self::complaint_batch_count(complaint)
+		))]
+		pub fn update_complaint_for_hash(
+			origin: OriginFor<T>,
+			complaint: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the complaint timeout event is due
+			ensure!(
+				Self::is_complaint_for_hash_update_due(&complaint, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_complaint_for_hash(complaint, timeout_block);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_project_owner_penalty_level())]
+		pub fn update_project_owner_penalty_level(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the penalty timeout event is due
+			ensure!(
+				Self::is_project_owner_penalty_update_due(&account_id),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_project_owner_penalty_level(account_id);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_validator_penalty_level())]
+		pub fn update_validator_penalty_level(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the penalty timeout event is due
+			ensure!(Self::is_validator_penalty_update_due(&account_id), Error::<T>::UpdateIsNotDue);
+
+			Self::do_update_validator_penalty_level(account_id);
+
+			Ok(())
+		}
+
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_project_penalty_level())]
+		pub fn update_project_penalty_level(origin: OriginFor<T>, hash: H256) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the penalty timeout event is due
+			ensure!(Self::is_project_penalty_update_due(hash), Error::<T>::UpdateIsNotDue);
+
+			Self::do_update_project_penalty_level(hash);
+
+			Ok(())
+		}
+
+		// Add authority account
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_authority())]
+		pub fn add_authority(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// Check if caller can manage authority accounts
+			T::AuthorityManagerOrigin::ensure_origin(origin)?;
+
+			// Check if the account is already an authority account
+			ensure!(
+				!AuthorityAccounts::<T>::contains_key(account_id.clone()),
+				Error::<T>::AuthorityAccountAlreadyExists
+			);
+
+			AuthorityAccounts::<T>::insert(
+				account_id.clone(),
+				AuthorityAccountInfo { added_date: T::Time::now() },
+			);
+
+			Self::deposit_event(Event::AuthorityAccountAdded(account_id));
+
+			Ok(().into())
+		}
+
+		// Remove authority account
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_authority())]
+		pub fn remove_authority(
+			origin: OriginFor<T>,
+			account_id: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			// Check if caller can manage authority accounts
+			T::AuthorityManagerOrigin::ensure_origin(origin)?;
+
+			// Check if the account is an authority account
+			ensure!(
+				AuthorityAccounts::<T>::contains_key(account_id.clone()),
+				Error::<T>::AuthorityAccountDoesntExist
+			);
+
+			AuthorityAccounts::<T>::remove(account_id.clone());
+
+			Self::deposit_event(Event::AuthorityAccountRemoved(account_id));
+
+			Ok(().into())
+		}
+
+		// Enable or disable beneficiary splits for direct carbon credit transfers
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_transfer_royalties())]
+		pub fn update_transfer_royalties(
+			origin: OriginFor<T>,
+			enabled: bool,
+		) -> DispatchResultWithPostInfo {
+			// Check if caller is an admin
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			TransferRoyaltiesEnabled::<T>::set(enabled);
+
+			Self::deposit_event(Event::TransferRoyaltiesUpdated(enabled));
+
+			Ok(().into())
+		}
+
+		// Transfer carbon credits directly to another account
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_carbon_credits())]
+		pub fn transfer_carbon_credits(
+			origin: OriginFor<T>,
+			batch_hash: H256,
+			to: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			// Check if both the sender and the receiver can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&from, batch_hash)?;
+			Self::ensure_can_transact_carbon_credits(&to, batch_hash)?;
+
+			// Check if the sender isn't the receiver
+			ensure!(from != to, Error::<T>::CantTransferCarbonCreditsToSelf);
+
+			// Check if user put a nonnegative number for the credit amount
+			ensure!(amount > BalanceOf::<T>::from(0u32), Error::<T>::InvalidCarbonCreditAmount);
+
+			// Check if the sender has enough available carbon credits
+			let mut sender_holdings = CarbonCreditHoldings::<T>::get(batch_hash, from.clone())
+				.ok_or(Error::<T>::CarbonCreditHoldingsDontExist)?;
+
+			ensure!(
+				sender_holdings.available_amount >= amount,
+				Error::<T>::NotEnoughtAvailableCredits
+			);
+
+			// Do beneficiary splits (paid by the sender)
+			// Note: Transferred carbon credits are valued at the batch penalty repay price
+			if TransferRoyaltiesEnabled::<T>::get() {
+				let carbon_credit_batch = CarbonCreditBatches::<T>::get(batch_hash).unwrap();
+				let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
+
+				let transfer_value = amount
+					.checked_mul(&carbon_credit_batch.penalty_repay_price)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+
+				ensure!(
+					transfer_value <= T::Currency::balance(&from),
+					Error::<T>::InsufficientFunds
+				);
+
+				Self::process_beneficiary_splits(
+					from.clone(),
+					&from,
+					carbon_credit_batch.project_hash,
+					project.project_owner,
+					carbon_credit_batch.validator_benefactors,
+					transfer_value,
+				)?;
+			}
+
+			// Update sender holdings
+			sender_holdings.available_amount -= amount;
+
+			if sender_holdings == CarbonCreditHoldingsInfo::default() {
+				CarbonCreditHoldings::<T>::remove(batch_hash, from.clone());
+			} else {
+				CarbonCreditHoldings::<T>::insert(batch_hash, from.clone(), sender_holdings);
+			}
+
+			// Update receiver holdings
+			CarbonCreditHoldings::<T>::mutate(batch_hash, to.clone(), |holdings| {
+				holdings.get_or_insert_with(CarbonCreditHoldingsInfo::default).available_amount +=
+					amount;
+			});
+
+			Self::deposit_event(Event::CarbonCreditsTransferred(batch_hash, from, to, amount));
+
+			Ok(().into())
+		}
+
+		// Create carbon credit buy order
+		// Note: The buyer funds for the whole order (credit amount * credit price) are held until
+		// the buy order is filled, cancelled or it times out
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_buy_order())]
+		pub fn create_buy_order(
+			origin: OriginFor<T>,
+			target: BuyOrderTarget,
+			credit_price: BalanceOf<T>,
+			credit_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+
+			// Check if the user can create a buy order
+			ensure!(
+				Self::is_eligible_for_carbon_credit_transaction(buyer.clone()),
+				Error::<T>::UserIsNotEligibleForCarbonCreditTransactions,
+			);
+
+			// Check if the caller is a project owner and if he has standing debts
+			if ProjectOwners::<T>::contains_key(buyer.clone()) {
+				ensure!(
+					!ProjectOwnerDebts::<T>::contains_key(buyer.clone()),
+					Error::<T>::ProjectOwnerHasStandingDebts
+				);
+			}
+
+			// Check if user put a nonnegative number for the credit amount
+			ensure!(
+				credit_amount > BalanceOf::<T>::from(0u32),
+				Error::<T>::InvalidCarbonCreditAmount
+			);
+
+			// Check if the buy order target exists
+			match target {
+				BuyOrderTarget::Batch(batch_hash) => {
+					let carbon_credit_batch = CarbonCreditBatches::<T>::get(batch_hash)
+						.ok_or(Error::<T>::CarbonCreditBatchDoesNotExist)?;
+
+					ensure!(
+						carbon_credit_batch.status == CarbonCreditBatchStatus::Active,
+						Error::<T>::CarbonCreditBatchIsNotActive,
+					);
+				},
+				BuyOrderTarget::Project(project_hash) => {
+					ensure!(
+						Projects::<T>::contains_key(project_hash),
+						Error::<T>::ProjectDoesntExist
+					);
+				},
+			}
+
+			// Check if the buyer has enough assets
+			let amount_to_hold =
+				credit_amount.checked_mul(&credit_price).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			ensure!(amount_to_hold <= T::Currency::balance(&buyer), Error::<T>::InsufficientFunds);
+
+			// Generate buy order hash
+			let buy_hash = Self::generate_hash(buyer.clone());
+
+			// Set buy order timeout
+			let timeout_block = frame_system::Pallet::<T>::block_number()
+				+ PalletTimeValues::<T>::get().sales_timeout;
+
+			// Create a carbon credit buy order timeout event
+			BuyOrderTimeouts::<T>::try_mutate(timeout_block, |buy_events| {
+				buy_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(buy_hash)
+			})
+			.map_err(|_| Error::<T>::TooManyTimeouts)?;
+
+			// Hold buyer funds
+			T::Currency::hold(&HoldReason::BuyOrderEscrow.into(), &buyer, amount_to_hold)?;
+
+			// Create a carbon credit buy order
+			let buy_order = CarbonCreditBuyOrderInfo {
+				target,
+				credit_amount,
+				credit_price,
+				buyer: buyer.clone(),
+				buy_active: true,
+				buy_timeout: timeout_block,
+			};
+
+			CarbonCreditBuyOrders::<T>::insert(buy_hash, buy_order);
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditBuyOrderCreated(
+				buyer,
+				buy_hash,
+				credit_amount,
+				credit_price,
+			));
+
+			Ok(().into())
+		}
+
+		// Cancel carbon credit buy order
+		// Note: Cancelling is always possible, so that the held buyer funds can't get stuck
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_buy_order())]
+		pub fn cancel_buy_order(
+			origin: OriginFor<T>,
+			buy_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+
+			// Check if buy order exists
+			let buy_order = CarbonCreditBuyOrders::<T>::get(buy_hash)
+				.ok_or(Error::<T>::CarbonCreditBuyOrderDoesntExist)?;
+
+			// Check if the buy order is still active
+			ensure!(buy_order.buy_active, Error::<T>::BuyOrderIsNotActive);
+
+			// Check if the buyer created the buy order
+			ensure!(buyer == buy_order.buyer, Error::<T>::UserDidntCreateTheBuyOrder);
+
+			Self::close_buy_order(buy_hash, buy_order)?;
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditBuyOrderCancelled(buyer, buy_hash));
+
+			Ok(().into())
+		}
+
+		// Fill carbon credit buy order (sell carbon credits)
+		// Note: Without an amount the whole remaining buy order amount is sold
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_buy_order())]
+		pub fn fill_buy_order(
+			origin: OriginFor<T>,
+			buy_hash: H256,
+			batch_hash: H256,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let seller = ensure_signed(origin)?;
+
+			// Check if buy order exists
+			let mut buy_order = CarbonCreditBuyOrders::<T>::get(buy_hash)
+				.ok_or(Error::<T>::CarbonCreditBuyOrderDoesntExist)?;
+
+			// Check if the buy order is active
+			ensure!(buy_order.buy_active, Error::<T>::BuyOrderIsNotActive);
+
+			// Check if the seller isn't the buyer
+			ensure!(seller != buy_order.buyer, Error::<T>::SellerCantFillHisOwnBuyOrder);
+
+			// Check if both the seller and the buyer can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&seller, batch_hash)?;
+			Self::ensure_can_transact_carbon_credits(&buy_order.buyer, batch_hash)?;
+
+			// Check if the carbon credit batch matches the buy order target
+			let carbon_credit_batch = CarbonCreditBatches::<T>::get(batch_hash).unwrap();
+
+			let is_matching_batch = match buy_order.target {
+				BuyOrderTarget::Batch(target_batch) => target_batch == batch_hash,
+				BuyOrderTarget::Project(project_hash) => {
+					carbon_credit_batch.project_hash == project_hash
+				},
+			};
+
+			ensure!(is_matching_batch, Error::<T>::CarbonCreditBatchDoesntMatchBuyOrder);
+
+			// Check if the fill amount is valid
+			let fill_amount = amount.unwrap_or(buy_order.credit_amount);
+
+			ensure!(
+				fill_amount > BalanceOf::<T>::from(0u32) && fill_amount <= buy_order.credit_amount,
+				Error::<T>::InvalidFillAmount
+			);
+
+			// Check if the seller has enough available credits
+			let mut seller_holdings = CarbonCreditHoldings::<T>::get(batch_hash, seller.clone())
+				.ok_or(Error::<T>::CarbonCreditHoldingsDontExist)?;
+
+			ensure!(
+				seller_holdings.available_amount >= fill_amount,
+				Error::<T>::NotEnoughtAvailableCredits
+			);
+
+			// Release held buyer funds for the fill
+			let buyer = buy_order.buyer.clone();
+			let mut amount_to_pay = fill_amount
+				.checked_mul(&buy_order.credit_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let (maker_fee, taker_fee) = Self::calculate_trading_fees(amount_to_pay);
+
+			// Check if the buyer can pay the maker fee (on top of the held funds)
+			ensure!(maker_fee <= T::Currency::balance(&buyer), Error::<T>::InsufficientFunds);
+
+			T::Currency::release(
+				&HoldReason::BuyOrderEscrow.into(),
+				&buyer,
+				amount_to_pay,
+				Precision::Exact,
+			)?;
+
+			let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
+
+			// Do beneficiary splits (paid from the released buyer funds)
+			let split_gains = Self::process_beneficiary_splits(
+				buyer.clone(),
+				&seller,
+				carbon_credit_batch.project_hash,
+				project.project_owner,
+				carbon_credit_batch.validator_benefactors,
+				amount_to_pay,
+			)?;
+
+			amount_to_pay =
+				amount_to_pay.checked_sub(&split_gains).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Pay trading fees
+			// Note: The buyer is the maker and the seller is the taker
+			amount_to_pay =
+				Self::process_trading_fees(&buyer, amount_to_pay, maker_fee, taker_fee)?;
+
+			// Transfer funds
+			T::Currency::transfer(&buyer, &seller, amount_to_pay, Preservation::Preserve)?;
+
+			// Update buy order
+			// Note: The buy order is closed once it has been fully filled
+			buy_order.credit_amount -= fill_amount;
+
+			let is_last_fill = buy_order.credit_amount == BalanceOf::<T>::from(0u32);
+
+			if is_last_fill {
+				Self::close_buy_order(buy_hash, buy_order)?;
+			} else {
+				CarbonCreditBuyOrders::<T>::insert(buy_hash, buy_order);
+			}
+
+			// Update seller holdings
+			seller_holdings.available_amount -= fill_amount;
+
+			if seller_holdings == CarbonCreditHoldingsInfo::default() {
+				CarbonCreditHoldings::<T>::remove(batch_hash, seller.clone());
+			} else {
+				CarbonCreditHoldings::<T>::insert(batch_hash, seller.clone(), seller_holdings);
+			}
+
+			// Update buyer holdings
+			CarbonCreditHoldings::<T>::mutate(batch_hash, buyer, |holdings| {
+				holdings.get_or_insert_with(CarbonCreditHoldingsInfo::default).available_amount +=
+					fill_amount;
+			});
+
+			// Deposit events
+			Self::deposit_event(Event::CarbonCreditBuyOrderFilled(
+				seller.clone(),
+				buy_hash,
+				batch_hash,
+				fill_amount,
+				maker_fee,
+				taker_fee,
+			));
+
+			if is_last_fill {
+				Self::deposit_event(Event::CarbonCreditBuyOrderCompleted(seller, buy_hash));
+			}
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_buy_order())]
+		pub fn update_carbon_credit_buy_order(
+			origin: OriginFor<T>,
+			buy_hash: H256,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the buy order timeout event is due
+			ensure!(
+				Self::is_carbon_credit_buy_order_update_due(buy_hash),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_carbon_credit_buy_order(buy_hash);
+
+			Ok(())
+		}
+
+		// Place limit order in the carbon credit batch order book
+		// Note: The order is first matched against the opposite side of the order book (price-time
+		// priority, at the maker price), the remaining amount is then added to the order book
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_limit_order(T::MaxOrdersPerBook::get()))]
+		pub fn place_limit_order(
+			origin: OriginFor<T>,
+			batch_hash: H256,
+			side: OrderSide,
+			credit_price: BalanceOf<T>,
+			credit_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let trader = ensure_signed(origin)?;
+
+			// Check if the user can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&trader, batch_hash)?;
+
+			// Check if user put a nonnegative number for the credit amount
+			ensure!(
+				credit_amount > BalanceOf::<T>::from(0u32),
+				Error::<T>::InvalidCarbonCreditAmount
+			);
+
+			// Check if the trader has enough assets for the whole order
+			let order_value =
+				credit_amount.checked_mul(&credit_price).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			match side {
+				OrderSide::Bid => {
+					// Note: The taker fee of the matched amount is paid on top of the order value
+					let (_, taker_fee) = Self::calculate_trading_fees(order_value);
+
+					ensure!(
+						order_value
+							.checked_add(&taker_fee)
+							.ok_or(Error::<T>::ArithmeticOverflow)?
+							<= T::Currency::balance(&trader),
+						Error::<T>::InsufficientFunds
+					);
+				},
+				OrderSide::Ask => {
+					let holdings = CarbonCreditHoldings::<T>::get(batch_hash, trader.clone())
+						.ok_or(Error::<T>::CarbonCreditHoldingsDontExist)?;
+
+					ensure!(
+						holdings.available_amount >= credit_amount,
+						Error::<T>::NotEnoughtAvailableCredits
+					);
+				},
+			}
+
+			// Match the order against the opposite side of the order book
+			let (remaining_amount, matched_orders) =
+				Self::match_limit_order(batch_hash, &trader, side, credit_price, credit_amount)?;
+
+			// Add the remaining amount to the order book
+			if remaining_amount > BalanceOf::<T>::from(0u32) {
+				let order_hash = Self::generate_hash(trader.clone());
+
+				Self::insert_into_order_book(batch_hash, side, credit_price, order_hash)?;
+
+				match side {
+					OrderSide::Bid => T::Currency::hold(
+						&HoldReason::OrderBookEscrow.into(),
+						&trader,
+						remaining_amount * credit_price,
+					)?,
+					OrderSide::Ask => {
+						CarbonCreditHoldings::<T>::mutate(batch_hash, trader.clone(), |holdings| {
+							if let Some(holdings) = holdings {
+								holdings.available_amount -= remaining_amount;
+								holdings.unavailable_amount += remaining_amount;
+							}
+						})
+					},
+				}
+
+				let limit_order = LimitOrderInfo {
+					batch_hash,
+					side,
+					owner: trader.clone(),
+					credit_price,
+					credit_amount: remaining_amount,
+					creation_block: frame_system::Pallet::<T>::block_number(),
+				};
+
+				LimitOrders::<T>::insert(order_hash, limit_order);
+
+				Self::deposit_event(Event::LimitOrderPlaced(
+					trader,
+					order_hash,
+					batch_hash,
+					side,
+					credit_price,
+					remaining_amount,
+				));
+			}
+
+			Ok(Some(T::WeightInfo::place_limit_order(matched_orders)).into())
+		}
+
+		// Cancel limit order and release the held funds (bids) or credits (asks)
+		// Note: Cancelling is always possible, so that the held funds and credits can't get stuck
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_limit_order())]
+		pub fn cancel_limit_order(
+			origin: OriginFor<T>,
+			order_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let trader = ensure_signed(origin)?;
+
+			// Check if limit order exists
+			let limit_order =
+				LimitOrders::<T>::get(order_hash).ok_or(Error::<T>::LimitOrderDoesntExist)?;
+
+			// Check if the trader created the limit order
+			ensure!(trader == limit_order.owner, Error::<T>::UserDidntCreateTheLimitOrder);
+
+			// Remove the order from the order book
+			let mut order_book =
+				OrderBooks::<T>::get(limit_order.batch_hash, limit_order.side).unwrap_or_default();
+
+			order_book.retain(|(_, hash)| *hash != order_hash);
+
+			Self::set_order_book(limit_order.batch_hash, limit_order.side, order_book);
+
+			// Release held funds or credits
+			match limit_order.side {
+				OrderSide::Bid => {
+					T::Currency::release(
+						&HoldReason::OrderBookEscrow.into(),
+						&trader,
+						limit_order.credit_amount * limit_order.credit_price,
+						Precision::Exact,
+					)?;
+				},
+				OrderSide::Ask => {
+					CarbonCreditHoldings::<T>::mutate(
+						limit_order.batch_hash,
+						trader.clone(),
+						|holdings| {
+							if let Some(holdings) = holdings {
+								holdings.available_amount += limit_order.credit_amount;
+								holdings.unavailable_amount -= limit_order.credit_amount;
+							}
+						},
+					);
+				},
+			}
+
+			LimitOrders::<T>::remove(order_hash);
+
+			// Deposit event
+			Self::deposit_event(Event::LimitOrderCancelled(trader, order_hash));
+
+			Ok(().into())
+		}
+
+		// Create carbon credit auction (the lot is sold as a whole)
+		// Note: The seller credits are unavailable until the auction ends or is cancelled
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			batch_hash: H256,
+			credit_amount: BalanceOf<T>,
+			auction_type: AuctionType<BalanceOf<T>>,
+			duration: BlockNumber<T>,
+		) -> DispatchResultWithPostInfo {
+			let seller = ensure_signed(origin)?;
+
+			// Check if the user can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&seller, batch_hash)?;
+
+			// Check if user put a nonnegative number for the credit amount
+			ensure!(
+				credit_amount > BalanceOf::<T>::from(0u32),
+				Error::<T>::InvalidCarbonCreditAmount
+			);
+
+			// Check if the auction duration is valid
+			ensure!(
+				duration > BlockNumber::<T>::from(0u32)
+					&& duration <= PalletTimeValues::<T>::get().sales_timeout,
+				Error::<T>::InvalidAuctionDuration
+			);
+
+			// Check if the Dutch auction prices are valid
+			if let AuctionType::Dutch { start_price, floor_price, .. } = auction_type {
+				ensure!(start_price >= floor_price, Error::<T>::InvalidAuctionPrices);
+			}
+
+			// Check if user has enough available credits
+			let mut seller_holdings = CarbonCreditHoldings::<T>::get(batch_hash, seller.clone())
+				.ok_or(Error::<T>::CarbonCreditHoldingsDontExist)?;
+
+			ensure!(
+				seller_holdings.available_amount >= credit_amount,
+				Error::<T>::NotEnoughtAvailableCredits
+			);
+
+			// Generate auction hash
+			let auction_hash = Self::generate_hash(seller.clone());
+
+			// Create an auction timeout event (auction end)
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let end_block = current_block + duration;
+
+			AuctionTimeouts::<T>::try_mutate(end_block, |auction_events| {
+				auction_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(auction_hash)
+			})
+			.map_err(|_| Error::<T>::TooManyTimeouts)?;
+
+			// Update seller carbon credit holdings
+			seller_holdings.available_amount -= credit_amount;
+			seller_holdings.unavailable_amount += credit_amount;
+
+			CarbonCreditHoldings::<T>::insert(batch_hash, seller.clone(), seller_holdings);
+
+			// Create a carbon credit auction
+			let auction = CarbonCreditAuctionInfo {
+				batch_hash,
+				credit_amount,
+				seller: seller.clone(),
+				auction_type,
+				highest_bid: None,
+				start_block: current_block,
+				end_block,
+				status: AuctionStatus::Open,
+			};
+
+			CarbonCreditAuctions::<T>::insert(auction_hash, auction);
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditAuctionCreated(
+				seller,
+				auction_hash,
+				batch_hash,
+				credit_amount,
+			));
+
+			Ok(().into())
+		}
+
+		// Place bid in an English auction
+		// Note: The bidder funds are held until the bid is outbid or the auction ends, a bid close
+		// to the auction end extends the auction (anti-sniping)
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_auction_bid())]
+		pub fn place_auction_bid(
+			origin: OriginFor<T>,
+			auction_hash: H256,
+			credit_price: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let bidder = ensure_signed(origin)?;
+
+			// Check if auction exists
+			let mut auction = CarbonCreditAuctions::<T>::get(auction_hash)
+				.ok_or(Error::<T>::CarbonCreditAuctionDoesntExist)?;
+
+			// Check if the auction is still active
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			ensure!(
+				auction.status == AuctionStatus::Open && current_block < auction.end_block,
+				Error::<T>::AuctionIsNotActive
+			);
+
+			// Check if the bidder isn't the seller
+			ensure!(bidder != auction.seller, Error::<T>::BuyerCantBuyHisOwnTokens);
+
+			// Check if the user can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&bidder, auction.batch_hash)?;
+
+			// Check if the bid reaches the reserve price and is above the highest bid
+			let reserve_price = match auction.auction_type {
+				AuctionType::English { reserve_price } => reserve_price,
+				AuctionType::Dutch { .. } => return Err(Error::<T>::WrongAuctionType.into()),
+			};
+
+			ensure!(
+				credit_price >= reserve_price
+					&& auction
+						.highest_bid
+						.as_ref()
+						.map_or(true, |(_, price)| credit_price > *price),
+				Error::<T>::BidIsTooLow
+			);
+
+			// Release the funds of the previous highest bid
+			if let Some((previous_bidder, previous_price)) = auction.highest_bid.take() {
+				T::Currency::release(
+					&HoldReason::AuctionBidEscrow.into(),
+					&previous_bidder,
+					auction.credit_amount * previous_price,
+					Precision::Exact,
+				)?;
+			}
+
+			// Check if the bidder has enough assets and hold them
+			let amount_to_hold = auction
+				.credit_amount
+				.checked_mul(&credit_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Note: The taker fee is paid on top of the bid when the auction is sold
+			let (_, taker_fee) = Self::calculate_trading_fees(amount_to_hold);
+
+			ensure!(
+				amount_to_hold.checked_add(&taker_fee).ok_or(Error::<T>::ArithmeticOverflow)?
+					<= T::Currency::balance(&bidder),
+				Error::<T>::InsufficientFunds
+			);
+
+			T::Currency::hold(&HoldReason::AuctionBidEscrow.into(), &bidder, amount_to_hold)?;
+
+			auction.highest_bid = Some((bidder.clone(), credit_price));
+
+			// Extend the auction if the bid is placed close to the auction end
+			let extended_end_block = current_block + T::AuctionExtensionPeriod::get();
+			let is_extended = extended_end_block > auction.end_block;
+
+			if is_extended {
+				Self::remove_auction_timeout(auction.end_block, auction_hash);
+
+				AuctionTimeouts::<T>::try_mutate(extended_end_block, |auction_events| {
+					auction_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(auction_hash)
+				})
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
+
+				auction.end_block = extended_end_block;
+			}
+
+			CarbonCreditAuctions::<T>::insert(auction_hash, auction);
+
+			// Deposit events
+			Self::deposit_event(Event::CarbonCreditAuctionBidPlaced(
+				bidder,
+				auction_hash,
+				credit_price,
+			));
+
+			if is_extended {
+				Self::deposit_event(Event::CarbonCreditAuctionExtended(
+					auction_hash,
+					extended_end_block,
+				));
+			}
+
+			Ok(().into())
+		}
+
+		// Buy the lot of a Dutch auction at the current price
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy_auction_lot())]
+		pub fn buy_auction_lot(
+			origin: OriginFor<T>,
+			auction_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+
+			// Check if auction exists
+			let auction = CarbonCreditAuctions::<T>::get(auction_hash)
+				.ok_or(Error::<T>::CarbonCreditAuctionDoesntExist)?;
+
+			// Check if the auction is still active
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			ensure!(
+				auction.status == AuctionStatus::Open && current_block < auction.end_block,
+				Error::<T>::AuctionIsNotActive
+			);
+
+			// Check if the buyer isn't the seller
+			ensure!(buyer != auction.seller, Error::<T>::BuyerCantBuyHisOwnTokens);
+
+			// Check if the user can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&buyer, auction.batch_hash)?;
+
+			// Get the current Dutch auction price
+			let credit_price = Self::dutch_auction_price(&auction, current_block)
+				.ok_or(Error::<T>::WrongAuctionType)?;
+
+			// Check if the buyer has enough assets
+			// Note: The buyer pays the taker fee on top of the lot price
+			let amount_to_pay = auction
+				.credit_amount
+				.checked_mul(&credit_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let (_, taker_fee) = Self::calculate_trading_fees(amount_to_pay);
+
+			ensure!(
+				amount_to_pay.checked_add(&taker_fee).ok_or(Error::<T>::ArithmeticOverflow)?
+					<= T::Currency::balance(&buyer),
+				Error::<T>::InsufficientFunds
+			);
+
+			// Sell the lot
+			let (maker_fee, taker_fee) =
+				Self::settle_auction(auction_hash, auction, buyer.clone(), credit_price)?;
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditAuctionSold(
+				buyer,
+				auction_hash,
+				credit_price,
+				maker_fee,
+				taker_fee,
+			));
+
+			Ok(().into())
+		}
+
+		// Cancel carbon credit auction and release the seller credits
+		// Note: English auctions can only be cancelled before the first bid
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			auction_hash: H256,
+		) -> DispatchResultWithPostInfo {
+			let seller = ensure_signed(origin)?;
+
+			// Check if auction exists
+			let auction = CarbonCreditAuctions::<T>::get(auction_hash)
+				.ok_or(Error::<T>::CarbonCreditAuctionDoesntExist)?;
+
+			// Check if the seller created the auction
+			ensure!(seller == auction.seller, Error::<T>::UserDidntCreateTheAuction);
+
+			// Check if the auction is still active
+			ensure!(auction.status == AuctionStatus::Open, Error::<T>::AuctionIsNotActive);
+
+			// Check if the auction doesn't have any bids
+			ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+			Self::close_auction(auction_hash, auction, AuctionStatus::Cancelled);
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditAuctionCancelled(seller, auction_hash));
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(43)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_auction())]
+		pub fn update_carbon_credit_auction(
+			origin: OriginFor<T>,
+			auction_hash: H256,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the auction timeout event is due
+			ensure!(
+				Self::is_carbon_credit_auction_update_due(auction_hash),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_carbon_credit_auction(auction_hash);
+
+			Ok(())
+		}
+
+		// Amend carbon credit sale order (reprice and/or resize it and set a new expiry)
+		// Note: The credit amount is the new remaining amount of the sale order and the expiry is
+		// counted from the current block (same as when creating a sale order)
+		#[pallet::call_index(44)]
+		#[pallet::weight(<T as Config>::WeightInfo::amend_sale_order())]
+		pub fn amend_sale_order(
+			origin: OriginFor<T>,
+			sale_hash: H256,
+			credit_price: BalanceOf<T>,
+			credit_amount: BalanceOf<T>,
+			expiry: Option<SaleOrderExpiry<BlockNumber<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let seller = ensure_signed(origin)?;
+
+			// Check if sale order exits
+			let sale_order = CarbonCreditSaleOrders::<T>::get(sale_hash)
+				.ok_or(Error::<T>::CarbonCreditSaleOrderDoesntExist)?;
+
+			// Check if the sale order is still active
+			ensure!(sale_order.status == SaleOrderStatus::Open, Error::<T>::SaleOrderIsNotActive);
+
+			// Check if the seller created the sale order
+			ensure!(seller == sale_order.seller, Error::<T>::UserDidntCreateTheSaleOrder);
+
+			// Check if the user can transact the carbon credits
+			Self::ensure_can_transact_carbon_credits(&seller, sale_order.batch_hash)?;
+
+			// Check if user put a nonnegative number for the credit amount
+			ensure!(
+				credit_amount > BalanceOf::<T>::from(0u32),
+				Error::<T>::InvalidCarbonCreditAmount
+			);
+
+			// Update seller carbon credit holdings (lock or release the amount difference)
+			let mut seller_holdings =
+				CarbonCreditHoldings::<T>::get(sale_order.batch_hash, seller.clone())
+					.ok_or(Error::<T>::CarbonCreditHoldingsDontExist)?;
+
+			if credit_amount > sale_order.credit_amount {
+				let amount_to_lock = credit_amount - sale_order.credit_amount;
+
+				ensure!(
+					seller_holdings.available_amount >= amount_to_lock,
+					Error::<T>::NotEnoughtAvailableCredits
+				);
+
+				seller_holdings.available_amount = seller_holdings
+					.available_amount
+					.checked_sub(&amount_to_lock)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				seller_holdings.unavailable_amount = seller_holdings
+					.unavailable_amount
+					.checked_add(&amount_to_lock)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+			} else {
+				let amount_to_release = sale_order.credit_amount - credit_amount;
+
+				seller_holdings.available_amount = seller_holdings
+					.available_amount
+					.checked_add(&amount_to_release)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+				seller_holdings.unavailable_amount = seller_holdings
+					.unavailable_amount
+					.checked_sub(&amount_to_release)
+					.ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+
+			// Move sale order timeout
+			let timeout_block = Self::sale_order_timeout(expiry)?;
+
+			if let Some(sale_timeout) = sale_order.sale_timeout {
+				Self::remove_sale_order_timeout(sale_timeout, sale_hash);
+			}
+
+			if let Some(timeout_block) = timeout_block {
+				Self::insert_sale_order_timeout(timeout_block, sale_hash)?;
+			}
+
+			CarbonCreditHoldings::<T>::insert(
+				sale_order.batch_hash,
+				seller.clone(),
+				seller_holdings,
+			);
+
+			// Update sale order
+			// Note: The minimum fill amount is lowered if the sale order is resized below it
+			let sale_order = CarbonCreditSaleOrderInfo {
+				credit_amount,
+				min_fill_amount: sale_order.min_fill_amount.min(credit_amount),
+				credit_price,
+				sale_timeout: timeout_block,
+				..sale_order
+			};
+
+			CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order);
+
+			// Deposit event
+			Self::deposit_event(Event::CarbonCreditSaleOrderAmended(
+				seller,
+				sale_hash,
+				credit_amount,
+				credit_price,
+			));
+
+			Ok(().into())
+		}
+
+		// Propose beneficiary splits and stakeholder shares for a project
+		// Note: The proposal replaces the pallet beneficiary splits for the project once validators
+		// approve it (the stakeholder shares are paid on every primary and secondary sale)
+		#[pallet::call_index(45)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_beneficiary_splits())]
+		pub fn propose_beneficiary_splits(
+			origin: OriginFor<T>,
+			project_hash: H256,
+			beneficiary_splits: BTreeMap<BeneficiarySplitType, Permill>,
+			stakeholders: BTreeMap<AccountIdOf<T>, Permill>,
+			ipfs: BoundedString<T::IPFSLength>,
+		) -> DispatchResultWithPostInfo {
+			let user = ensure_signed(origin)?;
+
+			// Check if caller is a Project Owner account
+			ensure!(ProjectOwners::<T>::contains_key(user.clone()), Error::<T>::Unauthorized);
+
+			// Check if project owner has standing debts
+			ensure!(
+				!ProjectOwnerDebts::<T>::contains_key(user.clone()),
+				Error::<T>::ProjectOwnerHasStandingDebts
+			);
+
+			// Check if project exists
+			let project = Projects::<T>::get(project_hash).ok_or(Error::<T>::ProjectDoesntExist)?;
+
+			// Check if the owner owns the mentioned project
+			ensure!(project.project_owner == user, Error::<T>::Unauthorized);
+
+			// Check if the beneficiary splits and stakeholder shares are valid
+			let stakeholder_share = Self::validate_stakeholder_shares(&stakeholders)?;
+
+			Self::validate_beneficiary_splits(&beneficiary_splits, stakeholder_share)?;
+
+			// Check if the documentation (IPFS link) has been used previously
+			ensure!(
+				Self::is_ipfs_available(ipfs.clone()),
+				Error::<T>::DocumentationWasUsedPreviously
+			);
+
+			let amount_to_pay = Self::calculate_complex_payment_made_to_pallet(
+				project.project_owner,
+				project_hash,
+				PalletFeeValues::<T>::get().project_proposal_fee,
+			)?;
+
+			// Check if caller has sufficient funds
+			ensure!(amount_to_pay <= T::Currency::balance(&user), Error::<T>::InsufficientFunds);
+
+			// Beneficiary split proposal info
+			let proposal_info = BeneficiarySplitProposalInfo {
+				project_hash,
+				creation_date: T::Time::now(),
+				beneficiary_splits: BeneficiarySplitsOf::<T>::try_from(beneficiary_splits)
+					.map_err(|_| Error::<T>::InvalidBeneficiarySplitValues)?,
+				stakeholders: StakeholderSharesOf::<T>::try_from(stakeholders)
+					.map_err(|_| Error::<T>::TooManyStakeholders)?,
+				votes_for: VotesOf::<T>::new(),
+				votes_against: VotesOf::<T>::new(),
+				voting_active: true,
+			};
+
+			// Write to info storage
+			BeneficiarySplitProposals::<T>::insert(ipfs.clone(), proposal_info);
+
+			// Mark documentation as used
+			UsedDocumentation::<T>::insert(
+				ipfs.clone(),
+				DocumentationOwner::BeneficiarySplitProposal(project_hash),
+			);
+
+			// Set for voting timeout
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let timeout_block = current_block + PalletTimeValues::<T>::get().voting_timeout;
+
+			let mut timeout_events = VotingTimeouts::<T>::get(timeout_block).unwrap_or_default();
+
+			timeout_events
+				.try_insert(ipfs.clone())
+				.map_err(|_| Error::<T>::TooManyTimeouts)?;
+
+			VotingTimeouts::<T>::insert(timeout_block, timeout_events);
+
+			// Collect fee
+			Self::collect_fee_or_deposit(
+				user.clone(),
+				ipfs.clone(),
+				PalletFeeValues::<T>::get().project_proposal_fee,
+				HoldReason::ProposalDeposit,
+			)?;
+
+			// Deposit event
+			Self::deposit_event(Event::BeneficiarySplitProposalCreated(user, ipfs));
+
+			Ok(().into())
+		}
+
+		#[pallet::call_index(46)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_beneficiary_split_proposal())]
+		pub fn update_beneficiary_split_proposal(
+			origin: OriginFor<T>,
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the call is unsigned
+			ensure_none(origin)?;
+
+			// Check if the voting timeout event is due
+			ensure!(
+				Self::is_beneficiary_split_proposal_update_due(&ipfs, timeout_block),
+				Error::<T>::UpdateIsNotDue
+			);
+
+			Self::do_update_beneficiary_split_proposal(ipfs, timeout_block);
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::update_pallet_base_time { new_pallet_base_time } => {
+					// Check if the base pallet time update is due
+					if !Self::is_pallet_base_time_update_due(*new_pallet_base_time) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_pallet_base_time")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([new_pallet_base_time])
+						.propagate(true)
+						.build()
+				},
+				Call::update_carbon_footprint_report { ipfs, timeout_block } => {
+					// Check if the voting timeout event is due
+					if !Self::is_carbon_footprint_report_update_due(ipfs, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_carbon_footprint_report")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([ipfs])
+						.propagate(true)
+						.build()
+				},
+				Call::update_project_proposal { ipfs, timeout_block } => {
+					// Check if the voting timeout event is due
+					if !Self::is_project_proposal_update_due(ipfs, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_project_proposal")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([ipfs])
+						.propagate(true)
+						.build()
+				},
+				Call::update_carbon_credit_batch_proposal { ipfs, timeout_block } => {
+					// Check if the voting timeout event is due
+					if !Self::is_carbon_credit_batch_proposal_update_due(ipfs, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_batch_proposal")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([ipfs])
+						.propagate(true)
+						.build()
+				},
+				Call::update_carbon_credit_sale_order { sale_hash } => {
+					// Check if the sale order timeout event is due
+					if !Self::is_carbon_credit_sale_order_update_due(*sale_hash) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_sale_order")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([sale_hash])
+						.propagate(true)
+						.build()
+				},
+				Call::update_carbon_credit_buy_order { buy_hash } => {
+					// Check if the buy order timeout event is due
+					if !Self::is_carbon_credit_buy_order_update_due(*buy_hash) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_buy_order")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([buy_hash])
+						.propagate(true)
+						.build()
+				},
+				Call::update_beneficiary_split_proposal { ipfs, timeout_block } => {
+					// Check if the voting timeout event is due
+					if !Self::is_beneficiary_split_proposal_update_due(ipfs, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_beneficiary_split_proposal")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([ipfs])
+						.propagate(true)
+						.build()
+				},
+				Call::update_carbon_credit_auction { auction_hash } => {
+					// Check if the auction timeout event is due
+					if !Self::is_carbon_credit_auction_update_due(*auction_hash) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_auction")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([auction_hash])
+						.propagate(true)
+						.build()
+				},
+				Call::update_complaint_for_account { complaint, timeout_block } => {
+					// Check if the complaint timeout event is due
+					if !Self::is_complaint_for_account_update_due(complaint, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_complaint_for_account")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([complaint])
+						.propagate(true)
+						.build()
+				},
+				Call::update_complaint_for_hash { complaint, timeout_block } => {
+					// Check if the complaint timeout event is due
+					if !Self::is_complaint_for_hash_update_due(complaint, *timeout_block) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_complaint_for_hash")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([complaint])
+						.propagate(true)
+						.build()
+				},
+				Call::update_project_owner_penalty_level { account_id } => {
+					// Check if the penalty timeout event is due
+					if !Self::is_project_owner_penalty_update_due(account_id) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_project_owner_penalty_level")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([account_id])
+						.propagate(true)
+						.build()
+				},
+				Call::update_validator_penalty_level { account_id } => {
+					// Check if the penalty timeout event is due
+					if !Self::is_validator_penalty_update_due(account_id) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_validator_penalty_level")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([account_id])
+						.propagate(true)
+						.build()
+				},
+				Call::update_project_penalty_level { hash } => {
+					// Check if the penalty timeout event is due
+					if !Self::is_project_penalty_update_due(*hash) {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("Veles::update_project_penalty_level")
+						.priority(T::UnsignedPriority::get())
+						.longevity(T::UnsignedLongevity::get())
+						.and_provides([hash])
+						.propagate(true)
+						.build()
+				},
+				_ => {
+					warn!("Unknown unsigned call {:?}", call);
+					InvalidTransaction::Call.into()
+				},
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumber<T>) -> Weight {
+			// Process all due timeout events (within the timeouts weight limit)
+			Self::process_timeouts(now)
+		}
+
+		fn on_idle(now: BlockNumber<T>, remaining_weight: Weight) -> Weight {
+			// Remove finished records whose retention period is over (within the remaining weight)
+			Self::process_pruning(now, remaining_weight)
+		}
+
+		fn offchain_worker(now: BlockNumber<T>) {
+			// Check if the offchain worker fallback is enabled
+			// Note: Timeout events are processed on initialize, the offchain worker only submits
+			// the ones that have been carried over
+			if !T::OffchainWorkerFallback::get() {
+				return;
+			}
+
+			// Check if a year has passed and update pallet base time if it has
+			// Note: The base pallet time will update once a year has passed (in blocks)
+			let new_pallet_base_time = Self::next_pallet_base_time(now);
+
+			if Self::is_pallet_base_time_update_due(new_pallet_base_time) {
+				info!("👷 Offchain worker: Updating base pallet time");
+
+				let call = Call::<T>::update_pallet_base_time { new_pallet_base_time };
+
+				if let Err(err) =
+					SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+				{
+					warn!(
+						"👷 Offchain worker: Failed to update base pallet time.🚧 Error: {:?}",
+						err
+					);
+				} else {
+					info!("👷 Offchain worker: Successfully updated base pallet time");
+				}
+			}
+
+			// Submit timeout events for every block that hasn't been fully processed
+			// Note: The remaining blocks are visited by the next runs (the timeouts cursor moves
+			// forward as the timeout events are processed)
+			let mut block = TimeoutsCursor::<T>::get();
+			let mut visited_blocks: u32 = 0;
+
+			while block <= now && visited_blocks < T::MaxOffchainWorkerTimeoutBlocks::get() {
+				Self::submit_timeouts(block);
+
+				block = block + BlockNumber::<T>::from(1u32);
+				visited_blocks += 1;
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_now: BlockNumber<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// Get account ID of pallet
+		fn pallet_id() -> T::AccountId {
+			PALLET_ID.into_account_truncating()
+		}
+
+		// Generate hash identifier
+		fn generate_hash(user: AccountIdOf<T>) -> H256 {
+			let nonce = frame_system::Pallet::<T>::account_nonce(&user);
+			let now = T::Time::now();
+
+			let encoded: [u8; 32] = (&user, nonce, now).using_encoded(blake2_256);
+
+			let hash = H256::from(encoded);
+
+			hash
+		}
+
+		// Validate penalty levels
+		pub fn validate_penalty_levels(penalty_levels: &BTreeMap<u8, Perbill>) -> DispatchResult {
+			// Check if all penalty levels have been submitted
+			ensure!(penalty_levels.len() == 5, Error::<T>::NotAllPenaltyLevelsHaveBeenSubmitted);
+
+			for (level, value) in penalty_levels.iter() {
+				// Check if the penalty level is one of the 5 levels
+				ensure!(*level < 5, Error::<T>::NotAllPenaltyLevelsHaveBeenSubmitted);
+
+				// Check if the penalty leaves anything to pay or gain
+				ensure!(*value < Perbill::one(), Error::<T>::InvalidPenaltyLevelValue);
+
+				if *level == 4 {
+					continue;
+				}
+
+				// Check if levels are of a increasing order
+				let next_value = penalty_levels
+					.get(&(level + 1))
+					.ok_or(Error::<T>::NotAllPenaltyLevelsHaveBeenSubmitted)?;
+
+				ensure!(*value < *next_value, Error::<T>::InvalidPenaltyLevelValue)
+			}
+
+			Ok(())
+		}
+
+		// Validate beneficiary splits
+		// Note: The stakeholder share (total of all stakeholder shares) is paid on every primary
+		// and secondary sale, so it counts towards both 50% limits
+		pub fn validate_beneficiary_splits(
+			beneficiary_splits: &BTreeMap<BeneficiarySplitType, Permill>,
+			stakeholder_share: Permill,
+		) -> DispatchResult {
+			// Check if the user submitted an adequate beneficiary split map
+			// Note: Every split type has to be set
+			ensure!(beneficiary_splits.len() == 3, Error::<T>::InvalidBeneficiarySplitValues);
+
+			// Check if the primary sale beneficiary split (for validators) exceeds 50%
+			// Note: The splits are added up with saturation, so any overflow exceeds 50% as well
+			ensure!(
+				beneficiary_splits[&BeneficiarySplitType::PrimarySaleValidators]
+					.saturating_add(stakeholder_share)
+					<= Permill::from_percent(50),
+				Error::<T>::InvalidPrimarySaleBeneficiarySplit
+			);
+
+			// Check if the secondary sale beneficiary split (for validators and the project owner) exceeds 50%
+			ensure!(
+				beneficiary_splits[&BeneficiarySplitType::SecondarySaleValidators]
+					.saturating_add(beneficiary_splits[&BeneficiarySplitType::SecondarySaleOwner])
+					.saturating_add(stakeholder_share)
+					<= Permill::from_percent(50),
+				Error::<T>::InvalidSecondarySaleBeneficiarySplit
+			);
+
+			Ok(())
+		}
+
+		// Validate stakeholder shares and return the total stakeholder share
+		pub fn validate_stakeholder_shares(
+			stakeholders: &BTreeMap<AccountIdOf<T>, Permill>,
+		) -> Result<Permill, DispatchError> {
+			// Check if the project can hold all stakeholders
+			ensure!(
+				stakeholders.len() as u32 <= T::MaxStakeholdersPerProject::get(),
+				Error::<T>::TooManyStakeholders
+			);
+
+			let mut stakeholder_share = Permill::zero();
+
+			for share in stakeholders.values() {
+				// Check if the stakeholder share is zero
+				ensure!(!share.is_zero(), Error::<T>::InvalidStakeholderShare);
+
+				stakeholder_share = stakeholder_share.saturating_add(*share);
+			}
+
+			Ok(stakeholder_share)
+		}
+
+		// Validate time value
+		pub fn validate_time_value(
+			time_type: &TimeType,
+			time_value: BlockNumber<T>,
+		) -> DispatchResult {
+			// Check if the user is trying to update the pallet base time
+			ensure!(
+				*time_type != TimeType::PalletBaseTime,
+				Error::<T>::UnableToChangePalletBaseTime
+			);
+
+			// Check if the new time value is not 0
+			ensure!(time_value != BlockNumber::<T>::from(0u32), Error::<T>::InvalidTimeoutValue);
+
+			Ok(())
+		}
+
+		// Check and convert documentation given in the genesis config
+		// Note: Panics if the account or documentation is already in use, or if the
+		//		 documentation isn't a valid IPFS link
+		fn genesis_documentation(
+			account_id: AccountIdOf<T>,
+			documentation: &[u8],
+		) -> BoundedString<T::IPFSLength> {
+			assert!(Self::is_account_id_available(account_id), "Genesis account ID already in use");
+
+			let documentation_ipfs = sp_std::str::from_utf8(documentation)
+				.ok()
+				.and_then(|ipfs| BoundedString::<T::IPFSLength>::try_from(ipfs).ok())
+				.expect("Invalid genesis documentation IPFS link");
+
+			assert!(
+				Self::is_ipfs_available(documentation_ipfs.clone()),
+				"Genesis documentation was used previously"
+			);
+
+			documentation_ipfs
+		}
+
+		// Check if the documentation (ipfs link) has been used previously
+		// Return false if the documentation is used
+		// Return true if the documentation is available
+		pub fn is_ipfs_available(ipfs: BoundedString<T::IPFSLength>) -> bool {
+			!UsedDocumentation::<T>::contains_key(ipfs)
+		}
+
+		// Check if the account is tied to any existing entity on the pallet
+		// Return false if the account_id is used
+		// Return true if the account_id is available
+		pub fn is_account_id_available(account_id: AccountIdOf<T>) -> bool {
+			// Check in carbon footprint, trader, project validator and project owner accounts
+			if CarbonFootprintAccounts::<T>::contains_key(account_id.clone())
+				|| TraderAccounts::<T>::contains_key(account_id.clone())
+				|| Validators::<T>::contains_key(account_id.clone())
+				|| ProjectOwners::<T>::contains_key(account_id.clone())
+			{
+				return false;
+			}
+
+			return true;
+		}
+
+		// Check if the account is eligible to be a carbon footpring account
+		// Return true if the account_id isn't in use for another account type
+		// Return false if the account_id is in use for another account type
+		pub fn is_eligible_for_cfa(account_id: AccountIdOf<T>) -> bool {
+			// Check in carbon footprint, trader, validator and project owner accounts
+			if TraderAccounts::<T>::contains_key(account_id.clone())
+				|| Validators::<T>::contains_key(account_id.clone())
+				|| ProjectOwners::<T>::contains_key(account_id.clone())
+			{
+				return false;
+			}
+
+			return true;
+		}
+
+		// Check if the account is eligible for carbon credit transactions
+		// Return true if the account_id can transact with carbon credits (CFA, Trader, Project Owner)
+		// Return true if the account_id can't transact with carbon credits (Validator, or non-registered account)
+		pub fn is_eligible_for_carbon_credit_transaction(account_id: AccountIdOf<T>) -> bool {
+			if CarbonFootprintAccounts::<T>::contains_key(account_id.clone())
+				|| TraderAccounts::<T>::contains_key(account_id.clone())
+				|| ProjectOwners::<T>::contains_key(account_id.clone())
+			{
+				return true;
+			}
+
+			return false;
+		}
+
+		// Check if the account can transact carbon credits from the carbon credit batch
+		// Note: Same checks as the ones used by the carbon credit trading extrinsics
+		pub fn ensure_can_transact_carbon_credits(
+			account_id: &AccountIdOf<T>,
+			batch_hash: H256,
+		) -> Result<(), Error<T>> {
+			ensure!(
+				Self::is_eligible_for_carbon_credit_transaction(account_id.clone()),
+				Error::<T>::UserIsNotEligibleForCarbonCreditTransactions
+			);
+
+			// Project owners with standing debts can't transact carbon credits
+			if ProjectOwners::<T>::contains_key(account_id) {
+				ensure!(
+					!ProjectOwnerDebts::<T>::contains_key(account_id),
+					Error::<T>::ProjectOwnerHasStandingDebts
+				);
+			}
+
+			let carbon_credit_batch = CarbonCreditBatches::<T>::get(batch_hash)
+				.ok_or(Error::<T>::CarbonCreditBatchDoesNotExist)?;
+
+			ensure!(
+				carbon_credit_batch.status == CarbonCreditBatchStatus::Active,
+				Error::<T>::CarbonCreditBatchIsNotActive
+			);
+
+			Ok(())
+		}
+
+		// Check if the account has submitted a carbon footprint report for voting
+		// Note: If a user has submitted a CF report he/she then can not registed as another account type
+		pub fn is_trying_to_register_as_cfa(account_id: AccountIdOf<T>) -> bool {
+			let mut result = false;
+
+			// Check in CF accounts
+			for (_, cf_report) in <CarbonFootprintReports<T>>::iter() {
+				// Check if user has an active CF report that is up for voting
+				if cf_report.cf_account == account_id && cf_report.voting_active {
+					result = true;
+				}
+			}
+
+			result
+		}
+
+		// Check if the carbon footprint account can hold another documentation IPFS link
+		pub fn has_room_for_cfa_documentation(account_id: AccountIdOf<T>) -> bool {
+			CarbonFootprintAccounts::<T>::get(account_id).map_or(true, |cfa_info| {
+				(cfa_info.documentation_ipfses.len() as u32) < T::MaxDocumentsPerCfa::get()
+			})
+		}
+
+		// Check if the project owner can hold another project
+		pub fn has_room_for_project(project_owner: AccountIdOf<T>) -> bool {
+			ProjectsByOwner::<T>::get(project_owner)
+				.map_or(true, |projects| (projects.len() as u32) < T::MaxProjectsPerOwner::get())
+		}
+
+		// Check if the project can hold another carbon credit batch
+		pub fn has_room_for_batch(project_hash: H256) -> bool {
+			BatchesByProject::<T>::get(project_hash)
+				.map_or(true, |batches| (batches.len() as u32) < T::MaxBatchesPerProject::get())
+		}
+
+		// Recalculate CFA balance for a redacted carbon credit retirement
+		fn recalculate_cfa_balance(
+			retirement_info: &CarbonCreditRetirementInfo<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>,
+		) {
+			let mut footprint_account =
+				CarbonFootprintAccounts::<T>::get(retirement_info.carbon_footprint_account.clone())
+					.unwrap();
+
+			if retirement_info.credit_amount >= footprint_account.carbon_footprint_surplus {
+				let change =
+					retirement_info.credit_amount - footprint_account.carbon_footprint_surplus;
+
+				footprint_account = CarbonFootprintAccountInfo {
+					carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
+					carbon_footprint_deficit: footprint_account.carbon_footprint_deficit + change,
+					..footprint_account
+				};
+			} else {
+				footprint_account = CarbonFootprintAccountInfo {
+					carbon_footprint_surplus: footprint_account.carbon_footprint_surplus
+						- retirement_info.credit_amount,
+					..footprint_account
+				};
+			}
+
+			CarbonFootprintAccounts::<T>::insert(
+				retirement_info.carbon_footprint_account.clone(),
+				footprint_account,
+			);
+		}
+
+		// Add project owner debt towards an account
+		// Note: Once only one creditor slot is left it is reserved for the pallet, debts towards
+		// any new creditors are then added to the pallet debt
+		fn add_debt(debts: &mut DebtsOf<T>, account_id: AccountIdOf<T>, amount: BalanceOf<T>) {
+			let pallet_id = Self::pallet_id();
+			let max_debts = T::MaxDebtsPerProjectOwner::get() as usize;
+
+			let has_room = debts.len() < max_debts
+				&& (account_id == pallet_id
+					|| debts.contains_key(&pallet_id)
+					|| debts.len() + 1 < max_debts);
+
+			let creditor =
+				if debts.contains_key(&account_id) || has_room { account_id } else { pallet_id };
+
+			let debt_amount = debts.get(&creditor).copied().unwrap_or_default() + amount;
+
+			let _ = debts.try_insert(creditor, debt_amount);
+		}
+
+		// Process pending carbon credit batch redactions
+		// Note: Processing stops once the weight limit has been reached, the redaction progress
+		// is saved so processing can resume in the next block
+		pub fn process_redactions(meter: &mut WeightMeter) {
+			let db_weight = T::DbWeight::get();
+
+			while let Some((batch_hash, mut progress)) = PendingRedactions::<T>::iter().next() {
+				// Read redaction progress, batch, project, debts and batch retirements,
+				// write redaction progress and debts
+				if meter.try_consume(db_weight.reads_writes(5, 2)).is_err() {
+					return;
+				}
+
+				let batch_info = CarbonCreditBatches::<T>::get(batch_hash).unwrap();
+				let project_owner =
+					Projects::<T>::get(batch_info.project_hash).unwrap().project_owner;
+
+				let mut debts = ProjectOwnerDebts::<T>::get(project_owner.clone());
+
+				let redaction_processed = Self::process_batch_redaction(
+					batch_hash,
+					batch_info.penalty_repay_price,
+					&project_owner,
+					&mut progress,
+					&mut debts,
+					meter,
+				);
+
+				if !debts.is_empty() {
+					ProjectOwnerDebts::<T>::insert(project_owner, debts);
+				}
+
+				if !redaction_processed {
+					PendingRedactions::<T>::insert(batch_hash, progress);
+
+					return;
+				}
+
+				PendingRedactions::<T>::remove(batch_hash);
+
+				Self::deposit_event(Event::CarbonCreditBatchRedactionProcessed(batch_hash));
+			}
+		}
+
+		// Recalculate CFA balances and project owner debts for a redacted carbon credit batch
+		// Note: Returns false if the weight limit was reached before the whole batch was processed
+		fn process_batch_redaction(
+			batch_hash: H256,
+			penalty_repay_price: BalanceOf<T>,
+			project_owner: &AccountIdOf<T>,
+			progress: &mut RedactionProgress<AccountIdOf<T>>,
+			debts: &mut DebtsOf<T>,
+			meter: &mut WeightMeter,
+		) -> bool {
+			let db_weight = T::DbWeight::get();
+
+			// Go through all batch retirements
+			if !progress.retirements_processed {
+				let retirements = RetirementsByBatch::<T>::get(batch_hash).unwrap_or_default();
+				let start = progress.last_retirement.map_or(Bound::Unbounded, Bound::Excluded);
+
+				for retirement_hash in retirements.range((start, Bound::Unbounded)) {
+					// Read retirement and CFA, write CFA
+					if meter.try_consume(db_weight.reads_writes(2, 1)).is_err() {
+						return false;
+					}
+
+					let retirement_info =
+						CarbonCreditRetirements::<T>::get(retirement_hash).unwrap();
+
+					Self::recalculate_cfa_balance(&retirement_info);
+
+					Self::add_debt(
+						debts,
+						retirement_info.carbon_footprint_account,
+						retirement_info.credit_amount.saturating_mul(penalty_repay_price),
+					);
+
+					progress.last_retirement = Some(*retirement_hash);
+				}
+
+				progress.retirements_processed = true;
+			}
+
+			// Go through all batch holdings
+			let holdings = match progress.last_holding.clone() {
+				Some(last_holding) => CarbonCreditHoldings::<T>::iter_prefix_from(
+					batch_hash,
+					CarbonCreditHoldings::<T>::hashed_key_for(batch_hash, last_holding),
+				),
+				None => CarbonCreditHoldings::<T>::iter_prefix(batch_hash),
+			};
+
+			for (holding_account, holdings_info) in holdings {
+				// Read holdings
+				if meter.try_consume(db_weight.reads(1)).is_err() {
+					return false;
+				}
+
+				let total_credits =
+					holdings_info.available_amount + holdings_info.unavailable_amount;
+				let debt_amount = total_credits.saturating_mul(penalty_repay_price);
+
+				if holding_account == *project_owner {
+					Self::add_debt(debts, Self::pallet_id(), debt_amount);
+				} else {
+					Self::add_debt(debts, holding_account.clone(), debt_amount);
+				}
+
+				progress.last_holding = Some(holding_account);
+			}
+
+			true
+		}
+
+		// Match a new limit order against the opposite side of the carbon credit batch order book
+		// Note: Maker orders are matched by price-time priority at the maker price, orders of the
+		// taker himself are skipped
+		// Note: Maker bids that can't pay the maker fee (on top of the held funds) are skipped
+		// Note: Returns the remaining (unmatched) amount and the number of matched maker orders
+		fn match_limit_order(
+			batch_hash: H256,
+			taker: &AccountIdOf<T>,
+			side: OrderSide,
+			credit_price: BalanceOf<T>,
+			credit_amount: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, u32), DispatchError> {
+			let maker_side = match side {
+				OrderSide::Bid => OrderSide::Ask,
+				OrderSide::Ask => OrderSide::Bid,
+			};
+
+			let mut order_book = OrderBooks::<T>::get(batch_hash, maker_side).unwrap_or_default();
+			let mut filled_orders = Vec::<H256>::new();
+			let mut remaining_amount = credit_amount;
+			let mut matched_orders = 0u32;
+
+			for (maker_price, maker_hash) in order_book.iter() {
+				let is_crossing = match side {
+					OrderSide::Bid => *maker_price <= credit_price,
+					OrderSide::Ask => *maker_price >= credit_price,
+				};
+
+				if remaining_amount == BalanceOf::<T>::from(0u32) || !is_crossing {
+					break;
+				}
+
+				let mut maker_order = LimitOrders::<T>::get(maker_hash).unwrap();
+
+				if maker_order.owner == *taker {
+					continue;
+				}
+
+				let fill_amount = remaining_amount.min(maker_order.credit_amount);
+
+				if maker_side == OrderSide::Bid {
+					let fill_value = fill_amount
+						.checked_mul(maker_price)
+						.ok_or(Error::<T>::ArithmeticOverflow)?;
+					let (maker_fee, _) = Self::calculate_trading_fees(fill_value);
+					let free_funds = T::Currency::reducible_balance(
+						&maker_order.owner,
+						Preservation::Preserve,
+						Fortitude::Polite,
+					);
+
+					if maker_fee > free_funds {
+						continue;
+					}
+				}
+
+				let (buyer, seller) = match side {
+					OrderSide::Bid => (taker, &maker_order.owner),
+					OrderSide::Ask => (&maker_order.owner, taker),
+				};
+
+				let (maker_fee, taker_fee) = Self::settle_limit_order_trade(
+					batch_hash,
+					buyer,
+					seller,
+					maker_side,
+					fill_amount,
+					*maker_price,
+				)?;
+
+				Self::deposit_event(Event::LimitOrderMatched(
+					batch_hash,
+					*maker_hash,
+					maker_order.owner.clone(),
+					taker.clone(),
+					fill_amount,
+					*maker_price,
+					maker_fee,
+					taker_fee,
+				));
+
+				remaining_amount -= fill_amount;
+				maker_order.credit_amount -= fill_amount;
+				matched_orders += 1;
+
+				if maker_order.credit_amount == BalanceOf::<T>::from(0u32) {
+					LimitOrders::<T>::remove(maker_hash);
+
+					filled_orders.push(*maker_hash);
+				} else {
+					LimitOrders::<T>::insert(maker_hash, maker_order);
+				}
+			}
+
+			order_book.retain(|(_, order_hash)| !filled_orders.contains(order_hash));
+
+			Self::set_order_book(batch_hash, maker_side, order_book);
+
+			Ok((remaining_amount, matched_orders))
+		}
+
+		// Settle an order book trade (do the beneficiary splits, pay the trading fees and the seller
+		// and move credits)
+		// Note: The maker side funds (bids) or credits (asks) are taken from the order book escrow
+		// Note: Returns the maker and taker fees
+		fn settle_limit_order_trade(
+			batch_hash: H256,
+			buyer: &AccountIdOf<T>,
+			seller: &AccountIdOf<T>,
+			maker_side: OrderSide,
+			credit_amount: BalanceOf<T>,
+			credit_price: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let mut amount_to_pay =
+				credit_amount.checked_mul(&credit_price).ok_or(Error::<T>::ArithmeticOverflow)?;
+			let (maker_fee, taker_fee) = Self::calculate_trading_fees(amount_to_pay);
+
+			if maker_side == OrderSide::Bid {
+				T::Currency::release(
+					&HoldReason::OrderBookEscrow.into(),
+					buyer,
+					amount_to_pay,
+					Precision::Exact,
+				)?;
+			}
+
+			let carbon_credit_batch = CarbonCreditBatches::<T>::get(batch_hash).unwrap();
+			let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
+
+			// Do beneficiary splits (paid by the buyer)
+			let split_gains = Self::process_beneficiary_splits(
+				buyer.clone(),
+				seller,
+				carbon_credit_batch.project_hash,
+				project.project_owner,
+				carbon_credit_batch.validator_benefactors,
+				amount_to_pay,
+			)?;
+
+			amount_to_pay =
+				amount_to_pay.checked_sub(&split_gains).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Pay trading fees
+			// Note: The maker is the seller for asks and the buyer for bids
+			let (buyer_fee, seller_fee) = match maker_side {
+				OrderSide::Ask => (taker_fee, maker_fee),
+				OrderSide::Bid => (maker_fee, taker_fee),
+			};
+
+			amount_to_pay =
+				Self::process_trading_fees(buyer, amount_to_pay, buyer_fee, seller_fee)?;
+
+			// Transfer funds
+			T::Currency::transfer(buyer, seller, amount_to_pay, Preservation::Preserve)?;
+
+			// Update seller holdings
+			let mut seller_holdings =
+				CarbonCreditHoldings::<T>::get(batch_hash, seller.clone()).unwrap();
+
+			if maker_side == OrderSide::Ask {
+				seller_holdings.unavailable_amount -= credit_amount;
+			} else {
+				seller_holdings.available_amount -= credit_amount;
+			}
+
+			if seller_holdings == CarbonCreditHoldingsInfo::default() {
+				CarbonCreditHoldings::<T>::remove(batch_hash, seller.clone());
+			} else {
+				CarbonCreditHoldings::<T>::insert(batch_hash, seller.clone(), seller_holdings);
+			}
+
+			// Update buyer holdings
+			CarbonCreditHoldings::<T>::mutate(batch_hash, buyer.clone(), |holdings| {
+				holdings.get_or_insert_with(CarbonCreditHoldingsInfo::default).available_amount +=
+					credit_amount;
+			});
+
+			Ok((maker_fee, taker_fee))
+		}
+
+		// Add limit order to the carbon credit batch order book
+		// Note: The order is placed after all orders with the same or a better price
+		fn insert_into_order_book(
+			batch_hash: H256,
+			side: OrderSide,
+			credit_price: BalanceOf<T>,
+			order_hash: H256,
+		) -> DispatchResult {
+			let mut order_book = OrderBooks::<T>::get(batch_hash, side).unwrap_or_default();
+
+			let index = order_book
+				.iter()
+				.position(|(price, _)| match side {
+					OrderSide::Bid => *price < credit_price,
+					OrderSide::Ask => *price > credit_price,
+				})
+				.unwrap_or(order_book.len());
+
+			order_book
+				.try_insert(index, (credit_price, order_hash))
+				.map_err(|_| Error::<T>::OrderBookIsFull)?;
+
+			OrderBooks::<T>::insert(batch_hash, side, order_book);
+
+			Ok(())
+		}
+
+		// Update the carbon credit batch order book side (empty sides are removed)
+		fn set_order_book(batch_hash: H256, side: OrderSide, order_book: OrderBookOf<T>) {
+			if order_book.is_empty() {
+				OrderBooks::<T>::remove(batch_hash, side);
+			} else {
+				OrderBooks::<T>::insert(batch_hash, side, order_book);
+			}
+		}
+
+		// Get the order book depth of a carbon credit batch side (e.g. for a runtime API)
+		// Note: Returns the price levels (price and total credit amount) from the best price
+		pub fn order_book_depth(
+			batch_hash: H256,
+			side: OrderSide,
+		) -> Vec<(BalanceOf<T>, BalanceOf<T>)> {
+			let mut depth = Vec::<(BalanceOf<T>, BalanceOf<T>)>::new();
+
+			for (credit_price, order_hash) in
+				OrderBooks::<T>::get(batch_hash, side).unwrap_or_default()
+			{
+				let credit_amount = LimitOrders::<T>::get(order_hash)
+					.map(|limit_order| limit_order.credit_amount)
+					.unwrap_or_default();
+
+				match depth.last_mut() {
+					Some((price, amount)) if *price == credit_price => *amount += credit_amount,
+					_ => depth.push((credit_price, credit_amount)),
+				}
+			}
+
+			depth
+		}
+
+		// Check if vote has passed
+		// Note: The votes needed are the ratio of all votes rounded down (e.g. 2 out of 4 votes
+		// pass with a ratio of two thirds), so a vote without any votes passes (unless the ratio
+		// is 0) and the smallest non-zero ratio passes any vote
+		pub fn has_vote_passed(total_votes: u16, votes_for: u16) -> bool {
+			let vote_pass_ratio = VotePassRatio::<T>::get();
+
+			if vote_pass_ratio.is_zero() {
+				return votes_for > total_votes.saturating_sub(votes_for);
+			}
+
+			votes_for >= vote_pass_ratio.mul_floor(total_votes)
+		}
+
+		// Check if a block has already passed
+		fn has_block_passed(block: BlockNumber<T>) -> bool {
+			block <= frame_system::Pallet::<T>::block_number()
+		}
+
+		// Check if the base pallet time update is due
+		// Note: The first base pallet time can be set to any passed block, after that the base
+		// pallet time can only move forward by a year (in blocks)
+		pub fn is_pallet_base_time_update_due(new_pallet_base_time: BlockNumber<T>) -> bool {
+			let pallet_times = PalletTimeValues::<T>::get();
+
+			if pallet_times.pallet_base_time == BlockNumber::<T>::from(0u32) {
+				return new_pallet_base_time != BlockNumber::<T>::from(0u32)
+					&& Self::has_block_passed(new_pallet_base_time);
+			}
+
+			new_pallet_base_time
+				== pallet_times.pallet_base_time + pallet_times.number_of_blocks_per_year
+				&& Self::has_block_passed(new_pallet_base_time)
+		}
+
+		// Check if a voting timeout event exists for a passed block
+		fn is_voting_timeout_due(
+			ipfs: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			Self::has_block_passed(timeout_block)
+				&& VotingTimeouts::<T>::get(timeout_block)
+					.map_or(false, |timeout_events| timeout_events.contains(ipfs))
+		}
+
+		// Check if a complaint timeout event exists for a passed block
+		fn is_complaint_timeout_due(
+			complaint: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			Self::has_block_passed(timeout_block)
+				&& ComplaintTimeouts::<T>::get(timeout_block)
+					.map_or(false, |complaint_events| complaint_events.contains(complaint))
+		}
+
+		// Check if a penalty timeout event exists for a passed block (for accounts)
+		fn is_account_penalty_timeout_due(
+			account_id: &AccountIdOf<T>,
+			penalty_level: u8,
+			penalty_timeout: BlockNumber<T>,
+		) -> bool {
+			penalty_level > 0
+				&& Self::has_block_passed(penalty_timeout)
+				&& PenaltyTimeoutsAccounts::<T>::get(penalty_timeout)
+					.map_or(false, |account_ids| account_ids.contains(account_id))
+		}
+
+		// Check if the carbon footprint report update is due
+		pub fn is_carbon_footprint_report_update_due(
+			ipfs: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			CarbonFootprintReports::<T>::get(ipfs).map_or(false, |report| report.voting_active)
+				&& Self::is_voting_timeout_due(ipfs, timeout_block)
+		}
+
+		// Check if the project proposal update is due
+		pub fn is_project_proposal_update_due(
+			ipfs: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			ProjectProposals::<T>::get(ipfs).map_or(false, |proposal| proposal.voting_active)
+				&& Self::is_voting_timeout_due(ipfs, timeout_block)
+		}
+
+		// Check if the carbon credit batch proposal update is due
+		pub fn is_carbon_credit_batch_proposal_update_due(
+			ipfs: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			CarbonCreditBatchProposals::<T>::get(ipfs)
+				.map_or(false, |proposal| proposal.voting_active)
+				&& Self::is_voting_timeout_due(ipfs, timeout_block)
+		}
+
+		// Check if the beneficiary split proposal update is due
+		pub fn is_beneficiary_split_proposal_update_due(
+			ipfs: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			BeneficiarySplitProposals::<T>::get(ipfs)
+				.map_or(false, |proposal| proposal.voting_active)
+				&& Self::is_voting_timeout_due(ipfs, timeout_block)
+		}
+
+		// Check if the carbon credit sale order update is due
+		// Note: The timeout block is taken from the sale order itself
+		pub fn is_carbon_credit_sale_order_update_due(sale_hash: H256) -> bool {
+			CarbonCreditSaleOrders::<T>::get(sale_hash).map_or(false, |sale_order| {
+				sale_order.status == SaleOrderStatus::Open
+					&& sale_order.sale_timeout.map_or(false, |sale_timeout| {
+						Self::has_block_passed(sale_timeout)
+							&& SaleOrderTimeouts::<T>::get(sale_timeout)
+								.map_or(false, |sale_events| sale_events.contains(&sale_hash))
+					})
+			})
+		}
+
+		// Check if the carbon credit buy order update is due
+		// Note: The timeout block is taken from the buy order itself
+		pub fn is_carbon_credit_buy_order_update_due(buy_hash: H256) -> bool {
+			CarbonCreditBuyOrders::<T>::get(buy_hash).map_or(false, |buy_order| {
+				buy_order.buy_active
+					&& Self::has_block_passed(buy_order.buy_timeout)
+					&& BuyOrderTimeouts::<T>::get(buy_order.buy_timeout)
+						.map_or(false, |buy_events| buy_events.contains(&buy_hash))
+			})
+		}
+
+		// Check if the carbon credit auction update is due
+		// Note: The timeout block is taken from the auction itself
+		pub fn is_carbon_credit_auction_update_due(auction_hash: H256) -> bool {
+			CarbonCreditAuctions::<T>::get(auction_hash).map_or(false, |auction| {
+				auction.status == AuctionStatus::Open
+					&& Self::has_block_passed(auction.end_block)
+					&& AuctionTimeouts::<T>::get(auction.end_block)
+						.map_or(false, |auction_events| auction_events.contains(&auction_hash))
+			})
+		}
+
+		// Check if the complaint for account update is due
+		pub fn is_complaint_for_account_update_due(
+			complaint: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			ComplaintsForAccounts::<T>::get(complaint)
+				.map_or(false, |specific_complaint| specific_complaint.complaint_active)
+				&& Self::is_complaint_timeout_due(complaint, timeout_block)
+		}
+
+		// Check if the complaint for hash update is due
+		pub fn is_complaint_for_hash_update_due(
+			complaint: &BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) -> bool {
+			ComplaintsForHashes::<T>::get(complaint)
+				.map_or(false, |specific_complaint| specific_complaint.complaint_active)
+				&& Self::is_complaint_timeout_due(complaint, timeout_block)
+		}
+
+		// Check if the project owner penalty level update is due
+		// Note: The timeout block is taken from the project owner itself
+		pub fn is_project_owner_penalty_update_due(account_id: &AccountIdOf<T>) -> bool {
+			ProjectOwners::<T>::get(account_id).map_or(false, |project_owner| {
+				Self::is_account_penalty_timeout_due(
+					account_id,
+					project_owner.penalty_level,
+					project_owner.penalty_timeout,
+				)
+			})
+		}
+
+		// Check if the validator penalty level update is due
+		// Note: The timeout block is taken from the validator itself
+		pub fn is_validator_penalty_update_due(account_id: &AccountIdOf<T>) -> bool {
+			Validators::<T>::get(account_id).map_or(false, |validator| {
+				Self::is_account_penalty_timeout_due(
+					account_id,
+					validator.penalty_level,
+					validator.penalty_timeout,
+				)
+			})
+		}
+
+		// Check if the project penalty level update is due
+		// Note: The timeout block is taken from the project itself
+		pub fn is_project_penalty_update_due(hash: H256) -> bool {
+			Projects::<T>::get(hash).map_or(false, |project| {
+				project.penalty_level > 0
+					&& Self::has_block_passed(project.penalty_timeout)
+					&& PenaltyTimeoutsHashes::<T>::get(project.penalty_timeout)
+						.map_or(false, |hashes| hashes.contains(&hash))
+			})
+		}
+
+		// Remove voting timeout event
+		fn remove_voting_timeout(
+			timeout_block: BlockNumber<T>,
+			ipfs: &BoundedString<T::IPFSLength>,
+		) {
+			VotingTimeouts::<T>::mutate_exists(timeout_block, |timeout_events| {
+				if let Some(events) = timeout_events {
+					events.remove(ipfs);
+
+					if events.is_empty() {
+						*timeout_events = None;
+					}
+				}
+			});
+		}
+
+		// Remove complaint timeout event
+		fn remove_complaint_timeout(
+			timeout_block: BlockNumber<T>,
+			complaint: &BoundedString<T::IPFSLength>,
+		) {
+			ComplaintTimeouts::<T>::mutate_exists(timeout_block, |complaint_events| {
+				if let Some(events) = complaint_events {
+					events.remove(complaint);
+
+					if events.is_empty() {
+						*complaint_events = None;
+					}
+				}
+			});
+		}
+
+		// Get the sale order timeout block for the expiry (None for good-til-cancelled sale orders)
+		// Note: Sale orders without an expiry time out after the sales timeout
+		fn sale_order_timeout(
+			expiry: Option<SaleOrderExpiry<BlockNumber<T>>>,
+		) -> Result<Option<BlockNumber<T>>, Error<T>> {
+			let sales_timeout = PalletTimeValues::<T>::get().sales_timeout;
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			match expiry.unwrap_or(SaleOrderExpiry::AfterBlocks(sales_timeout)) {
+				SaleOrderExpiry::AfterBlocks(blocks) => {
+					ensure!(
+						blocks > BlockNumber::<T>::from(0u32) && blocks <= sales_timeout,
+						Error::<T>::InvalidSaleOrderExpiry
+					);
+
+					Ok(Some(current_block + blocks))
+				},
+				SaleOrderExpiry::GoodTilCancelled => Ok(None),
+			}
+		}
+
+		// Insert sale order timeout event
+		fn insert_sale_order_timeout(
+			timeout_block: BlockNumber<T>,
+			sale_hash: H256,
+		) -> Result<(), Error<T>> {
+			SaleOrderTimeouts::<T>::try_mutate(timeout_block, |sale_events| {
+				sale_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(sale_hash)
+			})
+			.map(|_| ())
+			.map_err(|_| Error::<T>::TooManyTimeouts)
+		}
+
+		// Remove sale order timeout event
+		fn remove_sale_order_timeout(timeout_block: BlockNumber<T>, sale_hash: H256) {
+			SaleOrderTimeouts::<T>::mutate_exists(timeout_block, |sale_events| {
+				if let Some(events) = sale_events {
+					events.remove(&sale_hash);
+
+					if events.is_empty() {
+						*sale_events = None;
+					}
+				}
+			});
+		}
+
+		// Remove buy order timeout event
+		fn remove_buy_order_timeout(timeout_block: BlockNumber<T>, buy_hash: H256) {
+			BuyOrderTimeouts::<T>::mutate_exists(timeout_block, |buy_events| {
+				if let Some(events) = buy_events {
+					events.remove(&buy_hash);
+
+					if events.is_empty() {
+						*buy_events = None;
+					}
+				}
+			});
+		}
+
+		// Remove auction timeout event
+		fn remove_auction_timeout(timeout_block: BlockNumber<T>, auction_hash: H256) {
+			AuctionTimeouts::<T>::mutate_exists(timeout_block, |auction_events| {
+				if let Some(events) = auction_events {
+					events.remove(&auction_hash);
+
+					if events.is_empty() {
+						*auction_events = None;
+					}
+				}
+			});
+		}
+
+		// Remove penalty timeout event (for accounts)
+		fn remove_account_penalty_timeout(
+			timeout_block: BlockNumber<T>,
+			account_id: &AccountIdOf<T>,
+		) {
+			PenaltyTimeoutsAccounts::<T>::mutate_exists(timeout_block, |penalty_events| {
+				if let Some(events) = penalty_events {
+					events.remove(account_id);
+
+					if events.is_empty() {
+						*penalty_events = None;
+					}
+				}
+			});
+		}
+
+		// Remove penalty timeout event (for hashes)
+		fn remove_hash_penalty_timeout(timeout_block: BlockNumber<T>, hash: H256) {
+			PenaltyTimeoutsHashes::<T>::mutate_exists(timeout_block, |penalty_events| {
+				if let Some(events) = penalty_events {
+					events.remove(&hash);
+
+					if events.is_empty() {
+						*penalty_events = None;
+					}
+				}
+			});
+		}
+
+		// Create penalty timeout event (for accounts)
+		// Note: The timeout block should be taken from account_penalty_timeout_block
+		fn insert_account_penalty_timeout(
+			timeout_block: BlockNumber<T>,
+			account_id: AccountIdOf<T>,
+		) {
+			PenaltyTimeoutsAccounts::<T>::mutate(timeout_block, |penalty_events| {
+				let _ =
+					penalty_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(account_id);
+			});
+		}
+
+		// Create penalty timeout event (for hashes)
+		// Note: The timeout block should be taken from hash_penalty_timeout_block
+		fn insert_hash_penalty_timeout(timeout_block: BlockNumber<T>, hash: H256) {
+			PenaltyTimeoutsHashes::<T>::mutate(timeout_block, |penalty_events| {
+				let _ = penalty_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(hash);
+			});
+		}
+
+		// Get the first block (starting from the given block) with room for a penalty timeout
+		// event (for accounts)
+		fn account_penalty_timeout_block(block: BlockNumber<T>) -> BlockNumber<T> {
+			let mut timeout_block = block;
+
+			while PenaltyTimeoutsAccounts::<T>::get(timeout_block)
+				.map_or(false, |events| events.len() as u32 >= T::MaxTimeoutsPerBlock::get())
+			{
+				timeout_block += BlockNumber::<T>::from(1u32);
+			}
+
+			timeout_block
+		}
+
+		// Get the first block (starting from the given block) with room for a penalty timeout
+		// event (for hashes)
+		fn hash_penalty_timeout_block(block: BlockNumber<T>) -> BlockNumber<T> {
+			let mut timeout_block = block;
+
+			while PenaltyTimeoutsHashes::<T>::get(timeout_block)
+				.map_or(false, |events| events.len() as u32 >= T::MaxTimeoutsPerBlock::get())
+			{
+				timeout_block += BlockNumber::<T>::from(1u32);
+			}
+
+			timeout_block
+		}
+
+		// Create pruning timeout event for a finished record
+		// Note: The event is created in the first block with room for it once the retention
+		// period is over
+		pub(crate) fn insert_pruning_timeout(record: FinishedRecordOf<T>) {
+			let mut timeout_block =
+				frame_system::Pallet::<T>::block_number() + T::RetentionPeriod::get();
+
+			while PruningTimeouts::<T>::get(timeout_block)
+				.map_or(false, |events| events.len() as u32 >= T::MaxTimeoutsPerBlock::get())
+			{
+				timeout_block += BlockNumber::<T>::from(1u32);
+			}
+
+			PruningTimeouts::<T>::mutate(timeout_block, |pruning_events| {
+				let _ = pruning_events.get_or_insert_with(BoundedBTreeSet::new).try_insert(record);
+			});
+		}
+
+		// Remove pruning timeout event
+		fn remove_pruning_timeout(timeout_block: BlockNumber<T>, record: &FinishedRecordOf<T>) {
+			PruningTimeouts::<T>::mutate_exists(timeout_block, |pruning_events| {
+				if let Some(events) = pruning_events {
+					events.remove(record);
+
+					if events.is_empty() {
+						*pruning_events = None;
+					}
+				}
+			});
+		}
+
+		// Collect a fee for a report, proposal or complaint
+		// Note: In deposit mode the fee is held and settled once the vote finishes
+		fn collect_fee_or_deposit(
+			user: AccountIdOf<T>,
 			ipfs: BoundedString<T::IPFSLength>,
+			amount: BalanceOf<T>,
+			reason: HoldReason,
 		) -> DispatchResult {
+			if !T::DepositMode::get() {
+				T::Currency::transfer(&user, &Self::pallet_id(), amount, Preservation::Preserve)?;
+
+				return Ok(());
+			}
+
+			T::Currency::hold(&reason.into(), &user, amount)?;
+
+			Deposits::<T>::insert(ipfs.clone(), DepositInfo { depositor: user.clone(), amount });
+
+			Self::deposit_event(Event::DepositHeld(user, ipfs, amount));
+
+			Ok(())
+		}
+
+		// Settle a held deposit
+		// Note: The deposit is returned if the vote passed and moved to the pallet otherwise
+		fn settle_deposit(
+			ipfs: BoundedString<T::IPFSLength>,
+			vote_passed: bool,
+			reason: HoldReason,
+		) {
+			if let Some(deposit) = Deposits::<T>::get(ipfs.clone()) {
+				let result = if vote_passed {
+					T::Currency::release(
+						&reason.into(),
+						&deposit.depositor,
+						deposit.amount,
+						Precision::BestEffort,
+					)
+				} else {
+					T::Currency::transfer_on_hold(
+						&reason.into(),
+						&deposit.depositor,
+						&Self::pallet_id(),
+						deposit.amount,
+						Precision::BestEffort,
+						Restriction::Free,
+						Fortitude::Force,
+					)
+				};
+
+				// Keep the deposit record if the held funds couldn't be settled
+				if let Err(err) = result {
+					warn!("💰 Failed to settle deposit for {:?}. 🚧 Error: {:?}", ipfs, err);
+
+					return;
+				}
+
+				Deposits::<T>::remove(ipfs.clone());
+
+				if vote_passed {
+					Self::deposit_event(Event::DepositReleased(
+						deposit.depositor,
+						ipfs,
+						deposit.amount,
+					));
+				} else {
+					Self::deposit_event(Event::DepositSlashed(
+						deposit.depositor,
+						ipfs,
+						deposit.amount,
+					));
+				}
+			}
+		}
+
+		// Update base pallet time
+		fn do_update_pallet_base_time(new_pallet_base_time: BlockNumber<T>) {
+			let mut pallet_times = PalletTimeValues::<T>::get();
+
+			pallet_times = TimeValues { pallet_base_time: new_pallet_base_time, ..pallet_times };
+
+			PalletTimeValues::<T>::set(pallet_times);
+
+			Self::deposit_event(Event::BasePalletTimeUpdated(new_pallet_base_time));
+		}
+
+		// Close carbon footprint report voting and update the carbon footprint account
+		fn do_update_carbon_footprint_report(
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed voting timeout event
+			Self::remove_voting_timeout(timeout_block, &ipfs);
+
+			let report = CarbonFootprintReports::<T>::get(ipfs.clone()).unwrap();
+
+			// Get the votes that were made for the report
+			let votes_for: u16 = report.votes_for.len().try_into().unwrap();
+			let votes_against: u16 = report.votes_against.len().try_into().unwrap();
+			let votes_total: u16 = votes_for + votes_against;
+
+			// Check if the vote has passed
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			// Update the carbon footprint account only if it can hold the report documentation
+			// Note: The deposit is still settled based on the vote outcome
+			if vote_passed && Self::has_room_for_cfa_documentation(report.cf_account.clone()) {
+				let mut documentation_ipfses = BoundedBTreeSet::new();
+				let _ = documentation_ipfses.try_insert(ipfs.clone());
+
+				// Create an empty carbon footprint account
+				let mut new_account = CarbonFootprintAccountInfo {
+					documentation_ipfses,
+					carbon_footprint_surplus: report.carbon_footprint_surplus,
+					carbon_footprint_deficit: report.carbon_footprint_deficit,
+					creation_date: T::Time::now(),
+				};
+
+				// Check to see if a carbon footprint account with the given accountID exists
+				if CarbonFootprintAccounts::<T>::contains_key(report.cf_account.clone()) {
+					let old_account =
+						CarbonFootprintAccounts::<T>::get(report.cf_account.clone()).unwrap();
+
+					// Update documentation related to the carbon footprint account
+					let mut new_documentation = old_account.documentation_ipfses;
+					let _ = new_documentation.try_insert(ipfs.clone());
+
+					// Update the carbon footprint account structure
+					if report.carbon_footprint_surplus == BalanceOf::<T>::from(0u32) {
+						if report.carbon_footprint_deficit >= old_account.carbon_footprint_surplus {
+							let change_amount = report.carbon_footprint_deficit
+								- old_account.carbon_footprint_surplus;
+
+							new_account = CarbonFootprintAccountInfo {
+								documentation_ipfses: new_documentation,
+								carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
+								carbon_footprint_deficit: old_account.carbon_footprint_deficit
+									+ change_amount,
+								creation_date: old_account.creation_date,
+							};
+						} else {
+							let change_amount = report.carbon_footprint_surplus
+								- new_account.carbon_footprint_deficit;
+
+							new_account = CarbonFootprintAccountInfo {
+								documentation_ipfses: new_documentation,
+								carbon_footprint_surplus: old_account.carbon_footprint_surplus
+									+ change_amount,
+								carbon_footprint_deficit: BalanceOf::<T>::from(0u32),
+								creation_date: old_account.creation_date,
+							};
+						}
+					} else {
+						if report.carbon_footprint_surplus >= old_account.carbon_footprint_deficit {
+							let change_amount = report.carbon_footprint_surplus
+								- old_account.carbon_footprint_deficit;
+
+							new_account = CarbonFootprintAccountInfo {
+								documentation_ipfses: new_documentation,
+								carbon_footprint_surplus: old_account.carbon_footprint_surplus
+									+ change_amount,
+								carbon_footprint_deficit: BalanceOf::<T>::from(0u32),
+								creation_date: old_account.creation_date,
+							};
+						} else {
+							let change_amount =
+								report.carbon_footprint_deficit - report.carbon_footprint_surplus;
+
+							new_account = CarbonFootprintAccountInfo {
+								documentation_ipfses: new_documentation,
+								carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
+								carbon_footprint_deficit: old_account.carbon_footprint_deficit
+									+ change_amount,
+								creation_date: old_account.creation_date,
+							};
+						}
+					}
+				}
+
+				// Save the changes made to the carbon footprint account
+				CarbonFootprintAccounts::<T>::insert(report.cf_account.clone(), new_account);
+			}
+
+			// Create new report
+			// Note: Only change is made to the voting_active cycle status
+			let new_report = CarbonFootprintReportInfo {
+				cf_account: report.cf_account.clone(),
+				creation_date: report.creation_date,
+				carbon_footprint_surplus: report.carbon_footprint_surplus,
+				carbon_footprint_deficit: report.carbon_footprint_deficit,
+				votes_for: report.votes_for,
+				votes_against: report.votes_against,
+				voting_active: false,
+			};
+
+			// Save new report
+			CarbonFootprintReports::<T>::insert(ipfs.clone(), new_report);
+
+			Self::insert_pruning_timeout(FinishedRecord::CarbonFootprintReport(ipfs.clone()));
+
+			// Settle the deposit
+			Self::settle_deposit(ipfs.clone(), vote_passed, HoldReason::ProposalDeposit);
+
+			Self::deposit_event(Event::CarbonFootprintReportUpdated(ipfs));
+		}
+
+		// Close project proposal voting and create the project
+		fn do_update_project_proposal(
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed voting timeout event
+			Self::remove_voting_timeout(timeout_block, &ipfs);
+
 			let proposal = ProjectProposals::<T>::get(ipfs.clone()).unwrap();
 
 			// Get the votes that were made for the report
@@ -2681,7 +6532,11 @@ pub mod pallet {
 			let votes_total: u16 = votes_for + votes_against;
 
 			// Check if the vote has passed
-			if Self::has_vote_passed(votes_total, votes_for) {
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			// Create the project only if the project owner can hold another project
+			// Note: The deposit is still settled based on the vote outcome
+			if vote_passed && Self::has_room_for_project(proposal.project_owner.clone()) {
 				// Create a new project
 				let new_project = ProjectInfo {
 					documentation_ipfs: ipfs.clone(),
@@ -2693,6 +6548,13 @@ pub mod pallet {
 
 				// Save new project
 				Projects::<T>::insert(proposal.project_hash, new_project);
+
+				// Index project by project owner
+				ProjectsByOwner::<T>::mutate(proposal.project_owner.clone(), |projects| {
+					let _ = projects
+						.get_or_insert_with(BoundedBTreeSet::new)
+						.try_insert(proposal.project_hash);
+				});
 			}
 
 			// Update proposal
@@ -2702,17 +6564,22 @@ pub mod pallet {
 			// Save new proposal
 			ProjectProposals::<T>::insert(ipfs.clone(), new_proposal);
 
-			Self::deposit_event(Event::ProjectProposalUpdated(ipfs));
+			Self::insert_pruning_timeout(FinishedRecord::ProjectProposal(ipfs.clone()));
 
-			Ok(())
+			// Settle the deposit
+			Self::settle_deposit(ipfs.clone(), vote_passed, HoldReason::ProposalDeposit);
+
+			Self::deposit_event(Event::ProjectProposalUpdated(ipfs));
 		}
 
-		#[pallet::call_index(22)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_batch_proposal())]
-		pub fn update_carbon_credit_batch_proposal(
-			_origin: OriginFor<T>,
+		// Close carbon credit batch proposal voting and create the carbon credit batch
+		fn do_update_carbon_credit_batch_proposal(
 			ipfs: BoundedString<T::IPFSLength>,
-		) -> DispatchResult {
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed voting timeout event
+			Self::remove_voting_timeout(timeout_block, &ipfs);
+
 			let proposal = CarbonCreditBatchProposals::<T>::get(ipfs.clone()).unwrap();
 
 			// Get the votes that were made for the report
@@ -2721,7 +6588,11 @@ pub mod pallet {
 			let votes_total: u16 = votes_for + votes_against;
 
 			// Check if the vote has passed
-			if Self::has_vote_passed(votes_total, votes_for) {
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			// Create the carbon credit batch only if the project can hold another batch
+			// Note: The deposit is still settled based on the vote outcome
+			if vote_passed && Self::has_room_for_batch(proposal.project_hash) {
 				// Create a new project
 				let new_batch = CarbonCreditBatchInfo {
 					documentation_ipfs: ipfs.clone(),
@@ -2736,6 +6607,13 @@ pub mod pallet {
 				// Save new carbon credit batch
 				CarbonCreditBatches::<T>::insert(proposal.batch_hash, new_batch);
 
+				// Index carbon credit batch by project
+				BatchesByProject::<T>::mutate(proposal.project_hash, |batches| {
+					let _ = batches
+						.get_or_insert_with(BoundedBTreeSet::new)
+						.try_insert(proposal.batch_hash);
+				});
+
 				// Create carbon credit holdings for project owner
 				let new_holdings = CarbonCreditHoldingsInfo {
 					available_amount: proposal.credit_amount.into(),
@@ -2755,30 +6633,84 @@ pub mod pallet {
 
 			// Create new proposal
 			// Note: Only change is made to the voting_active cycle status
-			let new_proposal = CarbonCreditBatchProposalInfo { voting_active: false, ..proposal };
+			let new_proposal = CarbonCreditBatchProposalInfo { voting_active: false, ..proposal };
+
+			// Save new proposal
+			CarbonCreditBatchProposals::<T>::insert(ipfs.clone(), new_proposal);
+
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditBatchProposal(ipfs.clone()));
+
+			// Settle the deposit
+			Self::settle_deposit(ipfs.clone(), vote_passed, HoldReason::ProposalDeposit);
+
+			Self::deposit_event(Event::CarbonCreditBatchProposalUpdated(ipfs));
+		}
+
+		// Close beneficiary split proposal voting and apply the project beneficiary splits
+		fn do_update_beneficiary_split_proposal(
+			ipfs: BoundedString<T::IPFSLength>,
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed voting timeout event
+			Self::remove_voting_timeout(timeout_block, &ipfs);
+
+			let proposal = BeneficiarySplitProposals::<T>::get(ipfs.clone()).unwrap();
+
+			// Get the votes that were made for the proposal
+			let votes_for: u16 = proposal.votes_for.len().try_into().unwrap();
+			let votes_against: u16 = proposal.votes_against.len().try_into().unwrap();
+			let votes_total: u16 = votes_for + votes_against;
+
+			// Check if the vote has passed
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			// Apply the beneficiary splits only if the project still exists
+			// Note: An approved proposal replaces the previous project beneficiary splits
+			if vote_passed && Projects::<T>::contains_key(proposal.project_hash) {
+				ProjectBeneficiarySplits::<T>::insert(
+					proposal.project_hash,
+					ProjectBeneficiarySplitsInfo {
+						beneficiary_splits: proposal.beneficiary_splits.clone(),
+						stakeholders: proposal.stakeholders.clone(),
+					},
+				);
+
+				Self::deposit_event(Event::ProjectBeneficiarySplitsUpdated(proposal.project_hash));
+			}
+
+			// Update proposal
+			// Note: Only change is made to the voting_active cycle status
+			let new_proposal = BeneficiarySplitProposalInfo { voting_active: false, ..proposal };
 
 			// Save new proposal
-			CarbonCreditBatchProposals::<T>::insert(ipfs.clone(), new_proposal);
+			BeneficiarySplitProposals::<T>::insert(ipfs.clone(), new_proposal);
 
-			Self::deposit_event(Event::CarbonCreditBatchProposalUpdated(ipfs));
+			Self::insert_pruning_timeout(FinishedRecord::BeneficiarySplitProposal(ipfs.clone()));
 
-			Ok(())
+			// Settle the deposit
+			Self::settle_deposit(ipfs.clone(), vote_passed, HoldReason::ProposalDeposit);
+
+			Self::deposit_event(Event::BeneficiarySplitProposalUpdated(ipfs));
 		}
 
-		#[pallet::call_index(23)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_carbon_credit_sale_order())]
-		pub fn update_carbon_credit_sale_order(
-			_origin: OriginFor<T>,
-			sale_hash: H256,
-		) -> DispatchResult {
+		// Close carbon credit sale order and release the seller carbon credits
+		fn do_update_carbon_credit_sale_order(sale_hash: H256) {
 			// Get sale order
 			let mut sale_order = CarbonCreditSaleOrders::<T>::get(sale_hash).unwrap();
 
+			// Remove executed sale order timeout event
+			if let Some(sale_timeout) = sale_order.sale_timeout {
+				Self::remove_sale_order_timeout(sale_timeout, sale_hash);
+			}
+
 			// Update sale order
-			sale_order = CarbonCreditSaleOrderInfo { sale_active: false, ..sale_order };
+			sale_order =
+				CarbonCreditSaleOrderInfo { status: SaleOrderStatus::Expired, ..sale_order };
 
 			CarbonCreditSaleOrders::<T>::insert(sale_hash, sale_order.clone());
 
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditSaleOrder(sale_hash));
+
 			// Update seller holdings
 			let mut seller_holdings =
 				CarbonCreditHoldings::<T>::get(sale_order.batch_hash, sale_order.clone().seller)
@@ -2796,16 +6728,254 @@ pub mod pallet {
 			);
 
 			Self::deposit_event(Event::CarbonCreditSaleOrderUpdated(sale_hash));
+		}
+
+		// Close carbon credit buy order and release the held buyer funds
+		// Note: The buy order is still closed if the held buyer funds can't be released
+		fn do_update_carbon_credit_buy_order(buy_hash: H256) {
+			let buy_order = CarbonCreditBuyOrders::<T>::get(buy_hash).unwrap();
+			let buyer = buy_order.buyer.clone();
+			let amount_to_release = buy_order.credit_amount * buy_order.credit_price;
+
+			if let Err(err) = Self::close_buy_order(buy_hash, buy_order) {
+				Self::report_failed_escrow_release(buyer, buy_hash, amount_to_release, err);
+			}
+
+			Self::deposit_event(Event::CarbonCreditBuyOrderUpdated(buy_hash));
+		}
+
+		// Close carbon credit buy order
+		// Note: The held buyer funds for the remaining amount are released last, so a failed
+		// release reverts the whole extrinsic (hooks report it instead)
+		fn close_buy_order(
+			buy_hash: H256,
+			buy_order: CarbonCreditBuyOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+		) -> DispatchResult {
+			Self::remove_buy_order_timeout(buy_order.buy_timeout, buy_hash);
+
+			CarbonCreditBuyOrders::<T>::insert(
+				buy_hash,
+				CarbonCreditBuyOrderInfo { buy_active: false, ..buy_order.clone() },
+			);
+
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditBuyOrder(buy_hash));
+
+			T::Currency::release(
+				&HoldReason::BuyOrderEscrow.into(),
+				&buy_order.buyer,
+				buy_order.credit_amount * buy_order.credit_price,
+				Precision::Exact,
+			)?;
 
 			Ok(())
 		}
 
-		#[pallet::call_index(24)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_complaint_for_account())]
-		pub fn update_complaint_for_account(
-			_origin: OriginFor<T>,
+		// Report held funds that couldn't be released in a hook
+		// Note: Hooks can't fail, so the error is logged and an event is deposited instead
+		fn report_failed_escrow_release(
+			account_id: AccountIdOf<T>,
+			hash: H256,
+			amount: BalanceOf<T>,
+			err: DispatchError,
+		) {
+			warn!("💰 Failed to release held funds for {:?}. 🚧 Error: {:?}", hash, err);
+
+			Self::deposit_event(Event::EscrowReleaseFailed(account_id, hash, amount));
+		}
+
+		// End carbon credit auction
+		// Note: English auctions are sold to the highest bid, Dutch auctions (and English auctions
+		// without bids) end unsold and the seller credits are released
+		fn do_update_carbon_credit_auction(auction_hash: H256) {
+			let auction = CarbonCreditAuctions::<T>::get(auction_hash).unwrap();
+
+			match auction.highest_bid.clone() {
+				Some((bidder, credit_price)) => {
+					// Note: The sale is reverted if the settlement fails (the lot ends unsold)
+					let amount_to_release = auction.credit_amount * credit_price;
+					let sale = frame_support::storage::with_storage_layer(|| {
+						T::Currency::release(
+							&HoldReason::AuctionBidEscrow.into(),
+							&bidder,
+							amount_to_release,
+							Precision::Exact,
+						)?;
+
+						Self::settle_auction(
+							auction_hash,
+							auction.clone(),
+							bidder.clone(),
+							credit_price,
+						)
+					});
+
+					if let Ok((maker_fee, taker_fee)) = sale {
+						Self::deposit_event(Event::CarbonCreditAuctionSold(
+							bidder,
+							auction_hash,
+							credit_price,
+							maker_fee,
+							taker_fee,
+						));
+					} else {
+						// Note: The lot still ends unsold if the bid funds can't be released
+						if let Err(err) = T::Currency::release(
+							&HoldReason::AuctionBidEscrow.into(),
+							&bidder,
+							amount_to_release,
+							Precision::Exact,
+						) {
+							Self::report_failed_escrow_release(
+								bidder,
+								auction_hash,
+								amount_to_release,
+								err,
+							);
+						}
+
+						Self::close_auction(auction_hash, auction, AuctionStatus::Unsold);
+					}
+				},
+				None => Self::close_auction(auction_hash, auction, AuctionStatus::Unsold),
+			}
+
+			Self::deposit_event(Event::CarbonCreditAuctionUpdated(auction_hash));
+		}
+
+		// Close carbon credit auction without a sale and release the seller credits
+		fn close_auction(
+			auction_hash: H256,
+			auction: CarbonCreditAuctionInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+			status: AuctionStatus,
+		) {
+			Self::remove_auction_timeout(auction.end_block, auction_hash);
+
+			CarbonCreditHoldings::<T>::mutate(
+				auction.batch_hash,
+				auction.seller.clone(),
+				|holdings| {
+					if let Some(holdings) = holdings {
+						holdings.available_amount += auction.credit_amount;
+						holdings.unavailable_amount -= auction.credit_amount;
+					}
+				},
+			);
+
+			CarbonCreditAuctions::<T>::insert(
+				auction_hash,
+				CarbonCreditAuctionInfo { status, ..auction },
+			);
+
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditAuction(auction_hash));
+		}
+
+		// Sell the carbon credit auction lot (do the beneficiary splits, pay the trading fees and
+		// the seller and move credits)
+		// Note: The buyer funds have to be free (held English auction bids are released first)
+		// Note: Returns the maker and taker fees
+		fn settle_auction(
+			auction_hash: H256,
+			auction: CarbonCreditAuctionInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+			buyer: AccountIdOf<T>,
+			credit_price: BalanceOf<T>,
+		) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+			let mut amount_to_pay = auction
+				.credit_amount
+				.checked_mul(&credit_price)
+				.ok_or(Error::<T>::ArithmeticOverflow)?;
+			let (maker_fee, taker_fee) = Self::calculate_trading_fees(amount_to_pay);
+
+			let carbon_credit_batch = CarbonCreditBatches::<T>::get(auction.batch_hash).unwrap();
+			let project = Projects::<T>::get(carbon_credit_batch.project_hash).unwrap();
+
+			// Do beneficiary splits (paid by the buyer)
+			let split_gains = Self::process_beneficiary_splits(
+				buyer.clone(),
+				&auction.seller,
+				carbon_credit_batch.project_hash,
+				project.project_owner,
+				carbon_credit_batch.validator_benefactors,
+				amount_to_pay,
+			)?;
+
+			amount_to_pay =
+				amount_to_pay.checked_sub(&split_gains).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			// Pay trading fees
+			// Note: The seller is the maker and the buyer is the taker
+			amount_to_pay =
+				Self::process_trading_fees(&buyer, amount_to_pay, taker_fee, maker_fee)?;
+
+			// Transfer funds
+			T::Currency::transfer(&buyer, &auction.seller, amount_to_pay, Preservation::Preserve)?;
+
+			// Update seller holdings
+			let mut seller_holdings =
+				CarbonCreditHoldings::<T>::get(auction.batch_hash, auction.seller.clone()).unwrap();
+
+			seller_holdings.unavailable_amount -= auction.credit_amount;
+
+			if seller_holdings == CarbonCreditHoldingsInfo::default() {
+				CarbonCreditHoldings::<T>::remove(auction.batch_hash, auction.seller.clone());
+			} else {
+				CarbonCreditHoldings::<T>::insert(
+					auction.batch_hash,
+					auction.seller.clone(),
+					seller_holdings,
+				);
+			}
+
+			// Update buyer holdings
+			CarbonCreditHoldings::<T>::mutate(auction.batch_hash, buyer.clone(), |holdings| {
+				holdings.get_or_insert_with(CarbonCreditHoldingsInfo::default).available_amount +=
+					auction.credit_amount;
+			});
+
+			// Update auction
+			Self::remove_auction_timeout(auction.end_block, auction_hash);
+
+			CarbonCreditAuctions::<T>::insert(
+				auction_hash,
+				CarbonCreditAuctionInfo {
+					highest_bid: Some((buyer, credit_price)),
+					status: AuctionStatus::Sold,
+					..auction
+				},
+			);
+
+			Self::insert_pruning_timeout(FinishedRecord::CarbonCreditAuction(auction_hash));
+
+			Ok((maker_fee, taker_fee))
+		}
+
+		// Get the current price of a Dutch auction (None for English auctions)
+		// Note: The price is lowered by the price decrement every block until it reaches the floor
+		pub fn dutch_auction_price(
+			auction: &CarbonCreditAuctionInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>,
+			current_block: BlockNumber<T>,
+		) -> Option<BalanceOf<T>> {
+			match auction.auction_type {
+				AuctionType::English { .. } => None,
+				AuctionType::Dutch { start_price, floor_price, price_decrement } => {
+					let elapsed_blocks: u32 =
+						(current_block - auction.start_block).try_into().unwrap_or(u32::MAX);
+
+					let price_drop =
+						price_decrement.saturating_mul(BalanceOf::<T>::from(elapsed_blocks));
+
+					Some(start_price.saturating_sub(price_drop).max(floor_price))
+				},
+			}
+		}
+
+		// Close complaint voting and penalize the account
+		fn do_update_complaint_for_account(
 			complaint: BoundedString<T::IPFSLength>,
-		) -> DispatchResult {
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed complaint timeout event
+			Self::remove_complaint_timeout(timeout_block, &complaint);
+
 			let mut specific_complaint =
 				ComplaintsForAccounts::<T>::get(complaint.clone()).unwrap();
 
@@ -2818,10 +6988,13 @@ pub mod pallet {
 			let votes_total: u16 = votes_for + votes_against;
 
 			// Update penalties only if the complaint passed
-			if Self::has_vote_passed(votes_total, votes_for) {
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			if vote_passed {
 				let current_block = frame_system::Pallet::<T>::block_number();
-				let new_timeout_block =
-					current_block + PalletTimeValues::<T>::get().penalty_timeout;
+				let new_timeout_block = Self::account_penalty_timeout_block(
+					current_block + PalletTimeValues::<T>::get().penalty_timeout,
+				);
 
 				// Match complaint type
 				match specific_complaint.complaint_type {
@@ -2892,30 +7065,30 @@ pub mod pallet {
 					_ => {},
 				}
 
-				let mut penalty_timeouts = BTreeSet::<AccountIdOf<T>>::new();
-
-				if PenaltyTimeoutsAccounts::<T>::contains_key(new_timeout_block) {
-					penalty_timeouts = PenaltyTimeoutsAccounts::<T>::get(new_timeout_block).unwrap();
-				}
-					
-				penalty_timeouts.insert(specific_complaint.clone().complaint_for);
-
-				PenaltyTimeoutsAccounts::<T>::insert(new_timeout_block, penalty_timeouts);
+				Self::insert_account_penalty_timeout(
+					new_timeout_block,
+					specific_complaint.clone().complaint_for,
+				);
 			}
 
 			ComplaintsForAccounts::<T>::insert(complaint.clone(), specific_complaint);
 
-			Self::deposit_event(Event::AccountComplaintUpdated(complaint));
+			Self::insert_pruning_timeout(FinishedRecord::AccountComplaint(complaint.clone()));
 
-			Ok(())
+			// Settle the deposit
+			Self::settle_deposit(complaint.clone(), vote_passed, HoldReason::ComplaintDeposit);
+
+			Self::deposit_event(Event::AccountComplaintUpdated(complaint));
 		}
 
-		#[pallet::call_index(25)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_complaint_for_hash())]
-		pub fn update_complaint_for_hash(
-			_origin: OriginFor<T>,
+		// Close complaint voting and penalize the project or carbon credit batch
+		fn do_update_complaint_for_hash(
 			complaint: BoundedString<T::IPFSLength>,
-		) -> DispatchResult {
+			timeout_block: BlockNumber<T>,
+		) {
+			// Remove executed complaint timeout event
+			Self::remove_complaint_timeout(timeout_block, &complaint);
+
 			let mut specific_complaint = ComplaintsForHashes::<T>::get(complaint.clone()).unwrap();
 
 			specific_complaint =
@@ -2927,10 +7100,13 @@ pub mod pallet {
 			let votes_total: u16 = votes_for + votes_against;
 
 			// Update penalties only if the complaint passed
-			if Self::has_vote_passed(votes_total, votes_for) {
+			let vote_passed = Self::has_vote_passed(votes_total, votes_for);
+
+			if vote_passed {
 				let current_block = frame_system::Pallet::<T>::block_number();
-				let new_timeout_block =
-					current_block + PalletTimeValues::<T>::get().penalty_timeout;
+				let new_timeout_block = Self::hash_penalty_timeout_block(
+					current_block + PalletTimeValues::<T>::get().penalty_timeout,
+				);
 
 				// Match complaint type
 				match specific_complaint.complaint_type {
@@ -2950,12 +7126,11 @@ pub mod pallet {
 							batch,
 						);
 
-						// Recalculate carbon balances for CFAs
-						Self::recalculate_cfa_balances(specific_complaint.clone().complaint_for);
-
-						// Calculate and save repayments needed for project owner
-						Self::calculate_project_owner_debts(
+						// Queue recalculation of CFA balances and project owner debts
+						// Note: Redaction is processed on initialize within the weight limit
+						PendingRedactions::<T>::insert(
 							specific_complaint.clone().complaint_for,
+							RedactionProgress::default(),
 						);
 					},
 					ComplaintType::ProjectComplaint => {
@@ -2986,249 +7161,503 @@ pub mod pallet {
 						Self::unfreeze_all_project_batches(
 							specific_complaint.clone().complaint_for,
 						);
+
+						Self::insert_hash_penalty_timeout(
+							new_timeout_block,
+							specific_complaint.clone().complaint_for,
+						);
 					},
 					_ => {},
 				}
+			}
+
+			ComplaintsForHashes::<T>::insert(complaint.clone(), specific_complaint);
+
+			Self::insert_pruning_timeout(FinishedRecord::HashComplaint(complaint.clone()));
+
+			// Settle the deposit
+			Self::settle_deposit(complaint.clone(), vote_passed, HoldReason::ComplaintDeposit);
+
+			Self::deposit_event(Event::HashComplaintUpdated(complaint));
+		}
+
+		// Lower project owner penalty level
+		fn do_update_project_owner_penalty_level(account_id: AccountIdOf<T>) {
+			let mut project_owner = ProjectOwners::<T>::get(account_id.clone()).unwrap();
+
+			// Remove executed penalty timeout event
+			Self::remove_account_penalty_timeout(project_owner.penalty_timeout, &account_id);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let new_timeout_block = Self::account_penalty_timeout_block(
+				current_block + PalletTimeValues::<T>::get().penalty_timeout,
+			);
+
+			let new_penalty_level = project_owner.penalty_level - 1;
+			let mut new_penalty_timeout = new_timeout_block;
+
+			if new_penalty_level == 0 {
+				new_penalty_timeout = BlockNumber::<T>::from(0u32);
+			}
+
+			project_owner = ProjectValidatorOrProjectOwnerInfo {
+				penalty_level: new_penalty_level,
+				penalty_timeout: new_penalty_timeout,
+				..project_owner
+			};
+
+			ProjectOwners::<T>::insert(account_id.clone(), project_owner);
+
+			// Create the next penalty timeout event if a penalty level remains
+			if new_penalty_level > 0 {
+				Self::insert_account_penalty_timeout(new_penalty_timeout, account_id.clone());
+			}
+
+			Self::deposit_event(Event::ProjectOwnerPenaltyLevelUpdated(account_id));
+		}
+
+		// Lower validator penalty level
+		fn do_update_validator_penalty_level(account_id: AccountIdOf<T>) {
+			let mut validator = Validators::<T>::get(account_id.clone()).unwrap();
+
+			// Remove executed penalty timeout event
+			Self::remove_account_penalty_timeout(validator.penalty_timeout, &account_id);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let new_timeout_block = Self::account_penalty_timeout_block(
+				current_block + PalletTimeValues::<T>::get().penalty_timeout,
+			);
+
+			let new_penalty_level = validator.penalty_level - 1;
+			let mut new_penalty_timeout = new_timeout_block;
+
+			if new_penalty_level == 0 {
+				new_penalty_timeout = BlockNumber::<T>::from(0u32);
+			}
+
+			validator = ProjectValidatorOrProjectOwnerInfo {
+				penalty_level: new_penalty_level,
+				penalty_timeout: new_penalty_timeout,
+				..validator
+			};
+
+			Validators::<T>::insert(account_id.clone(), validator);
+
+			// Create the next penalty timeout event if a penalty level remains
+			if new_penalty_level > 0 {
+				Self::insert_account_penalty_timeout(new_penalty_timeout, account_id.clone());
+			}
+
+			Self::deposit_event(Event::ValidatorPenaltyLevelUpdated(account_id));
+		}
+
+		// Lower project penalty level
+		fn do_update_project_penalty_level(hash: H256) {
+			let mut project = Projects::<T>::get(hash).unwrap();
+
+			// Remove executed penalty timeout event
+			Self::remove_hash_penalty_timeout(project.penalty_timeout, hash);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let new_timeout_block = Self::hash_penalty_timeout_block(
+				current_block + PalletTimeValues::<T>::get().penalty_timeout,
+			);
+
+			let new_penalty_level = project.penalty_level - 1;
+			let mut new_penalty_timeout = new_timeout_block;
+
+			if new_penalty_level == 0 {
+				new_penalty_timeout = BlockNumber::<T>::from(0u32);
+			}
+
+			project = ProjectInfo {
+				penalty_level: new_penalty_level,
+				penalty_timeout: new_penalty_timeout,
+				..project
+			};
+
+			Projects::<T>::insert(hash, project);
+
+			// Create the next penalty timeout event if a penalty level remains
+			if new_penalty_level > 0 {
+				Self::insert_hash_penalty_timeout(new_penalty_timeout, hash);
+			}
+
+			Self::deposit_event(Event::ProjectPenaltyLevelUpdated(hash));
+		}
+
+		// Get the next base pallet time
+		// Note: The first base pallet time is the current block, after that the base pallet time
+		// moves forward by a year (in blocks)
+		fn next_pallet_base_time(now: BlockNumber<T>) -> BlockNumber<T> {
+			let pallet_times = PalletTimeValues::<T>::get();
+
+			if pallet_times.pallet_base_time == BlockNumber::<T>::from(0u32) {
+				return now;
+			}
+
+			pallet_times.pallet_base_time + pallet_times.number_of_blocks_per_year
+		}
+
+		// Process all due timeout events up to the current block
+		// Note: Processing stops once the timeouts weight limit has been reached, the remaining
+		// timeout events are carried over to the next block through the timeouts cursor
+		pub fn process_timeouts(now: BlockNumber<T>) -> Weight {
+			let mut meter = WeightMeter::with_limit(T::MaxTimeoutsWeight::get());
+			let db_weight = T::DbWeight::get();
+
+			// Read pallet time values and timeouts cursor, write timeouts cursor
+			if meter.try_consume(db_weight.reads_writes(2, 1)).is_err() {
+				return meter.consumed();
+			}
+
+			// Update base pallet time
+			let new_pallet_base_time = Self::next_pallet_base_time(now);
+
+			if Self::is_pallet_base_time_update_due(new_pallet_base_time)
+				&& meter.try_consume(T::WeightInfo::update_pallet_base_time()).is_ok()
+			{
+				Self::do_update_pallet_base_time(new_pallet_base_time);
+			}
+
+			let mut block = TimeoutsCursor::<T>::get();
+			let mut force_progress = true;
+
+			while block <= now {
+				// Read timeout events for the block
+				if meter.try_consume(db_weight.reads(7)).is_err() {
+					break;
+				}
+
+				if !Self::process_block_timeouts(block, &mut meter, &mut force_progress) {
+					break;
+				}
+
+				block = block + BlockNumber::<T>::from(1u32);
+			}
+
+			TimeoutsCursor::<T>::set(block);
+
+			// Process pending carbon credit batch redactions with the remaining weight
+			Self::process_redactions(&mut meter);
+
+			meter.consumed()
+		}
+
+		// Consume the weight of a timeout event
+		// Note: If no timeout event has been processed yet (force progress is set), the event is
+		// processed even if it's heavier than the remaining weight, so a timeout event that is
+		// heavier than the whole timeouts weight limit can't stall timeout processing
+		fn consume_timeout_weight(
+			meter: &mut WeightMeter,
+			weight: Weight,
+			force_progress: &mut bool,
+		) -> bool {
+			if meter.try_consume(weight).is_ok() {
+				*force_progress = false;
+
+				return true;
+			}
+
+			if !*force_progress {
+				return false;
+			}
+
+			*force_progress = false;
+
+			// Extend the weight limit by the event weight (the meter is full afterwards)
+			let consumed = meter.consumed().saturating_add(weight);
+
+			*meter = WeightMeter::with_limit(consumed);
+			meter.try_consume(consumed).is_ok()
+		}
+
+		// Process all timeout events for the given block
+		// Note: Returns false if the weight limit was reached before all events were processed,
+		// executed and stale timeout events are removed so processing can resume from where it
+		// stopped
+		fn process_block_timeouts(
+			block: BlockNumber<T>,
+			meter: &mut WeightMeter,
+			force_progress: &mut bool,
+		) -> bool {
+			// Process complaint timeout events
+			if let Some(complaint_events) = ComplaintTimeouts::<T>::get(block) {
+				for complaint in complaint_events {
+					let batch_count = Self::complaint_batch_count(&complaint);
+					let weight = T::WeightInfo::update_complaint_for_account(batch_count)
+						.max(T::WeightInfo::update_complaint_for_hash(batch_count));
+
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
+
+					if Self::is_complaint_for_account_update_due(&complaint, block) {
+						Self::do_update_complaint_for_account(complaint, block);
+					} else if Self::is_complaint_for_hash_update_due(&complaint, block) {
+						Self::do_update_complaint_for_hash(complaint, block);
+					} else {
+						Self::remove_complaint_timeout(block, &complaint);
+					}
+				}
+			}
+
+			// Process voting timeout events
+			if let Some(timeout_events) = VotingTimeouts::<T>::get(block) {
+				let weight = T::WeightInfo::update_carbon_footprint_report()
+					.max(T::WeightInfo::update_project_proposal())
+					.max(T::WeightInfo::update_carbon_credit_batch_proposal())
+					.max(T::WeightInfo::update_beneficiary_split_proposal());
+
+				for ipfs in timeout_events {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
+
+					if Self::is_carbon_footprint_report_update_due(&ipfs, block) {
+						Self::do_update_carbon_footprint_report(ipfs, block);
+					} else if Self::is_project_proposal_update_due(&ipfs, block) {
+						Self::do_update_project_proposal(ipfs, block);
+					} else if Self::is_carbon_credit_batch_proposal_update_due(&ipfs, block) {
+						Self::do_update_carbon_credit_batch_proposal(ipfs, block);
+					} else if Self::is_beneficiary_split_proposal_update_due(&ipfs, block) {
+						Self::do_update_beneficiary_split_proposal(ipfs, block);
+					} else {
+						Self::remove_voting_timeout(block, &ipfs);
+					}
+				}
+			}
+
+			// Process sale order timeout events
+			if let Some(sale_events) = SaleOrderTimeouts::<T>::get(block) {
+				let weight = T::WeightInfo::update_carbon_credit_sale_order();
+
+				for sale_hash in sale_events {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
+
+					if Self::is_carbon_credit_sale_order_update_due(sale_hash) {
+						Self::do_update_carbon_credit_sale_order(sale_hash);
+					}
+
+					Self::remove_sale_order_timeout(block, sale_hash);
+				}
+			}
+
+			// Process buy order timeout events
+			if let Some(buy_events) = BuyOrderTimeouts::<T>::get(block) {
+				let weight = T::WeightInfo::update_carbon_credit_buy_order();
+
+				for buy_hash in buy_events {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
 
-				let mut penalty_timeouts = BTreeSet::<H256>::new();
+					if Self::is_carbon_credit_buy_order_update_due(buy_hash) {
+						Self::do_update_carbon_credit_buy_order(buy_hash);
+					}
 
-				if PenaltyTimeoutsHashes::<T>::contains_key(new_timeout_block) {
-					penalty_timeouts = PenaltyTimeoutsHashes::<T>::get(new_timeout_block).unwrap();
+					Self::remove_buy_order_timeout(block, buy_hash);
 				}
-					
-				penalty_timeouts.insert(specific_complaint.clone().complaint_for);
-
-				PenaltyTimeoutsHashes::<T>::insert(new_timeout_block, penalty_timeouts);
 			}
 
-			ComplaintsForHashes::<T>::insert(complaint.clone(), specific_complaint);
+			// Process auction timeout events
+			if let Some(auction_events) = AuctionTimeouts::<T>::get(block) {
+				let weight = T::WeightInfo::update_carbon_credit_auction();
 
-			Self::deposit_event(Event::HashComplaintUpdated(complaint));
+				for auction_hash in auction_events {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
 
-			Ok(())
-		}
+					if Self::is_carbon_credit_auction_update_due(auction_hash) {
+						Self::do_update_carbon_credit_auction(auction_hash);
+					}
 
-		#[pallet::call_index(26)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_project_owner_penalty_level())]
-		pub fn update_project_owner_penalty_level(
-			_origin: OriginFor<T>,
-			account_id: AccountIdOf<T>,
-		) -> DispatchResult {
-			let mut project_owner = ProjectOwners::<T>::get(account_id.clone()).unwrap();
+					Self::remove_auction_timeout(block, auction_hash);
+				}
+			}
 
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let new_timeout_block = current_block + PalletTimeValues::<T>::get().penalty_timeout;
+			// Process penalty timeout events (for accounts)
+			if let Some(account_ids) = PenaltyTimeoutsAccounts::<T>::get(block) {
+				let weight = T::WeightInfo::update_project_owner_penalty_level()
+					.max(T::WeightInfo::update_validator_penalty_level());
 
-			let new_penalty_level = project_owner.penalty_level - 1;
-			let mut new_penalty_timeout = new_timeout_block;
+				for account_id in account_ids {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
 
-			if new_penalty_level == 0 {
-				new_penalty_timeout = BlockNumber::<T>::from(0u32);
+					if Self::is_project_owner_penalty_update_due(&account_id) {
+						Self::do_update_project_owner_penalty_level(account_id.clone());
+					} else if Self::is_validator_penalty_update_due(&account_id) {
+						Self::do_update_validator_penalty_level(account_id.clone());
+					}
+
+					Self::remove_account_penalty_timeout(block, &account_id);
+				}
 			}
 
-			project_owner = ProjectValidatorOrProjectOwnerInfo {
-				penalty_level: new_penalty_level,
-				penalty_timeout: new_penalty_timeout,
-				..project_owner
-			};
+			// Process penalty timeout events (for hashes)
+			if let Some(hashes) = PenaltyTimeoutsHashes::<T>::get(block) {
+				let weight = T::WeightInfo::update_project_penalty_level();
 
-			ProjectOwners::<T>::insert(account_id.clone(), project_owner);
+				for hash in hashes {
+					if !Self::consume_timeout_weight(meter, weight, force_progress) {
+						return false;
+					}
 
-			Self::deposit_event(Event::ProjectOwnerPenaltyLevelUpdated(account_id));
+					if Self::is_project_penalty_update_due(hash) {
+						Self::do_update_project_penalty_level(hash);
+					}
 
-			Ok(())
+					Self::remove_hash_penalty_timeout(block, hash);
+				}
+			}
+
+			true
 		}
 
-		#[pallet::call_index(27)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_validator_penalty_level())]
-		pub fn update_validator_penalty_level(
-			_origin: OriginFor<T>,
-			account_id: AccountIdOf<T>,
-		) -> DispatchResult {
-			let mut validator = Validators::<T>::get(account_id.clone()).unwrap();
+		// Remove all finished records whose retention period is over
+		// Note: Processing stops once the weight limit has been reached, the remaining pruning
+		// timeout events are carried over to the next block through the pruning cursor
+		pub fn process_pruning(now: BlockNumber<T>, limit: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(limit);
+			let db_weight = T::DbWeight::get();
 
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let new_timeout_block = current_block + PalletTimeValues::<T>::get().penalty_timeout;
+			// Read and write pruning cursor
+			if meter.try_consume(db_weight.reads_writes(1, 1)).is_err() {
+				return meter.consumed();
+			}
 
-			let new_penalty_level = validator.penalty_level - 1;
-			let mut new_penalty_timeout = new_timeout_block;
+			let mut block = PruningCursor::<T>::get();
 
-			if new_penalty_level == 0 {
-				new_penalty_timeout = BlockNumber::<T>::from(0u32);
-			}
+			while block <= now {
+				// Read pruning timeout events for the block
+				if meter.try_consume(db_weight.reads(1)).is_err() {
+					break;
+				}
 
-			validator = ProjectValidatorOrProjectOwnerInfo {
-				penalty_level: new_penalty_level,
-				penalty_timeout: new_penalty_timeout,
-				..validator
-			};
+				if !Self::process_block_pruning(block, &mut meter) {
+					break;
+				}
 
-			Validators::<T>::insert(account_id.clone(), validator);
+				block = block + BlockNumber::<T>::from(1u32);
+			}
 
-			Self::deposit_event(Event::ValidatorPenaltyLevelUpdated(account_id));
+			PruningCursor::<T>::set(block);
 
-			Ok(())
+			meter.consumed()
 		}
 
-		#[pallet::call_index(28)]
-		#[pallet::weight(<T as Config>::WeightInfo::update_project_penalty_level())]
-		pub fn update_project_penalty_level(_origin: OriginFor<T>, hash: H256) -> DispatchResult {
-			let mut project = Projects::<T>::get(hash).unwrap();
-
-			let current_block = frame_system::Pallet::<T>::block_number();
-			let new_timeout_block = current_block + PalletTimeValues::<T>::get().penalty_timeout;
+		// Remove all finished records with a pruning timeout event in the given block
+		// Note: Returns false if the weight limit was reached before all records were removed,
+		// processed pruning timeout events are removed so processing can resume from where it
+		// stopped
+		fn process_block_pruning(block: BlockNumber<T>, meter: &mut WeightMeter) -> bool {
+			let db_weight = T::DbWeight::get();
+
+			if let Some(records) = PruningTimeouts::<T>::get(block) {
+				for record in records {
+					// Read and remove the record, write pruning timeout events
+					if meter.try_consume(db_weight.reads_writes(1, 2)).is_err() {
+						return false;
+					}
 
-			let new_penalty_level = project.penalty_level - 1;
-			let mut new_penalty_timeout = new_timeout_block;
+					if Self::is_record_finished(&record) {
+						Self::prune_finished_record(record.clone());
+					}
 
-			if new_penalty_level == 0 {
-				new_penalty_timeout = BlockNumber::<T>::from(0u32);
+					Self::remove_pruning_timeout(block, &record);
+				}
 			}
 
-			project = ProjectInfo {
-				penalty_level: new_penalty_level,
-				penalty_timeout: new_penalty_timeout,
-				..project
-			};
-
-			Projects::<T>::insert(hash, project);
-
-			Self::deposit_event(Event::ProjectPenaltyLevelUpdated(hash));
-
-			Ok(())
+			true
 		}
-	}
-
-	#[pallet::validate_unsigned]
-	impl<T: Config> ValidateUnsigned for Pallet<T> {
-		type Call = Call<T>;
 
-		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			match call {
-				Call::update_pallet_base_time { new_pallet_base_time } => {
-					ValidTransaction::with_tag_prefix("Veles::update_pallet_base_time")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([new_pallet_base_time])
-						.propagate(true)
-						.build()
+		// Check if the record exists and is no longer active
+		pub fn is_record_finished(record: &FinishedRecordOf<T>) -> bool {
+			match record {
+				FinishedRecord::CarbonFootprintReport(ipfs) => {
+					CarbonFootprintReports::<T>::get(ipfs)
+						.map_or(false, |report| !report.voting_active)
 				},
-				Call::update_carbon_footprint_report { ipfs } => {
-					ValidTransaction::with_tag_prefix("Veles::update_carbon_footprint_report")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([ipfs])
-						.propagate(true)
-						.build()
+				FinishedRecord::ProjectProposal(ipfs) => ProjectProposals::<T>::get(ipfs)
+					.map_or(false, |proposal| !proposal.voting_active),
+				FinishedRecord::CarbonCreditBatchProposal(ipfs) => {
+					CarbonCreditBatchProposals::<T>::get(ipfs)
+						.map_or(false, |proposal| !proposal.voting_active)
 				},
-				Call::update_project_proposal { ipfs } => {
-					ValidTransaction::with_tag_prefix("Veles::update_project_proposal")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([ipfs])
-						.propagate(true)
-						.build()
+				FinishedRecord::CarbonCreditSaleOrder(sale_hash) => {
+					CarbonCreditSaleOrders::<T>::get(sale_hash)
+						.map_or(false, |sale_order| sale_order.status != SaleOrderStatus::Open)
 				},
-				Call::update_carbon_credit_batch_proposal { ipfs } => {
-					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_batch_proposal")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([ipfs])
-						.propagate(true)
-						.build()
+				FinishedRecord::AccountComplaint(ipfs) => ComplaintsForAccounts::<T>::get(ipfs)
+					.map_or(false, |complaint| !complaint.complaint_active),
+				FinishedRecord::HashComplaint(ipfs) => ComplaintsForHashes::<T>::get(ipfs)
+					.map_or(false, |complaint| !complaint.complaint_active),
+				FinishedRecord::CarbonCreditBuyOrder(buy_hash) => {
+					CarbonCreditBuyOrders::<T>::get(buy_hash)
+						.map_or(false, |buy_order| !buy_order.buy_active)
 				},
-				Call::update_carbon_credit_sale_order { sale_hash } => {
-					ValidTransaction::with_tag_prefix("Veles::update_carbon_credit_sale_order")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([sale_hash])
-						.propagate(true)
-						.build()
+				FinishedRecord::CarbonCreditAuction(auction_hash) => {
+					CarbonCreditAuctions::<T>::get(auction_hash)
+						.map_or(false, |auction| auction.status != AuctionStatus::Open)
 				},
-				Call::update_complaint_for_account { complaint } => {
-					ValidTransaction::with_tag_prefix("Veles::update_complaint_for_account")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([complaint])
-						.propagate(true)
-						.build()
+				FinishedRecord::BeneficiarySplitProposal(ipfs) => {
+					BeneficiarySplitProposals::<T>::get(ipfs)
+						.map_or(false, |proposal| !proposal.voting_active)
 				},
-				Call::update_complaint_for_hash { complaint } => {
-					ValidTransaction::with_tag_prefix("Veles::update_complaint_for_hash")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([complaint])
-						.propagate(true)
-						.build()
+			}
+		}
+
+		// Remove a finished record
+		// Note: The used documentation entry of the record is kept as a tombstone, so the
+		// documentation IPFS link still can't be reused
+		fn prune_finished_record(record: FinishedRecordOf<T>) {
+			match record.clone() {
+				FinishedRecord::CarbonFootprintReport(ipfs) => {
+					CarbonFootprintReports::<T>::remove(ipfs)
 				},
-				Call::update_project_owner_penalty_level { account_id } => {
-					ValidTransaction::with_tag_prefix("Veles::update_project_owner_penalty_level")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([account_id])
-						.propagate(true)
-						.build()
+				FinishedRecord::ProjectProposal(ipfs) => ProjectProposals::<T>::remove(ipfs),
+				FinishedRecord::CarbonCreditBatchProposal(ipfs) => {
+					CarbonCreditBatchProposals::<T>::remove(ipfs)
 				},
-				Call::update_validator_penalty_level { account_id } => {
-					ValidTransaction::with_tag_prefix("Veles::update_validator_penalty_level")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([account_id])
-						.propagate(true)
-						.build()
+				FinishedRecord::CarbonCreditSaleOrder(sale_hash) => {
+					CarbonCreditSaleOrders::<T>::remove(sale_hash);
+					SaleOrderFills::<T>::remove(sale_hash);
+					SaleOrderAllowedBuyers::<T>::remove(sale_hash);
 				},
-				Call::update_project_penalty_level { hash } => {
-					ValidTransaction::with_tag_prefix("Veles::update_project_penalty_level")
-						.priority(T::UnsignedPriority::get())
-						.longevity(T::UnsignedLongevity::get())
-						.and_provides([hash])
-						.propagate(true)
-						.build()
+				FinishedRecord::AccountComplaint(ipfs) => ComplaintsForAccounts::<T>::remove(ipfs),
+				FinishedRecord::HashComplaint(ipfs) => ComplaintsForHashes::<T>::remove(ipfs),
+				FinishedRecord::CarbonCreditBuyOrder(buy_hash) => {
+					CarbonCreditBuyOrders::<T>::remove(buy_hash)
 				},
-				_ => {
-					warn!("Unknown unsigned call {:?}", call);
-					InvalidTransaction::Call.into()
+				FinishedRecord::CarbonCreditAuction(auction_hash) => {
+					CarbonCreditAuctions::<T>::remove(auction_hash)
+				},
+				FinishedRecord::BeneficiarySplitProposal(ipfs) => {
+					BeneficiarySplitProposals::<T>::remove(ipfs)
 				},
 			}
-		}
-	}
-
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn offchain_worker(now: BlockNumber<T>) {
-			// Check if a year has passed and update pallet base time if it has
-			// Note: The base pallet time will update once a year has passed (in blocks)
-			let pallet_times = PalletTimeValues::<T>::get();
-
-			if pallet_times.pallet_base_time == 0u32.into()
-				|| now > pallet_times.pallet_base_time + pallet_times.number_of_blocks_per_year
-			{
-				info!("👷 Offchain worker: Updating base pallet time");
-
-				let call = Call::<T>::update_pallet_base_time { new_pallet_base_time: now.clone() };
 
-				if let Err(err) =
-					SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
-				{
-					warn!(
-						"👷 Offchain worker: Failed to update base pallet time.🚧 Error: {:?}",
-						err
-					);
-				} else {
-					info!("👷 Offchain worker: Successfully updated base pallet time");
-				}
-			}
+			Self::deposit_event(Event::FinishedRecordPruned(record));
+		}
 
+		// Submit unsigned transactions for all due timeout events of the given block
+		fn submit_timeouts(block: BlockNumber<T>) {
 			// Check if any complaint timeout event has occured
-			if ComplaintTimeouts::<T>::contains_key(now) {
-				let complaint_events = ComplaintTimeouts::<T>::get(now).unwrap();
+			if ComplaintTimeouts::<T>::contains_key(block) {
+				let complaint_events = ComplaintTimeouts::<T>::get(block).unwrap();
 
 				for complaint in complaint_events.iter() {
-					if ComplaintsForAccounts::<T>::contains_key(complaint.clone()) {
+					if Self::is_complaint_for_account_update_due(complaint, block) {
 						info!("👷 Offchain worker: Updating complaint for account ");
 
 						let call = Call::<T>::update_complaint_for_account {
 							complaint: complaint.clone(),
+							timeout_block: block,
 						};
 
 						if let Err(err) =
@@ -3246,11 +7675,13 @@ pub mod pallet {
 						}
 					}
 
-					if ComplaintsForHashes::<T>::contains_key(complaint.clone()) {
+					if Self::is_complaint_for_hash_update_due(complaint, block) {
 						info!("👷 Offchain worker: Updating complaint for hash ");
 
-						let call =
-							Call::<T>::update_complaint_for_hash { complaint: complaint.clone() };
+						let call = Call::<T>::update_complaint_for_hash {
+							complaint: complaint.clone(),
+							timeout_block: block,
+						};
 
 						if let Err(err) =
 							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
@@ -3265,21 +7696,21 @@ pub mod pallet {
 						}
 					}
 				}
-
-				// Remove execuded timeout events
-				ComplaintTimeouts::<T>::remove(now);
 			}
 
 			// Check if a voting timeout event has occured
-			if VotingTimeouts::<T>::contains_key(now) {
-				let timeout_events = VotingTimeouts::<T>::get(now).unwrap();
+			if VotingTimeouts::<T>::contains_key(block) {
+				let timeout_events = VotingTimeouts::<T>::get(block).unwrap();
 
 				for ipfs in timeout_events.iter() {
 					// Check if IPFS is related to a carbon footprint report
-					if CarbonFootprintReports::<T>::contains_key(ipfs.clone()) {
+					if Self::is_carbon_footprint_report_update_due(ipfs, block) {
 						info!("👷 Offchain worker: Updating carbon footprint report");
 
-						let call = Call::<T>::update_carbon_footprint_report { ipfs: ipfs.clone() };
+						let call = Call::<T>::update_carbon_footprint_report {
+							ipfs: ipfs.clone(),
+							timeout_block: block,
+						};
 
 						if let Err(err) =
 							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
@@ -3297,10 +7728,13 @@ pub mod pallet {
 					}
 
 					// Check if IPFS is related to a project proposal
-					if ProjectProposals::<T>::contains_key(ipfs.clone()) {
+					if Self::is_project_proposal_update_due(ipfs, block) {
 						info!("👷 Offchain worker: Updating project proposal");
 
-						let call = Call::<T>::update_project_proposal { ipfs: ipfs.clone() };
+						let call = Call::<T>::update_project_proposal {
+							ipfs: ipfs.clone(),
+							timeout_block: block,
+						};
 
 						if let Err(err) =
 							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
@@ -3316,11 +7750,13 @@ pub mod pallet {
 					}
 
 					// Check if IPFS is related to a carbon credits batch proposal
-					if CarbonCreditBatchProposals::<T>::contains_key(ipfs.clone()) {
+					if Self::is_carbon_credit_batch_proposal_update_due(ipfs, block) {
 						info!("👷 Offchain worker: Updating carbon credit batch proposal");
 
-						let call =
-							Call::<T>::update_carbon_credit_batch_proposal { ipfs: ipfs.clone() };
+						let call = Call::<T>::update_carbon_credit_batch_proposal {
+							ipfs: ipfs.clone(),
+							timeout_block: block,
+						};
 
 						if let Err(err) =
 							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
@@ -3334,41 +7770,115 @@ pub mod pallet {
 							info!("👷 Offchain worker: Successfully updated carbon credit batch proposal");
 						}
 					}
-				}
 
-				VotingTimeouts::<T>::remove(now);
+					// Check if IPFS is related to a beneficiary split proposal
+					if Self::is_beneficiary_split_proposal_update_due(ipfs, block) {
+						info!("👷 Offchain worker: Updating beneficiary split proposal");
+
+						let call = Call::<T>::update_beneficiary_split_proposal {
+							ipfs: ipfs.clone(),
+							timeout_block: block,
+						};
+
+						if let Err(err) =
+							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+								call.into(),
+							) {
+							warn!(
+								"👷 Offchain worker: Failed to update beneficiary split proposal.🚧 Error: {:?}",
+								err
+							);
+						} else {
+							info!("👷 Offchain worker: Successfully updated beneficiary split proposal");
+						}
+					}
+				}
 			}
 
 			// Check if a sale timeout event has occured
-			if SaleOrderTimeouts::<T>::contains_key(now) {
-				let sale_events = SaleOrderTimeouts::<T>::get(now).unwrap();
+			if SaleOrderTimeouts::<T>::contains_key(block) {
+				let sale_events = SaleOrderTimeouts::<T>::get(block).unwrap();
 
 				for sale_hash in sale_events.iter() {
-					info!("👷 Offchain worker: Updating carbon credit sale order");
+					if Self::is_carbon_credit_sale_order_update_due(*sale_hash) {
+						info!("👷 Offchain worker: Updating carbon credit sale order");
+
+						let call =
+							Call::<T>::update_carbon_credit_sale_order { sale_hash: *sale_hash };
+
+						if let Err(err) =
+							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+								call.into(),
+							) {
+							warn!(
+									"👷 Offchain worker: Failed to update carbon credit sale order.🚧 Error: {:?}",
+									err
+								);
+						} else {
+							info!(
+								"👷 Offchain worker: Successfully updated carbon credit sale order"
+							);
+						}
+					}
+				}
+			}
 
-					let call = Call::<T>::update_carbon_credit_sale_order { sale_hash: *sale_hash };
+			// Check if a buy order timeout event has occured
+			if let Some(buy_events) = BuyOrderTimeouts::<T>::get(block) {
+				for buy_hash in buy_events.iter() {
+					if Self::is_carbon_credit_buy_order_update_due(*buy_hash) {
+						info!("👷 Offchain worker: Updating carbon credit buy order");
+
+						let call =
+							Call::<T>::update_carbon_credit_buy_order { buy_hash: *buy_hash };
 
-					if let Err(err) =
-						SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
-					{
-						warn!(
-								"👷 Offchain worker: Failed to update carbon credit sale order.🚧 Error: {:?}",
+						if let Err(err) =
+							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+								call.into(),
+							) {
+							warn!(
+								"👷 Offchain worker: Failed to update carbon credit buy order.🚧 Error: {:?}",
 								err
 							);
-					} else {
-						info!("👷 Offchain worker: Successfully updated carbon credit sale order");
+						} else {
+							info!(
+								"👷 Offchain worker: Successfully updated carbon credit buy order"
+							);
+						}
+					}
+				}
+			}
+
+			// Check if an auction timeout event has occured
+			if let Some(auction_events) = AuctionTimeouts::<T>::get(block) {
+				for auction_hash in auction_events.iter() {
+					if Self::is_carbon_credit_auction_update_due(*auction_hash) {
+						info!("👷 Offchain worker: Updating carbon credit auction");
+
+						let call =
+							Call::<T>::update_carbon_credit_auction { auction_hash: *auction_hash };
+
+						if let Err(err) =
+							SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(
+								call.into(),
+							) {
+							warn!(
+								"👷 Offchain worker: Failed to update carbon credit auction.🚧 Error: {:?}",
+								err
+							);
+						} else {
+							info!("👷 Offchain worker: Successfully updated carbon credit auction");
+						}
 					}
 				}
-
-				SaleOrderTimeouts::<T>::remove(&now);
 			}
 
 			// Check if any penalty timeout event has occured
-			if PenaltyTimeoutsAccounts::<T>::contains_key(now) {
-				let account_ids = PenaltyTimeoutsAccounts::<T>::get(now).unwrap();
+			if PenaltyTimeoutsAccounts::<T>::contains_key(block) {
+				let account_ids = PenaltyTimeoutsAccounts::<T>::get(block).unwrap();
 
 				for account_id in account_ids.iter() {
-					if ProjectOwners::<T>::contains_key(account_id) {
+					if Self::is_project_owner_penalty_update_due(account_id) {
 						info!("👷 Offchain worker: Updating project owner penalty level");
 
 						let call = Call::<T>::update_project_owner_penalty_level {
@@ -3390,7 +7900,7 @@ pub mod pallet {
 						}
 					}
 
-					if Validators::<T>::contains_key(account_id) {
+					if Self::is_validator_penalty_update_due(account_id) {
 						info!("👷 Offchain worker: Updating validator penalty level");
 
 						let call = Call::<T>::update_validator_penalty_level {
@@ -3412,15 +7922,13 @@ pub mod pallet {
 						}
 					}
 				}
-
-				PenaltyTimeoutsAccounts::<T>::remove(now);
 			}
 
-			if PenaltyTimeoutsHashes::<T>::contains_key(now) {
-				let hashes = PenaltyTimeoutsHashes::<T>::get(now).unwrap();
+			if PenaltyTimeoutsHashes::<T>::contains_key(block) {
+				let hashes = PenaltyTimeoutsHashes::<T>::get(block).unwrap();
 
 				for hash in hashes.iter() {
-					if Projects::<T>::contains_key(hash) {
+					if Self::is_project_penalty_update_due(*hash) {
 						info!("👷 Offchain worker: Updating project  penalty level");
 
 						let call = Call::<T>::update_project_penalty_level { hash: *hash };
@@ -3440,533 +7948,830 @@ pub mod pallet {
 						}
 					}
 				}
+			}
+		}
 
-				PenaltyTimeoutsHashes::<T>::remove(now);
+		// Freeze all carbon credit batches for given project owner
+		pub fn freeze_all_owner_batches(project_owner: AccountIdOf<T>) {
+			for project_hash in ProjectsByOwner::<T>::get(project_owner).unwrap_or_default() {
+				Self::freeze_all_project_batches(project_hash);
 			}
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		// Get account ID of pallet
-		fn pallet_id() -> T::AccountId {
-			PALLET_ID.into_account_truncating()
+		// Unfreeze all carbon credit batches for given project owner
+		pub fn unfreeze_all_owner_batches(project_owner: AccountIdOf<T>) {
+			for project_hash in ProjectsByOwner::<T>::get(project_owner).unwrap_or_default() {
+				Self::unfreeze_all_project_batches(project_hash);
+			}
 		}
 
-		// Generate hash identifier
-		fn generate_hash(user: AccountIdOf<T>) -> H256 {
-			let nonce = frame_system::Pallet::<T>::account_nonce(&user);
-			let now = T::Time::now();
+		// Freeze all carbon credit batches for given project
+		pub fn freeze_all_project_batches(project: H256) {
+			Self::set_project_batches_status(project, CarbonCreditBatchStatus::Frozen);
+		}
 
-			let encoded: [u8; 32] = (&user, nonce, now).using_encoded(blake2_256);
+		// Unfreeze all carbon credit batches for given project
+		pub fn unfreeze_all_project_batches(project: H256) {
+			Self::set_project_batches_status(project, CarbonCreditBatchStatus::Active);
+		}
 
-			let hash = H256::from(encoded);
+		// Set the status of all carbon credit batches for given project
+		fn set_project_batches_status(project: H256, status: CarbonCreditBatchStatus) {
+			for batch_hash in BatchesByProject::<T>::get(project).unwrap_or_default() {
+				CarbonCreditBatches::<T>::mutate(batch_hash, |batch| {
+					if let Some(batch_info) = batch {
+						batch_info.status = status.clone();
+					}
+				});
+			}
+		}
 
-			hash
+		// Get the number of carbon credit batches for given project owner
+		pub fn owner_batch_count(project_owner: &AccountIdOf<T>) -> u32 {
+			ProjectsByOwner::<T>::get(project_owner)
+				.unwrap_or_default()
+				.into_iter()
+				.map(Self::project_batch_count)
+				.sum()
 		}
 
-		// Check if the documentation (ipfs link) has been used previously
-		// Return false if the documentation is used
-		// Return true if the documentation is available
-		pub fn is_ipfs_available(ipfs: BoundedString<T::IPFSLength>) -> bool {
-			// Check in reports and proposals
-			if CarbonFootprintReports::<T>::contains_key(ipfs.clone())
-				|| ProjectProposals::<T>::contains_key(ipfs.clone())
-				|| CarbonCreditBatchProposals::<T>::contains_key(ipfs.clone())
-				|| ComplaintsForAccounts::<T>::contains_key(ipfs.clone())
-				|| ComplaintsForHashes::<T>::contains_key(ipfs.clone())
-			{
-				return false;
+		// Get the number of carbon credit batches for given project
+		pub fn project_batch_count(project: H256) -> u32 {
+			BatchesByProject::<T>::get(project).map_or(0, |batches| batches.len() as u32)
+		}
+
+		// Get the number of carbon credit batches affected by given complaint
+		pub fn complaint_batch_count(complaint: &BoundedString<T::IPFSLength>) -> u32 {
+			if let Some(complaint_info) = ComplaintsForAccounts::<T>::get(complaint) {
+				return Self::owner_batch_count(&complaint_info.complaint_for);
 			}
 
-			// Check in CF accounts
-			for (_, cfa_info) in <CarbonFootprintAccounts<T>>::iter() {
-				let documentation = cfa_info.documentation_ipfses;
+			if let Some(complaint_info) = ComplaintsForHashes::<T>::get(complaint) {
+				return Self::project_batch_count(complaint_info.complaint_for);
+			}
 
-				if documentation.contains(&ipfs) {
-					return false;
-				}
+			0
+		}
+
+		// Check if at max potential penalty level for account type entity
+		pub fn is_account_at_max_potential_penalty(account_id: AccountIdOf<T>) -> bool {
+			let mut active_complaints: u8 = 0;
+
+			if Validators::<T>::contains_key(account_id.clone()) {
+				let account = Validators::<T>::get(account_id.clone()).unwrap();
+
+				active_complaints += account.penalty_level;
+			} else {
+				let account = ProjectOwners::<T>::get(account_id.clone()).unwrap();
+
+				active_complaints += account.penalty_level;
 			}
 
-			// Check in validators
-			for (_, validator) in <Validators<T>>::iter() {
-				if validator.documentation_ipfs == ipfs {
-					return false;
+			for (_, complaint_info) in ComplaintsForAccounts::<T>::iter() {
+				if complaint_info.complaint_active && complaint_info.complaint_for == account_id {
+					active_complaints += 1;
 				}
 			}
 
-			// Check in project owners
-			for (_, project_owner) in <ProjectOwners<T>>::iter() {
-				if project_owner.documentation_ipfs == ipfs {
-					return false;
+			return active_complaints == 4;
+		}
+
+		// Check if at max potential penalty level for hash type entity
+		pub fn is_hash_at_max_potential_penalty(hash: H256) -> bool {
+			let mut active_complaints: u8 = 0;
+
+			let project = Projects::<T>::get(hash).unwrap();
+
+			active_complaints += project.penalty_level;
+
+			for (_, complaint_info) in ComplaintsForHashes::<T>::iter() {
+				if complaint_info.complaint_active && complaint_info.complaint_for == hash {
+					active_complaints += 1;
 				}
 			}
 
-			return true;
+			return active_complaints == 4;
 		}
 
-		// Check if the account is tied to any existing entity on the pallet
-		// Return false if the account_id is used
-		// Return true if the account_id is available
-		pub fn is_account_id_available(account_id: AccountIdOf<T>) -> bool {
-			// Check in carbon footprint, trader, project validator and project owner accounts
-			if CarbonFootprintAccounts::<T>::contains_key(account_id.clone())
-				|| TraderAccounts::<T>::get().contains(&account_id.clone())
-				|| Validators::<T>::contains_key(account_id.clone())
-				|| ProjectOwners::<T>::contains_key(account_id.clone())
-			{
-				return false;
+		// Check if there is a ongoing complaint for the given carbon credit batch
+		pub fn has_a_ongoing_complaint(batch_hash: H256) -> bool {
+			let mut result = false;
+
+			for (_, complaint_info) in ComplaintsForHashes::<T>::iter() {
+				if complaint_info.complaint_active && complaint_info.complaint_for == batch_hash {
+					result = true
+				}
 			}
 
-			return true;
+			return result;
 		}
 
-		// Check if the account is eligible to be a carbon footpring account
-		// Return true if the account_id isn't in use for another account type
-		// Return false if the account_id is in use for another account type
-		pub fn is_eligible_for_cfa(account_id: AccountIdOf<T>) -> bool {
-			// Check in carbon footprint, trader, validator and project owner accounts
-			if TraderAccounts::<T>::get().contains(&account_id.clone())
-				|| Validators::<T>::contains_key(account_id.clone())
-				|| ProjectOwners::<T>::contains_key(account_id.clone())
-			{
-				return false;
+		// Calculate basic payment made to pallet
+		pub fn calculate_basic_payment_made_to_pallet(
+			from: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			// Retrieve needed account info data
+			let mut account_info = None;
+
+			if let Some(validator) = Validators::<T>::get(from.clone()) {
+				account_info = Some(validator);
 			}
 
-			return true;
+			if let Some(project_owner) = ProjectOwners::<T>::get(from.clone()) {
+				account_info = Some(project_owner);
+			}
+
+			// Set penalty level percentage
+			let mut penalty = Perbill::zero();
+
+			if let Some(account_info) = account_info {
+				penalty = Self::get_penalty_level(account_info.penalty_level)?;
+			}
+
+			let actual_amount = penalty.left_from_one().mul_floor(amount);
+
+			Ok(actual_amount)
 		}
 
-		// Check if the account is eligible for carbon credit transactions
-		// Return true if the account_id can transact with carbon credits (CFA, Trader, Project Owner)
-		// Return true if the account_id can't transact with carbon credits (Validator, or non-registered account)
-		pub fn is_eligible_for_carbon_credit_transaction(account_id: AccountIdOf<T>) -> bool {
-			if CarbonFootprintAccounts::<T>::contains_key(account_id.clone())
-				|| TraderAccounts::<T>::get().contains(&account_id.clone())
-				|| ProjectOwners::<T>::contains_key(account_id.clone())
-			{
-				return true;
+		// Calculate complex payment made to pallet
+		// Note: Only used for project owners during proposition for carbon credit batches
+		pub fn calculate_complex_payment_made_to_pallet(
+			owner_id: AccountIdOf<T>,
+			project_hash: H256,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			// Get owner info
+			let owner_info =
+				ProjectOwners::<T>::get(owner_id).ok_or(Error::<T>::ProjectOwnerDoesntExist)?;
+
+			// Get project info
+			let project_info =
+				Projects::<T>::get(project_hash).ok_or(Error::<T>::ProjectDoesntExist)?;
+
+			// Get owner and project penalty level values
+			let owner_penalty = Self::get_penalty_level(owner_info.penalty_level)?;
+			let project_penalty = Self::get_penalty_level(project_info.penalty_level)?;
+
+			// Calculate the share that is left after both penalties
+			let payment_share = owner_penalty.left_from_one() * project_penalty.left_from_one();
+
+			// Calculate actual amount that needs to be paid
+			let actual_amount = payment_share.mul_floor(amount);
+
+			Ok(actual_amount)
+		}
+
+		// Calculate gains penalties for a specific account
+		pub fn calculate_gains_penalties(
+			account_id: AccountIdOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let mut penalized_gains = BalanceOf::<T>::from(0u32);
+
+			if let Some(project_owner) = ProjectOwners::<T>::get(account_id.clone()) {
+				penalized_gains = Self::get_penalty_level(project_owner.penalty_level)?
+					.left_from_one()
+					.mul_floor(amount);
 			}
 
-			return false;
+			if let Some(validator) = Validators::<T>::get(account_id) {
+				penalized_gains = Self::get_penalty_level(validator.penalty_level)?
+					.left_from_one()
+					.mul_floor(amount);
+			}
+
+			Ok(penalized_gains)
 		}
 
-		// Check if the account has submitted a carbon footprint report for voting
-		// Note: If a user has submitted a CF report he/she then can not registed as another account type
-		pub fn is_trying_to_register_as_cfa(account_id: AccountIdOf<T>) -> bool {
-			let mut result = false;
+		// Get the penalty (the withheld share of a payment or gain) for a penalty level
+		fn get_penalty_level(penalty_level: u8) -> Result<Perbill, DispatchError> {
+			let penalty_levels = PenaltyLevels::<T>::get();
 
-			// Check in CF accounts
-			for (_, cf_report) in <CarbonFootprintReports<T>>::iter() {
-				// Check if user has an active CF report that is up for voting
-				if cf_report.cf_account == account_id && cf_report.voting_active {
-					result = true;
+			let penalty =
+				penalty_levels.get(&penalty_level).ok_or(Error::<T>::PenaltyLevelDoesntExist)?;
+
+			Ok(*penalty)
+		}
+
+		// Process validator gains from benefctor split during a carbon credit sale
+		// Note: Returns the validator gains that were split (nothing if the batch has no validator
+		// benefactors, in which case the validator share stays with the seller)
+		// Note: The remainder of an uneven split stays with the seller
+		pub fn process_validator_gains(
+			buyer: AccountIdOf<T>,
+			validator_benefactors: VotesOf<T>,
+			validator_gains: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let num_of_validator_benefactors: u32 = validator_benefactors.len().saturated_into();
+
+			if num_of_validator_benefactors == 0 {
+				return Ok(BalanceOf::<T>::from(0u32));
+			}
+
+			let gains_per_validator =
+				validator_gains / BalanceOf::<T>::from(num_of_validator_benefactors);
+
+			for validator in validator_benefactors.iter() {
+				let validator_gains =
+					Self::calculate_gains_penalties(validator.clone(), gains_per_validator)?;
+
+				// Transfer funds
+				T::Currency::transfer(&buyer, validator, validator_gains, Preservation::Preserve)?;
+
+				let remainder = gains_per_validator.saturating_sub(validator_gains);
+
+				if remainder == BalanceOf::<T>::from(0u32) {
+					continue;
 				}
+
+				// Transfer funds
+				T::Currency::transfer(
+					&buyer,
+					&Self::pallet_id(),
+					remainder,
+					Preservation::Preserve,
+				)?;
 			}
 
-			result
+			Ok(gains_per_validator
+				.saturating_mul(BalanceOf::<T>::from(num_of_validator_benefactors)))
 		}
 
-		// Recalculate CFA balances
-		pub fn recalculate_cfa_balances(batch_hash: H256) {
-			for (_, retirement_info) in CarbonCreditRetirements::<T>::iter() {
-				if retirement_info.batch_hash == batch_hash {
-					let mut footprint_account = CarbonFootprintAccounts::<T>::get(
-						retirement_info.clone().carbon_footprint_account,
-					)
-					.unwrap();
+		// Process project owner gains from benefactor split during a carbon credit sale
+		pub fn process_owner_gains(
+			buyer: AccountIdOf<T>,
+			project_owner: AccountIdOf<T>,
+			owner_gains: BalanceOf<T>,
+		) -> DispatchResult {
+			let real_owner_gains =
+				Self::calculate_gains_penalties(project_owner.clone(), owner_gains)?;
 
-					if retirement_info.credit_amount >= footprint_account.carbon_footprint_surplus {
-						let change = retirement_info.credit_amount
-							- footprint_account.carbon_footprint_surplus;
+			// Transfer funds
+			T::Currency::transfer(
+				&buyer,
+				&project_owner,
+				real_owner_gains,
+				Preservation::Preserve,
+			)?;
 
-						footprint_account = CarbonFootprintAccountInfo {
-							carbon_footprint_surplus: BalanceOf::<T>::from(0u32),
-							carbon_footprint_deficit: footprint_account.carbon_footprint_deficit
-								+ change,
-							..footprint_account
-						};
-					} else {
-						footprint_account = CarbonFootprintAccountInfo {
-							carbon_footprint_surplus: footprint_account.carbon_footprint_surplus
-								- retirement_info.credit_amount,
-							..footprint_account
-						};
-					}
+			let remainder = owner_gains.saturating_sub(real_owner_gains);
 
-					CarbonFootprintAccounts::<T>::insert(
-						retirement_info.carbon_footprint_account,
-						footprint_account,
-					);
-				}
+			if remainder != BalanceOf::<T>::from(0u32) {
+				// Transfer funds
+				T::Currency::transfer(
+					&buyer,
+					&Self::pallet_id(),
+					remainder,
+					Preservation::Preserve,
+				)?;
 			}
+
+			Ok(())
 		}
 
-		// Calculate project owner standing debts
-		pub fn calculate_project_owner_debts(batch_hash: H256) {
-			let batch_info = CarbonCreditBatches::<T>::get(batch_hash).unwrap();
-			let project_info = Projects::<T>::get(batch_info.project_hash).unwrap();
+		// Calculate the maker and taker trading fees for a fill price
+		pub fn calculate_trading_fees(fill_price: BalanceOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
+			let trading_fees = TradingFees::<T>::get();
 
-			let mut debts = BTreeMap::<AccountIdOf<T>, BalanceOf<T>>::new();
+			(
+				trading_fees.maker_fee.mul_floor(fill_price),
+				trading_fees.taker_fee.mul_floor(fill_price),
+			)
+		}
 
-			if ProjectOwnerDebts::<T>::contains_key(project_info.project_owner.clone()) {
-				debts = ProjectOwnerDebts::<T>::get(project_info.project_owner.clone());
+		// Transfer the trading fees of a fill to the trading fee beneficiary (or the pallet)
+		// Note: Both fees are paid by the buyer, the buyer fee on top of the fill price and the
+		// seller fee out of the seller proceeds
+		// Note: Returns the seller proceeds without the seller fee
+		pub fn process_trading_fees(
+			buyer: &AccountIdOf<T>,
+			seller_proceeds: BalanceOf<T>,
+			buyer_fee: BalanceOf<T>,
+			seller_fee: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let seller_proceeds = seller_proceeds
+				.checked_sub(&seller_fee)
+				.ok_or(Error::<T>::TradingFeeExceedsSaleProceeds)?;
+			let trading_fees =
+				buyer_fee.checked_add(&seller_fee).ok_or(Error::<T>::ArithmeticOverflow)?;
+
+			if trading_fees > BalanceOf::<T>::from(0u32) {
+				let beneficiary = T::TradingFeeBeneficiary::get().unwrap_or_else(Self::pallet_id);
+
+				T::Currency::transfer(buyer, &beneficiary, trading_fees, Preservation::Preserve)?;
 			}
 
-			// Go through all carbon credit retirements
-			for (_, retirement_info) in CarbonCreditRetirements::<T>::iter() {
-				if retirement_info.batch_hash == batch_hash {
-					let mut debt_amount =
-						retirement_info.credit_amount * batch_info.penalty_repay_price;
+			Ok(seller_proceeds)
+		}
 
-					if debts.contains_key(&retirement_info.carbon_footprint_account) {
-						debt_amount +=
-							*debts.get(&retirement_info.carbon_footprint_account).unwrap();
-					}
+		// Do the beneficiary splits for a carbon credit sale (paid by the payer)
+		// Note: The primary split is used if the seller is the project owner and the secondary
+		// split otherwise
+		// Note: Projects with approved beneficiary splits use them (and pay their stakeholders on
+		// every sale) instead of the pallet beneficiary splits
+		// Note: Returns the total amount of validator, owner and stakeholder gains
+		pub fn process_beneficiary_splits(
+			payer: AccountIdOf<T>,
+			seller: &AccountIdOf<T>,
+			project_hash: H256,
+			project_owner: AccountIdOf<T>,
+			validator_benefactors: VotesOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let (beneficiary_splits, stakeholders) =
+				match ProjectBeneficiarySplits::<T>::get(project_hash) {
+					Some(project_splits) => {
+						(project_splits.beneficiary_splits, project_splits.stakeholders)
+					},
+					None => (BeneficiarySplits::<T>::get(), StakeholderSharesOf::<T>::new()),
+				};
 
-					debts.insert(retirement_info.carbon_footprint_account, debt_amount);
-				}
+			let split_gains = |split_type: BeneficiarySplitType| {
+				beneficiary_splits[&split_type].mul_floor(amount)
+			};
+
+			let stakeholder_gains =
+				Self::process_stakeholder_gains(payer.clone(), stakeholders, amount)?;
+
+			if project_owner == *seller {
+				// Do primary beneficiary split
+				let validator_gains = Self::process_validator_gains(
+					payer,
+					validator_benefactors,
+					split_gains(BeneficiarySplitType::PrimarySaleValidators),
+				)?;
+
+				Ok(validator_gains.saturating_add(stakeholder_gains))
+			} else {
+				// Do secondary beneficiary split
+				let owner_gains = split_gains(BeneficiarySplitType::SecondarySaleOwner);
+
+				let validator_gains = Self::process_validator_gains(
+					payer.clone(),
+					validator_benefactors,
+					split_gains(BeneficiarySplitType::SecondarySaleValidators),
+				)?;
+
+				Self::process_owner_gains(payer, project_owner, owner_gains)?;
+
+				Ok(validator_gains.saturating_add(owner_gains).saturating_add(stakeholder_gains))
 			}
+		}
+
+		// Process stakeholder gains from the beneficiary split during a carbon credit sale
+		// Note: Returns the total amount paid to the stakeholders (a share that rounds down to
+		// nothing stays with the seller)
+		pub fn process_stakeholder_gains(
+			payer: AccountIdOf<T>,
+			stakeholders: StakeholderSharesOf<T>,
+			amount: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let mut stakeholder_gains = BalanceOf::<T>::from(0u32);
+
+			for (stakeholder, share) in stakeholders.into_iter() {
+				let gains = share.mul_floor(amount);
+
+				if gains == BalanceOf::<T>::from(0u32) {
+					continue;
+				}
 
-			// Go through all carbon credit holdings
-			for (hash, holding_account, holdings_info) in CarbonCreditHoldings::<T>::iter() {
-				if hash == batch_hash {
-					let total_credits =
-						holdings_info.available_amount + holdings_info.unavailable_amount;
+				// Transfer funds
+				T::Currency::transfer(&payer, &stakeholder, gains, Preservation::Preserve)?;
 
-					let mut debt_amount = total_credits * batch_info.penalty_repay_price;
+				stakeholder_gains = stakeholder_gains.saturating_add(gains);
+			}
 
-					if debts.contains_key(&holding_account) {
-						debt_amount += *debts.get(&holding_account).unwrap();
-					}
+			Ok(stakeholder_gains)
+		}
 
-					if holding_account == project_info.project_owner {
-						debts.insert(Self::pallet_id(), debt_amount);
-					} else {
-						debts.insert(holding_account, debt_amount);
-					}
-				}
-			}
+		// Check all pallet storage invariants
+		// Note: Used by the try_state hook and run after every pallet test
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), DispatchError> {
+			Self::check_carbon_credit_ledger()?;
+			Self::check_sale_orders()?;
+			Self::check_buy_orders()?;
+			Self::check_order_books()?;
+			Self::check_auctions()?;
+			Self::check_timeouts()?;
+			Self::check_penalties()?;
+			Self::check_debts()?;
 
-			ProjectOwnerDebts::<T>::insert(project_info.project_owner, debts);
+			Ok(())
 		}
 
-		// Check if vote has passed
-		pub fn has_vote_passed(total_votes: u16, votes_for: u16) -> bool {
-			let vote_pass_ratio = VotePassRatio::<T>::get();
+		// Check if the holdings and retirements of every active or frozen carbon credit batch
+		// add up to the batch credit amount
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_carbon_credit_ledger() -> Result<(), DispatchError> {
+			let mut retired_credits = BTreeMap::<H256, BalanceOf<T>>::new();
 
-			if vote_pass_ratio.upper_limit_part == 0 {
-				if votes_for > total_votes - votes_for {
-					return true;
-				} else {
-					return false;
-				}
+			for (_, retirement_info) in CarbonCreditRetirements::<T>::iter() {
+				*retired_credits.entry(retirement_info.batch_hash).or_default() +=
+					retirement_info.credit_amount;
 			}
 
-			if vote_pass_ratio.upper_limit_part == vote_pass_ratio.proportion_part {
-				if votes_for == total_votes {
-					return true;
-				} else {
-					return false;
+			for (batch_hash, batch_info) in CarbonCreditBatches::<T>::iter() {
+				if batch_info.status == CarbonCreditBatchStatus::Redacted {
+					continue;
 				}
-			}
 
-			let needed_votes =
-				(vote_pass_ratio.proportion_part * total_votes) / vote_pass_ratio.upper_limit_part;
+				let mut total_credits =
+					retired_credits.get(&batch_hash).copied().unwrap_or_default();
 
-			if votes_for >= needed_votes {
-				return true;
+				for (_, holdings) in CarbonCreditHoldings::<T>::iter_prefix(batch_hash) {
+					total_credits += holdings.available_amount + holdings.unavailable_amount;
+				}
+
+				ensure!(
+					total_credits == batch_info.credit_amount,
+					"Carbon credit batch holdings and retirements don't match the credit amount"
+				);
 			}
 
-			return false;
+			Ok(())
 		}
 
-		// Freeze all carbon credit batches for given project owner
-		pub fn freeze_all_owner_batches(project_owner: AccountIdOf<T>) {
-			let mut project_hashes = BTreeSet::<H256>::new();
+		// Check if every active sale order has a timeout event and if the unavailable credits
+		// of every holding match the credits in the active sale orders and asks of the seller
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_sale_orders() -> Result<(), DispatchError> {
+			let mut credits_on_sale = BTreeMap::<(H256, AccountIdOf<T>), BalanceOf<T>>::new();
 
-			for (project_hash, project_info) in Projects::<T>::iter() {
-				if project_info.project_owner == project_owner {
-					project_hashes.insert(project_hash);
+			for (sale_hash, sale_order) in CarbonCreditSaleOrders::<T>::iter() {
+				if sale_order.status != SaleOrderStatus::Open {
+					continue;
 				}
-			}
-
-			for (batch_hash, mut batch_info) in CarbonCreditBatches::<T>::iter() {
-				if project_hashes.contains(&batch_info.project_hash) {
-					batch_info = CarbonCreditBatchInfo {
-						status: CarbonCreditBatchStatus::Frozen,
-						..batch_info
-					};
 
-					CarbonCreditBatches::<T>::insert(batch_hash, batch_info);
+				if let Some(sale_timeout) = sale_order.sale_timeout {
+					ensure!(
+						SaleOrderTimeouts::<T>::get(sale_timeout)
+							.map_or(false, |sale_events| sale_events.contains(&sale_hash)),
+						"Active sale order doesn't have a sale order timeout event"
+					);
 				}
-			}
-		}
 
-		// Unfreeze all carbon credit batches for given project owner
-		pub fn unfreeze_all_owner_batches(project_owner: AccountIdOf<T>) {
-			let mut project_hashes = BTreeSet::<H256>::new();
+				*credits_on_sale.entry((sale_order.batch_hash, sale_order.seller)).or_default() +=
+					sale_order.credit_amount;
+			}
 
-			for (project_hash, project_info) in Projects::<T>::iter() {
-				if project_info.project_owner == project_owner {
-					project_hashes.insert(project_hash);
+			for (_, limit_order) in LimitOrders::<T>::iter() {
+				if limit_order.side == OrderSide::Ask {
+					*credits_on_sale
+						.entry((limit_order.batch_hash, limit_order.owner))
+						.or_default() += limit_order.credit_amount;
 				}
 			}
 
-			for (batch_hash, mut batch_info) in CarbonCreditBatches::<T>::iter() {
-				if project_hashes.contains(&batch_info.project_hash) {
-					batch_info = CarbonCreditBatchInfo {
-						status: CarbonCreditBatchStatus::Active,
-						..batch_info
-					};
-
-					CarbonCreditBatches::<T>::insert(batch_hash, batch_info);
+			for (_, auction) in CarbonCreditAuctions::<T>::iter() {
+				if auction.status == AuctionStatus::Open {
+					*credits_on_sale.entry((auction.batch_hash, auction.seller)).or_default() +=
+						auction.credit_amount;
 				}
 			}
-		}
 
-		// Freeze all carbon credit batches for given project
-		pub fn freeze_all_project_batches(project: H256) {
-			for (batch_hash, mut batch_info) in CarbonCreditBatches::<T>::iter() {
-				if batch_info.project_hash == project {
-					batch_info = CarbonCreditBatchInfo {
-						status: CarbonCreditBatchStatus::Frozen,
-						..batch_info
-					};
+			for (batch_hash, account_id, holdings) in CarbonCreditHoldings::<T>::iter() {
+				let on_sale = credits_on_sale.remove(&(batch_hash, account_id)).unwrap_or_default();
 
-					CarbonCreditBatches::<T>::insert(batch_hash, batch_info);
-				}
+				ensure!(
+					holdings.unavailable_amount == on_sale,
+					"Unavailable carbon credits don't match the open sale orders, asks and auctions"
+				);
 			}
-		}
 
-		// Unfreeze all carbon credit batches for given project
-		pub fn unfreeze_all_project_batches(project: H256) {
-			for (batch_hash, mut batch_info) in CarbonCreditBatches::<T>::iter() {
-				if batch_info.project_hash == project {
-					batch_info = CarbonCreditBatchInfo {
-						status: CarbonCreditBatchStatus::Active,
-						..batch_info
-					};
+			ensure!(
+				credits_on_sale.is_empty(),
+				"Active sale order isn't backed by the seller holdings"
+			);
 
-					CarbonCreditBatches::<T>::insert(batch_hash, batch_info);
-				}
+			for sale_hash in SaleOrderFills::<T>::iter_keys() {
+				ensure!(
+					CarbonCreditSaleOrders::<T>::contains_key(sale_hash),
+					"Sale order fills exist for a sale order that doesn't exist"
+				);
+			}
+
+			for sale_hash in SaleOrderAllowedBuyers::<T>::iter_keys() {
+				ensure!(
+					CarbonCreditSaleOrders::<T>::contains_key(sale_hash),
+					"Allowed buyers exist for a sale order that doesn't exist"
+				);
 			}
+
+			Ok(())
 		}
 
-		// Check if at max potential penalty level for account type entity
-		pub fn is_account_at_max_potential_penalty(account_id: AccountIdOf<T>) -> bool {
-			let mut active_complaints: u8 = 0;
+		// Check if every active buy order has a timeout event and if the held buy order funds
+		// of every buyer match the remaining amount of the active buy orders of the buyer
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_buy_orders() -> Result<(), DispatchError> {
+			let mut held_funds = BTreeMap::<AccountIdOf<T>, BalanceOf<T>>::new();
 
-			if Validators::<T>::contains_key(account_id.clone()) {
-				let account = Validators::<T>::get(account_id.clone()).unwrap();
+			for (buy_hash, buy_order) in CarbonCreditBuyOrders::<T>::iter() {
+				if !buy_order.buy_active {
+					continue;
+				}
 
-				active_complaints += account.penalty_level;
-			} else {
-				let account = ProjectOwners::<T>::get(account_id.clone()).unwrap();
+				ensure!(
+					BuyOrderTimeouts::<T>::get(buy_order.buy_timeout)
+						.map_or(false, |buy_events| buy_events.contains(&buy_hash)),
+					"Active buy order doesn't have a buy order timeout event"
+				);
 
-				active_complaints += account.penalty_level;
+				*held_funds.entry(buy_order.buyer).or_default() +=
+					buy_order.credit_amount * buy_order.credit_price;
 			}
 
-			for (_, complaint_info) in ComplaintsForAccounts::<T>::iter() {
-				if complaint_info.complaint_active && complaint_info.complaint_for == account_id {
-					active_complaints += 1;
-				}
+			for (buyer, amount) in held_funds {
+				ensure!(
+					T::Currency::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &buyer)
+						== amount,
+					"Held buyer funds don't match the active buy orders"
+				);
 			}
 
-			return active_complaints == 4;
+			Ok(())
 		}
 
-		// Check if at max potential penalty level for hash type entity
-		pub fn is_hash_at_max_potential_penalty(hash: H256) -> bool {
-			let mut active_complaints: u8 = 0;
+		// Check if every open auction has a timeout event and if the held bid funds of every
+		// bidder match the highest bids of the bidder
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_auctions() -> Result<(), DispatchError> {
+			let mut held_funds = BTreeMap::<AccountIdOf<T>, BalanceOf<T>>::new();
 
-			let project = Projects::<T>::get(hash).unwrap();
+			for (auction_hash, auction) in CarbonCreditAuctions::<T>::iter() {
+				if auction.status != AuctionStatus::Open {
+					continue;
+				}
 
-			active_complaints += project.penalty_level;
+				ensure!(
+					AuctionTimeouts::<T>::get(auction.end_block)
+						.map_or(false, |auction_events| auction_events.contains(&auction_hash)),
+					"Open auction doesn't have an auction timeout event"
+				);
 
-			for (_, complaint_info) in ComplaintsForHashes::<T>::iter() {
-				if complaint_info.complaint_active && complaint_info.complaint_for == hash {
-					active_complaints += 1;
+				if let Some((bidder, credit_price)) = auction.highest_bid {
+					*held_funds.entry(bidder).or_default() += auction.credit_amount * credit_price;
 				}
 			}
 
-			return active_complaints == 4;
+			for (bidder, amount) in held_funds {
+				ensure!(
+					T::Currency::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &bidder)
+						== amount,
+					"Held bidder funds don't match the highest auction bids"
+				);
+			}
+
+			Ok(())
 		}
 
-		// Check if there is a ongoing complaint for the given carbon credit batch
-		pub fn has_a_ongoing_complaint(batch_hash: H256) -> bool {
-			let mut result = false;
+		// Check if every order book entry points to a matching limit order, if every limit order
+		// is in the order book and if the held bid funds of every buyer match the bids of the buyer
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_order_books() -> Result<(), DispatchError> {
+			let mut booked_orders = 0usize;
 
-			for (_, complaint_info) in ComplaintsForHashes::<T>::iter() {
-				if complaint_info.complaint_active && complaint_info.complaint_for == batch_hash {
-					result = true
+			for (batch_hash, side, order_book) in OrderBooks::<T>::iter() {
+				ensure!(!order_book.is_empty(), "Order book side is empty");
+
+				for (credit_price, order_hash) in order_book.iter() {
+					ensure!(
+						LimitOrders::<T>::get(order_hash).map_or(false, |limit_order| {
+							limit_order.batch_hash == batch_hash
+								&& limit_order.side == side
+								&& limit_order.credit_price == *credit_price
+						}),
+						"Order book entry doesn't point to a matching limit order"
+					);
 				}
+
+				ensure!(
+					order_book.windows(2).all(|entries| match side {
+						OrderSide::Bid => entries[0].0 >= entries[1].0,
+						OrderSide::Ask => entries[0].0 <= entries[1].0,
+					}),
+					"Order book isn't sorted by price"
+				);
+
+				booked_orders += order_book.len();
 			}
 
-			return result;
-		}
+			let mut held_funds = BTreeMap::<AccountIdOf<T>, BalanceOf<T>>::new();
+			let mut limit_orders = 0usize;
 
-		// Calculate basic payment made to pallet
-		pub fn calculate_basic_payment_made_to_pallet(
-			from: AccountIdOf<T>,
-			amount: BalanceOf<T>,
-		) -> BalanceOf<T> {
-			// Retrieve needed account info data
-			let mut account_info = ProjectValidatorOrProjectOwnerInfo {
-				documentation_ipfs: BoundedString::<T::IPFSLength>::truncate_from("empty_ipfs"),
-				penalty_level: 0u8,
-				penalty_timeout: frame_system::Pallet::<T>::block_number(),
-			};
+			for (_, limit_order) in LimitOrders::<T>::iter() {
+				limit_orders += 1;
 
-			if Validators::<T>::contains_key(from.clone()) {
-				account_info = Validators::<T>::get(from.clone()).unwrap();
+				if limit_order.side == OrderSide::Bid {
+					*held_funds.entry(limit_order.owner).or_default() +=
+						limit_order.credit_amount * limit_order.credit_price;
+				}
 			}
 
-			if ProjectOwners::<T>::contains_key(from.clone()) {
-				account_info = ProjectOwners::<T>::get(from.clone()).unwrap();
-			}
+			ensure!(booked_orders == limit_orders, "Limit order isn't in the order book");
 
-			// Set penalty level percentage
-			let mut penalty_percentage = BalanceOf::<T>::from(10000u32);
+			for (buyer, amount) in held_funds {
+				ensure!(
+					T::Currency::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &buyer)
+						== amount,
+					"Held bid funds don't match the bids"
+				);
+			}
 
-			if account_info.documentation_ipfs
-				!= BoundedString::<T::IPFSLength>::truncate_from("empty_ipfs")
-			{
-				let penalty_percentages = PenaltyLevels::<T>::get();
+			Ok(())
+		}
 
-				penalty_percentage = penalty_percentages[&account_info.penalty_level];
+		// Check if every timeout event points to a live item
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_timeouts() -> Result<(), DispatchError> {
+			for (_, timeout_events) in VotingTimeouts::<T>::iter() {
+				for ipfs in timeout_events {
+					ensure!(
+						CarbonFootprintReports::<T>::get(&ipfs)
+							.map_or(false, |report| report.voting_active)
+							|| ProjectProposals::<T>::get(&ipfs)
+								.map_or(false, |proposal| proposal.voting_active)
+							|| CarbonCreditBatchProposals::<T>::get(&ipfs)
+								.map_or(false, |proposal| proposal.voting_active)
+							|| BeneficiarySplitProposals::<T>::get(&ipfs)
+								.map_or(false, |proposal| proposal.voting_active),
+						"Voting timeout event doesn't point to an active vote"
+					);
+				}
 			}
 
-			let actual_amount = BalanceOf::<T>::from(10000u32) * amount / penalty_percentage;
+			for (_, complaint_events) in ComplaintTimeouts::<T>::iter() {
+				for complaint in complaint_events {
+					ensure!(
+						ComplaintsForAccounts::<T>::get(&complaint)
+							.map_or(false, |complaint_info| complaint_info.complaint_active)
+							|| ComplaintsForHashes::<T>::get(&complaint)
+								.map_or(false, |complaint_info| complaint_info.complaint_active),
+						"Complaint timeout event doesn't point to an active complaint"
+					);
+				}
+			}
 
-			actual_amount
-		}
+			for (timeout_block, sale_events) in SaleOrderTimeouts::<T>::iter() {
+				for sale_hash in sale_events {
+					ensure!(
+						CarbonCreditSaleOrders::<T>::get(sale_hash).map_or(false, |sale_order| {
+							sale_order.status == SaleOrderStatus::Open
+								&& sale_order.sale_timeout == Some(timeout_block)
+						}),
+						"Sale order timeout event doesn't point to an active sale order"
+					);
+				}
+			}
 
-		// Calculate complex payment made to pallet
-		// Note: Only used for project owners during proposition for carbon credit batches
-		pub fn calculate_complex_payment_made_to_pallet(
-			owner_id: AccountIdOf<T>,
-			project_hash: H256,
-			amount: BalanceOf<T>,
-		) -> BalanceOf<T> {
-			// Get owner info
-			let owner_info = ProjectOwners::<T>::get(owner_id).unwrap();
+			for (timeout_block, buy_events) in BuyOrderTimeouts::<T>::iter() {
+				for buy_hash in buy_events {
+					ensure!(
+						CarbonCreditBuyOrders::<T>::get(buy_hash).map_or(false, |buy_order| {
+							buy_order.buy_active && buy_order.buy_timeout == timeout_block
+						}),
+						"Buy order timeout event doesn't point to an active buy order"
+					);
+				}
+			}
 
-			// Get project info
-			let project_info = Projects::<T>::get(project_hash).unwrap();
+			for (timeout_block, auction_events) in AuctionTimeouts::<T>::iter() {
+				for auction_hash in auction_events {
+					ensure!(
+						CarbonCreditAuctions::<T>::get(auction_hash).map_or(false, |auction| {
+							auction.status == AuctionStatus::Open
+								&& auction.end_block == timeout_block
+						}),
+						"Auction timeout event doesn't point to an open auction"
+					);
+				}
+			}
 
-			// Get penalty levels
-			let penalty_levels = PenaltyLevels::<T>::get();
+			for (timeout_block, account_ids) in PenaltyTimeoutsAccounts::<T>::iter() {
+				for account_id in account_ids {
+					let account = Validators::<T>::get(&account_id)
+						.or_else(|| ProjectOwners::<T>::get(&account_id));
 
-			// Get owner and project penalty level values
-			let owner_penalty_percentage = penalty_levels[&owner_info.penalty_level];
-			let project_penalty_percentage = penalty_levels[&project_info.penalty_level];
+					ensure!(
+						account.map_or(false, |account| {
+							account.penalty_level > 0 && account.penalty_timeout == timeout_block
+						}),
+						"Penalty timeout event doesn't point to a penalized account"
+					);
+				}
+			}
 
-			// Calculate total penalty percentage
-			let penalty_percentage = owner_penalty_percentage * project_penalty_percentage
-				/ BalanceOf::<T>::from(10000u32);
+			for (timeout_block, hashes) in PenaltyTimeoutsHashes::<T>::iter() {
+				for hash in hashes {
+					ensure!(
+						Projects::<T>::get(hash).map_or(false, |project| {
+							project.penalty_level > 0 && project.penalty_timeout == timeout_block
+						}),
+						"Penalty timeout event doesn't point to a penalized project"
+					);
+				}
+			}
 
-			// Calculate actual amount that needs to be paid
-			let actual_amount = BalanceOf::<T>::from(10000u32) * amount / penalty_percentage;
+			for (_, records) in PruningTimeouts::<T>::iter() {
+				for record in records {
+					ensure!(
+						Self::is_record_finished(&record),
+						"Pruning timeout event doesn't point to a finished record"
+					);
+				}
+			}
 
-			actual_amount
+			Ok(())
 		}
 
-		// Calculate gains penalties for a specific account
-		pub fn calculate_gains_penalties(
-			account_id: AccountIdOf<T>,
-			amount: BalanceOf<T>,
-		) -> BalanceOf<T> {
-			let mut penalized_gains = BalanceOf::<T>::from(0u32);
-
-			let penalty_values = PenaltyLevels::<T>::get();
+		// Check if every penalty level has a penalty value and if every penalized account and
+		// project has a penalty timeout event
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_penalties() -> Result<(), DispatchError> {
+			let penalty_levels = PenaltyLevels::<T>::get();
 
-			if ProjectOwners::<T>::contains_key(account_id.clone()) {
-				let project_owner = ProjectOwners::<T>::get(account_id.clone()).unwrap();
+			for (account_id, account) in Validators::<T>::iter().chain(ProjectOwners::<T>::iter()) {
+				ensure!(
+					penalty_levels.contains_key(&account.penalty_level),
+					"Account penalty level doesn't have a penalty value"
+				);
 
-				penalized_gains = amount * BalanceOf::<T>::from(10000u32)
-					/ penalty_values[&project_owner.penalty_level];
+				if account.penalty_level > 0 {
+					ensure!(
+						PenaltyTimeoutsAccounts::<T>::get(account.penalty_timeout)
+							.map_or(false, |account_ids| account_ids.contains(&account_id)),
+						"Penalized account doesn't have a penalty timeout event"
+					);
+				}
 			}
 
-			if Validators::<T>::contains_key(account_id.clone()) {
-				let validator = Validators::<T>::get(account_id).unwrap();
+			for (hash, project) in Projects::<T>::iter() {
+				ensure!(
+					penalty_levels.contains_key(&project.penalty_level),
+					"Project penalty level doesn't have a penalty value"
+				);
 
-				penalized_gains = amount * BalanceOf::<T>::from(10000u32)
-					/ penalty_values[&validator.penalty_level];
+				if project.penalty_level > 0 {
+					ensure!(
+						PenaltyTimeoutsHashes::<T>::get(project.penalty_timeout)
+							.map_or(false, |hashes| hashes.contains(&hash)),
+						"Penalized project doesn't have a penalty timeout event"
+					);
+				}
 			}
 
-			penalized_gains
+			Ok(())
 		}
 
-		// Process validator gains from benefctor split during a carbon credit sale
-		pub fn process_validator_gains(
-			buyer: AccountIdOf<T>,
-			validator_benefactors: BTreeSet<AccountIdOf<T>>,
-			validator_gains: BalanceOf<T>,
-		) {
-			let num_of_validator_benefactors: u32 = validator_benefactors.len().try_into().unwrap();
-
-			let gains_per_validator =
-				validator_gains / BalanceOf::<T>::from(num_of_validator_benefactors);
-
-			for validator in validator_benefactors.iter() {
-				let validator_gains =
-					Self::calculate_gains_penalties(validator.clone(), gains_per_validator);
+		// Check if project owner debts belong to project owners and if pending redactions
+		// belong to redacted carbon credit batches
+		#[cfg(any(feature = "try-runtime", test))]
+		fn check_debts() -> Result<(), DispatchError> {
+			for (project_owner, debts) in ProjectOwnerDebts::<T>::iter() {
+				ensure!(
+					ProjectOwners::<T>::contains_key(project_owner),
+					"Debts exist for an account that isn't a project owner"
+				);
+				ensure!(!debts.is_empty(), "Project owner debts are empty");
+			}
 
-				// Transfer funds
-				T::Currency::transfer(
-					&buyer,
-					&validator,
-					validator_gains,
-					ExistenceRequirement::KeepAlive,
-				)
-				.unwrap();
+			for (batch_hash, _) in PendingRedactions::<T>::iter() {
+				ensure!(
+					CarbonCreditBatches::<T>::get(batch_hash)
+						.map_or(false, |batch| batch.status == CarbonCreditBatchStatus::Redacted),
+					"Pending redaction for a carbon credit batch that isn't redacted"
+				);
+			}
 
-				let remainder = gains_per_validator - validator_gains;
+			Ok(())
+		}
+	}
+}
 
-				if remainder != BalanceOf::<T>::from(0u32) {
-					continue;
-				}
+// Admin origin that accepts signed origins from the authority accounts
+// Note: Returns the authority account ID on success
+pub struct EnsureAuthority<T>(sp_std::marker::PhantomData<T>);
 
-				// Transfer funds
-				T::Currency::transfer(
-					&buyer,
-					&Self::pallet_id(),
-					remainder,
-					ExistenceRequirement::KeepAlive,
-				)
-				.unwrap();
-			}
-		}
+impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for EnsureAuthority<T> {
+	type Success = AccountIdOf<T>;
 
-		// Process project owner gains from benefactor split during a carbon credit sale
-		pub fn process_owner_gains(
-			buyer: AccountIdOf<T>,
-			project_owner: AccountIdOf<T>,
-			owner_gains: BalanceOf<T>,
-		) {
-			let real_owner_gains =
-				Self::calculate_gains_penalties(project_owner.clone(), owner_gains);
+	fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if AuthorityAccounts::<T>::contains_key(&who) => {
+				Ok(who)
+			},
+			r => Err(T::RuntimeOrigin::from(r)),
+		})
+	}
 
-			// Transfer funds
-			T::Currency::transfer(
-				&buyer,
-				&project_owner,
-				real_owner_gains,
-				ExistenceRequirement::KeepAlive,
-			)
-			.unwrap();
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+		let authority: AccountIdOf<T> = frame_benchmarking::account("authority", 0, 0);
 
-			let remainder = owner_gains - real_owner_gains;
+		AuthorityAccounts::<T>::insert(authority.clone(), AuthorityAccountInfo::default());
 
-			if remainder != BalanceOf::<T>::from(0u32) {
-				// Transfer funds
-				T::Currency::transfer(
-					&buyer,
-					&Self::pallet_id(),
-					remainder,
-					ExistenceRequirement::KeepAlive,
-				)
-				.unwrap();
-			}
-		}
+		Ok(frame_system::RawOrigin::Signed(authority).into())
 	}
 }
@@ -0,0 +1,1226 @@
+// Storage migrations
+// Note: Backfills run once as part of a versioned migration, only versioned migrations should be
+// added to the runtime
+use super::*;
+use frame_support::{
+	traits::{OnRuntimeUpgrade, Time},
+	weights::Weight,
+};
+use log::{info, warn};
+use sp_std::marker::PhantomData;
+
+// Backfill the used documentation index from existing storage
+// Note: Documentation that is already in the index is left unchanged (documentation that is
+// already used by another owner is dropped and logged)
+// Note: Runs once as part of MigrateV0ToV1
+pub struct BackfillUsedDocumentation<T>(PhantomData<T>);
+
+impl<T: Config> BackfillUsedDocumentation<T> {
+	// Call the closure for every documentation IPFS link in storage (with its owner)
+	// Note: Returns the number of storage items read
+	fn for_each_documentation(
+		mut f: impl FnMut(BoundedString<T::IPFSLength>, DocumentationOwner<AccountIdOf<T>>),
+	) -> u64 {
+		let mut item_reads: u64 = 0;
+
+		// Validators and project owners
+		for (account_id, validator) in Validators::<T>::iter() {
+			item_reads += 1;
+
+			f(validator.documentation_ipfs, DocumentationOwner::Validator(account_id));
+		}
+
+		for (account_id, project_owner) in ProjectOwners::<T>::iter() {
+			item_reads += 1;
+
+			f(project_owner.documentation_ipfs, DocumentationOwner::ProjectOwner(account_id));
+		}
+
+		// Carbon footprint reports and accounts
+		for (ipfs, report) in CarbonFootprintReports::<T>::iter() {
+			item_reads += 1;
+
+			f(ipfs, DocumentationOwner::CarbonFootprintReport(report.cf_account));
+		}
+
+		for (account_id, cfa_info) in CarbonFootprintAccounts::<T>::iter() {
+			item_reads += 1;
+
+			for ipfs in cfa_info.documentation_ipfses.into_iter() {
+				f(ipfs, DocumentationOwner::CarbonFootprintReport(account_id.clone()));
+			}
+		}
+
+		// Project and carbon credit batch proposals
+		for (ipfs, proposal) in ProjectProposals::<T>::iter() {
+			item_reads += 1;
+
+			f(ipfs, DocumentationOwner::ProjectProposal(proposal.project_hash));
+		}
+
+		for (ipfs, proposal) in CarbonCreditBatchProposals::<T>::iter() {
+			item_reads += 1;
+
+			f(ipfs, DocumentationOwner::CarbonCreditBatchProposal(proposal.batch_hash));
+		}
+
+		// Complaints
+		for (ipfs, complaint) in ComplaintsForAccounts::<T>::iter() {
+			item_reads += 1;
+
+			f(ipfs, DocumentationOwner::AccountComplaint(complaint.complaint_for));
+		}
+
+		for (ipfs, complaint) in ComplaintsForHashes::<T>::iter() {
+			item_reads += 1;
+
+			f(ipfs, DocumentationOwner::HashComplaint(complaint.complaint_for));
+		}
+
+		item_reads
+	}
+
+	// Count the documentation in storage that isn't in the index under its owner
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn count_missing_entries() -> u32 {
+		let mut missing: u32 = 0;
+
+		Self::for_each_documentation(|ipfs, owner| {
+			if UsedDocumentation::<T>::get(ipfs) != Some(owner) {
+				missing += 1;
+			}
+		});
+
+		missing
+	}
+}
+
+impl<T: Config> OnRuntimeUpgrade for BackfillUsedDocumentation<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let mut dropped: u32 = 0;
+
+		let item_reads = Self::for_each_documentation(|ipfs, owner| {
+			reads += 1;
+
+			match UsedDocumentation::<T>::get(ipfs.clone()) {
+				None => {
+					UsedDocumentation::<T>::insert(ipfs, owner);
+					writes += 1;
+				},
+				Some(used_by) if used_by != owner => dropped += 1,
+				Some(_) => (),
+			}
+		});
+
+		info!("👷 Migration: Backfilled {} used documentation entries", writes);
+
+		if dropped > 0 {
+			warn!("👷 Migration: Dropped {} documentation entries used by another owner", dropped);
+		}
+
+		T::DbWeight::get().reads_writes(reads.saturating_add(item_reads), writes)
+	}
+}
+
+// Backfill the project owner and project indexes from existing storage
+// Note: Entries that don't fit within the index bounds are dropped and logged
+// Note: Runs once as part of MigrateV0ToV1
+pub struct BackfillProjectAndBatchIndexes<T>(PhantomData<T>);
+
+impl<T: Config> BackfillProjectAndBatchIndexes<T> {
+	// Count the projects and carbon credit batches that aren't in the indexes
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn count_missing_entries() -> u32 {
+		let missing_projects = Projects::<T>::iter().filter(|(project_hash, project_info)| {
+			!ProjectsByOwner::<T>::get(project_info.project_owner.clone())
+				.map_or(false, |projects| projects.contains(project_hash))
+		});
+
+		let missing_batches =
+			CarbonCreditBatches::<T>::iter().filter(|(batch_hash, batch_info)| {
+				!BatchesByProject::<T>::get(batch_info.project_hash)
+					.map_or(false, |batches| batches.contains(batch_hash))
+			});
+
+		(missing_projects.count() + missing_batches.count()) as u32
+	}
+}
+
+impl<T: Config> OnRuntimeUpgrade for BackfillProjectAndBatchIndexes<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let mut dropped: u32 = 0;
+
+		// Projects by project owner
+		for (project_hash, project_info) in Projects::<T>::iter() {
+			ProjectsByOwner::<T>::mutate(project_info.project_owner, |projects| {
+				if projects
+					.get_or_insert_with(BoundedBTreeSet::new)
+					.try_insert(project_hash)
+					.is_err()
+				{
+					dropped += 1;
+				}
+			});
+
+			reads += 2;
+			writes += 1;
+		}
+
+		// Carbon credit batches by project
+		for (batch_hash, batch_info) in CarbonCreditBatches::<T>::iter() {
+			BatchesByProject::<T>::mutate(batch_info.project_hash, |batches| {
+				if batches.get_or_insert_with(BoundedBTreeSet::new).try_insert(batch_hash).is_err()
+				{
+					dropped += 1;
+				}
+			});
+
+			reads += 2;
+			writes += 1;
+		}
+
+		info!("👷 Migration: Backfilled project and carbon credit batch indexes");
+
+		if dropped > 0 {
+			warn!(
+				"👷 Migration: Dropped {} project and carbon credit batch index entries",
+				dropped
+			);
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+// Backfill the carbon credit batch retirements index from existing storage
+// Note: Entries that don't fit within the index bounds are dropped and logged
+// Note: Runs once as part of MigrateV0ToV1
+pub struct BackfillRetirementsByBatch<T>(PhantomData<T>);
+
+impl<T: Config> BackfillRetirementsByBatch<T> {
+	// Count the carbon credit retirements that aren't in the index
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn count_missing_entries() -> u32 {
+		CarbonCreditRetirements::<T>::iter()
+			.filter(|(retirement_hash, retirement_info)| {
+				!RetirementsByBatch::<T>::get(retirement_info.batch_hash)
+					.map_or(false, |retirements| retirements.contains(retirement_hash))
+			})
+			.count() as u32
+	}
+}
+
+impl<T: Config> OnRuntimeUpgrade for BackfillRetirementsByBatch<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+		let mut dropped: u32 = 0;
+
+		for (retirement_hash, retirement_info) in CarbonCreditRetirements::<T>::iter() {
+			RetirementsByBatch::<T>::mutate(retirement_info.batch_hash, |retirements| {
+				if retirements
+					.get_or_insert_with(BoundedBTreeSet::new)
+					.try_insert(retirement_hash)
+					.is_err()
+				{
+					dropped += 1;
+				}
+			});
+
+			reads += 2;
+			writes += 1;
+		}
+
+		info!("👷 Migration: Backfilled carbon credit retirements index");
+
+		if dropped > 0 {
+			warn!("👷 Migration: Dropped {} carbon credit retirements index entries", dropped);
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+// Backfill the pruning timeouts for finished records from existing storage
+// Note: Finished records are scheduled for pruning once the retention period (starting from the
+// time of the migration) is over
+// Note: Records that are already scheduled for pruning are skipped
+// Note: Runs once as part of MigrateV4ToV5 (sale orders are only decoded from storage version 4)
+pub struct BackfillPruningTimeouts<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for BackfillPruningTimeouts<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 0;
+		let mut writes: u64 = 0;
+
+		// Records that are already scheduled for pruning
+		let mut scheduled = BTreeSet::new();
+
+		for events in PruningTimeouts::<T>::iter_values() {
+			scheduled.extend(events);
+			reads += 1;
+		}
+
+		let mut schedule = |is_finished: bool, record: FinishedRecordOf<T>| {
+			reads += 1;
+
+			if is_finished && !scheduled.contains(&record) {
+				Pallet::<T>::insert_pruning_timeout(record);
+				reads += 1;
+				writes += 1;
+			}
+		};
+
+		// Carbon footprint reports
+		for (ipfs, report) in CarbonFootprintReports::<T>::iter() {
+			schedule(!report.voting_active, FinishedRecord::CarbonFootprintReport(ipfs));
+		}
+
+		// Project and carbon credit batch proposals
+		for (ipfs, proposal) in ProjectProposals::<T>::iter() {
+			schedule(!proposal.voting_active, FinishedRecord::ProjectProposal(ipfs));
+		}
+
+		for (ipfs, proposal) in CarbonCreditBatchProposals::<T>::iter() {
+			schedule(!proposal.voting_active, FinishedRecord::CarbonCreditBatchProposal(ipfs));
+		}
+
+		// Carbon credit sale orders
+		for (sale_hash, sale_order) in CarbonCreditSaleOrders::<T>::iter() {
+			schedule(
+				sale_order.status != SaleOrderStatus::Open,
+				FinishedRecord::CarbonCreditSaleOrder(sale_hash),
+			);
+		}
+
+		// Complaints
+		for (ipfs, complaint) in ComplaintsForAccounts::<T>::iter() {
+			schedule(!complaint.complaint_active, FinishedRecord::AccountComplaint(ipfs));
+		}
+
+		for (ipfs, complaint) in ComplaintsForHashes::<T>::iter() {
+			schedule(!complaint.complaint_active, FinishedRecord::HashComplaint(ipfs));
+		}
+
+		info!("👷 Migration: Backfilled {} pruning timeout events", writes);
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+// Seed the timeouts and pruning cursors
+// Note: Cursors are moved to the current block, or to the earliest block with pending timeout
+// events (if it's before the current block), so existing timeout events aren't skipped and
+// timeout processing doesn't start from the genesis block
+// Note: Cursors are never moved backwards
+pub struct SeedTimeoutCursors<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for SeedTimeoutCursors<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads: u64 = 3;
+
+		let now = frame_system::Pallet::<T>::block_number();
+
+		// Timeouts cursor
+		let mut timeouts_block = now;
+		let mut seed = |block: BlockNumber<T>| {
+			reads += 1;
+			timeouts_block = timeouts_block.min(block);
+		};
+
+		ComplaintTimeouts::<T>::iter_keys().for_each(&mut seed);
+		VotingTimeouts::<T>::iter_keys().for_each(&mut seed);
+		SaleOrderTimeouts::<T>::iter_keys().for_each(&mut seed);
+		BuyOrderTimeouts::<T>::iter_keys().for_each(&mut seed);
+		AuctionTimeouts::<T>::iter_keys().for_each(&mut seed);
+		PenaltyTimeoutsAccounts::<T>::iter_keys().for_each(&mut seed);
+		PenaltyTimeoutsHashes::<T>::iter_keys().for_each(&mut seed);
+
+		TimeoutsCursor::<T>::mutate(|cursor| *cursor = (*cursor).max(timeouts_block));
+
+		// Pruning cursor
+		let mut pruning_block = now;
+
+		for block in PruningTimeouts::<T>::iter_keys() {
+			reads += 1;
+			pruning_block = pruning_block.min(block);
+		}
+
+		PruningCursor::<T>::mutate(|cursor| *cursor = (*cursor).max(pruning_block));
+
+		info!("👷 Migration: Seeded timeouts and pruning cursors");
+
+		T::DbWeight::get().reads_writes(reads, 2)
+	}
+}
+
+// Storage version 0
+pub(crate) mod v0 {
+	use super::*;
+	use frame_support::pallet_prelude::ValueQuery;
+
+	// Trader and authority account sets (before they were moved into account maps)
+	#[frame_support::storage_alias]
+	pub type TraderAccounts<T: Config> =
+		StorageValue<Pallet<T>, BTreeSet<AccountIdOf<T>>, ValueQuery>;
+
+	#[frame_support::storage_alias]
+	pub type AuthorityAccounts<T: Config> =
+		StorageValue<Pallet<T>, BTreeSet<AccountIdOf<T>>, ValueQuery>;
+}
+
+// Storage version 1
+pub mod v1 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::{Identity, OptionQuery},
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	// Carbon credit sale order info structure (before partial fills were added)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct CarbonCreditSaleOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
+		pub batch_hash: H256,
+		pub credit_amount: BalanceOf,
+		pub credit_price: BalanceOf,
+		pub seller: AccountIdOf,
+		pub buyer: AccountIdOf,
+		pub sale_active: bool,
+		pub sale_timeout: BlockNumber,
+	}
+
+	pub type CarbonCreditSaleOrderInfoOf<T> =
+		CarbonCreditSaleOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>;
+
+	#[frame_support::storage_alias]
+	pub type CarbonCreditSaleOrders<T: Config> =
+		StorageMap<Pallet<T>, Identity, H256, CarbonCreditSaleOrderInfoOf<T>, OptionQuery>;
+
+	// Move trader and authority accounts from the account sets into the account maps
+	// Note: Accounts are recorded as registered (or added) at the time of the migration
+	// Note: Also seeds the timeouts and pruning cursors (see SeedTimeoutCursors) and backfills
+	// the used documentation, project owner, project and carbon credit batch retirements indexes
+	// (see BackfillUsedDocumentation, BackfillProjectAndBatchIndexes and
+	// BackfillRetirementsByBatch)
+	// Note: Use MigrateV0ToV1, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 3;
+			let mut writes: u64 = 0;
+
+			let now = T::Time::now();
+
+			// Trader accounts
+			if v0::TraderAccounts::<T>::exists() {
+				for account_id in v0::TraderAccounts::<T>::take() {
+					TraderAccounts::<T>::insert(
+						account_id,
+						TraderAccountInfo { registration_date: now },
+					);
+
+					writes += 1;
+				}
+
+				reads += 1;
+				writes += 1;
+			}
+
+			// Authority accounts
+			if v0::AuthorityAccounts::<T>::exists() {
+				for account_id in v0::AuthorityAccounts::<T>::take() {
+					AuthorityAccounts::<T>::insert(
+						account_id,
+						AuthorityAccountInfo { added_date: now },
+					);
+
+					writes += 1;
+				}
+
+				reads += 1;
+				writes += 1;
+			}
+
+			info!("👷 Migration: Moved trader and authority accounts into account maps");
+
+			T::DbWeight::get()
+				.reads_writes(reads, writes)
+				.saturating_add(SeedTimeoutCursors::<T>::on_runtime_upgrade())
+				.saturating_add(BackfillUsedDocumentation::<T>::on_runtime_upgrade())
+				.saturating_add(BackfillProjectAndBatchIndexes::<T>::on_runtime_upgrade())
+				.saturating_add(BackfillRetirementsByBatch::<T>::on_runtime_upgrade())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let trader_count = v0::TraderAccounts::<T>::get().len() as u32;
+			let authority_count = v0::AuthorityAccounts::<T>::get().len() as u32;
+
+			Ok((trader_count, authority_count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (trader_count, authority_count): (u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if the account sets were removed
+			frame_support::ensure!(
+				!v0::TraderAccounts::<T>::exists(),
+				"Trader account set wasn't removed"
+			);
+			frame_support::ensure!(
+				!v0::AuthorityAccounts::<T>::exists(),
+				"Authority account set wasn't removed"
+			);
+
+			// Check if all accounts were moved into the account maps
+			frame_support::ensure!(
+				TraderAccounts::<T>::iter_keys().count() as u32 == trader_count,
+				"Trader account count doesn't match"
+			);
+			frame_support::ensure!(
+				AuthorityAccounts::<T>::iter_keys().count() as u32 == authority_count,
+				"Authority account count doesn't match"
+			);
+
+			// Check if the backfills didn't drop any entries
+			frame_support::ensure!(
+				BackfillUsedDocumentation::<T>::count_missing_entries() == 0,
+				"Used documentation backfill dropped entries"
+			);
+			frame_support::ensure!(
+				BackfillProjectAndBatchIndexes::<T>::count_missing_entries() == 0,
+				"Project and carbon credit batch index backfill dropped entries"
+			);
+			frame_support::ensure!(
+				BackfillRetirementsByBatch::<T>::count_missing_entries() == 0,
+				"Carbon credit retirements index backfill dropped entries"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 0 to 1
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 0
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Storage version 2
+pub mod v2 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::{Identity, OptionQuery},
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	// Carbon credit sale order info structure (before the sale order status was added)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct CarbonCreditSaleOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
+		pub batch_hash: H256,
+		pub credit_amount: BalanceOf,
+		pub min_fill_amount: BalanceOf,
+		pub credit_price: BalanceOf,
+		pub seller: AccountIdOf,
+		pub buyer: AccountIdOf,
+		pub sale_active: bool,
+		pub sale_timeout: BlockNumber,
+	}
+
+	pub type CarbonCreditSaleOrderInfoOf<T> =
+		CarbonCreditSaleOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>;
+
+	#[frame_support::storage_alias]
+	pub type CarbonCreditSaleOrders<T: Config> =
+		StorageMap<Pallet<T>, Identity, H256, CarbonCreditSaleOrderInfoOf<T>, OptionQuery>;
+
+	// Add the minimum fill amount to the carbon credit sale orders
+	// Note: Existing sale orders can only be filled as a whole (same as before partial fills)
+	// Note: Use MigrateV1ToV2, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			v2::CarbonCreditSaleOrders::<T>::translate::<v1::CarbonCreditSaleOrderInfoOf<T>, _>(
+				|_, sale_order| {
+					translated += 1;
+
+					Some(CarbonCreditSaleOrderInfo {
+						batch_hash: sale_order.batch_hash,
+						credit_amount: sale_order.credit_amount,
+						min_fill_amount: sale_order.credit_amount,
+						credit_price: sale_order.credit_price,
+						seller: sale_order.seller,
+						buyer: sale_order.buyer,
+						sale_active: sale_order.sale_active,
+						sale_timeout: sale_order.sale_timeout,
+					})
+				},
+			);
+
+			info!("👷 Migration: Added minimum fill amounts to {} sale orders", translated);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let sale_order_count = v1::CarbonCreditSaleOrders::<T>::iter_keys().count() as u32;
+
+			Ok(sale_order_count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let sale_order_count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if all sale orders were translated
+			frame_support::ensure!(
+				v2::CarbonCreditSaleOrders::<T>::iter_values().count() as u32 == sale_order_count,
+				"Sale order count doesn't match"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 1 to 2
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 1
+	pub type MigrateV1ToV2<T> = VersionedMigration<
+		1,
+		2,
+		UncheckedMigrateV1ToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Storage version 3
+pub mod v3 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::{Identity, OptionQuery},
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	// Carbon credit sale order info structure (before good-til-cancelled sale orders were added)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct CarbonCreditSaleOrderInfo<BalanceOf, AccountIdOf, BlockNumber> {
+		pub batch_hash: H256,
+		pub credit_amount: BalanceOf,
+		pub min_fill_amount: BalanceOf,
+		pub credit_price: BalanceOf,
+		pub seller: AccountIdOf,
+		pub buyer: Option<AccountIdOf>,
+		pub status: SaleOrderStatus,
+		pub sale_timeout: BlockNumber,
+	}
+
+	pub type CarbonCreditSaleOrderInfoOf<T> =
+		CarbonCreditSaleOrderInfo<BalanceOf<T>, AccountIdOf<T>, BlockNumber<T>>;
+
+	#[frame_support::storage_alias]
+	pub type CarbonCreditSaleOrders<T: Config> =
+		StorageMap<Pallet<T>, Identity, H256, CarbonCreditSaleOrderInfoOf<T>, OptionQuery>;
+
+	// Replace the sale order buyer convention (buyer is the seller until the sale order is filled)
+	// and the sale order active flag with an optional buyer and the sale order status
+	// Note: Closed sale orders without a buyer are recorded as expired if their timeout has passed
+	// and as cancelled otherwise (existing sale orders are public)
+	// Note: Use MigrateV2ToV3, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV2ToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			v3::CarbonCreditSaleOrders::<T>::translate::<v2::CarbonCreditSaleOrderInfoOf<T>, _>(
+				|_, sale_order| {
+					translated += 1;
+
+					let is_filled = sale_order.buyer != sale_order.seller;
+
+					let status = if sale_order.sale_active {
+						SaleOrderStatus::Open
+					} else if is_filled {
+						SaleOrderStatus::Filled
+					} else if sale_order.sale_timeout <= current_block {
+						SaleOrderStatus::Expired
+					} else {
+						SaleOrderStatus::Cancelled
+					};
+
+					Some(CarbonCreditSaleOrderInfo {
+						batch_hash: sale_order.batch_hash,
+						credit_amount: sale_order.credit_amount,
+						min_fill_amount: sale_order.min_fill_amount,
+						credit_price: sale_order.credit_price,
+						seller: sale_order.seller,
+						buyer: if is_filled { Some(sale_order.buyer) } else { None },
+						status,
+						sale_timeout: sale_order.sale_timeout,
+					})
+				},
+			);
+
+			info!("👷 Migration: Added the sale order status to {} sale orders", translated);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let active_count = v2::CarbonCreditSaleOrders::<T>::iter_values()
+				.filter(|sale_order| sale_order.sale_active)
+				.count() as u32;
+			let sale_order_count = v2::CarbonCreditSaleOrders::<T>::iter_keys().count() as u32;
+
+			Ok((sale_order_count, active_count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (sale_order_count, active_count): (u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if all sale orders were translated
+			frame_support::ensure!(
+				v3::CarbonCreditSaleOrders::<T>::iter_values().count() as u32 == sale_order_count,
+				"Sale order count doesn't match"
+			);
+
+			// Check if the active sale orders are still open
+			frame_support::ensure!(
+				v3::CarbonCreditSaleOrders::<T>::iter_values()
+					.filter(|sale_order| sale_order.status == SaleOrderStatus::Open)
+					.count() as u32 == active_count,
+				"Open sale order count doesn't match"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 2 to 3
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 2
+	pub type MigrateV2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		UncheckedMigrateV2ToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Storage version 4
+pub mod v4 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::{Identity, OptionQuery},
+		BoundedVec,
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	// Carbon credit sale order fill info structure (before trading fees were added)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct SaleOrderFillInfo<AccountIdOf, BalanceOf, MomentOf> {
+		pub buyer: AccountIdOf,
+		pub credit_amount: BalanceOf,
+		pub credit_price: BalanceOf,
+		pub fill_date: MomentOf,
+	}
+
+	pub type SaleOrderFillsOf<T> = BoundedVec<
+		SaleOrderFillInfo<AccountIdOf<T>, BalanceOf<T>, MomentOf<T>>,
+		<T as Config>::MaxFillsPerSaleOrder,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type SaleOrderFills<T: Config> =
+		StorageMap<Pallet<T>, Identity, H256, SaleOrderFillsOf<T>, OptionQuery>;
+
+	// Make the sale order timeout optional (good-til-cancelled sale orders don't have a timeout)
+	// Note: Existing sale orders keep their timeout
+	// Note: Use MigrateV3ToV4, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV3ToV4<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV3ToV4<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			CarbonCreditSaleOrders::<T>::translate::<v3::CarbonCreditSaleOrderInfoOf<T>, _>(
+				|_, sale_order| {
+					translated += 1;
+
+					Some(CarbonCreditSaleOrderInfo {
+						batch_hash: sale_order.batch_hash,
+						credit_amount: sale_order.credit_amount,
+						min_fill_amount: sale_order.min_fill_amount,
+						credit_price: sale_order.credit_price,
+						seller: sale_order.seller,
+						buyer: sale_order.buyer,
+						status: sale_order.status,
+						sale_timeout: Some(sale_order.sale_timeout),
+					})
+				},
+			);
+
+			info!(
+				"👷 Migration: Made the sale order timeout optional for {} sale orders",
+				translated
+			);
+
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let sale_order_count = v3::CarbonCreditSaleOrders::<T>::iter_keys().count() as u32;
+
+			Ok(sale_order_count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let sale_order_count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if all sale orders were translated (and kept their timeout)
+			frame_support::ensure!(
+				CarbonCreditSaleOrders::<T>::iter_values()
+					.filter(|sale_order| sale_order.sale_timeout.is_some())
+					.count() as u32 == sale_order_count,
+				"Sale order count doesn't match"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 3 to 4
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 3
+	pub type MigrateV3ToV4<T> = VersionedMigration<
+		3,
+		4,
+		UncheckedMigrateV3ToV4<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Storage version 5
+pub mod v5 {
+	use super::*;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::{
+		migrations::VersionedMigration,
+		pallet_prelude::{Identity, OptionQuery},
+	};
+	use sp_std::vec::Vec;
+
+	// Vote pass ratio structure (before the vote pass ratio was stored as a Perbill)
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, scale_info::TypeInfo, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct ProportionStructure {
+		pub proportion_part: u16,
+		pub upper_limit_part: u16,
+	}
+
+	// Beneficiary split proposal info structure (before the shares were stored as a Permill)
+	#[derive(Encode, Decode)]
+	pub struct BeneficiarySplitProposalInfo<
+		MomentOf,
+		BalanceOf,
+		AccountIdOf: Ord,
+		MaxVotes: Get<u32>,
+		MaxStakeholders: Get<u32>,
+	> {
+		pub project_hash: H256,
+		pub creation_date: MomentOf,
+		pub beneficiary_splits: BoundedBTreeMap<BeneficiarySplitType, BalanceOf, ConstU32<3>>,
+		pub stakeholders: BoundedBTreeMap<AccountIdOf, BalanceOf, MaxStakeholders>,
+		pub votes_for: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+		pub votes_against: BoundedBTreeSet<AccountIdOf, MaxVotes>,
+		pub voting_active: bool,
+	}
+
+	// Project beneficiary splits info structure (before the shares were stored as a Permill)
+	#[derive(Encode, Decode)]
+	pub struct ProjectBeneficiarySplitsInfo<BalanceOf, AccountIdOf: Ord, MaxStakeholders: Get<u32>> {
+		pub beneficiary_splits: BoundedBTreeMap<BeneficiarySplitType, BalanceOf, ConstU32<3>>,
+		pub stakeholders: BoundedBTreeMap<AccountIdOf, BalanceOf, MaxStakeholders>,
+	}
+
+	// Trading fee values structure (before the fees were stored as a Permill)
+	#[derive(Encode, Decode)]
+	pub struct TradingFeeValues<BalanceOf> {
+		pub maker_fee: BalanceOf,
+		pub taker_fee: BalanceOf,
+	}
+
+	// Penalty levels, beneficiary splits, stakeholder shares and trading fees were given in basis
+	// points
+	// Note: A penalty level value was the amount (in basis points) that had to be paid for every
+	// 10000 basis points that were due (e.g. 16700 for a 6700 / 16700 penalty)
+	pub type PenaltyLevelsOf<T> = BoundedBTreeMap<u8, BalanceOf<T>, ConstU32<5>>;
+	pub type BeneficiarySplitsOf<T> =
+		BoundedBTreeMap<BeneficiarySplitType, BalanceOf<T>, ConstU32<3>>;
+	pub type StakeholderSharesOf<T> =
+		BoundedBTreeMap<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxStakeholdersPerProject>;
+	pub type ProjectBeneficiarySplitsOf<T> = ProjectBeneficiarySplitsInfo<
+		BalanceOf<T>,
+		AccountIdOf<T>,
+		<T as Config>::MaxStakeholdersPerProject,
+	>;
+	pub type BeneficiarySplitProposalInfoOf<T> = BeneficiarySplitProposalInfo<
+		MomentOf<T>,
+		BalanceOf<T>,
+		AccountIdOf<T>,
+		<T as Config>::MaxValidatorsPerVote,
+		<T as Config>::MaxStakeholdersPerProject,
+	>;
+
+	#[frame_support::storage_alias]
+	pub type VotePassRatio<T: Config> = StorageValue<Pallet<T>, ProportionStructure, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type PenaltyLevels<T: Config> = StorageValue<Pallet<T>, PenaltyLevelsOf<T>, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type BeneficiarySplits<T: Config> =
+		StorageValue<Pallet<T>, BeneficiarySplitsOf<T>, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type ProjectBeneficiarySplits<T: Config> =
+		StorageMap<Pallet<T>, Identity, H256, ProjectBeneficiarySplitsOf<T>, OptionQuery>;
+
+	#[frame_support::storage_alias]
+	pub type TradingFees<T: Config> =
+		StorageValue<Pallet<T>, TradingFeeValues<BalanceOf<T>>, OptionQuery>;
+
+	// Add the maker and taker trading fees to the sale order fills
+	// Note: Existing fills didn't pay any trading fees
+	// Note: Also schedules the finished records for pruning (see BackfillPruningTimeouts)
+	// Note: Use MigrateV4ToV5, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV4ToV5<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV4ToV5<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			SaleOrderFills::<T>::translate::<v4::SaleOrderFillsOf<T>, _>(|_, fills| {
+				translated += 1;
+
+				let fills = fills
+					.into_iter()
+					.map(|fill| SaleOrderFillInfo {
+						buyer: fill.buyer,
+						credit_amount: fill.credit_amount,
+						credit_price: fill.credit_price,
+						fill_date: fill.fill_date,
+						maker_fee: BalanceOf::<T>::from(0u32),
+						taker_fee: BalanceOf::<T>::from(0u32),
+					})
+					.collect::<Vec<_>>();
+
+				// Note: The fill count doesn't change, so the bound still holds
+				SaleOrderFillsOf::<T>::try_from(fills).ok()
+			});
+
+			info!("👷 Migration: Added trading fees to the fills of {} sale orders", translated);
+
+			T::DbWeight::get()
+				.reads_writes(translated, translated)
+				.saturating_add(BackfillPruningTimeouts::<T>::on_runtime_upgrade())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let fill_count = v4::SaleOrderFills::<T>::iter_values()
+				.map(|fills| fills.len() as u32)
+				.sum::<u32>();
+
+			Ok(fill_count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let fill_count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if all fills were translated (without trading fees)
+			frame_support::ensure!(
+				SaleOrderFills::<T>::iter_values()
+					.flat_map(|fills| fills.into_iter())
+					.filter(|fill| {
+						fill.maker_fee == BalanceOf::<T>::from(0u32)
+							&& fill.taker_fee == BalanceOf::<T>::from(0u32)
+					})
+					.count() as u32 == fill_count,
+				"Sale order fill count doesn't match"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 4 to 5
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 4
+	pub type MigrateV4ToV5<T> = VersionedMigration<
+		4,
+		5,
+		UncheckedMigrateV4ToV5<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+// Storage version 6
+pub mod v6 {
+	use super::*;
+	use frame_support::migrations::VersionedMigration;
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+	use frame_support::sp_runtime::{PerThing, Rounding};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	// Convert a basis point value to a Permill
+	fn basis_points_to_permill<T: Config>(value: BalanceOf<T>) -> Permill {
+		Permill::from_rational(value, BalanceOf::<T>::from(10000u32))
+	}
+
+	// Convert beneficiary splits given in basis points
+	fn translate_beneficiary_splits<T: Config>(
+		beneficiary_splits: v5::BeneficiarySplitsOf<T>,
+	) -> Option<BeneficiarySplitsOf<T>> {
+		let beneficiary_splits = beneficiary_splits
+			.into_iter()
+			.map(|(split_type, value)| (split_type, basis_points_to_permill::<T>(value)))
+			.collect::<BTreeMap<_, _>>();
+
+		// Note: The split count doesn't change, so the bound still holds
+		BeneficiarySplitsOf::<T>::try_from(beneficiary_splits).ok()
+	}
+
+	// Convert stakeholder shares given in basis points
+	fn translate_stakeholder_shares<T: Config>(
+		stakeholders: v5::StakeholderSharesOf<T>,
+	) -> Option<StakeholderSharesOf<T>> {
+		let stakeholders = stakeholders
+			.into_iter()
+			.map(|(account_id, share)| (account_id, basis_points_to_permill::<T>(share)))
+			.collect::<BTreeMap<_, _>>();
+
+		// Note: The stakeholder count doesn't change, so the bound still holds
+		StakeholderSharesOf::<T>::try_from(stakeholders).ok()
+	}
+
+	// Store the vote pass ratio as a Perbill, the penalty levels as a Perbill penalty and the
+	// beneficiary splits, stakeholder shares and trading fees as a Permill
+	// Note: A vote pass ratio with a zero upper limit part (a simple majority) is stored as zero
+	// Note: The vote pass ratio is rounded up, so that the votes needed (the ratio of all votes
+	// rounded down) stay the same as in the old format
+	// Note: A vote pass ratio with a zero proportion part (any vote passes) is stored as the
+	// smallest non-zero ratio, since a zero ratio now means a simple majority (the votes needed are
+	// still zero, so any vote passes)
+	// Note: Use MigrateV5ToV6, which only runs this once and bumps the storage version
+	pub struct UncheckedMigrateV5ToV6<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrateV5ToV6<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+
+			let _ = VotePassRatio::<T>::translate::<v5::ProportionStructure, _>(|ratio| {
+				ratio.map(|ratio| {
+					translated += 1;
+
+					if ratio.upper_limit_part == 0 {
+						Perbill::zero()
+					} else if ratio.proportion_part == 0 {
+						Perbill::from_parts(1)
+					} else {
+						Perbill::from_rational_with_rounding(
+							u32::from(ratio.proportion_part),
+							u32::from(ratio.upper_limit_part),
+							Rounding::Up,
+						)
+						.unwrap_or(Perbill::one())
+					}
+				})
+			});
+
+			let _ = PenaltyLevels::<T>::translate::<v5::PenaltyLevelsOf<T>, _>(|penalty_levels| {
+				let penalty_levels = penalty_levels?
+					.into_iter()
+					.map(|(level, value)| {
+						let penalty = value.saturating_sub(BalanceOf::<T>::from(10000u32));
+
+						(level, Perbill::from_rational(penalty, value))
+					})
+					.collect::<BTreeMap<_, _>>();
+
+				translated += 1;
+
+				PenaltyLevelsOf::<T>::try_from(penalty_levels).ok()
+			});
+
+			let _ = BeneficiarySplits::<T>::translate::<v5::BeneficiarySplitsOf<T>, _>(
+				|beneficiary_splits| {
+					translated += 1;
+
+					translate_beneficiary_splits::<T>(beneficiary_splits?)
+				},
+			);
+
+			ProjectBeneficiarySplits::<T>::translate::<v5::ProjectBeneficiarySplitsOf<T>, _>(
+				|_, project_splits| {
+					translated += 1;
+
+					Some(ProjectBeneficiarySplitsInfo {
+						beneficiary_splits: translate_beneficiary_splits::<T>(
+							project_splits.beneficiary_splits,
+						)?,
+						stakeholders: translate_stakeholder_shares::<T>(
+							project_splits.stakeholders,
+						)?,
+					})
+				},
+			);
+
+			BeneficiarySplitProposals::<T>::translate::<v5::BeneficiarySplitProposalInfoOf<T>, _>(
+				|_, proposal| {
+					translated += 1;
+
+					Some(BeneficiarySplitProposalInfo {
+						project_hash: proposal.project_hash,
+						creation_date: proposal.creation_date,
+						beneficiary_splits: translate_beneficiary_splits::<T>(
+							proposal.beneficiary_splits,
+						)?,
+						stakeholders: translate_stakeholder_shares::<T>(proposal.stakeholders)?,
+						votes_for: proposal.votes_for,
+						votes_against: proposal.votes_against,
+						voting_active: proposal.voting_active,
+					})
+				},
+			);
+
+			let _ = TradingFees::<T>::translate::<v5::TradingFeeValues<BalanceOf<T>>, _>(
+				|trading_fees| {
+					let trading_fees = trading_fees?;
+
+					translated += 1;
+
+					Some(TradingFeeValues {
+						maker_fee: basis_points_to_permill::<T>(trading_fees.maker_fee),
+						taker_fee: basis_points_to_permill::<T>(trading_fees.taker_fee),
+					})
+				},
+			);
+
+			info!(
+				"👷 Migration: Converted {} vote ratio, penalty level, split and trading fee values",
+				translated
+			);
+
+			T::DbWeight::get().reads_writes(translated + 4, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let project_splits_count =
+				v5::ProjectBeneficiarySplits::<T>::iter_values().count() as u32;
+			let proposal_count = BeneficiarySplitProposals::<T>::iter_keys().count() as u32;
+
+			Ok((project_splits_count, proposal_count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (project_splits_count, proposal_count): (u32, u32) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+			// Check if the penalty levels and beneficiary splits are still valid
+			Pallet::<T>::validate_penalty_levels(&PenaltyLevels::<T>::get().into_inner())
+				.map_err(|_| "Penalty levels are invalid")?;
+
+			Pallet::<T>::validate_beneficiary_splits(
+				&BeneficiarySplits::<T>::get().into_inner(),
+				Permill::zero(),
+			)
+			.map_err(|_| "Beneficiary splits are invalid")?;
+
+			// Check if the trading fees are within the maximum trading fee
+			let trading_fees = TradingFees::<T>::get();
+
+			frame_support::ensure!(
+				trading_fees.maker_fee <= T::MaxTradingFee::get()
+					&& trading_fees.taker_fee <= T::MaxTradingFee::get(),
+				"Trading fees are higher than the maximum trading fee"
+			);
+
+			// Check if all project beneficiary splits and proposals were translated
+			frame_support::ensure!(
+				ProjectBeneficiarySplits::<T>::iter_values().count() as u32 == project_splits_count,
+				"Project beneficiary splits count doesn't match"
+			);
+
+			frame_support::ensure!(
+				BeneficiarySplitProposals::<T>::iter_values().count() as u32 == proposal_count,
+				"Beneficiary split proposal count doesn't match"
+			);
+
+			Ok(())
+		}
+	}
+
+	// Migrate from storage version 5 to 6
+	// Note: Skipped (with a warning) if the on-chain storage version isn't 5
+	pub type MigrateV5ToV6<T> = VersionedMigration<
+		5,
+		6,
+		UncheckedMigrateV5ToV6<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
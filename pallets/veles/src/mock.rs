@@ -1,9 +1,11 @@
 use crate as pallet_veles;
+use codec::Decode;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU16, ConstU64, Hooks},
+	traits::{ConstU16, ConstU64, EitherOfDiverse, Hooks},
+	weights::Weight,
 };
-use frame_system::{self, offchain::SendTransactionTypes};
+use frame_system::{self, offchain::SendTransactionTypes, EnsureRoot};
 use sp_runtime::{
 	testing::TestXt,
 	traits::{BlakeTwo256, IdentityLookup},
@@ -14,6 +16,7 @@ pub use crate::*;
 #[allow(unused_imports)]
 pub use common::*;
 use frame_support::PalletId;
+use sp_core::offchain::{testing::TestTransactionPoolExt, TransactionPoolExt};
 pub use sp_core::H256;
 pub use sp_std::collections::btree_set::BTreeSet;
 
@@ -136,15 +139,34 @@ impl pallet_balances::Config for Test {
 	type MaxReserves = ();
 	type ReserveIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type FreezeIdentifier = ();
-	type RuntimeFreezeReason = ();
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 parameter_types! {
 	pub const IPFSLength: u32 = 64;
 	pub const BlockFinalizationTime: u32 = 6;
 	pub const MinimumPeriod: u64 = 5;
+	pub static MaxTimeoutsWeight: Weight = Weight::MAX;
+	pub static DepositMode: bool = false;
+	pub static MaxValidatorsPerVote: u32 = 100;
+	pub const MaxDocumentsPerCfa: u32 = 100;
+	pub const MaxTimeoutsPerBlock: u32 = 100;
+	pub const MaxDebtsPerProjectOwner: u32 = 100;
+	pub const MaxProjectsPerOwner: u32 = 100;
+	pub const MaxBatchesPerProject: u32 = 100;
+	pub const MaxRetirementsPerBatch: u32 = 100;
+	pub const MaxFillsPerSaleOrder: u32 = 3;
+	pub const MaxAllowedBuyersPerSaleOrder: u32 = 3;
+	pub const MaxOrdersPerBook: u32 = 5;
+	pub const MaxStakeholdersPerProject: u32 = 3;
+	pub const AuctionExtensionPeriod: u64 = 5;
+	pub const MaxTradingFee: Permill = Permill::from_percent(10);
+	pub static TradingFeeBeneficiary: Option<AccountId> = None;
+	pub const RetentionPeriod: u64 = 100;
+	pub static OffchainWorkerFallback: bool = false;
+	pub const MaxOffchainWorkerTimeoutBlocks: u32 = 10;
 }
 
 impl pallet_timestamp::Config for Test {
@@ -161,13 +183,74 @@ impl pallet_veles::Config for Test {
 	type Time = Timestamp;
 	type UnsignedPriority = ConstU64<100>;
 	type UnsignedLongevity = ConstU64<100>;
+	type MaxTimeoutsWeight = MaxTimeoutsWeight;
+	type OffchainWorkerFallback = OffchainWorkerFallback;
+	type MaxOffchainWorkerTimeoutBlocks = MaxOffchainWorkerTimeoutBlocks;
+	type DepositMode = DepositMode;
+	type MaxValidatorsPerVote = MaxValidatorsPerVote;
+	type MaxDocumentsPerCfa = MaxDocumentsPerCfa;
+	type MaxTimeoutsPerBlock = MaxTimeoutsPerBlock;
+	type MaxDebtsPerProjectOwner = MaxDebtsPerProjectOwner;
+	type MaxProjectsPerOwner = MaxProjectsPerOwner;
+	type MaxBatchesPerProject = MaxBatchesPerProject;
+	type MaxRetirementsPerBatch = MaxRetirementsPerBatch;
+	type MaxFillsPerSaleOrder = MaxFillsPerSaleOrder;
+	type MaxAllowedBuyersPerSaleOrder = MaxAllowedBuyersPerSaleOrder;
+	type MaxOrdersPerBook = MaxOrdersPerBook;
+	type MaxStakeholdersPerProject = MaxStakeholdersPerProject;
+	type AuctionExtensionPeriod = AuctionExtensionPeriod;
+	type MaxTradingFee = MaxTradingFee;
+	type TradingFeeBeneficiary = TradingFeeBeneficiary;
+	type RetentionPeriod = RetentionPeriod;
 	type BlockFinalizationTime = BlockFinalizationTime;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type WeightInfo = ();
+	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureAuthority<Test>>;
+	type AuthorityManagerOrigin = EnsureRoot<AccountId>;
+}
+
+// Test externalities that check the pallet invariants after every test
+pub struct TestExt(sp_io::TestExternalities);
+
+impl TestExt {
+	pub fn execute_with<R>(&mut self, execute: impl FnOnce() -> R) -> R {
+		self.0.execute_with(|| {
+			let result = execute();
+
+			Veles::do_try_state().expect("Pallet invariants don't hold");
+
+			result
+		})
+	}
+
+	// Register a test transaction pool and return a getter for the submitted transactions
+	// Note: Needed for tests that run the offchain worker (with the offchain worker fallback)
+	pub fn with_transaction_pool(mut self) -> (Self, impl Fn() -> Vec<MockExtrinsic>) {
+		let (pool, pool_state) = TestTransactionPoolExt::new();
+		self.0.register_extension(TransactionPoolExt::new(pool));
+
+		let transactions = move || {
+			pool_state
+				.read()
+				.transactions
+				.iter()
+				.map(|transaction| MockExtrinsic::decode(&mut &transaction[..]).unwrap())
+				.collect()
+		};
+
+		(self, transactions)
+	}
+}
+
+impl From<sp_runtime::Storage> for TestExt {
+	fn from(storage: sp_runtime::Storage) -> Self {
+		Self(storage.into())
+	}
 }
 
 // Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
+pub fn new_test_ext() -> TestExt {
 	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 	let endowed_accounts: Vec<(AccountId, Balance)> =
@@ -1,5 +1,17 @@
 use crate::{mock::*, Error};
-use frame_support::{assert_err, assert_ok};
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{
+		fungible::InspectHold, fungibles, GetStorageVersion, Hooks, OnRuntimeUpgrade,
+		StorageVersion,
+	},
+	weights::Weight,
+};
+use sp_runtime::{
+	traits::ValidateUnsigned,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	BuildStorage, DispatchError, TokenError,
+};
 
 #[test]
 fn update_vote_pass_ratio_unauthorized() {
@@ -7,55 +19,45 @@ fn update_vote_pass_ratio_unauthorized() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
-			Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), 0, 0),
-			Error::<Test>::Unauthorized
+			Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), Perbill::zero()),
+			DispatchError::BadOrigin
 		);
 	});
 }
 
 #[test]
-fn update_vote_pass_ratio_ok_zero_upper_limit_part() {
+fn update_vote_pass_ratio_ok_zero() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update pass ratio
-		assert_ok!(Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), 1, 0));
+		assert_ok!(Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), Perbill::zero()));
 
-		let vote_pass_ratio = VotePassRatio::<Test>::get();
-
-		// Check updated pass ratios
-		assert_eq!(vote_pass_ratio.proportion_part, 0);
-		assert_eq!(vote_pass_ratio.upper_limit_part, 0);
+		// Check updated pass ratio (a simple majority)
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::zero());
 	});
 }
 
 #[test]
-fn update_vote_pass_ratio_ok_portion_part_bigger_than_upper_limit_part() {
+fn update_vote_pass_ratio_ok_unanimous() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update pass ratio
-		assert_ok!(Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), 5, 3));
-
-		let vote_pass_ratio = VotePassRatio::<Test>::get();
+		assert_ok!(Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), Perbill::one()));
 
-		// Check updated pass ratios
-		assert_eq!(vote_pass_ratio.proportion_part, 3);
-		assert_eq!(vote_pass_ratio.upper_limit_part, 3);
+		// Check updated pass ratio (all votes)
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::one());
 	});
 }
 
@@ -66,18 +68,16 @@ fn update_vote_pass_ratio_ok() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update pass ratio
-		assert_ok!(Veles::update_vote_pass_ratio(RuntimeOrigin::signed(alice()), 2, 3));
-
-		let vote_pass_ratio = VotePassRatio::<Test>::get();
+		assert_ok!(Veles::update_vote_pass_ratio(
+			RuntimeOrigin::signed(alice()),
+			Perbill::from_rational(2u32, 3u32)
+		));
 
-		// Check updated pass ratios
-		assert_eq!(vote_pass_ratio.proportion_part, 2);
-		assert_eq!(vote_pass_ratio.upper_limit_part, 3);
+		// Check updated pass ratio
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::from_rational(2u32, 3u32));
 	});
 }
 
@@ -87,17 +87,17 @@ fn update_penalty_levels_unauthorized() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let mut new_penalty_levels = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_penalty_levels.insert(0, 10000);
-		new_penalty_levels.insert(1, 10000);
-		new_penalty_levels.insert(2, 10000);
-		new_penalty_levels.insert(3, 10000);
-		new_penalty_levels.insert(4, 10000);
+		let mut new_penalty_levels = BTreeMap::<u8, Perbill>::new();
+		new_penalty_levels.insert(0, Perbill::zero());
+		new_penalty_levels.insert(1, Perbill::from_percent(20));
+		new_penalty_levels.insert(2, Perbill::from_percent(60));
+		new_penalty_levels.insert(3, Perbill::from_percent(71));
+		new_penalty_levels.insert(4, Perbill::from_percent(72));
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
 			Veles::update_penalty_levels(RuntimeOrigin::signed(alice()), new_penalty_levels),
-			Error::<Test>::Unauthorized
+			DispatchError::BadOrigin
 		);
 	});
 }
@@ -109,16 +109,13 @@ fn update_penalty_levels_not_all_penalty_levels_have_been_submitted() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		let mut new_penalty_levels = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_penalty_levels.insert(0, 10000);
-		new_penalty_levels.insert(1, 10000);
-		new_penalty_levels.insert(2, 10000);
-		new_penalty_levels.insert(3, 10000);
+		let mut new_penalty_levels = BTreeMap::<u8, Perbill>::new();
+		new_penalty_levels.insert(0, Perbill::zero());
+		new_penalty_levels.insert(1, Perbill::from_percent(20));
+		new_penalty_levels.insert(2, Perbill::from_percent(60));
+		new_penalty_levels.insert(3, Perbill::from_percent(71));
 
 		// Check for NotAllPenaltyLevelsHaveBeenSubmitted error
 		assert_err!(
@@ -135,19 +132,27 @@ fn update_penalty_levels_invalid_penalty_level_value() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		AuthorityAccounts::<Test>::set(new_authorities);
+		let mut new_penalty_levels = BTreeMap::<u8, Perbill>::new();
+		new_penalty_levels.insert(0, Perbill::zero());
+		new_penalty_levels.insert(1, Perbill::from_percent(20));
+		new_penalty_levels.insert(2, Perbill::from_percent(60));
+		new_penalty_levels.insert(3, Perbill::from_percent(71));
+		new_penalty_levels.insert(4, Perbill::one());
+
+		// Check for InvalidPenaltyLevelValue error (the penalty leaves nothing to pay)
+		assert_err!(
+			Veles::update_penalty_levels(
+				RuntimeOrigin::signed(alice()),
+				new_penalty_levels.clone()
+			),
+			Error::<Test>::InvalidPenaltyLevelValue
+		);
 
-		let mut new_penalty_levels = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_penalty_levels.insert(0, 9000);
-		new_penalty_levels.insert(1, 12500);
-		new_penalty_levels.insert(2, 25000);
-		new_penalty_levels.insert(3, 35200);
-		new_penalty_levels.insert(4, 35250);
+		new_penalty_levels.insert(4, Perbill::from_percent(71));
 
-		// Check for InvalidPenaltyLevelValue error
+		// Check for InvalidPenaltyLevelValue error (the levels aren't of an increasing order)
 		assert_err!(
 			Veles::update_penalty_levels(RuntimeOrigin::signed(alice()), new_penalty_levels),
 			Error::<Test>::InvalidPenaltyLevelValue
@@ -162,17 +167,14 @@ fn update_penalty_levels_ok() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		AuthorityAccounts::<Test>::set(new_authorities);
-
-		let mut new_penalty_levels = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_penalty_levels.insert(0, 10000);
-		new_penalty_levels.insert(1, 12500);
-		new_penalty_levels.insert(2, 25000);
-		new_penalty_levels.insert(3, 35200);
-		new_penalty_levels.insert(4, 36050);
+		let mut new_penalty_levels = BTreeMap::<u8, Perbill>::new();
+		new_penalty_levels.insert(0, Perbill::zero());
+		new_penalty_levels.insert(1, Perbill::from_percent(20));
+		new_penalty_levels.insert(2, Perbill::from_percent(60));
+		new_penalty_levels.insert(3, Perbill::from_percent(71));
+		new_penalty_levels.insert(4, Perbill::from_percent(72));
 
 		// Successfully update penalty levels
 		assert_ok!(Veles::update_penalty_levels(
@@ -183,11 +185,11 @@ fn update_penalty_levels_ok() {
 		// Check if penalty values match
 		let penalty_levels = PenaltyLevels::<Test>::get();
 
-		assert_eq!(penalty_levels[&0], BalanceOf::<Test>::from(10000u32));
-		assert_eq!(penalty_levels[&1], BalanceOf::<Test>::from(12500u32));
-		assert_eq!(penalty_levels[&2], BalanceOf::<Test>::from(25000u32));
-		assert_eq!(penalty_levels[&3], BalanceOf::<Test>::from(35200u32));
-		assert_eq!(penalty_levels[&4], BalanceOf::<Test>::from(36050u32));
+		assert_eq!(penalty_levels[&0], Perbill::zero());
+		assert_eq!(penalty_levels[&1], Perbill::from_percent(20));
+		assert_eq!(penalty_levels[&2], Perbill::from_percent(60));
+		assert_eq!(penalty_levels[&3], Perbill::from_percent(71));
+		assert_eq!(penalty_levels[&4], Perbill::from_percent(72));
 	});
 }
 
@@ -197,18 +199,21 @@ fn update_beneficiary_splits_unauthorized() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_beneficiary_splits.insert(0, 5000);
-		new_beneficiary_splits.insert(1, 2500);
-		new_beneficiary_splits.insert(2, 2500);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(50));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(25));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_percent(25));
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
 			Veles::update_beneficiary_splits(
 				RuntimeOrigin::signed(alice()),
 				new_beneficiary_splits
 			),
-			Error::<Test>::Unauthorized
+			DispatchError::BadOrigin
 		);
 	});
 }
@@ -220,16 +225,13 @@ fn update_beneficiary_splits_invalid_beneficiary_split_values() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_beneficiary_splits.insert(0, 5000);
-		new_beneficiary_splits.insert(1, 2500);
-		new_beneficiary_splits.insert(2, 2500);
-		new_beneficiary_splits.insert(3, 2500);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(50));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(25));
 
 		// Check for InvalidBeneficiarySplitValues error
 		assert_err!(
@@ -249,15 +251,15 @@ fn update_beneficiary_splits_invalid_primary_sale_beneficiary_split() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		AuthorityAccounts::<Test>::set(new_authorities);
-
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_beneficiary_splits.insert(0, 5001);
-		new_beneficiary_splits.insert(1, 2500);
-		new_beneficiary_splits.insert(2, 2500);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_parts(500_100));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(25));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_percent(25));
 
 		// Check for InvalidPrimarySaleBeneficiarySplit error
 		assert_err!(
@@ -277,15 +279,15 @@ fn update_beneficiary_splits_invalid_secondary_sale_beneficiary_split() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_beneficiary_splits.insert(0, 5000);
-		new_beneficiary_splits.insert(1, 2500);
-		new_beneficiary_splits.insert(2, 2501);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(50));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(25));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_parts(250_100));
 
 		// Check for InvalidSecondarySaleBeneficiarySplit error
 		assert_err!(
@@ -305,15 +307,15 @@ fn update_beneficiary_splits_ok() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
-		AuthorityAccounts::<Test>::set(new_authorities);
-
-		let mut new_beneficiary_splits = BTreeMap::<u8, BalanceOf<Test>>::new();
-		new_beneficiary_splits.insert(0, 5000);
-		new_beneficiary_splits.insert(1, 2500);
-		new_beneficiary_splits.insert(2, 2500);
+		let mut new_beneficiary_splits = BTreeMap::<BeneficiarySplitType, Permill>::new();
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::PrimarySaleValidators, Permill::from_percent(50));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleValidators, Permill::from_percent(25));
+		new_beneficiary_splits
+			.insert(BeneficiarySplitType::SecondarySaleOwner, Permill::from_percent(25));
 
 		// Successfully update beneficiary splits
 		assert_ok!(Veles::update_beneficiary_splits(
@@ -323,9 +325,18 @@ fn update_beneficiary_splits_ok() {
 
 		let beneficiary_splits = BeneficiarySplits::<Test>::get();
 
-		assert_eq!(beneficiary_splits[&0], BalanceOf::<Test>::from(5000u32));
-		assert_eq!(beneficiary_splits[&1], BalanceOf::<Test>::from(2500u32));
-		assert_eq!(beneficiary_splits[&2], BalanceOf::<Test>::from(2500u32));
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::PrimarySaleValidators],
+			Permill::from_percent(50)
+		);
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::SecondarySaleValidators],
+			Permill::from_percent(25)
+		);
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::SecondarySaleOwner],
+			Permill::from_percent(25)
+		);
 	});
 }
 
@@ -335,10 +346,10 @@ fn update_time_value_unauthorized() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
 			Veles::update_time_value(RuntimeOrigin::signed(alice()), TimeType::PenaltyTimeout, 0),
-			Error::<Test>::Unauthorized
+			DispatchError::BadOrigin
 		);
 	});
 }
@@ -349,10 +360,10 @@ fn update_time_value_unable_to_change_pallet_base_time() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
 			Veles::update_time_value(RuntimeOrigin::signed(alice()), TimeType::PalletBaseTime, 0),
-			Error::<Test>::Unauthorized
+			DispatchError::BadOrigin
 		);
 	});
 }
@@ -364,9 +375,7 @@ fn update_time_value_invalid_timeout_value() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Check for InvalidTimeoutValue error
 		assert_err!(
@@ -383,9 +392,7 @@ fn update_time_value_updating_to_current_value() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		let block_finalization_time: u32 = 6;
 
@@ -446,9 +453,7 @@ fn update_time_value_ok_number_of_blocks_yearly() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update timeout time
 		assert_ok!(Veles::update_time_value(
@@ -471,9 +476,7 @@ fn update_time_value_ok_penalty() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update timeout time
 		assert_ok!(Veles::update_time_value(
@@ -496,9 +499,7 @@ fn update_time_value_ok_voting() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update timeout time
 		assert_ok!(Veles::update_time_value(
@@ -521,9 +522,7 @@ fn update_time_value_ok_sales() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update timeout time
 		assert_ok!(Veles::update_time_value(
@@ -545,10 +544,10 @@ fn update_fee_value_unauthorized() {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Check for Unauthorized error
+		// Check for BadOrigin error
 		assert_err!(
 			Veles::update_fee_value(RuntimeOrigin::signed(alice()), FeeType::TraderAccountFee, 0),
-			Error::<Test>::Unauthorized
+			DispatchError::BadOrigin
 		);
 	});
 }
@@ -560,9 +559,7 @@ fn update_fee_value_updating_to_current_value() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		let trader_account_fee = BalanceOf::<Test>::from(100u32);
 		let project_validator_account_fee = BalanceOf::<Test>::from(100u32);
@@ -658,9 +655,7 @@ fn update_fee_value_ok_trader_account_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -683,9 +678,7 @@ fn update_fee_value_ok_project_validator_account_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -708,9 +701,7 @@ fn update_fee_value_ok_project_owner_account_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -733,9 +724,7 @@ fn update_fee_value_ok_carbon_footprint_report_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -758,9 +747,7 @@ fn update_fee_value_ok_project_proposal_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -783,9 +770,7 @@ fn update_fee_value_ok_carbon_credit_batch_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -808,9 +793,7 @@ fn update_fee_value_ok_voting_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(RuntimeOrigin::signed(alice()), FeeType::VotingFee, 0));
@@ -829,9 +812,7 @@ fn update_fee_value_ok_complaint_fee() {
 		run_to_block(1);
 
 		// Insert authority account
-		let mut new_authorities = AuthorityAccounts::<Test>::get();
-		new_authorities.insert(alice());
-		AuthorityAccounts::<Test>::set(new_authorities);
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
 
 		// Update fee amount
 		assert_ok!(Veles::update_fee_value(
@@ -847,6 +828,233 @@ fn update_fee_value_ok_complaint_fee() {
 	});
 }
 
+#[test]
+fn update_fee_value_trading_fee_too_high() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for TradingFeeTooHigh error
+		assert_err!(
+			Veles::update_fee_value(
+				RuntimeOrigin::root(),
+				FeeType::TradingFee(TradingFeeRole::Taker),
+				MaxTradingFee::get().deconstruct() as u64 + 1
+			),
+			Error::<Test>::TradingFeeTooHigh
+		);
+	});
+}
+
+#[test]
+fn update_fee_value_ok_trading_fee() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert authority account
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
+
+		// Update maker (1%) and taker (2%) fees (in parts per million)
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::signed(alice()),
+			FeeType::TradingFee(TradingFeeRole::Maker),
+			10_000
+		));
+
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::signed(alice()),
+			FeeType::TradingFee(TradingFeeRole::Taker),
+			20_000
+		));
+
+		System::assert_last_event(
+			Event::<Test>::FeeValueUpdated(FeeType::TradingFee(TradingFeeRole::Taker), 20_000)
+				.into(),
+		);
+
+		// Check updated fees
+		let trading_fees = TradingFees::<Test>::get();
+
+		assert_eq!(trading_fees.maker_fee, Permill::from_percent(1));
+		assert_eq!(trading_fees.taker_fee, Permill::from_percent(2));
+
+		// Check for UpdatingToCurrentValue error
+		assert_err!(
+			Veles::update_fee_value(
+				RuntimeOrigin::signed(alice()),
+				FeeType::TradingFee(TradingFeeRole::Maker),
+				10_000
+			),
+			Error::<Test>::UpdatingToCurrentValue
+		);
+	});
+}
+
+#[test]
+fn update_fee_value_ok_root_origin() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Update fee amount
+		assert_ok!(Veles::update_fee_value(RuntimeOrigin::root(), FeeType::ComplaintFee, 0));
+
+		// Check updated fee amount
+		let pallet_fee_values = PalletFeeValues::<Test>::get();
+
+		assert_eq!(pallet_fee_values.complaint_fee, 0);
+	});
+}
+
+#[test]
+fn add_authority_bad_origin() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for BadOrigin error
+		assert_err!(
+			Veles::add_authority(RuntimeOrigin::signed(alice()), bob()),
+			DispatchError::BadOrigin
+		);
+
+		// Insert authority account
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
+
+		// Check for BadOrigin error (authorities can't manage authority accounts)
+		assert_err!(
+			Veles::add_authority(RuntimeOrigin::signed(alice()), bob()),
+			DispatchError::BadOrigin
+		);
+		assert_err!(
+			Veles::remove_authority(RuntimeOrigin::signed(alice()), alice()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_authority_authority_account_already_exists() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert authority account
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
+
+		// Check for AuthorityAccountAlreadyExists error
+		assert_err!(
+			Veles::add_authority(RuntimeOrigin::root(), alice()),
+			Error::<Test>::AuthorityAccountAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn add_authority_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Add authority accounts
+		assert_ok!(Veles::add_authority(RuntimeOrigin::root(), alice()));
+		assert_ok!(Veles::add_authority(RuntimeOrigin::root(), bob()));
+
+		System::assert_last_event(Event::<Test>::AuthorityAccountAdded(bob()).into());
+
+		// Check authority accounts
+		assert_eq!(AuthorityAccounts::<Test>::contains_key(alice()), true);
+		assert_eq!(AuthorityAccounts::<Test>::contains_key(bob()), true);
+
+		// New authority can update pallet values
+		assert_ok!(Veles::update_vote_pass_ratio(
+			RuntimeOrigin::signed(bob()),
+			Perbill::from_percent(50)
+		));
+	});
+}
+
+#[test]
+fn remove_authority_authority_account_doesnt_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for AuthorityAccountDoesntExist error
+		assert_err!(
+			Veles::remove_authority(RuntimeOrigin::root(), alice()),
+			Error::<Test>::AuthorityAccountDoesntExist
+		);
+	});
+}
+
+#[test]
+fn remove_authority_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert authority accounts
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
+		AuthorityAccounts::<Test>::insert(bob(), AuthorityAccountInfo::default());
+
+		// Remove authority account
+		assert_ok!(Veles::remove_authority(RuntimeOrigin::root(), bob()));
+
+		System::assert_last_event(Event::<Test>::AuthorityAccountRemoved(bob()).into());
+
+		// Removed authority can't update pallet values
+		assert_eq!(AuthorityAccounts::<Test>::contains_key(bob()), false);
+		assert_err!(
+			Veles::update_vote_pass_ratio(RuntimeOrigin::signed(bob()), Perbill::from_percent(50)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_transfer_royalties_unauthorized() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for BadOrigin error
+		assert_err!(
+			Veles::update_transfer_royalties(RuntimeOrigin::signed(alice()), true),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_transfer_royalties_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert authority account
+		AuthorityAccounts::<Test>::insert(alice(), AuthorityAccountInfo::default());
+
+		// Transfer royalties are disabled by default
+		assert_eq!(TransferRoyaltiesEnabled::<Test>::get(), false);
+
+		// Enable transfer royalties
+		assert_ok!(Veles::update_transfer_royalties(RuntimeOrigin::signed(alice()), true));
+
+		System::assert_last_event(Event::<Test>::TransferRoyaltiesUpdated(true).into());
+
+		assert_eq!(TransferRoyaltiesEnabled::<Test>::get(), true);
+
+		// Disable transfer royalties
+		assert_ok!(Veles::update_transfer_royalties(RuntimeOrigin::root(), false));
+
+		System::assert_last_event(Event::<Test>::TransferRoyaltiesUpdated(false).into());
+
+		assert_eq!(TransferRoyaltiesEnabled::<Test>::get(), false);
+	});
+}
+
 #[test]
 fn register_for_trader_account_account_id_already_in_use() {
 	new_test_ext().execute_with(|| {
@@ -854,9 +1062,7 @@ fn register_for_trader_account_account_id_already_in_use() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Check for AccountIdAlreadyInUse error
 		assert_err!(
@@ -879,8 +1085,8 @@ fn register_for_trader_account_user_is_active_in_carbon_footprint_report_voting_
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -922,7 +1128,7 @@ fn register_for_trader_account_ok() {
 		assert_ok!(Veles::register_for_trader_account(RuntimeOrigin::signed(charlie())));
 
 		// Check if trader account was stored
-		assert_eq!(TraderAccounts::<Test>::get().contains(&charlie()), true);
+		assert_eq!(TraderAccounts::<Test>::contains_key(charlie()), true);
 
 		// Check balances before extrinsic call
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
@@ -937,9 +1143,7 @@ fn register_for_project_validator_account_account_id_already_in_use() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 
@@ -967,8 +1171,8 @@ fn register_for_project_validator_account_user_is_active_in_carbon_footprint_rep
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1002,6 +1206,11 @@ fn register_for_project_validator_account_documentation_was_used_previously() {
 
 		Validators::<Test>::insert(bob(), validator);
 
+		UsedDocumentation::<Test>::insert(
+			documentation_ipfs.clone(),
+			DocumentationOwner::Validator(bob()),
+		);
+
 		// Check for DocumentationWasUsedPreviously error
 		assert_err!(
 			Veles::register_for_project_validator_account(
@@ -1070,9 +1279,7 @@ fn register_for_project_owner_account_account_id_already_in_use() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 
@@ -1100,8 +1307,8 @@ fn register_for_project_owner_account_user_is_active_in_carbon_footprint_report_
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1135,6 +1342,11 @@ fn register_for_project_owner_account_documentation_was_used_previously() {
 
 		Validators::<Test>::insert(bob(), validator);
 
+		UsedDocumentation::<Test>::insert(
+			documentation_ipfs.clone(),
+			DocumentationOwner::Validator(bob()),
+		);
+
 		// Check for DocumentationWasUsedPreviously error
 		assert_err!(
 			Veles::register_for_project_owner_account(
@@ -1203,9 +1415,7 @@ fn submit_carbon_footprint_report_invalid_carbon_footprint_values() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 		let carbon_footprint_surplus = BalanceOf::<Test>::from(0u32);
@@ -1231,9 +1441,7 @@ fn submit_carbon_footprint_report_account_id_already_in_use() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 		let carbon_footprint_surplus = BalanceOf::<Test>::from(0u32);
@@ -1265,8 +1473,8 @@ fn submit_carbon_footprint_report_user_is_active_in_carbon_footprint_report_voti
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(10u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1305,6 +1513,11 @@ fn submit_carbon_footprint_report_documentation_was_used_previously() {
 
 		Validators::<Test>::insert(bob(), validator);
 
+		UsedDocumentation::<Test>::insert(
+			documentation_ipfs.clone(),
+			DocumentationOwner::Validator(bob()),
+		);
+
 		let carbon_footprint_surplus = BalanceOf::<Test>::from(0u32);
 		let carbon_footprint_deficit = BalanceOf::<Test>::from(10u32);
 
@@ -1379,6 +1592,12 @@ fn submit_carbon_footprint_report_ok() {
 		assert_eq!(report.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
 		assert_eq!(report.voting_active, true);
 
+		// Check if the documentation was marked as used
+		assert_eq!(
+			Veles::used_documentation(documentation_ipfs),
+			Some(DocumentationOwner::CarbonFootprintReport(charlie()))
+		);
+
 		// Check balances before extrinsic call
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4700);
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 300);
@@ -1493,8 +1712,8 @@ fn cast_vote_carbon_footprint_report_vote_voting_cycle_is_over() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: false,
 		};
 
@@ -1540,8 +1759,8 @@ fn cast_vote_carbon_footprint_report_ok() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1605,8 +1824,8 @@ fn cast_vote_carbon_footprint_report_vote_already_submitted() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1633,6 +1852,67 @@ fn cast_vote_carbon_footprint_report_vote_already_submitted() {
 	});
 }
 
+#[test]
+fn cast_vote_carbon_footprint_report_too_many_votes() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Allow only a single vote for (or against)
+		MaxValidatorsPerVote::set(1);
+
+		// Insert validator
+		let validator_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation_ipfs");
+
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert carbon footprint report (with a vote already cast)
+		let report_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("report_documentation_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let report = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
+			votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		CarbonFootprintReports::<Test>::insert(report_documentation_ipfs.clone(), report);
+
+		// Check for TooManyVotes error
+		assert_err!(
+			Veles::cast_vote(
+				RuntimeOrigin::signed(charlie()),
+				VoteType::CarbonFootprintReportVote,
+				report_documentation_ipfs.clone(),
+				true
+			),
+			Error::<Test>::TooManyVotes
+		);
+
+		// Votes against are bounded separately
+		assert_ok!(Veles::cast_vote(
+			RuntimeOrigin::signed(charlie()),
+			VoteType::CarbonFootprintReportVote,
+			report_documentation_ipfs,
+			false
+		));
+	});
+}
+
 #[test]
 fn cast_vote_project_proposal_vote_project_proposal_not_found() {
 	new_test_ext().execute_with(|| {
@@ -1689,8 +1969,8 @@ fn cast_vote_project_proposal_vote_voting_cycle_is_over() {
 			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			project_hash: generate_hash(alice()),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: false,
 		};
 
@@ -1736,8 +2016,8 @@ fn cast_vote_project_proposal_vote_ok() {
 			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			project_hash,
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1799,8 +2079,8 @@ fn cast_vote_project_proposal_vote_already_submitted() {
 			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			project_hash,
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1875,8 +2155,8 @@ fn cast_vote_carbon_credit_batch_vote_voting_cycle_is_over() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			credit_amount: 0u32.into(),
 			penalty_repay_price: 0u32.into(),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: false,
 		};
 
@@ -1924,8 +2204,8 @@ fn cast_vote_carbon_credit_batch_vote_ok() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			credit_amount: 0u32.into(),
 			penalty_repay_price: 0u32.into(),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -1991,8 +2271,8 @@ fn cast_vote_carbon_credit_batch_vote_vote_already_submitted() {
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			credit_amount: 0u32.into(),
 			penalty_repay_price: 0u32.into(),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -2035,51 +2315,138 @@ fn cast_vote_carbon_credit_batch_vote_vote_already_submitted() {
 }
 
 #[test]
-fn cast_vote_complaint_vote_complaint_not_found() {
+fn cast_vote_beneficiary_split_vote_beneficiary_split_proposal_not_found() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert validator
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
-
 		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: documentation_ipfs.clone(),
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
 			penalty_level: 0,
 			penalty_timeout: 0,
 		};
 
 		Validators::<Test>::insert(charlie(), validator);
 
-		// Check for ComplaintNotFound error
+		// Check for BeneficiarySplitProposalNotFound error
 		assert_err!(
 			Veles::cast_vote(
 				RuntimeOrigin::signed(charlie()),
-				VoteType::ComplaintVote,
-				documentation_ipfs,
+				VoteType::BeneficiarySplitVote,
+				BoundedString::<IPFSLength>::truncate_from("proposal_ipfs"),
 				true
 			),
-			Error::<Test>::ComplaintNotFound
+			Error::<Test>::BeneficiarySplitProposalNotFound
 		);
 	});
 }
 
 #[test]
-fn cast_vote_complaint_accounts_voting_cycle_is_over() {
+fn cast_vote_beneficiary_split_vote_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Insert complaint for account type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		// Insert beneficiary split proposal
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
 
-		let complaint = ComplaintAccountBasedInfo {
-			complaint_proposer: alice(),
-			complaint_type: ComplaintType::ValidatorComplaint,
-			complaint_for: bob(),
+		let proposal = BeneficiarySplitProposalInfo {
+			project_hash: generate_hash(bob()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			beneficiary_splits: BeneficiarySplits::<Test>::get(),
+			stakeholders: StakeholderSharesOf::<Test>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		BeneficiarySplitProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Successfully cast vote
+		assert_ok!(Veles::cast_vote(
+			RuntimeOrigin::signed(charlie()),
+			VoteType::BeneficiarySplitVote,
+			proposal_ipfs.clone(),
+			false
+		));
+
+		// Check if the proposal was updated
+		let proposal = BeneficiarySplitProposals::<Test>::get(proposal_ipfs.clone()).unwrap();
+
+		assert_eq!(proposal.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(proposal.votes_against.len(), 1);
+		assert_eq!(proposal.votes_against.contains(&charlie()), true);
+		assert_eq!(proposal.voting_active, true);
+
+		// Check for VoteAlreadySubmitted error
+		assert_err!(
+			Veles::cast_vote(
+				RuntimeOrigin::signed(charlie()),
+				VoteType::BeneficiarySplitVote,
+				proposal_ipfs,
+				true
+			),
+			Error::<Test>::VoteAlreadySubmitted
+		);
+	});
+}
+
+#[test]
+fn cast_vote_complaint_vote_complaint_not_found() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert validator
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Check for ComplaintNotFound error
+		assert_err!(
+			Veles::cast_vote(
+				RuntimeOrigin::signed(charlie()),
+				VoteType::ComplaintVote,
+				documentation_ipfs,
+				true
+			),
+			Error::<Test>::ComplaintNotFound
+		);
+	});
+}
+
+#[test]
+fn cast_vote_complaint_accounts_voting_cycle_is_over() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert complaint for account type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let complaint = ComplaintAccountBasedInfo {
+			complaint_proposer: alice(),
+			complaint_type: ComplaintType::ValidatorComplaint,
+			complaint_for: bob(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: false,
 		};
 
@@ -2123,8 +2490,8 @@ fn cast_vote_complaint_accounts_ok() {
 			complaint_type: ComplaintType::ValidatorComplaint,
 			complaint_for: bob(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: true,
 		};
 
@@ -2185,8 +2552,8 @@ fn cast_vote_complaint_accounts_vote_already_submitted() {
 			complaint_type: ComplaintType::ValidatorComplaint,
 			complaint_for: bob(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: true,
 		};
 
@@ -2239,8 +2606,8 @@ fn cast_vote_complaint_hashes_voting_cycle_is_over() {
 			complaint_type: ComplaintType::ProjectComplaint,
 			complaint_for: complaint_for_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: false,
 		};
 
@@ -2285,8 +2652,8 @@ fn cast_vote_complaint_hashes_ok() {
 			complaint_type: ComplaintType::ProjectComplaint,
 			complaint_for: complaint_for_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: true,
 		};
 
@@ -2348,8 +2715,8 @@ fn cast_vote_complaint_hashes_vote_already_submitted() {
 			complaint_type: ComplaintType::ProjectComplaint,
 			complaint_for: complaint_for_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			complaint_active: true,
 		};
 
@@ -2422,10 +2789,10 @@ fn propose_project_project_owner_has_standing_debts() {
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
 		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		let mut debts = DebtsOf::<Test>::new();
 
-		debts.insert(bob(), BalanceOf::<Test>::from(10u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(20u32));
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(20u32)).unwrap();
 
 		ProjectOwnerDebts::<Test>::insert(alice(), debts);
 
@@ -2467,8 +2834,8 @@ fn propose_project_project_proposal_already_exists() {
 			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			project_hash,
-			votes_for: BTreeSet::<AccountIdOf<Test>>::new(),
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -2500,6 +2867,11 @@ fn propose_project_documentation_was_used_previously() {
 
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
+		UsedDocumentation::<Test>::insert(
+			owner_documentation_ipfs.clone(),
+			DocumentationOwner::ProjectOwner(alice()),
+		);
+
 		// Check for DocumentationWasUsedPreviously error
 		assert_err!(
 			Veles::propose_project(RuntimeOrigin::signed(alice()), owner_documentation_ipfs,),
@@ -2644,10 +3016,10 @@ fn propose_carbon_credit_batch_project_owner_has_standing_debts() {
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
 		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		let mut debts = DebtsOf::<Test>::new();
 
-		debts.insert(bob(), BalanceOf::<Test>::from(10u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(20u32));
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(20u32)).unwrap();
 
 		ProjectOwnerDebts::<Test>::insert(alice(), debts);
 
@@ -2812,6 +3184,11 @@ fn propose_carbon_credit_batch_documentation_was_used_previously() {
 
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
+		UsedDocumentation::<Test>::insert(
+			owner_documentation_ipfs.clone(),
+			DocumentationOwner::ProjectOwner(alice()),
+		);
+
 		// Insert project
 		let project_documentation_ipfs =
 			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
@@ -2970,6 +3347,189 @@ fn propose_carbon_credit_batch_ok() {
 	});
 }
 
+#[test]
+fn propose_beneficiary_splits_unauthorized() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for Unauthorized error
+		assert_err!(
+			Veles::propose_beneficiary_splits(
+				RuntimeOrigin::signed(alice()),
+				generate_hash(alice()),
+				BeneficiarySplits::<Test>::get().into_inner(),
+				BTreeMap::<AccountIdOf<Test>, Permill>::new(),
+				BoundedString::<IPFSLength>::truncate_from("proposal_ipfs")
+			),
+			Error::<Test>::Unauthorized
+		);
+	});
+}
+
+#[test]
+fn propose_beneficiary_splits_invalid_stakeholders() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project owner
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(charlie(), project_owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: charlie(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		let project_hash = generate_hash(charlie());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		let beneficiary_splits = BeneficiarySplits::<Test>::get().into_inner();
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+
+		// Check for TooManyStakeholders error
+		let mut stakeholders = BTreeMap::<AccountIdOf<Test>, Permill>::new();
+		stakeholders.insert(dave(), Permill::from_parts(10_000));
+		stakeholders.insert(fred(), Permill::from_parts(10_000));
+		stakeholders.insert(george(), Permill::from_parts(10_000));
+		stakeholders.insert(hank(), Permill::from_parts(10_000));
+
+		assert_err!(
+			Veles::propose_beneficiary_splits(
+				RuntimeOrigin::signed(charlie()),
+				project_hash,
+				beneficiary_splits.clone(),
+				stakeholders,
+				proposal_ipfs.clone()
+			),
+			Error::<Test>::TooManyStakeholders
+		);
+
+		// Check for InvalidStakeholderShare error
+		let mut stakeholders = BTreeMap::<AccountIdOf<Test>, Permill>::new();
+		stakeholders.insert(dave(), Permill::from_parts(10_000));
+		stakeholders.insert(fred(), Permill::zero());
+
+		assert_err!(
+			Veles::propose_beneficiary_splits(
+				RuntimeOrigin::signed(charlie()),
+				project_hash,
+				beneficiary_splits.clone(),
+				stakeholders,
+				proposal_ipfs.clone()
+			),
+			Error::<Test>::InvalidStakeholderShare
+		);
+
+		// Check for InvalidPrimarySaleBeneficiarySplit error (validators and stakeholders get
+		// more than 50% of a primary sale)
+		let mut stakeholders = BTreeMap::<AccountIdOf<Test>, Permill>::new();
+		stakeholders.insert(dave(), Permill::from_parts(50_100));
+
+		assert_err!(
+			Veles::propose_beneficiary_splits(
+				RuntimeOrigin::signed(charlie()),
+				project_hash,
+				beneficiary_splits,
+				stakeholders,
+				proposal_ipfs
+			),
+			Error::<Test>::InvalidPrimarySaleBeneficiarySplit
+		);
+	});
+}
+
+#[test]
+fn propose_beneficiary_splits_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project owner
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(charlie(), project_owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: charlie(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		let project_hash = generate_hash(charlie());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		let beneficiary_splits = BeneficiarySplits::<Test>::get().into_inner();
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+
+		let mut stakeholders = BTreeMap::<AccountIdOf<Test>, Permill>::new();
+		stakeholders.insert(dave(), Permill::from_parts(25_000));
+		stakeholders.insert(fred(), Permill::from_parts(25_000));
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+
+		// Successfully propose beneficiary splits
+		assert_ok!(Veles::propose_beneficiary_splits(
+			RuntimeOrigin::signed(charlie()),
+			project_hash,
+			beneficiary_splits.clone(),
+			stakeholders.clone(),
+			proposal_ipfs.clone()
+		));
+
+		// Check saved proposal data
+		let proposal = BeneficiarySplitProposals::<Test>::get(proposal_ipfs.clone()).unwrap();
+
+		assert_eq!(proposal.project_hash, project_hash);
+		assert_eq!(proposal.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(proposal.beneficiary_splits.into_inner(), beneficiary_splits);
+		assert_eq!(proposal.stakeholders.into_inner(), stakeholders);
+		assert_eq!(proposal.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(proposal.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(proposal.voting_active, true);
+
+		assert_eq!(Veles::is_ipfs_available(proposal_ipfs.clone()), false);
+
+		// Check voting timeout data
+		let current_block = frame_system::Pallet::<Test>::block_number();
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+
+		let timeout_events = VotingTimeouts::<Test>::get(timeout_block).unwrap();
+
+		assert_eq!(timeout_events.len(), 1);
+		assert_eq!(timeout_events.contains(&proposal_ipfs), true);
+
+		System::assert_last_event(
+			Event::<Test>::BeneficiarySplitProposalCreated(charlie(), proposal_ipfs).into(),
+		);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+	});
+}
+
 #[test]
 fn create_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
 	new_test_ext().execute_with(|| {
@@ -2987,6 +3547,9 @@ fn create_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
 		);
@@ -3000,10 +3563,7 @@ fn create_sale_order_project_owner_has_standing_debtst() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert project owner
 		let owner_documentation_ipfs =
@@ -3018,10 +3578,10 @@ fn create_sale_order_project_owner_has_standing_debtst() {
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
 		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		let mut debts = DebtsOf::<Test>::new();
 
-		debts.insert(bob(), BalanceOf::<Test>::from(10u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(20u32));
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(20u32)).unwrap();
 
 		ProjectOwnerDebts::<Test>::insert(alice(), debts);
 
@@ -3036,6 +3596,9 @@ fn create_sale_order_project_owner_has_standing_debtst() {
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::ProjectOwnerHasStandingDebts
 		);
@@ -3049,10 +3612,7 @@ fn create_sale_order_invalid_carbon_credit_amount() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let batch_hash = generate_hash(alice());
 		let credit_price = BalanceOf::<Test>::from(0u32);
@@ -3065,6 +3625,9 @@ fn create_sale_order_invalid_carbon_credit_amount() {
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::InvalidCarbonCreditAmount
 		);
@@ -3072,93 +3635,188 @@ fn create_sale_order_invalid_carbon_credit_amount() {
 }
 
 #[test]
-fn create_sale_order_carbon_credit_batch_does_not_exist() {
+fn create_sale_order_invalid_min_fill_amount() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let batch_hash = generate_hash(alice());
 		let credit_price = BalanceOf::<Test>::from(0u32);
-		let credit_amount = BalanceOf::<Test>::from(1u32);
+		let credit_amount = BalanceOf::<Test>::from(10u32);
 
-		// Check for InvalidCarbonCreditAmount error
+		// Check for InvalidMinFillAmount error (zero minimum fill amount)
 		assert_err!(
 			Veles::create_sale_order(
 				RuntimeOrigin::signed(alice()),
 				batch_hash,
 				credit_price,
 				credit_amount,
+				Some(BalanceOf::<Test>::from(0u32)),
+				None,
+				None,
 			),
-			Error::<Test>::CarbonCreditBatchDoesNotExist
+			Error::<Test>::InvalidMinFillAmount
+		);
+
+		// Check for InvalidMinFillAmount error (minimum fill amount exceeds the credit amount)
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				credit_price,
+				credit_amount,
+				Some(BalanceOf::<Test>::from(11u32)),
+				None,
+				None,
+			),
+			Error::<Test>::InvalidMinFillAmount
 		);
 	});
 }
 
 #[test]
-fn create_sale_order_carbon_credit_batch_is_not_active() {
+fn create_sale_order_invalid_allowed_buyers() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
-
-		// Insert carbon credit batch
-		let batch_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
-
-		let project_hash = generate_hash(bob());
-
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation_ipfs,
-			project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(1u32),
-			penalty_repay_price: BalanceOf::<Test>::from(1u32),
-			status: CarbonCreditBatchStatus::Frozen,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
-		};
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let batch_hash = generate_hash(alice());
-
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
-
 		let credit_price = BalanceOf::<Test>::from(0u32);
-		let credit_amount = BalanceOf::<Test>::from(1u32);
+		let credit_amount = BalanceOf::<Test>::from(10u32);
 
-		// Check for CarbonCreditBatchIsNotActive error
+		// Check for InvalidAllowedBuyers error (no allowed buyers)
 		assert_err!(
 			Veles::create_sale_order(
 				RuntimeOrigin::signed(alice()),
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				Some(AllowedBuyersOf::<Test>::new()),
+				None,
 			),
-			Error::<Test>::CarbonCreditBatchIsNotActive
+			Error::<Test>::InvalidAllowedBuyers
 		);
-	});
-}
 
-#[test]
-fn create_sale_order_carbon_credit_holdings_dont_exist() {
-	new_test_ext().execute_with(|| {
+		// Check for InvalidAllowedBuyers error (seller is an allowed buyer)
+		let mut allowed_buyers = AllowedBuyersOf::<Test>::new();
+		allowed_buyers.try_insert(alice()).unwrap();
+		allowed_buyers.try_insert(bob()).unwrap();
+
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				credit_price,
+				credit_amount,
+				None,
+				Some(allowed_buyers),
+				None,
+			),
+			Error::<Test>::InvalidAllowedBuyers
+		);
+	});
+}
+
+#[test]
+fn create_sale_order_carbon_credit_batch_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+
+		let batch_hash = generate_hash(alice());
+		let credit_price = BalanceOf::<Test>::from(0u32);
+		let credit_amount = BalanceOf::<Test>::from(1u32);
+
+		// Check for InvalidCarbonCreditAmount error
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				credit_price,
+				credit_amount,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::CarbonCreditBatchDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn create_sale_order_carbon_credit_batch_is_not_active() {
+	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+
+		let project_hash = generate_hash(bob());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(1u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Frozen,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(1u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		TraderAccounts::<Test>::set(traders);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		let credit_price = BalanceOf::<Test>::from(0u32);
+		let credit_amount = BalanceOf::<Test>::from(1u32);
+
+		// Check for CarbonCreditBatchIsNotActive error
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				credit_price,
+				credit_amount,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::CarbonCreditBatchIsNotActive
+		);
+	});
+}
+
+#[test]
+fn create_sale_order_carbon_credit_holdings_dont_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
 		let batch_documentation_ipfs =
@@ -3173,13 +3831,21 @@ fn create_sale_order_carbon_credit_holdings_dont_exist() {
 			credit_amount: BalanceOf::<Test>::from(1u32),
 			penalty_repay_price: BalanceOf::<Test>::from(1000u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(alice());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(1u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
 		let credit_price = BalanceOf::<Test>::from(0u32);
 		let credit_amount = BalanceOf::<Test>::from(1u32);
 
@@ -3190,6 +3856,9 @@ fn create_sale_order_carbon_credit_holdings_dont_exist() {
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::CarbonCreditHoldingsDontExist
 		);
@@ -3203,10 +3872,7 @@ fn create_sale_order_not_enought_available_credits() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
 		let batch_documentation_ipfs =
@@ -3218,10 +3884,10 @@ fn create_sale_order_not_enought_available_credits() {
 			documentation_ipfs: batch_documentation_ipfs,
 			project_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
+			credit_amount: BalanceOf::<Test>::from(0u32),
 			penalty_repay_price: BalanceOf::<Test>::from(1u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(alice());
@@ -3246,6 +3912,9 @@ fn create_sale_order_not_enought_available_credits() {
 				batch_hash,
 				credit_price,
 				credit_amount,
+				None,
+				None,
+				None,
 			),
 			Error::<Test>::NotEnoughtAvailableCredits
 		);
@@ -3259,10 +3928,7 @@ fn create_sale_order_ok() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
 		let batch_documentation_ipfs =
@@ -3274,10 +3940,10 @@ fn create_sale_order_ok() {
 			documentation_ipfs: batch_documentation_ipfs,
 			project_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
 			penalty_repay_price: BalanceOf::<Test>::from(1u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(alice());
@@ -3305,6 +3971,9 @@ fn create_sale_order_ok() {
 			batch_hash,
 			credit_price,
 			credit_amount,
+			None,
+			None,
+			None,
 		));
 
 		// Check cabon credit holding for seller after sale order creation
@@ -3319,15 +3988,15 @@ fn create_sale_order_ok() {
 		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_order_hash).unwrap();
 
 		let current_block = frame_system::Pallet::<Test>::block_number();
-		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().sales_timeout;
 
 		assert_eq!(sale_order.batch_hash, batch_hash);
 		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(5u32));
 		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(0u32));
 		assert_eq!(sale_order.seller, alice());
-		assert_eq!(sale_order.buyer, alice());
-		assert_eq!(sale_order.sale_active, true);
-		assert_eq!(sale_order.sale_timeout, timeout_block);
+		assert_eq!(sale_order.buyer, None);
+		assert_eq!(sale_order.status, SaleOrderStatus::Open);
+		assert_eq!(sale_order.sale_timeout, Some(timeout_block));
 
 		// Check for sale timeout event
 		let sale_timeouts = SaleOrderTimeouts::<Test>::get(timeout_block).unwrap();
@@ -3337,6 +4006,163 @@ fn create_sale_order_ok() {
 	});
 }
 
+#[test]
+fn create_sale_order_invalid_sale_order_expiry() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(bob()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		let sales_timeout = PalletTimeValues::<Test>::get().sales_timeout;
+
+		// Check for InvalidSaleOrderExpiry error (no expiry)
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(10u32),
+				None,
+				None,
+				Some(SaleOrderExpiry::AfterBlocks(0)),
+			),
+			Error::<Test>::InvalidSaleOrderExpiry
+		);
+
+		// Check for InvalidSaleOrderExpiry error (expiry is longer than the sales timeout)
+		assert_err!(
+			Veles::create_sale_order(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(10u32),
+				None,
+				None,
+				Some(SaleOrderExpiry::AfterBlocks(sales_timeout + 1)),
+			),
+			Error::<Test>::InvalidSaleOrderExpiry
+		);
+	});
+}
+
+#[test]
+fn create_sale_order_expiry_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(bob()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Successfully create a sale order that expires after 10 blocks
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+			None,
+			None,
+			Some(SaleOrderExpiry::AfterBlocks(10)),
+		));
+
+		let expiring_hash = generate_hash(alice());
+
+		let expiring_sale_order = CarbonCreditSaleOrders::<Test>::get(expiring_hash).unwrap();
+
+		assert_eq!(expiring_sale_order.sale_timeout, Some(11));
+		assert_eq!(SaleOrderTimeouts::<Test>::get(11).unwrap().contains(&expiring_hash), true);
+
+		// Successfully create a good-til-cancelled sale order
+		System::inc_account_nonce(alice());
+
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+			None,
+			None,
+			Some(SaleOrderExpiry::GoodTilCancelled),
+		));
+
+		let gtc_hash = generate_hash(alice());
+
+		assert_eq!(CarbonCreditSaleOrders::<Test>::get(gtc_hash).unwrap().sale_timeout, None);
+
+		// Check if only the expiring sale order times out
+		run_to_block(11);
+
+		let expiring_sale_order = CarbonCreditSaleOrders::<Test>::get(expiring_hash).unwrap();
+		let gtc_sale_order = CarbonCreditSaleOrders::<Test>::get(gtc_hash).unwrap();
+
+		assert_eq!(expiring_sale_order.status, SaleOrderStatus::Expired);
+		assert_eq!(gtc_sale_order.status, SaleOrderStatus::Open);
+
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(30u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(10u32));
+
+		// Successfully close the good-til-cancelled sale order
+		assert_ok!(Veles::close_sale_order(RuntimeOrigin::signed(alice()), gtc_hash));
+
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(40u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+	});
+}
+
 #[test]
 fn complete_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
 	new_test_ext().execute_with(|| {
@@ -3347,7 +4173,7 @@ fn complete_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
 
 		// Check for UserIsNotEligibleForCarbonCreditTransactions error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
 		);
 	});
@@ -3372,10 +4198,10 @@ fn complete_sale_order_project_owner_has_standing_debts() {
 		ProjectOwners::<Test>::insert(alice(), project_owner);
 
 		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		let mut debts = DebtsOf::<Test>::new();
 
-		debts.insert(bob(), BalanceOf::<Test>::from(10u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(20u32));
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(20u32)).unwrap();
 
 		ProjectOwnerDebts::<Test>::insert(alice(), debts);
 
@@ -3383,7 +4209,7 @@ fn complete_sale_order_project_owner_has_standing_debts() {
 
 		// Check for ProjectOwnerHasStandingDebts error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::ProjectOwnerHasStandingDebts
 		);
 	});
@@ -3396,16 +4222,13 @@ fn complete_sale_order_carbon_credit_sale_order_doesnt_exist() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		let sale_hash = generate_hash(alice());
 
 		// Check for CarbonCreditSaleOrderDoesntExist error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::CarbonCreditSaleOrderDoesntExist
 		);
 	});
@@ -3418,29 +4241,41 @@ fn complete_sale_order_buyer_cant_buy_his_own_tokens() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-		TraderAccounts::<Test>::set(traders);
+		CarbonCreditHoldings::<Test>::insert(generate_hash(alice()), alice(), credit_holdings);
 
 		// Insert sale order
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash: generate_hash(alice()),
 			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
 			credit_price: BalanceOf::<Test>::from(5u32),
 			seller: alice(),
-			buyer: alice(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
 		let sale_hash = generate_hash(bob());
 
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
 		// Check for BuyerCantBuyHisOwnTokens error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::BuyerCantBuyHisOwnTokens
 		);
 	});
@@ -3453,29 +4288,41 @@ fn complete_sale_order_carbon_credit_batch_does_not_exists() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-		TraderAccounts::<Test>::set(traders);
+		CarbonCreditHoldings::<Test>::insert(generate_hash(alice()), bob(), credit_holdings);
 
 		// Insert sale order
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash: generate_hash(alice()),
 			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
 			credit_price: BalanceOf::<Test>::from(5u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
 		let sale_hash = generate_hash(bob());
 
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
 		// Check for CarbonCreditBatchDoesNotExist error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::CarbonCreditBatchDoesNotExist
 		);
 	});
@@ -3488,10 +4335,7 @@ fn complete_sale_order_carbon_credit_batch_is_not_active() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
 		let credit_batch = CarbonCreditBatchInfo {
@@ -3503,31 +4347,46 @@ fn complete_sale_order_carbon_credit_batch_is_not_active() {
 			credit_amount: BalanceOf::<Test>::from(100u32),
 			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Frozen,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(alice());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
 		// Insert sale order
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash: generate_hash(alice()),
 			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
 			credit_price: BalanceOf::<Test>::from(5u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
 		let sale_hash = generate_hash(bob());
 
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
 		// Check for CarbonCreditBatchIsNotActive error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::CarbonCreditBatchIsNotActive
 		);
 	});
@@ -3540,10 +4399,7 @@ fn complete_sale_order_insufficient_funds() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
 		let credit_batch = CarbonCreditBatchInfo {
@@ -3555,31 +4411,46 @@ fn complete_sale_order_insufficient_funds() {
 			credit_amount: BalanceOf::<Test>::from(100u32),
 			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(alice());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
 		// Insert sale order
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash: generate_hash(alice()),
 			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
 			credit_price: BalanceOf::<Test>::from(5u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
 		let sale_hash = generate_hash(bob());
 
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
 		// Check for InsufficientFunds error
 		assert_err!(
-			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
 			Error::<Test>::InsufficientFunds
 		);
 	});
@@ -3592,10 +4463,7 @@ fn complete_sale_order_ok() {
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(charlie());
-
-		TraderAccounts::<Test>::set(traders);
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
 		// Insert validators
 		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
@@ -3636,9 +4504,9 @@ fn complete_sale_order_ok() {
 		Projects::<Test>::insert(project_hash, project);
 
 		// Insert carbon credit batch
-		let mut validator_benefactors = BTreeSet::<AccountIdOf<Test>>::new();
-		validator_benefactors.insert(dave());
-		validator_benefactors.insert(fred());
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
 
 		let credit_batch = CarbonCreditBatchInfo {
 			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
@@ -3656,8 +4524,8 @@ fn complete_sale_order_ok() {
 
 		// Insert carbon credit holdings
 		let credit_holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(50u32),
-			unavailable_amount: BalanceOf::<Test>::from(20u32),
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
 		};
 
 		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
@@ -3668,11 +4536,12 @@ fn complete_sale_order_ok() {
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash,
 			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
 			credit_price: BalanceOf::<Test>::from(5u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: timeout_block,
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
 		let sale_hash = generate_hash(bob());
@@ -3680,8 +4549,8 @@ fn complete_sale_order_ok() {
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
 		// Insert sale timeout
-		let mut sale_timeouts = BTreeSet::<H256>::new();
-		sale_timeouts.insert(sale_hash);
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
 
 		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
 
@@ -3690,12 +4559,12 @@ fn complete_sale_order_ok() {
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 100);
 
 		// Successfully complete sale order
-		assert_ok!(Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash,));
+		assert_ok!(Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash, None));
 
 		// Check seller carbon credit holdings
 		let seller_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
-		assert_eq!(seller_holdings.available_amount, BalanceOf::<Test>::from(50u32));
-		assert_eq!(seller_holdings.unavailable_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(seller_holdings.available_amount, BalanceOf::<Test>::from(90u32));
+		assert_eq!(seller_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
 
 		// Check buyer carbon credit holdings
 		let buyer_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
@@ -3705,12 +4574,23 @@ fn complete_sale_order_ok() {
 		// Check sale order structure
 		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
 		assert_eq!(sale_order.batch_hash, batch_hash);
-		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(0u32));
 		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(5u32));
 		assert_eq!(sale_order.seller, bob());
-		assert_eq!(sale_order.buyer, charlie());
-		assert_eq!(sale_order.sale_active, false);
-		assert_eq!(sale_order.sale_timeout, timeout_block);
+		assert_eq!(sale_order.buyer, Some(charlie()));
+		assert_eq!(sale_order.status, SaleOrderStatus::Filled);
+		assert_eq!(sale_order.sale_timeout, Some(timeout_block));
+
+		// Check sale order fills
+		let fills = SaleOrderFills::<Test>::get(sale_hash).unwrap();
+		assert_eq!(fills.len(), 1);
+		assert_eq!(fills[0].buyer, charlie());
+		assert_eq!(fills[0].credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(fills[0].credit_price, BalanceOf::<Test>::from(5u32));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderCompleted(charlie(), sale_hash, 0, 0).into(),
+		);
 
 		// Check sale timeout structure
 		let sale_timeouts = SaleOrderTimeouts::<Test>::get(timeout_block);
@@ -3718,8 +4598,8 @@ fn complete_sale_order_ok() {
 		assert_eq!(sale_timeouts, None);
 
 		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4951);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 128);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 129);
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 8);
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 8);
 		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 6);
@@ -3727,217 +4607,228 @@ fn complete_sale_order_ok() {
 }
 
 #[test]
-fn close_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
+fn complete_sale_order_trading_fees_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let sale_hash = generate_hash(alice());
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		// Check for UserIsNotEligibleForCarbonCreditTransactions error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
-		);
-	});
-}
-
-#[test]
-fn close_sale_order_project_owner_has_standing_debts() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
-
-		// Insert project owner
-		let owner_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
-
-		let project_owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation_ipfs.clone(),
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
 			penalty_level: 0,
 			penalty_timeout: 0,
 		};
 
-		ProjectOwners::<Test>::insert(alice(), project_owner);
-
-		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		debts.insert(bob(), BalanceOf::<Test>::from(10u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(20u32));
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
 
-		ProjectOwnerDebts::<Test>::insert(alice(), debts);
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		let sale_hash = generate_hash(alice());
+		ProjectOwners::<Test>::insert(alice(), owner);
 
-		// Check for ProjectOwnerHasStandingDebts error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::ProjectOwnerHasStandingDebts
-		);
-	});
-}
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-#[test]
-fn close_sale_order_carbon_credit_sale_order_doesnt_exist() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		let project_hash = generate_hash(alice());
 
-		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		Projects::<Test>::insert(project_hash, project);
 
-		TraderAccounts::<Test>::set(traders);
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
 
-		let sale_hash = generate_hash(alice());
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
 
-		// Check for CarbonCreditSaleOrderDoesntExist error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::CarbonCreditSaleOrderDoesntExist
-		);
-	});
-}
+		let batch_hash = generate_hash(bob());
 
-#[test]
-fn close_sale_order_sale_order_is_not_active() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
-		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-		TraderAccounts::<Test>::set(traders);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
 		// Insert sale order
+		let timeout_block = BlockNumber::<Test>::from(100u32);
+
 		let sale_order = CarbonCreditSaleOrderInfo {
-			batch_hash: generate_hash(alice()),
+			batch_hash,
 			credit_amount: BalanceOf::<Test>::from(10u32),
-			credit_price: BalanceOf::<Test>::from(5u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(50u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: false,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
 		let sale_hash = generate_hash(bob());
 
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		// Check for SaleOrderIsNotActive error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::SaleOrderIsNotActive
-		);
-	});
-}
+		// Insert sale timeout
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
 
-#[test]
-fn close_sale_order_user_didnt_create_the_sale_order() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
 
-		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		// Set maker (1%) and taker (2%) trading fees
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Maker),
+			10_000
+		));
 
-		TraderAccounts::<Test>::set(traders);
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Taker),
+			20_000
+		));
 
-		// Insert sale order
-		let sale_order = CarbonCreditSaleOrderInfo {
-			batch_hash: generate_hash(alice()),
-			credit_amount: BalanceOf::<Test>::from(10u32),
-			credit_price: BalanceOf::<Test>::from(5u32),
-			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
-		};
+		let pallet_balance = pallet_balances::Pallet::<Test>::free_balance(pallet_id());
 
-		let sale_hash = generate_hash(bob());
+		// Successfully fill part of the sale order (trading fees are paid to the pallet)
+		assert_ok!(Veles::complete_sale_order(
+			RuntimeOrigin::signed(charlie()),
+			sale_hash,
+			Some(BalanceOf::<Test>::from(4u32))
+		));
 
-		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), pallet_balance + 6);
 
-		// Check for UserDidntCreateTheSaleOrder error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::UserDidntCreateTheSaleOrder
+		// Successfully complete sale order (trading fees are paid to the beneficiary)
+		TradingFeeBeneficiary::set(Some(george()));
+
+		assert_ok!(Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash, None));
+
+		TradingFeeBeneficiary::set(None);
+
+		// Check sale order fills
+		let fills = SaleOrderFills::<Test>::get(sale_hash).unwrap();
+		assert_eq!(fills.len(), 2);
+		assert_eq!(fills[0].maker_fee, BalanceOf::<Test>::from(2u32));
+		assert_eq!(fills[0].taker_fee, BalanceOf::<Test>::from(4u32));
+		assert_eq!(fills[1].maker_fee, BalanceOf::<Test>::from(3u32));
+		assert_eq!(fills[1].taker_fee, BalanceOf::<Test>::from(6u32));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderCompleted(charlie(), sale_hash, 5, 10).into(),
 		);
+
+		// Check balances after extrinsic calls
+		// Note: The seller proceeds are reduced by the maker fee and the buyer pays the taker fee
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4490);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 371);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 87);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 87);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 51);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 9);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), pallet_balance + 6);
 	});
 }
 
 #[test]
-fn close_sale_order_carbon_credit_batch_does_not_exist() {
+fn complete_sale_order_trading_fee_exceeds_sale_proceeds() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
 		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(alice());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		TraderAccounts::<Test>::set(traders);
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Insert sale order
-		let sale_order = CarbonCreditSaleOrderInfo {
-			batch_hash: generate_hash(alice()),
-			credit_amount: BalanceOf::<Test>::from(10u32),
-			credit_price: BalanceOf::<Test>::from(5u32),
-			seller: alice(),
-			buyer: alice(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		let sale_hash = generate_hash(bob());
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
 
-		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Check for CarbonCreditBatchDoesNotExist error
-		assert_err!(
-			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
-			Error::<Test>::CarbonCreditBatchDoesNotExist
-		);
-	});
-}
+		ProjectOwners::<Test>::insert(alice(), owner);
 
-#[test]
-fn close_sale_order_ok() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Insert trader account
-		let mut traders = BTreeSet::<AccountId>::new();
-		traders.insert(bob());
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
 
-		TraderAccounts::<Test>::set(traders);
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
 
-		// Insert credit batch
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from(
-				"batch_documentation_ipfs",
-			),
-			project_hash: generate_hash(charlie()),
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			credit_amount: BalanceOf::<Test>::from(100u32),
 			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors,
 		};
 
-		let batch_hash = generate_hash(alice());
+		let batch_hash = generate_hash(bob());
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
 		// Insert carbon credit holdings
 		let credit_holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(50u32),
-			unavailable_amount: BalanceOf::<Test>::from(20u32),
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
 		};
 
 		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
@@ -3945,15 +4836,15 @@ fn close_sale_order_ok() {
 		// Insert sale order
 		let timeout_block = BlockNumber::<Test>::from(100u32);
 
-		// Insert sale order
 		let sale_order = CarbonCreditSaleOrderInfo {
 			batch_hash,
 			credit_amount: BalanceOf::<Test>::from(10u32),
-			credit_price: BalanceOf::<Test>::from(5u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(50u32),
 			seller: bob(),
-			buyer: bob(),
-			sale_active: true,
-			sale_timeout: timeout_block,
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
 		let sale_hash = generate_hash(bob());
@@ -3961,238 +4852,196 @@ fn close_sale_order_ok() {
 		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
 		// Insert sale timeout
-		let mut sale_timeouts = BTreeSet::<H256>::new();
-		sale_timeouts.insert(sale_hash);
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
 
 		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
 
-		// Successfully close sale order
-		assert_ok!(Veles::close_sale_order(RuntimeOrigin::signed(bob()), sale_hash,));
-
-		// Check sale order structure
-		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
-		assert_eq!(sale_order.batch_hash, batch_hash);
-		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(10u32));
-		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(5u32));
-		assert_eq!(sale_order.seller, bob());
-		assert_eq!(sale_order.buyer, bob());
-		assert_eq!(sale_order.sale_active, false);
-		assert_eq!(sale_order.sale_timeout, timeout_block);
-
-		// Check credit holdings structure
-		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
-		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(60u32));
-		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(10u32));
-
-		// Check sale timeouts
-		let sale_timeouts = SaleOrderTimeouts::<Test>::get(timeout_block);
-		assert_eq!(sale_timeouts, None);
-	});
-}
-
-#[test]
-fn open_account_complaint_user_is_not_a_registered_validator() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
-
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		// Set a maker fee that takes the whole fill price (above the maximum trading fee)
+		TradingFees::<Test>::set(TradingFeeValues {
+			maker_fee: Permill::one(),
+			taker_fee: Permill::zero(),
+		});
 
-		// Check for UserIsNotARegisteredValidator error
+		// Check for TradingFeeExceedsSaleProceeds error (the beneficiary splits are paid first)
 		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(alice()),
-				documentation_ipfs,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::UserIsNotARegisteredValidator
+			Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash, None),
+			Error::<Test>::TradingFeeExceedsSaleProceeds
 		);
 	});
 }
 
 #[test]
-fn open_account_complaint_documentation_was_used_previously() {
+fn complete_sale_order_project_beneficiary_splits_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: documentation_ipfs.clone(),
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(alice(), validator);
-
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Check for DocumentationWasUsedPreviously error
-		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(alice()),
-				documentation_ipfs,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::DocumentationWasUsedPreviously
-		);
-	});
-}
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
 
-#[test]
-fn open_account_complaint_insufficient_funds() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		ProjectOwners::<Test>::insert(alice(), owner);
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(alice(), validator);
+		let project_hash = generate_hash(alice());
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		Projects::<Test>::insert(project_hash, project);
 
-		// Check for InsufficientFunds error
-		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(alice()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::InsufficientFunds
-		);
-	});
-}
+		// Insert project beneficiary splits (george gets 4% and hank gets 1% of every sale)
+		let mut stakeholders = StakeholderSharesOf::<Test>::new();
+		stakeholders.try_insert(george(), Permill::from_parts(40_000)).unwrap();
+		stakeholders.try_insert(hank(), Permill::from_parts(10_000)).unwrap();
 
-#[test]
-fn open_account_complaint_unregistered_account_id() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		let project_splits = ProjectBeneficiarySplitsInfo {
+			beneficiary_splits: BeneficiarySplits::<Test>::get(),
+			stakeholders,
+		};
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		ProjectBeneficiarySplits::<Test>::insert(project_hash, project_splits);
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let batch_hash = generate_hash(bob());
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
-		// Check for UnregisteredAccountId error
-		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::UnregisteredAccountId
-		);
-	});
-}
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-#[test]
-fn open_account_complaint_invalid_complaint_type() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert sale order
+		let timeout_block = BlockNumber::<Test>::from(100u32);
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash,
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: bob(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let sale_hash = generate_hash(bob());
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		// Check for InvalidComplaintType error
-		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::InvalidComplaintType
+		// Insert sale timeout
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
+
+		// Successfully complete sale order
+		assert_ok!(Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash, None));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderCompleted(charlie(), sale_hash, 0, 0).into(),
 		);
+
+		// Check balances after extrinsic call
+		// Note: The hank share is below the smallest unit so it stays with the seller
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 127);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 6);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 2);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(hank()), 0);
 	});
 }
 
 #[test]
-fn open_account_complaint_project_owner_ok() {
+fn complete_sale_order_private_sale_order_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Insert project owner
-		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
 
+		// Insert project owner
 		let owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		ProjectOwners::<Test>::insert(bob(), owner);
+		ProjectOwners::<Test>::insert(alice(), owner);
 
 		// Insert project
-		let project_documentation =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation");
-
 		let project = ProjectInfo {
-			documentation_ipfs: project_documentation,
-			project_owner: bob(),
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
 		let project_hash = generate_hash(alice());
@@ -4200,1242 +5049,7028 @@ fn open_account_complaint_project_owner_ok() {
 		Projects::<Test>::insert(project_hash, project);
 
 		// Insert carbon credit batch
-		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
 
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation,
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
 			project_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			credit_amount: BalanceOf::<Test>::from(100u32),
-			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors,
 		};
 
 		let batch_hash = generate_hash(bob());
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ProjectOwnerComplaint;
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		// Check balances before extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Successfully open account complaint
-		assert_ok!(Veles::open_account_complaint(
-			RuntimeOrigin::signed(charlie()),
-			complaint_documentation.clone(),
-			complaint_for,
-			complaint_type,
+		// Successfully create a private sale order (only charlie can fill it)
+		let mut allowed_buyers = AllowedBuyersOf::<Test>::new();
+		allowed_buyers.try_insert(charlie()).unwrap();
+
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+			None,
+			Some(allowed_buyers.clone()),
+			None,
 		));
 
-		// Check complaint structure
-		let complaint =
-			ComplaintsForAccounts::<Test>::get(complaint_documentation.clone()).unwrap();
+		let sale_hash = generate_hash(bob());
 
-		assert_eq!(complaint.complaint_for, bob());
-		assert_eq!(complaint.complaint_type, ComplaintType::ProjectOwnerComplaint);
-		assert_eq!(complaint.complaint_proposer, charlie());
-		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.complaint_active, true);
+		assert_eq!(SaleOrderAllowedBuyers::<Test>::get(sale_hash), Some(allowed_buyers));
 
-		// Check complaint timeout
-		let current_block = frame_system::Pallet::<Test>::block_number();
-		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+		// Check for BuyerIsNotAllowedToFillTheSaleOrder error
+		assert_err!(
+			Veles::complete_sale_order(RuntimeOrigin::signed(alice()), sale_hash, None),
+			Error::<Test>::BuyerIsNotAllowedToFillTheSaleOrder
+		);
 
-		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+		// Successfully complete sale order
+		assert_ok!(Veles::complete_sale_order(RuntimeOrigin::signed(charlie()), sale_hash, None));
 
-		assert_eq!(complaint_timeouts.len(), 1);
-		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderCompleted(charlie(), sale_hash, 0, 0).into(),
+		);
 
-		// Check carbon credit batch status
-		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+		assert_eq!(sale_order.buyer, Some(charlie()));
+		assert_eq!(sale_order.status, SaleOrderStatus::Filled);
 
-		assert_eq!(
-			batch.documentation_ipfs,
-			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
-		);
-		assert_eq!(batch.project_hash, project_hash);
-		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
-		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
-		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
+		// Check if the allowed buyers are pruned with the sale order
+		run_to_block(101);
+		Veles::on_idle(101, Weight::MAX);
 
-		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+		assert_eq!(CarbonCreditSaleOrders::<Test>::contains_key(sale_hash), false);
+		assert_eq!(SaleOrderAllowedBuyers::<Test>::contains_key(sale_hash), false);
 	});
 }
 
 #[test]
-fn open_account_complaint_validator_ok() {
+fn complete_sale_order_partial_fills_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_1_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_1_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		// Insert validator #1
+		// Insert validators
 		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_1_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(charlie(), validator_1);
-
-		// Insert validator #2
-		let validator_2_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_2_documentation");
-
 		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_2_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(bob(), validator_2);
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Check balances before extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+		ProjectOwners::<Test>::insert(alice(), owner);
 
-		// Successfully open account complaint
-		assert_ok!(Veles::open_account_complaint(
-			RuntimeOrigin::signed(charlie()),
-			complaint_documentation.clone(),
-			complaint_for,
-			complaint_type,
-		));
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		// Check complaint structure
-		let complaint =
-			ComplaintsForAccounts::<Test>::get(complaint_documentation.clone()).unwrap();
+		let project_hash = generate_hash(alice());
 
-		assert_eq!(complaint.complaint_for, bob());
-		assert_eq!(complaint.complaint_type, ComplaintType::ValidatorComplaint);
-		assert_eq!(complaint.complaint_proposer, charlie());
-		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.complaint_active, true);
+		Projects::<Test>::insert(project_hash, project);
 
-		// Check complaint timeout
-		let current_block = frame_system::Pallet::<Test>::block_number();
-		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
 
-		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+		let credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
 
-		assert_eq!(complaint_timeouts.len(), 1);
-		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+		let batch_hash = generate_hash(bob());
 
-		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
-	});
-}
+		CarbonCreditBatches::<Test>::insert(batch_hash, credit_batch);
 
-#[test]
-fn open_account_complaint_max_potential_penalty_level_exceeded() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-		let validator_1_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_1_documentation");
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Insert validator #1
-		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_1_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert sale order
+		let timeout_block = BlockNumber::<Test>::from(100u32);
+
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash,
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(2u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: bob(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
-		Validators::<Test>::insert(charlie(), validator_1);
+		let sale_hash = generate_hash(bob());
 
-		// Insert validator #2
-		let validator_2_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_2_documentation");
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_2_documentation,
-			penalty_level: 4u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert sale timeout
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
+
+		let fill = |amount: Option<u32>| {
+			Veles::complete_sale_order(
+				RuntimeOrigin::signed(charlie()),
+				sale_hash,
+				amount.map(BalanceOf::<Test>::from),
+			)
 		};
 
-		Validators::<Test>::insert(bob(), validator_2);
+		// Check for InvalidFillAmount error
+		assert_err!(fill(Some(0)), Error::<Test>::InvalidFillAmount);
+		assert_err!(fill(Some(11)), Error::<Test>::InvalidFillAmount);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = bob();
-		let complaint_type = ComplaintType::ValidatorComplaint;
+		// Check for FillAmountBelowMinimum error
+		assert_err!(fill(Some(1)), Error::<Test>::FillAmountBelowMinimum);
 
-		// Check for MaxPotentialPenaltyLevelExceeded error
-		assert_err!(
-			Veles::open_account_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation.clone(),
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::MaxPotentialPenaltyLevelExceeded,
+		// Successfully fill a part of the sale order
+		assert_ok!(fill(Some(2)));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderFilled(charlie(), sale_hash, 2).into(),
+		);
+
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(8u32));
+		assert_eq!(sale_order.buyer, None);
+		assert_eq!(sale_order.status, SaleOrderStatus::Open);
+
+		let seller_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(seller_holdings.available_amount, BalanceOf::<Test>::from(90u32));
+		assert_eq!(seller_holdings.unavailable_amount, BalanceOf::<Test>::from(8u32));
+
+		let buyer_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(buyer_holdings.available_amount, BalanceOf::<Test>::from(2u32));
+
+		// Successfully fill another part of the sale order
+		assert_ok!(fill(Some(3)));
+
+		// Check for TooManyFills error (the last fill has to take the remaining amount)
+		assert_err!(fill(Some(2)), Error::<Test>::TooManyFills);
+
+		// Successfully fill the rest of the sale order
+		assert_ok!(fill(None));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderCompleted(charlie(), sale_hash, 0, 0).into(),
 		);
+
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(0u32));
+		assert_eq!(sale_order.buyer, Some(charlie()));
+		assert_eq!(sale_order.status, SaleOrderStatus::Filled);
+		assert_eq!(SaleOrderTimeouts::<Test>::get(timeout_block), None);
+
+		let seller_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(seller_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		let buyer_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(buyer_holdings.available_amount, BalanceOf::<Test>::from(10u32));
+
+		// Check sale order fills
+		let fills = SaleOrderFills::<Test>::get(sale_hash).unwrap();
+		assert_eq!(fills.len(), 3);
+		assert_eq!(fills[0].credit_amount, BalanceOf::<Test>::from(2u32));
+		assert_eq!(fills[1].credit_amount, BalanceOf::<Test>::from(3u32));
+		assert_eq!(fills[2].credit_amount, BalanceOf::<Test>::from(5u32));
+
+		// Check for SaleOrderIsNotActive error
+		assert_err!(fill(None), Error::<Test>::SaleOrderIsNotActive);
+
+		// Check if the sale order fills are pruned together with the sale order
+		run_to_block(101);
+		Veles::on_idle(101, Weight::MAX);
+
+		assert_eq!(CarbonCreditSaleOrders::<Test>::contains_key(sale_hash), false);
+		assert_eq!(SaleOrderFills::<Test>::contains_key(sale_hash), false);
 	});
 }
 
 #[test]
-fn open_hash_complaint_user_is_not_a_registered_validator() {
+fn close_sale_order_user_is_not_eligible_for_carbon_credit_transactions() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::ProjectComplaint;
+		let sale_hash = generate_hash(alice());
 
-		// Check for UserIsNotARegisteredValidator error
+		// Check for UserIsNotEligibleForCarbonCreditTransactions error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(alice()),
-				documentation_ipfs,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::UserIsNotARegisteredValidator
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_documentation_was_used_previously() {
+fn close_sale_order_project_owner_has_standing_debts() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+		// Insert project owner
+		let owner_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: documentation_ipfs.clone(),
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		Validators::<Test>::insert(alice(), validator);
+		ProjectOwners::<Test>::insert(alice(), project_owner);
 
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::ProjectComplaint;
+		// Insert project owner debts
+		let mut debts = DebtsOf::<Test>::new();
 
-		// Check for DocumentationWasUsedPreviously error
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(20u32)).unwrap();
+
+		ProjectOwnerDebts::<Test>::insert(alice(), debts);
+
+		let sale_hash = generate_hash(alice());
+
+		// Check for ProjectOwnerHasStandingDebts error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(alice()),
-				documentation_ipfs,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::DocumentationWasUsedPreviously
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::ProjectOwnerHasStandingDebts
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_insufficient_funds() {
+fn close_sale_order_carbon_credit_sale_order_doesnt_exist() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
-
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
-
-		Validators::<Test>::insert(alice(), validator);
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::ProjectComplaint;
+		let sale_hash = generate_hash(alice());
 
-		// Check for InsufficientFunds error
+		// Check for CarbonCreditSaleOrderDoesntExist error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(alice()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::InsufficientFunds
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::CarbonCreditSaleOrderDoesntExist
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_unregistered_hash() {
+fn close_sale_order_sale_order_is_not_active() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: generate_hash(alice()),
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: bob(),
+			buyer: Some(bob()),
+			status: SaleOrderStatus::Filled,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let sale_hash = generate_hash(bob());
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::ProjectComplaint;
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		// Check for UnregisteredHash error
+		// Check for SaleOrderIsNotActive error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::UnregisteredHash
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::SaleOrderIsNotActive
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_carbon_credit_batch_does_not_exist() {
+fn close_sale_order_user_didnt_create_the_sale_order() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		CarbonCreditHoldings::<Test>::insert(generate_hash(alice()), bob(), credit_holdings);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: generate_hash(alice()),
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: bob(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
+		};
 
-		// Check for CarbonCreditBatchDoesNotExist error
+		let sale_hash = generate_hash(bob());
+
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
+		// Check for UserDidntCreateTheSaleOrder error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::CarbonCreditBatchDoesNotExist
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::UserDidntCreateTheSaleOrder
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_invalid_complaint_type() {
+fn close_sale_order_carbon_credit_batch_does_not_exist() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		CarbonCreditHoldings::<Test>::insert(generate_hash(alice()), alice(), credit_holdings);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = generate_hash(bob());
-		let complaint_type = ComplaintType::ProjectOwnerComplaint;
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: generate_hash(alice()),
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: alice(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
+		};
 
-		// Check for InvalidComplaintType error
+		let sale_hash = generate_hash(bob());
+
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
+		// Check for CarbonCreditBatchDoesNotExist error
 		assert_err!(
-			Veles::open_hash_complaint(
-				RuntimeOrigin::signed(charlie()),
-				complaint_documentation,
-				complaint_for,
-				complaint_type,
-			),
-			Error::<Test>::InvalidComplaintType
+			Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash,),
+			Error::<Test>::CarbonCreditBatchDoesNotExist
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_project_max_potential_penalty_level_exceeded() {
+fn close_sale_order_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from(
+				"batch_documentation_ipfs",
+			),
+			project_hash: generate_hash(charlie()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let batch_hash = generate_hash(alice());
 
-		// Insert project owner
-		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(90u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
 		};
 
-		ProjectOwners::<Test>::insert(bob(), owner);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Insert project
-		let project_documentation =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+		// Insert sale order
+		let timeout_block = BlockNumber::<Test>::from(100u32);
 
-		let project = ProjectInfo {
-			documentation_ipfs: project_documentation,
-			project_owner: bob(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 4u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash,
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: bob(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(timeout_block),
 		};
 
-		let project_hash = generate_hash(alice());
+		let sale_hash = generate_hash(bob());
 
-		Projects::<Test>::insert(project_hash, project);
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		// Insert carbon credit batch
-		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+		// Insert sale timeout
+		let mut sale_timeouts = TimeoutsOf::<Test, H256>::new();
+		sale_timeouts.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(timeout_block, sale_timeouts);
+
+		// Successfully close sale order
+		assert_ok!(Veles::close_sale_order(RuntimeOrigin::signed(bob()), sale_hash,));
+
+		// Check sale order structure
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+		assert_eq!(sale_order.batch_hash, batch_hash);
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(sale_order.seller, bob());
+		assert_eq!(sale_order.buyer, None);
+		assert_eq!(sale_order.status, SaleOrderStatus::Cancelled);
+		assert_eq!(sale_order.sale_timeout, Some(timeout_block));
+
+		// Check credit holdings structure
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(100u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		// Check sale timeouts
+		let sale_timeouts = SaleOrderTimeouts::<Test>::get(timeout_block);
+		assert_eq!(sale_timeouts, None);
+	});
+}
+
+#[test]
+fn transfer_carbon_credits_user_is_not_eligible_for_carbon_credit_transactions() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
 
+		// Insert carbon credit batch
 		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation,
-			project_hash,
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
-			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(bob());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = project_hash;
-		let complaint_type = ComplaintType::ProjectComplaint;
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		// Check for MaxPotentialPenaltyLevelExceeded error
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Check for UserIsNotEligibleForCarbonCreditTransactions error (sender)
 		assert_err!(
-			Veles::open_hash_complaint(
+			Veles::transfer_carbon_credits(
 				RuntimeOrigin::signed(charlie()),
-				complaint_documentation.clone(),
-				complaint_for,
-				complaint_type,
+				batch_hash,
+				bob(),
+				BalanceOf::<Test>::from(10u32),
 			),
-			Error::<Test>::MaxPotentialPenaltyLevelExceeded,
+			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
+		);
+
+		// Check for UserIsNotEligibleForCarbonCreditTransactions error (receiver)
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::UserIsNotEligibleForCarbonCreditTransactions
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_project_ok() {
+fn transfer_carbon_credits_project_owner_has_standing_debts() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+		// Insert trader account and project owner with standing debts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		ProjectOwners::<Test>::insert(charlie(), ProjectValidatorOrProjectOwnerInfo::default());
 
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		let mut debts = DebtsOf::<Test>::new();
+		debts.try_insert(dave(), BalanceOf::<Test>::from(10u32)).unwrap();
+
+		ProjectOwnerDebts::<Test>::insert(charlie(), debts);
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		Validators::<Test>::insert(charlie(), validator);
+		let batch_hash = generate_hash(bob());
 
-		// Insert project owner
-		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
 		};
 
-		ProjectOwners::<Test>::insert(bob(), owner);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Insert project
-		let project_documentation =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+		// Check for ProjectOwnerHasStandingDebts error
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::ProjectOwnerHasStandingDebts
+		);
+	});
+}
 
-		let project = ProjectInfo {
-			documentation_ipfs: project_documentation,
-			project_owner: bob(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
+#[test]
+fn transfer_carbon_credits_carbon_credit_batch_is_not_active() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		let project_hash = generate_hash(alice());
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		Projects::<Test>::insert(project_hash, project);
+		// Check for CarbonCreditBatchDoesNotExist error
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				generate_hash(bob()),
+				charlie(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::CarbonCreditBatchDoesNotExist
+		);
 
 		// Insert carbon credit batch
-		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
-
 		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation,
-			project_hash,
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
-			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(bob());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = project_hash;
-		let complaint_type = ComplaintType::ProjectComplaint;
-
-		// Check balances before extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
-
-		// Successfully open account complaint
-		assert_ok!(Veles::open_hash_complaint(
-			RuntimeOrigin::signed(charlie()),
-			complaint_documentation.clone(),
-			complaint_for,
-			complaint_type,
-		));
-
-		// Check complaint structure
-		let complaint = ComplaintsForHashes::<Test>::get(complaint_documentation.clone()).unwrap();
-
-		assert_eq!(complaint.complaint_for, project_hash);
-		assert_eq!(complaint.complaint_type, ComplaintType::ProjectComplaint);
-		assert_eq!(complaint.complaint_proposer, charlie());
-		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.complaint_active, true);
-
-		// Check complaint timeout
-		let current_block = frame_system::Pallet::<Test>::block_number();
-		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
-
-		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		assert_eq!(complaint_timeouts.len(), 1);
-		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Check carbon credit batch status
-		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+		CarbonCreditBatches::<Test>::mutate(batch_hash, |batch| {
+			batch.as_mut().unwrap().status = CarbonCreditBatchStatus::Frozen;
+		});
 
-		assert_eq!(
-			batch.documentation_ipfs,
-			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
+		// Check for CarbonCreditBatchIsNotActive error
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::CarbonCreditBatchIsNotActive
 		);
-		assert_eq!(batch.project_hash, project_hash);
-		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
-		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
-		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
-
-		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
 	});
 }
 
 #[test]
-fn open_hash_complaint_batch_ongoing_carbon_batch_complaint() {
+fn transfer_carbon_credits_not_enought_available_credits() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
-
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
-
-		Validators::<Test>::insert(charlie(), validator);
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
-		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
-		let project_hash = generate_hash(alice());
-
 		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation,
-			project_hash,
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
-			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(bob());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let complaint_documentation_1 =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation_1");
-		let complaint_for = batch_hash;
-		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		// Successfully open account complaint
-		assert_ok!(Veles::open_hash_complaint(
-			RuntimeOrigin::signed(charlie()),
-			complaint_documentation_1,
-			complaint_for,
-			complaint_type,
-		));
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		let complaint_documentation_2 =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation_2");
-		let complaint_for = batch_hash;
-		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+		// Check for CantTransferCarbonCreditsToSelf error
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				bob(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::CantTransferCarbonCreditsToSelf
+		);
 
-		// Check for OngoingCarbonBatchComplaint error
+		// Check for InvalidCarbonCreditAmount error
 		assert_err!(
-			Veles::open_hash_complaint(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(0u32),
+			),
+			Error::<Test>::InvalidCarbonCreditAmount
+		);
+
+		// Check for CarbonCreditHoldingsDontExist error
+		assert_err!(
+			Veles::transfer_carbon_credits(
 				RuntimeOrigin::signed(charlie()),
-				complaint_documentation_2,
-				complaint_for,
-				complaint_type,
+				batch_hash,
+				bob(),
+				BalanceOf::<Test>::from(10u32),
 			),
-			Error::<Test>::OngoingCarbonBatchComplaint,
+			Error::<Test>::CarbonCreditHoldingsDontExist
+		);
+
+		// Check for NotEnoughtAvailableCredits error
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(50u32),
+			),
+			Error::<Test>::NotEnoughtAvailableCredits
 		);
 	});
 }
 
 #[test]
-fn open_hash_complaint_batch_ok() {
+fn transfer_carbon_credits_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let validator_documentation =
-			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
-
-		// Insert validator
-		let validator = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: validator_documentation,
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
-
-		Validators::<Test>::insert(charlie(), validator);
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
 		// Insert carbon credit batch
-		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
-		let project_hash = generate_hash(alice());
-
 		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation,
-			project_hash,
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(100u32),
-			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
 			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
 		let batch_hash = generate_hash(bob());
 
 		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let complaint_documentation =
-			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
-		let complaint_for = batch_hash;
-		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		// Check balances before extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Successfully open account complaint
-		assert_ok!(Veles::open_hash_complaint(
-			RuntimeOrigin::signed(charlie()),
-			complaint_documentation.clone(),
-			complaint_for,
-			complaint_type,
+		// Successfully transfer carbon credits
+		assert_ok!(Veles::transfer_carbon_credits(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			charlie(),
+			BalanceOf::<Test>::from(15u32),
 		));
 
-		// Check complaint structure
-		let complaint = ComplaintsForHashes::<Test>::get(complaint_documentation.clone()).unwrap();
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditsTransferred(batch_hash, bob(), charlie(), 15).into(),
+		);
 
-		assert_eq!(complaint.complaint_for, batch_hash);
-		assert_eq!(complaint.complaint_type, ComplaintType::CarbonCreditBatchComplaint);
-		assert_eq!(complaint.complaint_proposer, charlie());
-		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
-		assert_eq!(complaint.complaint_active, true);
+		// Check carbon credit holdings
+		let sender_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(sender_holdings.available_amount, BalanceOf::<Test>::from(25u32));
+		assert_eq!(sender_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
 
-		// Check complaint timeout
-		let current_block = frame_system::Pallet::<Test>::block_number();
-		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+		let receiver_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(receiver_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+		assert_eq!(receiver_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
 
-		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+		// Transfer all of the remaining carbon credits (empty holdings are removed)
+		assert_ok!(Veles::transfer_carbon_credits(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			charlie(),
+			BalanceOf::<Test>::from(25u32),
+		));
 
-		assert_eq!(complaint_timeouts.len(), 1);
-		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+		assert_eq!(CarbonCreditHoldings::<Test>::contains_key(batch_hash, bob()), false);
 
-		// Check carbon credit batch status
-		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+		let receiver_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(receiver_holdings.available_amount, BalanceOf::<Test>::from(40u32));
 
-		assert_eq!(
-			batch.documentation_ipfs,
-			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
+		// Check that no beneficiary splits were made (transfer royalties are disabled)
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 100);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+	});
+}
+
+#[test]
+fn transfer_carbon_credits_with_royalties_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Enable transfer royalties
+		TransferRoyaltiesEnabled::<Test>::set(true);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Check for InsufficientFunds error (transfer is valued at 200)
+		assert_err!(
+			Veles::transfer_carbon_credits(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				charlie(),
+				BalanceOf::<Test>::from(20u32),
+			),
+			Error::<Test>::InsufficientFunds
 		);
-		assert_eq!(batch.project_hash, project_hash);
-		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
-		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
-		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
 
-		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+		// Successfully transfer carbon credits (transfer is valued at 50)
+		assert_ok!(Veles::transfer_carbon_credits(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			charlie(),
+			BalanceOf::<Test>::from(5u32),
+		));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditsTransferred(batch_hash, bob(), charlie(), 5).into(),
+		);
+
+		// Check carbon credit holdings
+		let sender_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(sender_holdings.available_amount, BalanceOf::<Test>::from(35u32));
+
+		let receiver_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(receiver_holdings.available_amount, BalanceOf::<Test>::from(5u32));
+
+		// Check balances (secondary beneficiary split is paid by the sender)
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 79);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 6);
 	});
 }
 
 #[test]
-fn retire_carbon_credits_user_is_not_of_a_carbon_footprint_account_type() {
+fn create_buy_order_carbon_credit_batch_does_not_exist() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		let batch_hash = generate_hash(alice());
-		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
 
-		// Check for UserIsNotOfACarbonFootprintAccountType error
+		// Check for CarbonCreditBatchDoesNotExist error
 		assert_err!(
-			Veles::retire_carbon_credits(
-				RuntimeOrigin::signed(alice()),
-				batch_hash,
-				amount_to_retire,
+			Veles::create_buy_order(
+				RuntimeOrigin::signed(charlie()),
+				BuyOrderTarget::Batch(generate_hash(bob())),
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(10u32),
 			),
-			Error::<Test>::UserIsNotOfACarbonFootprintAccountType
+			Error::<Test>::CarbonCreditBatchDoesNotExist
+		);
+
+		// Check for ProjectDoesntExist error
+		assert_err!(
+			Veles::create_buy_order(
+				RuntimeOrigin::signed(charlie()),
+				BuyOrderTarget::Project(generate_hash(alice())),
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::ProjectDoesntExist
 		);
 	});
 }
 
 #[test]
-fn retire_carbon_credits_carbon_credit_batch_does_not_exist() {
+fn create_buy_order_insufficient_funds() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+		// Insert trader account
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
 
-		documentation_ipfses.insert(documentation_ipfs);
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		let carbon_footprint_account = CarbonFootprintAccountInfo {
-			documentation_ipfses,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Check for InsufficientFunds error (buy order is worth 150)
+		assert_err!(
+			Veles::create_buy_order(
+				RuntimeOrigin::signed(bob()),
+				BuyOrderTarget::Project(project_hash),
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(30u32),
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn create_buy_order_arithmetic_overflow() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+		let project_hash = generate_hash(alice());
 
-		let batch_hash = generate_hash(alice());
-		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+		Projects::<Test>::insert(project_hash, project);
+
+		// Check for ArithmeticOverflow error (buy order value overflows)
+		assert_err!(
+			Veles::create_buy_order(
+				RuntimeOrigin::signed(bob()),
+				BuyOrderTarget::Project(project_hash),
+				BalanceOf::<Test>::MAX,
+				BalanceOf::<Test>::from(2u32),
+			),
+			Error::<Test>::ArithmeticOverflow
+		);
+	});
+}
+
+#[test]
+fn create_buy_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(0u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Successfully create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Batch(batch_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+		let timeout_block = 1 + PalletTimeValues::<Test>::get().sales_timeout;
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditBuyOrderCreated(charlie(), buy_hash, 10, 5).into(),
+		);
+
+		// Check buy order and buy order timeout event
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
+
+		assert_eq!(buy_order.target, BuyOrderTarget::Batch(batch_hash));
+		assert_eq!(buy_order.credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(buy_order.credit_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(buy_order.buyer, charlie());
+		assert_eq!(buy_order.buy_active, true);
+		assert_eq!(buy_order.buy_timeout, timeout_block);
+		assert!(BuyOrderTimeouts::<Test>::get(timeout_block).unwrap().contains(&buy_hash));
+
+		// Check held buyer funds
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()),
+			50
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
+	});
+}
+
+#[test]
+fn cancel_buy_order_user_didnt_create_the_buy_order() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account and project
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+
+		// Check for CarbonCreditBuyOrderDoesntExist error
+		assert_err!(
+			Veles::cancel_buy_order(RuntimeOrigin::signed(charlie()), generate_hash(bob())),
+			Error::<Test>::CarbonCreditBuyOrderDoesntExist
+		);
+
+		// Check for UserDidntCreateTheBuyOrder error
+		assert_err!(
+			Veles::cancel_buy_order(RuntimeOrigin::signed(bob()), buy_hash),
+			Error::<Test>::UserDidntCreateTheBuyOrder
+		);
+	});
+}
+
+#[test]
+fn cancel_buy_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account and project
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+		let timeout_block = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap().buy_timeout;
+
+		// Successfully cancel buy order
+		assert_ok!(Veles::cancel_buy_order(RuntimeOrigin::signed(charlie()), buy_hash));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditBuyOrderCancelled(charlie(), buy_hash).into(),
+		);
+
+		// Check buy order, timeout events and released buyer funds
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
+
+		assert_eq!(buy_order.buy_active, false);
+		assert_eq!(BuyOrderTimeouts::<Test>::get(timeout_block), None);
+		assert!(PruningTimeouts::<Test>::get(101)
+			.unwrap()
+			.contains(&FinishedRecord::CarbonCreditBuyOrder(buy_hash)));
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()),
+			0
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+
+		// Check for BuyOrderIsNotActive error
+		assert_err!(
+			Veles::cancel_buy_order(RuntimeOrigin::signed(charlie()), buy_hash),
+			Error::<Test>::BuyOrderIsNotActive
+		);
+	});
+}
+
+#[test]
+fn fill_buy_order_carbon_credit_batch_doesnt_match_buy_order() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch (from another project)
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(dave()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+
+		// Check for SellerCantFillHisOwnBuyOrder error
+		assert_err!(
+			Veles::fill_buy_order(RuntimeOrigin::signed(charlie()), buy_hash, batch_hash, None),
+			Error::<Test>::SellerCantFillHisOwnBuyOrder
+		);
+
+		// Check for CarbonCreditBatchDoesntMatchBuyOrder error
+		assert_err!(
+			Veles::fill_buy_order(RuntimeOrigin::signed(bob()), buy_hash, batch_hash, None),
+			Error::<Test>::CarbonCreditBatchDoesntMatchBuyOrder
+		);
+	});
+}
+
+#[test]
+fn fill_buy_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Create buy order (for any batch from the project)
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+		let timeout_block = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap().buy_timeout;
+
+		// Check for InvalidFillAmount error
+		assert_err!(
+			Veles::fill_buy_order(
+				RuntimeOrigin::signed(bob()),
+				buy_hash,
+				batch_hash,
+				Some(BalanceOf::<Test>::from(11u32)),
+			),
+			Error::<Test>::InvalidFillAmount
+		);
+
+		// Successfully fill part of the buy order (fill is worth 20)
+		assert_ok!(Veles::fill_buy_order(
+			RuntimeOrigin::signed(bob()),
+			buy_hash,
+			batch_hash,
+			Some(BalanceOf::<Test>::from(4u32)),
+		));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditBuyOrderFilled(bob(), buy_hash, batch_hash, 4, 0, 0).into(),
+		);
+
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
+
+		assert_eq!(buy_order.credit_amount, BalanceOf::<Test>::from(6u32));
+		assert_eq!(buy_order.buy_active, true);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()),
+			30
+		);
+
+		// Successfully fill the rest of the buy order (fill is worth 30)
+		assert_ok!(Veles::fill_buy_order(RuntimeOrigin::signed(bob()), buy_hash, batch_hash, None));
+
+		System::assert_has_event(
+			Event::<Test>::CarbonCreditBuyOrderFilled(bob(), buy_hash, batch_hash, 6, 0, 0).into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditBuyOrderCompleted(bob(), buy_hash).into(),
+		);
+
+		// Check buy order and timeout events
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
+
+		assert_eq!(buy_order.credit_amount, BalanceOf::<Test>::from(0u32));
+		assert_eq!(buy_order.buy_active, false);
+		assert_eq!(BuyOrderTimeouts::<Test>::get(timeout_block), None);
+		assert!(PruningTimeouts::<Test>::get(101)
+			.unwrap()
+			.contains(&FinishedRecord::CarbonCreditBuyOrder(buy_hash)));
+
+		// Check carbon credit holdings
+		let seller_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(seller_holdings.available_amount, BalanceOf::<Test>::from(30u32));
+
+		let buyer_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(buyer_holdings.available_amount, BalanceOf::<Test>::from(10u32));
+
+		// Check balances (secondary beneficiary split is done for every fill)
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()),
+			0
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 129);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 8);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 6);
+
+		// Check for BuyOrderIsNotActive error
+		assert_err!(
+			Veles::fill_buy_order(RuntimeOrigin::signed(bob()), buy_hash, batch_hash, None),
+			Error::<Test>::BuyOrderIsNotActive
+		);
+	});
+}
+
+#[test]
+fn fill_buy_order_trading_fees_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Set maker (2%) and taker (10%) trading fees (paid to the beneficiary)
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Maker),
+			20_000
+		));
+
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Taker),
+			100_000
+		));
+
+		TradingFeeBeneficiary::set(Some(george()));
+
+		// Create buy order (charlie is the maker)
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let buy_hash = generate_hash(charlie());
+
+		// Successfully fill the buy order (fill is worth 50)
+		// Note: The buyer pays the maker fee on top of the held funds and the taker fee is
+		// deducted from the seller proceeds
+		assert_ok!(Veles::fill_buy_order(RuntimeOrigin::signed(bob()), buy_hash, batch_hash, None));
+
+		System::assert_has_event(
+			Event::<Test>::CarbonCreditBuyOrderFilled(bob(), buy_hash, batch_hash, 10, 1, 5).into(),
+		);
+
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 6);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()), 0);
+
+		TradingFeeBeneficiary::set(None);
+	});
+}
+
+#[test]
+fn place_limit_order_insufficient_funds() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let bob_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(10u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), bob_holdings);
+
+		// Check for InvalidCarbonCreditAmount error
+		assert_err!(
+			Veles::place_limit_order(
+				RuntimeOrigin::signed(charlie()),
+				batch_hash,
+				OrderSide::Bid,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(0u32),
+			),
+			Error::<Test>::InvalidCarbonCreditAmount
+		);
+
+		// Check for InsufficientFunds error
+		assert_err!(
+			Veles::place_limit_order(
+				RuntimeOrigin::signed(charlie()),
+				batch_hash,
+				OrderSide::Bid,
+				BalanceOf::<Test>::from(1000u32),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::InsufficientFunds
+		);
+
+		// Check for CarbonCreditHoldingsDontExist error
+		assert_err!(
+			Veles::place_limit_order(
+				RuntimeOrigin::signed(charlie()),
+				batch_hash,
+				OrderSide::Ask,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(10u32),
+			),
+			Error::<Test>::CarbonCreditHoldingsDontExist
+		);
+
+		// Check for NotEnoughtAvailableCredits error
+		assert_err!(
+			Veles::place_limit_order(
+				RuntimeOrigin::signed(bob()),
+				batch_hash,
+				OrderSide::Ask,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(11u32),
+			),
+			Error::<Test>::NotEnoughtAvailableCredits
+		);
+	});
+}
+
+#[test]
+fn place_limit_order_order_book_is_full() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader account
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(0u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Fill the bid side of the order book (new nonce for every order)
+		for _ in 0..MaxOrdersPerBook::get() {
+			assert_ok!(Veles::place_limit_order(
+				RuntimeOrigin::signed(charlie()),
+				batch_hash,
+				OrderSide::Bid,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(1u32),
+			));
+
+			System::inc_account_nonce(charlie());
+		}
+
+		// Check for OrderBookIsFull error
+		assert_err!(
+			Veles::place_limit_order(
+				RuntimeOrigin::signed(charlie()),
+				batch_hash,
+				OrderSide::Bid,
+				BalanceOf::<Test>::from(5u32),
+				BalanceOf::<Test>::from(1u32),
+			),
+			Error::<Test>::OrderBookIsFull
+		);
+	});
+}
+
+#[test]
+fn place_limit_order_resting_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let bob_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), bob_holdings);
+
+		// Successfully place bids and an ask that don't cross
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(charlie()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(4u32),
+			BalanceOf::<Test>::from(2u32),
+		));
+
+		let charlie_order_hash_1 = generate_hash(charlie());
+		System::inc_account_nonce(charlie());
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(charlie()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let charlie_order_hash_2 = generate_hash(charlie());
+
+		System::assert_last_event(
+			Event::<Test>::LimitOrderPlaced(
+				charlie(),
+				charlie_order_hash_2,
+				batch_hash,
+				OrderSide::Bid,
+				5,
+				10,
+			)
+			.into(),
+		);
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(4u32),
+		));
+
+		let bob_bid_hash = generate_hash(bob());
+		System::inc_account_nonce(bob());
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(6u32),
+			BalanceOf::<Test>::from(5u32),
+		));
+
+		let bob_ask_hash = generate_hash(bob());
+
+		// Check order book (best price first, then time priority)
+		assert_eq!(
+			OrderBooks::<Test>::get(batch_hash, OrderSide::Bid).unwrap().into_inner(),
+			vec![(5, charlie_order_hash_2), (5, bob_bid_hash), (4, charlie_order_hash_1)]
+		);
+		assert_eq!(
+			OrderBooks::<Test>::get(batch_hash, OrderSide::Ask).unwrap().into_inner(),
+			vec![(6, bob_ask_hash)]
+		);
+		assert_eq!(Veles::order_book_depth(batch_hash, OrderSide::Bid), vec![(5, 14), (4, 2)]);
+		assert_eq!(Veles::order_book_depth(batch_hash, OrderSide::Ask), vec![(6, 5)]);
+
+		let limit_order = LimitOrders::<Test>::get(bob_ask_hash).unwrap();
+
+		assert_eq!(limit_order.owner, bob());
+		assert_eq!(limit_order.credit_amount, BalanceOf::<Test>::from(5u32));
+		assert_eq!(limit_order.creation_block, 1);
+
+		// Check held funds and credits
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &charlie()),
+			58
+		);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &bob()), 20);
+
+		let bob_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(bob_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+		assert_eq!(bob_holdings.unavailable_amount, BalanceOf::<Test>::from(5u32));
+	});
+}
+
+#[test]
+fn place_limit_order_matching_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		let bob_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), bob_holdings);
+
+		// Place asks (alice is the project owner, so her ask is a primary sale)
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(5u32),
+		));
+
+		let alice_order_hash = generate_hash(alice());
+
+		run_to_block(2);
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let bob_order_hash = generate_hash(bob());
+
+		run_to_block(3);
+
+		// Successfully place a crossing bid (fills both asks at their price in time priority)
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(charlie()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(6u32),
+			BalanceOf::<Test>::from(20u32),
+		));
+
+		let charlie_order_hash = generate_hash(charlie());
+
+		System::assert_has_event(
+			Event::<Test>::LimitOrderMatched(
+				batch_hash,
+				alice_order_hash,
+				alice(),
+				charlie(),
+				5,
+				5,
+				0,
+				0,
+			)
+			.into(),
+		);
+		System::assert_has_event(
+			Event::<Test>::LimitOrderMatched(
+				batch_hash,
+				bob_order_hash,
+				bob(),
+				charlie(),
+				10,
+				5,
+				0,
+				0,
+			)
+			.into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::LimitOrderPlaced(
+				charlie(),
+				charlie_order_hash,
+				batch_hash,
+				OrderSide::Bid,
+				6,
+				5,
+			)
+			.into(),
+		);
+
+		// Check order books and limit orders
+		assert_eq!(LimitOrders::<Test>::get(alice_order_hash), None);
+		assert_eq!(LimitOrders::<Test>::get(bob_order_hash), None);
+		assert_eq!(OrderBooks::<Test>::get(batch_hash, OrderSide::Ask), None);
+		assert_eq!(Veles::order_book_depth(batch_hash, OrderSide::Bid), vec![(6, 5)]);
+
+		// Check carbon credit holdings
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		assert_eq!(alice_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+		assert_eq!(alice_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		let bob_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(bob_holdings.available_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(bob_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		let charlie_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(charlie_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+
+		// Check balances (primary beneficiary split for alice, secondary split for bob)
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &charlie()),
+			30
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4895);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 21);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 129);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 13);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 13);
+
+		run_to_block(4);
+
+		// Successfully place a crossing ask (fills the resting bid at its price)
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(4u32),
+			BalanceOf::<Test>::from(3u32),
+		));
+
+		System::assert_last_event(
+			Event::<Test>::LimitOrderMatched(
+				batch_hash,
+				charlie_order_hash,
+				charlie(),
+				bob(),
+				3,
+				6,
+				0,
+				0,
+			)
+			.into(),
+		);
+
+		assert_eq!(LimitOrders::<Test>::get(charlie_order_hash).unwrap().credit_amount, 2);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &charlie()),
+			12
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4895);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 22);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 140);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), 16);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(fred()), 16);
+
+		let charlie_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+		assert_eq!(charlie_holdings.available_amount, BalanceOf::<Test>::from(18u32));
+	});
+}
+
+#[test]
+fn place_limit_order_trading_fees_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(hank(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Set maker (2%) and taker (10%) trading fees (paid to the beneficiary)
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Maker),
+			20_000
+		));
+
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Taker),
+			100_000
+		));
+
+		TradingFeeBeneficiary::set(Some(george()));
+
+		// Place a resting ask (bob is the maker)
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(10u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let bob_order_hash = generate_hash(bob());
+
+		run_to_block(2);
+
+		// Successfully place a crossing bid (fill is worth 100)
+		// Note: The maker fee is deducted from the seller proceeds and the taker pays the taker
+		// fee on top of the fill price
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(charlie()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(10u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		System::assert_has_event(
+			Event::<Test>::LimitOrderMatched(
+				batch_hash,
+				bob_order_hash,
+				bob(),
+				charlie(),
+				10,
+				10,
+				2,
+				10,
+			)
+			.into(),
+		);
+
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 12);
+		assert_eq!(OrderBooks::<Test>::get(batch_hash, OrderSide::Ask), None);
+
+		// Place a resting bid that can't pay the maker fee once its funds are held
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(charlie()), hank(), 56));
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(hank()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let hank_order_hash = generate_hash(hank());
+
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(hank()), charlie(), 5));
+
+		run_to_block(3);
+
+		// Successfully place a crossing ask (the bid that can't pay the maker fee is skipped)
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		System::assert_last_event(
+			Event::<Test>::LimitOrderPlaced(
+				bob(),
+				generate_hash(bob()),
+				batch_hash,
+				OrderSide::Ask,
+				5,
+				10,
+			)
+			.into(),
+		);
+
+		assert_eq!(LimitOrders::<Test>::get(hank_order_hash).unwrap().credit_amount, 10);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 12);
+
+		TradingFeeBeneficiary::set(None);
+	});
+}
+
+#[test]
+fn cancel_limit_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(alice()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let bob_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), bob_holdings);
+
+		// Place a bid and an ask
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(charlie()),
+			batch_hash,
+			OrderSide::Bid,
+			BalanceOf::<Test>::from(4u32),
+			BalanceOf::<Test>::from(10u32),
+		));
+
+		let bid_hash = generate_hash(charlie());
+
+		assert_ok!(Veles::place_limit_order(
+			RuntimeOrigin::signed(bob()),
+			batch_hash,
+			OrderSide::Ask,
+			BalanceOf::<Test>::from(6u32),
+			BalanceOf::<Test>::from(5u32),
+		));
+
+		let ask_hash = generate_hash(bob());
+
+		// Check for UserDidntCreateTheLimitOrder error
+		assert_err!(
+			Veles::cancel_limit_order(RuntimeOrigin::signed(bob()), bid_hash),
+			Error::<Test>::UserDidntCreateTheLimitOrder
+		);
+
+		// Successfully cancel the bid
+		assert_ok!(Veles::cancel_limit_order(RuntimeOrigin::signed(charlie()), bid_hash));
+
+		System::assert_last_event(Event::<Test>::LimitOrderCancelled(charlie(), bid_hash).into());
+
+		assert_eq!(LimitOrders::<Test>::get(bid_hash), None);
+		assert_eq!(OrderBooks::<Test>::get(batch_hash, OrderSide::Bid), None);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::OrderBookEscrow.into(), &charlie()),
+			0
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+
+		// Successfully cancel the ask
+		assert_ok!(Veles::cancel_limit_order(RuntimeOrigin::signed(bob()), ask_hash));
+
+		System::assert_last_event(Event::<Test>::LimitOrderCancelled(bob(), ask_hash).into());
+
+		assert_eq!(LimitOrders::<Test>::get(ask_hash), None);
+		assert_eq!(OrderBooks::<Test>::get(batch_hash, OrderSide::Ask), None);
+
+		let bob_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+		assert_eq!(bob_holdings.available_amount, BalanceOf::<Test>::from(20u32));
+		assert_eq!(bob_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		// Check for LimitOrderDoesntExist error
+		assert_err!(
+			Veles::cancel_limit_order(RuntimeOrigin::signed(bob()), ask_hash),
+			Error::<Test>::LimitOrderDoesntExist
+		);
+	});
+}
+
+#[test]
+fn create_auction_invalid_auction_parameters() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (alice is the project owner, so her auctions are primary
+		// sales)
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+
+		let english_auction = AuctionType::English { reserve_price: BalanceOf::<Test>::from(2u32) };
+		let sales_timeout = PalletTimeValues::<Test>::get().sales_timeout;
+
+		// Create auction without a duration
+		assert_err!(
+			Veles::create_auction(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(10u32),
+				english_auction.clone(),
+				0,
+			),
+			Error::<Test>::InvalidAuctionDuration
+		);
+
+		// Create auction that lasts longer than the sales timeout
+		assert_err!(
+			Veles::create_auction(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(10u32),
+				english_auction.clone(),
+				sales_timeout + 1,
+			),
+			Error::<Test>::InvalidAuctionDuration
+		);
+
+		// Create Dutch auction with a start price below the floor price
+		assert_err!(
+			Veles::create_auction(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(10u32),
+				AuctionType::Dutch {
+					start_price: BalanceOf::<Test>::from(4u32),
+					floor_price: BalanceOf::<Test>::from(5u32),
+					price_decrement: BalanceOf::<Test>::from(1u32),
+				},
+				10,
+			),
+			Error::<Test>::InvalidAuctionPrices
+		);
+
+		// Create auction with more credits than available
+		assert_err!(
+			Veles::create_auction(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				BalanceOf::<Test>::from(21u32),
+				english_auction,
+				10,
+			),
+			Error::<Test>::NotEnoughtAvailableCredits
+		);
+	});
+}
+
+#[test]
+fn place_auction_bid_english_auction_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (alice is the project owner, so her auctions are primary
+		// sales)
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+
+		// Successfully create an English auction (ends in block 21)
+		assert_ok!(Veles::create_auction(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(10u32),
+			AuctionType::English { reserve_price: BalanceOf::<Test>::from(2u32) },
+			20,
+		));
+
+		let auction_hash = generate_hash(alice());
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditAuctionCreated(
+				alice(),
+				auction_hash,
+				batch_hash,
+				BalanceOf::<Test>::from(10u32),
+			)
+			.into(),
+		);
+
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(alice_holdings.available_amount, 10);
+		assert_eq!(alice_holdings.unavailable_amount, 10);
+		assert_eq!(AuctionTimeouts::<Test>::get(21).unwrap().contains(&auction_hash), true);
+
+		// Place invalid bids
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(bob()), auction_hash, 1),
+			Error::<Test>::BidIsTooLow
+		);
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(alice()), auction_hash, 5),
+			Error::<Test>::BuyerCantBuyHisOwnTokens
+		);
+		assert_err!(
+			Veles::buy_auction_lot(RuntimeOrigin::signed(bob()), auction_hash),
+			Error::<Test>::WrongAuctionType
+		);
+
+		// Successfully place a bid
+		assert_ok!(Veles::place_auction_bid(RuntimeOrigin::signed(bob()), auction_hash, 5));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditAuctionBidPlaced(bob(), auction_hash, 5).into(),
+		);
+
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &bob()), 50);
+
+		// Check if the auction can't be cancelled once it has bids
+		assert_err!(
+			Veles::cancel_auction(RuntimeOrigin::signed(alice()), auction_hash),
+			Error::<Test>::AuctionHasBids
+		);
+
+		// Outbid the highest bid (the previous bid is released)
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(charlie()), auction_hash, 5),
+			Error::<Test>::BidIsTooLow
+		);
+		assert_ok!(Veles::place_auction_bid(RuntimeOrigin::signed(charlie()), auction_hash, 6));
+
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &bob()), 0);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &charlie()), 60);
+
+		// Place a bid close to the auction end (the auction is extended to block 23)
+		run_to_block(18);
+
+		assert_ok!(Veles::place_auction_bid(RuntimeOrigin::signed(bob()), auction_hash, 7));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditAuctionExtended(auction_hash, 23).into(),
+		);
+
+		assert_eq!(AuctionTimeouts::<Test>::get(21), None);
+		assert_eq!(AuctionTimeouts::<Test>::get(23).unwrap().contains(&auction_hash), true);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &charlie()), 0);
+
+		// Check if the auction is still open at the original auction end
+		run_to_block(22);
+
+		assert_eq!(
+			CarbonCreditAuctions::<Test>::get(auction_hash).unwrap().status,
+			AuctionStatus::Open
+		);
+
+		// End the auction (the lot is sold to the highest bid)
+		run_to_block(23);
+
+		System::assert_has_event(
+			Event::<Test>::CarbonCreditAuctionSold(bob(), auction_hash, 7, 0, 0).into(),
+		);
+		System::assert_last_event(Event::<Test>::CarbonCreditAuctionUpdated(auction_hash).into());
+
+		let auction = CarbonCreditAuctions::<Test>::get(auction_hash).unwrap();
+
+		assert_eq!(auction.status, AuctionStatus::Sold);
+		assert_eq!(auction.highest_bid, Some((bob(), 7)));
+		assert_eq!(auction.end_block, 23);
+		assert_eq!(AuctionTimeouts::<Test>::get(23), None);
+
+		// Check holdings
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		let bob_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, bob()).unwrap();
+
+		assert_eq!(alice_holdings.available_amount, 10);
+		assert_eq!(alice_holdings.unavailable_amount, 0);
+		assert_eq!(bob_holdings.available_amount, 10);
+
+		// Check balances (primary sale of 70: 30 validator gains)
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &bob()), 0);
+		assert_eq!(Balances::free_balance(bob()), 30);
+		assert_eq!(Balances::free_balance(charlie()), 5000);
+		assert_eq!(Balances::free_balance(alice()), 41);
+		assert_eq!(Balances::free_balance(dave()), 15);
+		assert_eq!(Balances::free_balance(fred()), 15);
+
+		// Check if the auction has been scheduled for pruning
+		let record = FinishedRecord::CarbonCreditAuction(auction_hash);
+
+		assert_eq!(PruningTimeouts::<Test>::get(123).unwrap().contains(&record), true);
+
+		// Check if bids can't be placed after the auction end
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(charlie()), auction_hash, 8),
+			Error::<Test>::AuctionIsNotActive
+		);
+	});
+}
+
+#[test]
+fn buy_auction_lot_dutch_auction_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (alice is the project owner, so her auctions are primary
+		// sales)
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+
+		// Successfully create a Dutch auction (ends in block 11)
+		assert_ok!(Veles::create_auction(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(10u32),
+			AuctionType::Dutch {
+				start_price: BalanceOf::<Test>::from(20u32),
+				floor_price: BalanceOf::<Test>::from(4u32),
+				price_decrement: BalanceOf::<Test>::from(4u32),
+			},
+			10,
+		));
+
+		let auction_hash = generate_hash(alice());
+
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(charlie()), auction_hash, 20),
+			Error::<Test>::WrongAuctionType
+		);
+
+		// Check if the price falls every block until it reaches the floor price
+		let auction = CarbonCreditAuctions::<Test>::get(auction_hash).unwrap();
+
+		assert_eq!(Veles::dutch_auction_price(&auction, 1), Some(20));
+		assert_eq!(Veles::dutch_auction_price(&auction, 3), Some(12));
+		assert_eq!(Veles::dutch_auction_price(&auction, 5), Some(4));
+		assert_eq!(Veles::dutch_auction_price(&auction, 10), Some(4));
+
+		// Buy the lot without enough funds (120 at the current price)
+		run_to_block(3);
+
+		assert_err!(
+			Veles::buy_auction_lot(RuntimeOrigin::signed(bob()), auction_hash),
+			Error::<Test>::InsufficientFunds
+		);
+
+		// Successfully buy the lot at the floor price
+		run_to_block(5);
+
+		assert_ok!(Veles::buy_auction_lot(RuntimeOrigin::signed(charlie()), auction_hash));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditAuctionSold(charlie(), auction_hash, 4, 0, 0).into(),
+		);
+
+		let auction = CarbonCreditAuctions::<Test>::get(auction_hash).unwrap();
+
+		assert_eq!(auction.status, AuctionStatus::Sold);
+		assert_eq!(auction.highest_bid, Some((charlie(), 4)));
+		assert_eq!(AuctionTimeouts::<Test>::get(11), None);
+
+		// Check holdings
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		let charlie_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
+
+		assert_eq!(alice_holdings.available_amount, 10);
+		assert_eq!(alice_holdings.unavailable_amount, 0);
+		assert_eq!(charlie_holdings.available_amount, 10);
+
+		// Check balances (primary sale of 40: 18 validator gains)
+		assert_eq!(Balances::free_balance(charlie()), 4960);
+		assert_eq!(Balances::free_balance(alice()), 23);
+		assert_eq!(Balances::free_balance(dave()), 9);
+		assert_eq!(Balances::free_balance(fred()), 9);
+
+		// Check if the lot can't be bought twice
+		assert_err!(
+			Veles::buy_auction_lot(RuntimeOrigin::signed(bob()), auction_hash),
+			Error::<Test>::AuctionIsNotActive
+		);
+	});
+}
+
+#[test]
+fn update_carbon_credit_auction_unsold_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (alice is the project owner, so her auctions are primary
+		// sales)
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+
+		// Successfully create an English auction without bids (ends in block 11)
+		assert_ok!(Veles::create_auction(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(10u32),
+			AuctionType::English { reserve_price: BalanceOf::<Test>::from(2u32) },
+			10,
+		));
+
+		let auction_hash = generate_hash(alice());
+
+		assert_err!(
+			Veles::update_carbon_credit_auction(RuntimeOrigin::none(), auction_hash),
+			Error::<Test>::UpdateIsNotDue
+		);
+
+		// End the auction (the seller credits are released)
+		run_to_block(11);
+
+		System::assert_last_event(Event::<Test>::CarbonCreditAuctionUpdated(auction_hash).into());
+
+		assert_eq!(
+			CarbonCreditAuctions::<Test>::get(auction_hash).unwrap().status,
+			AuctionStatus::Unsold
+		);
+		assert_eq!(AuctionTimeouts::<Test>::get(11), None);
+
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(alice_holdings.available_amount, 20);
+		assert_eq!(alice_holdings.unavailable_amount, 0);
+
+		// Prune the finished auction
+		run_to_block(111);
+		Veles::on_idle(111, Weight::MAX);
+
+		assert_eq!(CarbonCreditAuctions::<Test>::contains_key(auction_hash), false);
+	});
+}
+
+#[test]
+fn update_carbon_credit_auction_trading_fees_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Set maker (2%) and taker (10%) trading fees (paid to the beneficiary)
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Maker),
+			20_000
+		));
+
+		assert_ok!(Veles::update_fee_value(
+			RuntimeOrigin::root(),
+			FeeType::TradingFee(TradingFeeRole::Taker),
+			100_000
+		));
+
+		TradingFeeBeneficiary::set(Some(george()));
+
+		// Successfully create an English auction (alice is the maker, ends in block 11)
+		assert_ok!(Veles::create_auction(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(10u32),
+			AuctionType::English { reserve_price: BalanceOf::<Test>::from(5u32) },
+			10,
+		));
+
+		let auction_hash = generate_hash(alice());
+
+		// Check for InsufficientFunds error (the taker fee is paid on top of the bid)
+		assert_err!(
+			Veles::place_auction_bid(RuntimeOrigin::signed(bob()), auction_hash, 10),
+			Error::<Test>::InsufficientFunds
+		);
+
+		// Successfully place a bid (lot is worth 100)
+		assert_ok!(Veles::place_auction_bid(RuntimeOrigin::signed(charlie()), auction_hash, 10));
+
+		// End the auction (the lot is sold to the highest bid)
+		run_to_block(11);
+
+		System::assert_has_event(
+			Event::<Test>::CarbonCreditAuctionSold(charlie(), auction_hash, 10, 2, 10).into(),
+		);
+
+		assert_eq!(
+			CarbonCreditAuctions::<Test>::get(auction_hash).unwrap().status,
+			AuctionStatus::Sold
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(george()), 12);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::AuctionBidEscrow.into(), &charlie()), 0);
+
+		TradingFeeBeneficiary::set(None);
+	});
+}
+
+#[test]
+fn cancel_auction_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert validators
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_1"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator_2"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator_1);
+		Validators::<Test>::insert(fred(), validator_2);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+		validator_benefactors.try_insert(fred()).unwrap();
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(20u32),
+			penalty_repay_price: BalanceOf::<Test>::from(10u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors,
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (alice is the project owner, so her auctions are primary
+		// sales)
+		let alice_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), alice_holdings);
+
+		// Successfully create a Dutch auction
+		assert_ok!(Veles::create_auction(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(10u32),
+			AuctionType::Dutch {
+				start_price: BalanceOf::<Test>::from(10u32),
+				floor_price: BalanceOf::<Test>::from(4u32),
+				price_decrement: BalanceOf::<Test>::from(2u32),
+			},
+			10,
+		));
+
+		let auction_hash = generate_hash(alice());
+
+		// Cancel the auction of another user
+		assert_err!(
+			Veles::cancel_auction(RuntimeOrigin::signed(bob()), auction_hash),
+			Error::<Test>::UserDidntCreateTheAuction
+		);
+
+		// Successfully cancel the auction
+		assert_ok!(Veles::cancel_auction(RuntimeOrigin::signed(alice()), auction_hash));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditAuctionCancelled(alice(), auction_hash).into(),
+		);
+
+		assert_eq!(
+			CarbonCreditAuctions::<Test>::get(auction_hash).unwrap().status,
+			AuctionStatus::Cancelled
+		);
+		assert_eq!(AuctionTimeouts::<Test>::get(11), None);
+
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(alice_holdings.available_amount, 20);
+		assert_eq!(alice_holdings.unavailable_amount, 0);
+
+		// Check if the auction can't be cancelled twice
+		assert_err!(
+			Veles::cancel_auction(RuntimeOrigin::signed(alice()), auction_hash),
+			Error::<Test>::AuctionIsNotActive
+		);
+	});
+}
+
+#[test]
+fn amend_sale_order_invalid_amendment() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(bob()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+			None,
+			None,
+			None,
+		));
+
+		let sale_hash = generate_hash(alice());
+
+		// Check for UserDidntCreateTheSaleOrder error
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(bob()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(10u32),
+				None,
+			),
+			Error::<Test>::UserDidntCreateTheSaleOrder
+		);
+
+		// Check for InvalidCarbonCreditAmount error
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(alice()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(0u32),
+				None,
+			),
+			Error::<Test>::InvalidCarbonCreditAmount
+		);
+
+		// Check for NotEnoughtAvailableCredits error (only 30 more credits are available)
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(alice()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(41u32),
+				None,
+			),
+			Error::<Test>::NotEnoughtAvailableCredits
+		);
+
+		// Check for InvalidSaleOrderExpiry error
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(alice()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(10u32),
+				Some(SaleOrderExpiry::AfterBlocks(0)),
+			),
+			Error::<Test>::InvalidSaleOrderExpiry
+		);
+
+		// Check for ArithmeticOverflow error (the released credits aren't locked)
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(30u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(alice()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(5u32),
+				None,
+			),
+			Error::<Test>::ArithmeticOverflow
+		);
+
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(30u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Check for SaleOrderIsNotActive error
+		assert_ok!(Veles::close_sale_order(RuntimeOrigin::signed(alice()), sale_hash));
+
+		assert_err!(
+			Veles::amend_sale_order(
+				RuntimeOrigin::signed(alice()),
+				sale_hash,
+				BalanceOf::<Test>::from(6u32),
+				BalanceOf::<Test>::from(10u32),
+				None,
+			),
+			Error::<Test>::SaleOrderIsNotActive
+		);
+	});
+}
+
+#[test]
+fn amend_sale_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(bob(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch"),
+			project_hash: generate_hash(bob()),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Create a sale order (expires in block 21)
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+			Some(BalanceOf::<Test>::from(4u32)),
+			None,
+			Some(SaleOrderExpiry::AfterBlocks(20)),
+		));
+
+		let sale_hash = generate_hash(alice());
+
+		// Successfully reprice and resize the sale order up (the timeout is moved to block 35)
+		run_to_block(5);
+
+		assert_ok!(Veles::amend_sale_order(
+			RuntimeOrigin::signed(alice()),
+			sale_hash,
+			BalanceOf::<Test>::from(6u32),
+			BalanceOf::<Test>::from(25u32),
+			Some(SaleOrderExpiry::AfterBlocks(30)),
+		));
+
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditSaleOrderAmended(alice(), sale_hash, 25, 6).into(),
+		);
+
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+
+		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(6u32));
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(25u32));
+		assert_eq!(sale_order.min_fill_amount, BalanceOf::<Test>::from(4u32));
+		assert_eq!(sale_order.sale_timeout, Some(35));
+		assert_eq!(SaleOrderTimeouts::<Test>::get(21), None);
+		assert_eq!(SaleOrderTimeouts::<Test>::get(35).unwrap().contains(&sale_hash), true);
+
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(25u32));
+
+		// Successfully resize the sale order down and make it good-til-cancelled
+		// Note: The minimum fill amount is lowered to the new sale order amount
+		assert_ok!(Veles::amend_sale_order(
+			RuntimeOrigin::signed(alice()),
+			sale_hash,
+			BalanceOf::<Test>::from(6u32),
+			BalanceOf::<Test>::from(2u32),
+			Some(SaleOrderExpiry::GoodTilCancelled),
+		));
+
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(2u32));
+		assert_eq!(sale_order.min_fill_amount, BalanceOf::<Test>::from(2u32));
+		assert_eq!(sale_order.sale_timeout, None);
+		assert_eq!(SaleOrderTimeouts::<Test>::get(35), None);
+
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(38u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(2u32));
+	});
+}
+
+#[test]
+fn open_account_complaint_user_is_not_a_registered_validator() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check for UserIsNotARegisteredValidator error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(alice()),
+				documentation_ipfs,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::UserIsNotARegisteredValidator
+		);
+	});
+}
+
+#[test]
+fn open_account_complaint_documentation_was_used_previously() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: documentation_ipfs.clone(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator);
+
+		UsedDocumentation::<Test>::insert(
+			documentation_ipfs.clone(),
+			DocumentationOwner::Validator(alice()),
+		);
+
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check for DocumentationWasUsedPreviously error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(alice()),
+				documentation_ipfs,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::DocumentationWasUsedPreviously
+		);
+	});
+}
+
+#[test]
+fn open_account_complaint_insufficient_funds() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check for InsufficientFunds error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(alice()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn open_account_complaint_unregistered_account_id() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check for UnregisteredAccountId error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::UnregisteredAccountId
+		);
+	});
+}
+
+#[test]
+fn open_account_complaint_invalid_complaint_type() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+
+		// Check for InvalidComplaintType error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::InvalidComplaintType
+		);
+	});
+}
+
+#[test]
+fn open_account_complaint_project_owner_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert project owner
+		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		ProjectOwners::<Test>::insert(bob(), owner);
+
+		// Insert project
+		let project_documentation =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+
+		let project = ProjectInfo {
+			documentation_ipfs: project_documentation,
+			project_owner: bob(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Insert carbon credit batch index
+		let mut project_batches = BoundedBTreeSet::<H256, MaxBatchesPerProject>::new();
+		project_batches.try_insert(batch_hash).unwrap();
+
+		BatchesByProject::<Test>::insert(project_hash, project_batches);
+
+		// Insert project index
+		let mut owner_projects = BoundedBTreeSet::<H256, MaxProjectsPerOwner>::new();
+		owner_projects.try_insert(project_hash).unwrap();
+
+		ProjectsByOwner::<Test>::insert(bob(), owner_projects);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ProjectOwnerComplaint;
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+
+		// Successfully open account complaint
+		assert_ok!(Veles::open_account_complaint(
+			RuntimeOrigin::signed(charlie()),
+			complaint_documentation.clone(),
+			complaint_for,
+			complaint_type,
+		));
+
+		// Check complaint structure
+		let complaint =
+			ComplaintsForAccounts::<Test>::get(complaint_documentation.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_for, bob());
+		assert_eq!(complaint.complaint_type, ComplaintType::ProjectOwnerComplaint);
+		assert_eq!(complaint.complaint_proposer, charlie());
+		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.complaint_active, true);
+
+		// Check complaint timeout
+		let current_block = frame_system::Pallet::<Test>::block_number();
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+
+		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+
+		assert_eq!(complaint_timeouts.len(), 1);
+		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+
+		// Check carbon credit batch status
+		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(
+			batch.documentation_ipfs,
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
+		);
+		assert_eq!(batch.project_hash, project_hash);
+		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
+		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+	});
+}
+
+#[test]
+fn open_account_complaint_validator_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_1_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_1_documentation");
+
+		// Insert validator #1
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_1_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator_1);
+
+		// Insert validator #2
+		let validator_2_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_2_documentation");
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_2_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(bob(), validator_2);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+
+		// Successfully open account complaint
+		assert_ok!(Veles::open_account_complaint(
+			RuntimeOrigin::signed(charlie()),
+			complaint_documentation.clone(),
+			complaint_for,
+			complaint_type,
+		));
+
+		// Check complaint structure
+		let complaint =
+			ComplaintsForAccounts::<Test>::get(complaint_documentation.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_for, bob());
+		assert_eq!(complaint.complaint_type, ComplaintType::ValidatorComplaint);
+		assert_eq!(complaint.complaint_proposer, charlie());
+		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.complaint_active, true);
+
+		// Check complaint timeout
+		let current_block = frame_system::Pallet::<Test>::block_number();
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+
+		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+
+		assert_eq!(complaint_timeouts.len(), 1);
+		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+	});
+}
+
+#[test]
+fn open_account_complaint_max_potential_penalty_level_exceeded() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_1_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_1_documentation");
+
+		// Insert validator #1
+		let validator_1 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_1_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator_1);
+
+		// Insert validator #2
+		let validator_2_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_2_documentation");
+
+		let validator_2 = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_2_documentation,
+			penalty_level: 4u8,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Validators::<Test>::insert(bob(), validator_2);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(bob()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = bob();
+		let complaint_type = ComplaintType::ValidatorComplaint;
+
+		// Check for MaxPotentialPenaltyLevelExceeded error
+		assert_err!(
+			Veles::open_account_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation.clone(),
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::MaxPotentialPenaltyLevelExceeded,
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_user_is_not_a_registered_validator() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check for UserIsNotARegisteredValidator error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(alice()),
+				documentation_ipfs,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::UserIsNotARegisteredValidator
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_documentation_was_used_previously() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: documentation_ipfs.clone(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator);
+
+		UsedDocumentation::<Test>::insert(
+			documentation_ipfs.clone(),
+			DocumentationOwner::Validator(alice()),
+		);
+
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check for DocumentationWasUsedPreviously error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(alice()),
+				documentation_ipfs,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::DocumentationWasUsedPreviously
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_insufficient_funds() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check for InsufficientFunds error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(alice()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_unregistered_hash() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check for UnregisteredHash error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::UnregisteredHash
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_carbon_credit_batch_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+
+		// Check for CarbonCreditBatchDoesNotExist error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::CarbonCreditBatchDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_invalid_complaint_type() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = generate_hash(bob());
+		let complaint_type = ComplaintType::ProjectOwnerComplaint;
+
+		// Check for InvalidComplaintType error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::InvalidComplaintType
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_project_max_potential_penalty_level_exceeded() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert project owner
+		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		ProjectOwners::<Test>::insert(bob(), owner);
+
+		// Insert project
+		let project_documentation =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+
+		let project = ProjectInfo {
+			documentation_ipfs: project_documentation,
+			project_owner: bob(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 4u8,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
+
+		PenaltyTimeoutsHashes::<Test>::insert(120, penalty_hashes);
+
+		// Insert carbon credit batch
+		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = project_hash;
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check for MaxPotentialPenaltyLevelExceeded error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation.clone(),
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::MaxPotentialPenaltyLevelExceeded,
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_project_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert project owner
+		let owner_documentation = BoundedString::<IPFSLength>::truncate_from("owner_documentation");
+
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		ProjectOwners::<Test>::insert(bob(), owner);
+
+		// Insert project
+		let project_documentation =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+
+		let project = ProjectInfo {
+			documentation_ipfs: project_documentation,
+			project_owner: bob(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		let project_hash = generate_hash(alice());
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		// Insert carbon credit batch index
+		let mut project_batches = BoundedBTreeSet::<H256, MaxBatchesPerProject>::new();
+		project_batches.try_insert(batch_hash).unwrap();
+
+		BatchesByProject::<Test>::insert(project_hash, project_batches);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = project_hash;
+		let complaint_type = ComplaintType::ProjectComplaint;
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+
+		// Successfully open account complaint
+		assert_ok!(Veles::open_hash_complaint(
+			RuntimeOrigin::signed(charlie()),
+			complaint_documentation.clone(),
+			complaint_for,
+			complaint_type,
+		));
+
+		// Check complaint structure
+		let complaint = ComplaintsForHashes::<Test>::get(complaint_documentation.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_for, project_hash);
+		assert_eq!(complaint.complaint_type, ComplaintType::ProjectComplaint);
+		assert_eq!(complaint.complaint_proposer, charlie());
+		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.complaint_active, true);
+
+		// Check complaint timeout
+		let current_block = frame_system::Pallet::<Test>::block_number();
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+
+		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+
+		assert_eq!(complaint_timeouts.len(), 1);
+		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+
+		// Check carbon credit batch status
+		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(
+			batch.documentation_ipfs,
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
+		);
+		assert_eq!(batch.project_hash, project_hash);
+		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
+		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+	});
+}
+
+#[test]
+fn open_hash_complaint_batch_ongoing_carbon_batch_complaint() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert carbon credit batch
+		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+		let project_hash = generate_hash(alice());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		let complaint_documentation_1 =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation_1");
+		let complaint_for = batch_hash;
+		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+
+		// Successfully open account complaint
+		assert_ok!(Veles::open_hash_complaint(
+			RuntimeOrigin::signed(charlie()),
+			complaint_documentation_1,
+			complaint_for,
+			complaint_type,
+		));
+
+		let complaint_documentation_2 =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation_2");
+		let complaint_for = batch_hash;
+		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+
+		// Check for OngoingCarbonBatchComplaint error
+		assert_err!(
+			Veles::open_hash_complaint(
+				RuntimeOrigin::signed(charlie()),
+				complaint_documentation_2,
+				complaint_for,
+				complaint_type,
+			),
+			Error::<Test>::OngoingCarbonBatchComplaint,
+		);
+	});
+}
+
+#[test]
+fn open_hash_complaint_batch_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let validator_documentation =
+			BoundedString::<IPFSLength>::truncate_from("validator_documentation");
+
+		// Insert validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_documentation,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(charlie(), validator);
+
+		// Insert carbon credit batch
+		let batch_documentation = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+		let project_hash = generate_hash(alice());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(bob());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		let complaint_documentation =
+			BoundedString::<IPFSLength>::truncate_from("complaint_documentation");
+		let complaint_for = batch_hash;
+		let complaint_type = ComplaintType::CarbonCreditBatchComplaint;
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+
+		// Successfully open account complaint
+		assert_ok!(Veles::open_hash_complaint(
+			RuntimeOrigin::signed(charlie()),
+			complaint_documentation.clone(),
+			complaint_for,
+			complaint_type,
+		));
+
+		// Check complaint structure
+		let complaint = ComplaintsForHashes::<Test>::get(complaint_documentation.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_for, batch_hash);
+		assert_eq!(complaint.complaint_type, ComplaintType::CarbonCreditBatchComplaint);
+		assert_eq!(complaint.complaint_proposer, charlie());
+		assert_eq!(complaint.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(complaint.votes_for, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.votes_against, BTreeSet::<AccountIdOf<Test>>::new());
+		assert_eq!(complaint.complaint_active, true);
+
+		// Check complaint timeout
+		let current_block = frame_system::Pallet::<Test>::block_number();
+		let timeout_block = current_block + PalletTimeValues::<Test>::get().voting_timeout;
+
+		let complaint_timeouts = ComplaintTimeouts::<Test>::get(timeout_block).unwrap();
+
+		assert_eq!(complaint_timeouts.len(), 1);
+		assert_eq!(complaint_timeouts.contains(&complaint_documentation), true);
+
+		// Check carbon credit batch status
+		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(
+			batch.documentation_ipfs,
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation")
+		);
+		assert_eq!(batch.project_hash, project_hash);
+		assert_eq!(batch.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(batch.credit_amount, BalanceOf::<Test>::from(100u32));
+		assert_eq!(batch.penalty_repay_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 100);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_user_is_not_of_a_carbon_footprint_account_type() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		let batch_hash = generate_hash(alice());
+		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+
+		// Check for UserIsNotOfACarbonFootprintAccountType error
+		assert_err!(
+			Veles::retire_carbon_credits(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				amount_to_retire,
+			),
+			Error::<Test>::UserIsNotOfACarbonFootprintAccountType
+		);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_carbon_credit_batch_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_documentation");
+
+		documentation_ipfses.try_insert(documentation_ipfs).unwrap();
+
+		let carbon_footprint_account = CarbonFootprintAccountInfo {
+			documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+
+		let batch_hash = generate_hash(alice());
+		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+
+		// Check for CarbonCreditBatchDoesNotExist error
+		assert_err!(
+			Veles::retire_carbon_credits(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				amount_to_retire,
+			),
+			Error::<Test>::CarbonCreditBatchDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_carbon_credit_batch_is_not_active() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let footprint_account_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+
+		documentation_ipfses.try_insert(footprint_account_ipfs).unwrap();
+
+		let carbon_footprint_account = CarbonFootprintAccountInfo {
+			documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+
+		// Insert carbon credit batch
+		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
+		let project_hash = generate_hash(bob());
+
+		let carbon_credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(200u32),
+			penalty_repay_price: BalanceOf::<Test>::from(20u32),
+			status: CarbonCreditBatchStatus::Frozen,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, carbon_credit_batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(200u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+
+		// Check for CarbonCreditBatchIsNotActive error
+		assert_err!(
+			Veles::retire_carbon_credits(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				amount_to_retire,
+			),
+			Error::<Test>::CarbonCreditBatchIsNotActive
+		);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_carbon_credit_holdings_dont_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let footprint_account_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+
+		documentation_ipfses.try_insert(footprint_account_ipfs).unwrap();
+
+		let carbon_footprint_account = CarbonFootprintAccountInfo {
+			documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+
+		// Insert carbon credit batch
+		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
+		let project_hash = generate_hash(bob());
+
+		let carbon_credit_batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(200u32),
+			penalty_repay_price: BalanceOf::<Test>::from(20u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, carbon_credit_batch);
+
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(200u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
+
+		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+
+		// Check for CarbonCreditHoldingsDontExist error
+		assert_err!(
+			Veles::retire_carbon_credits(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				amount_to_retire,
+			),
+			Error::<Test>::CarbonCreditHoldingsDontExist
+		);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_not_enought_available_credits() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let footprint_account_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+
+		documentation_ipfses.try_insert(footprint_account_ipfs).unwrap();
+
+		let carbon_footprint_account = CarbonFootprintAccountInfo {
+			documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+
+		// Insert carbon credit batch
+		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
+		let project_hash = generate_hash(bob());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(110u32),
+			penalty_repay_price: BalanceOf::<Test>::from(20u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(110u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), holdings);
+
+		let amount_to_retire = BalanceOf::<Test>::from(200u32);
+
+		// Check for NotEnoughtAvailableCredits error
+		assert_err!(
+			Veles::retire_carbon_credits(
+				RuntimeOrigin::signed(alice()),
+				batch_hash,
+				amount_to_retire,
+			),
+			Error::<Test>::NotEnoughtAvailableCredits
+		);
+	});
+}
+
+#[test]
+fn retire_carbon_credits_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let footprint_account_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+
+		documentation_ipfses.try_insert(footprint_account_ipfs).unwrap();
+
+		let carbon_footprint_account = CarbonFootprintAccountInfo {
+			documentation_ipfses: documentation_ipfses.clone(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+
+		// Insert carbon credit batch
+		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
+		let project_hash = generate_hash(bob());
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(110u32),
+			penalty_repay_price: BalanceOf::<Test>::from(20u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		let batch_hash = generate_hash(alice());
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings
+		let holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(110u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), holdings);
+
+		let amount_to_retire = BalanceOf::<Test>::from(50u32);
+
+		// Successfully retire carbon credits
+		assert_ok!(Veles::retire_carbon_credits(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			amount_to_retire,
+		));
+
+		// Check carbon credit holdings
+		let holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(holdings.available_amount, BalanceOf::<Test>::from(60u32));
+		assert_eq!(holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		// Check carbon footprint account
+		let carbon_footprint_account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+
+		assert_eq!(carbon_footprint_account.documentation_ipfses, documentation_ipfses);
+		assert_eq!(
+			carbon_footprint_account.carbon_footprint_surplus,
+			BalanceOf::<Test>::from(0u32)
+		);
+		assert_eq!(
+			carbon_footprint_account.carbon_footprint_deficit,
+			BalanceOf::<Test>::from(950u32)
+		);
+		assert_eq!(
+			carbon_footprint_account.creation_date,
+			<mock::Test as pallet::Config>::Time::now()
+		);
+
+		// Check carbon credit retirement info
+		let retirement_hash = generate_hash(alice());
+
+		let retirement = CarbonCreditRetirements::<Test>::get(retirement_hash).unwrap();
+
+		assert_eq!(retirement.carbon_footprint_account, alice());
+		assert_eq!(retirement.batch_hash, batch_hash);
+		assert_eq!(retirement.credit_amount, BalanceOf::<Test>::from(50u32));
+		assert_eq!(retirement.retirement_date, <mock::Test as pallet::Config>::Time::now());
+
+		// Check if retirement has been indexed by carbon credit batch
+		let retirements = RetirementsByBatch::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(retirements.contains(&retirement_hash), true);
+	});
+}
+
+#[test]
+fn repay_project_owner_debts_project_owner_doesnt_exist() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Check for ProjectOwnerDoesntExist error
+		assert_err!(
+			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
+			Error::<Test>::ProjectOwnerDoesntExist
+		);
+	});
+}
+
+#[test]
+fn repay_project_owner_debts_project_owner_doesnt_have_any_debts() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project owner
+		let owner_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner);
+
+		// Check for ProjectOwnerDoesntHaveAnyDebts error
+		assert_err!(
+			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
+			Error::<Test>::ProjectOwnerDoesntHaveAnyDebts
+		);
+	});
+}
+
+#[test]
+fn repay_project_owner_debts_insufficient_funds() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project owner
+		let owner_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner);
+
+		// Insert project owner debts
+		let mut debts = DebtsOf::<Test>::new();
+
+		debts.try_insert(bob(), BalanceOf::<Test>::from(20u32)).unwrap();
+		debts.try_insert(charlie(), BalanceOf::<Test>::from(30u32)).unwrap();
+
+		ProjectOwnerDebts::<Test>::insert(alice(), debts);
+
+		// Check for InsufficientFunds error
+		assert_err!(
+			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn repay_project_owner_debts_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project owner
+		let owner_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(charlie(), project_owner);
+
+		// Insert project owner debts
+		let mut debts = DebtsOf::<Test>::new();
+
+		debts.try_insert(alice(), BalanceOf::<Test>::from(20u32)).unwrap();
+		debts.try_insert(bob(), BalanceOf::<Test>::from(30u32)).unwrap();
+
+		ProjectOwnerDebts::<Test>::insert(charlie(), debts);
+
+		// Check balances before extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 1);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 100);
+
+		// Check debt storage
+		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(charlie()), true);
+
+		// Successfully pay off project owner debts
+		assert_ok!(Veles::repay_project_owner_debts(RuntimeOrigin::signed(charlie()),));
+
+		// Check debt storage
+		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(charlie()), false);
+
+		// Check balances after extrinsic call
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 21);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 130);
+	});
+}
+
+// Genesis config tests
+
+#[test]
+fn genesis_config_ok() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	crate::GenesisConfig::<Test> {
+		authority_accounts: vec![alice()],
+		vote_pass_ratio: Some(Perbill::from_percent(75)),
+		validators: vec![(bob(), b"validator_ipfs".to_vec())],
+		project_owners: vec![(charlie(), b"project_owner_ipfs".to_vec())],
+		traders: vec![dave()],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	TestExt::from(storage).execute_with(|| {
+		// Check authority accounts
+		assert_eq!(AuthorityAccounts::<Test>::contains_key(alice()), true);
+
+		// Check vote pass ratio
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::from_percent(75));
+
+		// Check default values
+		assert_eq!(PenaltyLevels::<Test>::get().len(), 5);
+		assert_eq!(BeneficiarySplits::<Test>::get().len(), 3);
+
+		// Check registered accounts
+		let validator = Validators::<Test>::get(bob()).unwrap();
+		let project_owner = ProjectOwners::<Test>::get(charlie()).unwrap();
+
+		assert_eq!(
+			validator.documentation_ipfs,
+			BoundedString::<IPFSLength>::truncate_from("validator_ipfs")
+		);
+		assert_eq!(
+			project_owner.documentation_ipfs,
+			BoundedString::<IPFSLength>::truncate_from("project_owner_ipfs")
+		);
+		assert_eq!(TraderAccounts::<Test>::contains_key(dave()), true);
+		assert_eq!(Veles::is_ipfs_available(validator.documentation_ipfs), false);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Invalid genesis penalty levels")]
+fn genesis_config_invalid_penalty_levels() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut penalty_levels = BTreeMap::<u8, Perbill>::new();
+	penalty_levels.insert(0, Perbill::from_percent(20));
+	penalty_levels.insert(1, Perbill::from_percent(10));
+
+	crate::GenesisConfig::<Test> { penalty_levels: Some(penalty_levels), ..Default::default() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Genesis documentation was used previously")]
+fn genesis_config_documentation_was_used_previously() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	crate::GenesisConfig::<Test> {
+		validators: vec![(bob(), b"documentation_ipfs".to_vec())],
+		project_owners: vec![(charlie(), b"documentation_ipfs".to_vec())],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+}
+
+// Utility functions tests
+
+#[test]
+pub fn has_vote_passed_ok() {
+	new_test_ext().execute_with(|| {
+		// Example 1
+		assert_eq!(Veles::has_vote_passed(6, 4), true);
+		assert_eq!(Veles::has_vote_passed(6, 1), false);
+		assert_eq!(Veles::has_vote_passed(6, 5), true);
+
+		// Example 2
+		VotePassRatio::<Test>::set(Perbill::zero());
+
+		assert_eq!(Veles::has_vote_passed(6, 4), true);
+		assert_eq!(Veles::has_vote_passed(6, 5), true);
+		assert_eq!(Veles::has_vote_passed(3, 1), false);
+
+		// // Example 3
+		VotePassRatio::<Test>::set(Perbill::one());
+
+		assert_eq!(Veles::has_vote_passed(6, 4), false);
+		assert_eq!(Veles::has_vote_passed(6, 6), true);
+		assert_eq!(Veles::has_vote_passed(2, 1), false);
+	});
+}
+
+#[test]
+pub fn has_vote_passed_rounding_ok() {
+	new_test_ext().execute_with(|| {
+		// Votes needed with the default ratio (two thirds) are rounded down
+		assert_eq!(Veles::has_vote_passed(3, 2), true);
+		assert_eq!(Veles::has_vote_passed(3, 1), false);
+		assert_eq!(Veles::has_vote_passed(4, 2), true);
+		assert_eq!(Veles::has_vote_passed(4, 1), false);
+		assert_eq!(Veles::has_vote_passed(5, 3), true);
+		assert_eq!(Veles::has_vote_passed(5, 2), false);
+		assert_eq!(Veles::has_vote_passed(0, 0), true);
+
+		// Votes needed for an exact ratio aren't rounded down
+		VotePassRatio::<Test>::set(Perbill::from_percent(50));
+
+		assert_eq!(Veles::has_vote_passed(4, 2), true);
+		assert_eq!(Veles::has_vote_passed(4, 1), false);
+		assert_eq!(Veles::has_vote_passed(5, 2), true);
+		assert_eq!(Veles::has_vote_passed(5, 1), false);
+
+		// Smallest non-zero ratio (any vote passes)
+		VotePassRatio::<Test>::set(Perbill::from_parts(1));
+
+		assert_eq!(Veles::has_vote_passed(3, 0), true);
+		assert_eq!(Veles::has_vote_passed(u16::MAX, 0), true);
+	});
+}
+
+#[test]
+pub fn process_validator_gains_no_validator_benefactors_ok() {
+	new_test_ext().execute_with(|| {
+		// Nothing is split if there are no validator benefactors
+		assert_eq!(
+			Veles::process_validator_gains(charlie(), VotesOf::<Test>::new(), 100),
+			Ok(BalanceOf::<Test>::from(0u32))
+		);
+
+		// Check that the validator share stayed with the payer
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+	});
+}
+
+#[test]
+pub fn process_validator_gains_penalized_validator_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert penalized validator
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator"),
+			penalty_level: 1,
+			penalty_timeout: 10,
+		};
+
+		Validators::<Test>::insert(dave(), validator);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(dave()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(10, penalty_accounts);
+
+		let mut validator_benefactors = VotesOf::<Test>::new();
+		validator_benefactors.try_insert(dave()).unwrap();
+
+		let validator_gains = Veles::calculate_gains_penalties(dave(), 100).unwrap();
+
+		assert_ok!(Veles::process_validator_gains(charlie(), validator_benefactors, 100));
+
+		// Check that the penalized part of the validator gains was moved to the pallet
+		assert_eq!(validator_gains < 100, true);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(dave()), validator_gains);
+		assert_eq!(
+			pallet_balances::Pallet::<Test>::free_balance(pallet_id()),
+			100 - validator_gains
+		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4900);
+	});
+}
+
+#[test]
+pub fn calculate_payments_missing_info() {
+	new_test_ext().execute_with(|| {
+		// Insert validator with a penalty level that doesn't exist
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("validator"),
+			penalty_level: 9,
+			penalty_timeout: 0,
+		};
+
+		Validators::<Test>::insert(dave(), validator);
+
+		// Check for PenaltyLevelDoesntExist error
+		assert_err!(
+			Veles::calculate_basic_payment_made_to_pallet(dave(), 100),
+			Error::<Test>::PenaltyLevelDoesntExist
+		);
+		assert_err!(
+			Veles::calculate_gains_penalties(dave(), 100),
+			Error::<Test>::PenaltyLevelDoesntExist
+		);
+
+		// Check for ProjectOwnerDoesntExist error
+		assert_err!(
+			Veles::calculate_complex_payment_made_to_pallet(alice(), generate_hash(alice()), 100),
+			Error::<Test>::ProjectOwnerDoesntExist
+		);
+
+		// Insert project owner
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("owner"),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), owner);
+
+		// Check for ProjectDoesntExist error
+		assert_err!(
+			Veles::calculate_complex_payment_made_to_pallet(alice(), generate_hash(alice()), 100),
+			Error::<Test>::ProjectDoesntExist
+		);
+
+		// Check that accounts without a penalty pay the full amount
+		assert_eq!(Veles::calculate_basic_payment_made_to_pallet(bob(), 100), Ok(100));
+		assert_eq!(Veles::calculate_gains_penalties(alice(), 100), Ok(100));
+	});
+}
+
+// Offchain worker tests
+
+#[test]
+pub fn offchain_worker_fallback_disabled() {
+	let (mut ext, transactions) = new_test_ext().with_transaction_pool();
+
+	ext.execute_with(|| {
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Run the offchain worker (the offchain worker fallback is disabled)
+		Veles::offchain_worker(0);
+
+		// Check that no transactions have been submitted
+		assert_eq!(transactions().len(), 0);
+	});
+}
+
+#[test]
+pub fn offchain_worker_fallback_timeouts_ok() {
+	let (mut ext, transactions) = new_test_ext().with_transaction_pool();
+
+	ext.execute_with(|| {
+		// Enable the offchain worker fallback
+		OffchainWorkerFallback::set(true);
+
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Run the offchain worker
+		Veles::offchain_worker(0);
+
+		// Check if the carbon footprint report update has been submitted
+		let submitted = transactions();
+		assert_eq!(submitted.len(), 1);
+
+		let call = Call::<Test>::update_carbon_footprint_report {
+			ipfs: report_ipfs.clone(),
+			timeout_block: 0,
+		};
+
+		assert_eq!(submitted[0].call, RuntimeCall::Veles(call.clone()));
+
+		// Check if the unsigned transaction is accepted
+		assert_ok!(Veles::validate_unsigned(TransactionSource::Local, &call));
+
+		// Successfully update carbon footprint report
+		assert_ok!(Veles::update_carbon_footprint_report(
+			RuntimeOrigin::none(),
+			report_ipfs.clone(),
+			0
+		));
+
+		// Check that no transactions are submitted once the timeout event has been processed
+		Veles::offchain_worker(0);
+
+		assert_eq!(transactions().len(), 1);
+
+		OffchainWorkerFallback::set(false);
+	});
+}
+
+#[test]
+pub fn offchain_worker_fallback_pallet_base_time_ok() {
+	let (mut ext, transactions) = new_test_ext().with_transaction_pool();
+
+	ext.execute_with(|| {
+		// Enable the offchain worker fallback
+		OffchainWorkerFallback::set(true);
+
+		// Go to a block after the timeouts cursor
+		System::set_block_number(20);
+
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+
+		// Insert voting timeout (past the blocks visited by a single offchain worker run)
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(15, timeout_events);
+
+		// Run the offchain worker
+		Veles::offchain_worker(20);
+
+		// Check if only the pallet base time update has been submitted
+		// Note: The timeout event is outside of the MaxOffchainWorkerTimeoutBlocks blocks after
+		// the timeouts cursor
+		let submitted = transactions();
+		assert_eq!(submitted.len(), 1);
+
+		assert_eq!(
+			submitted[0].call,
+			RuntimeCall::Veles(Call::<Test>::update_pallet_base_time { new_pallet_base_time: 20 })
+		);
+
+		OffchainWorkerFallback::set(false);
+	});
+}
+
+#[test]
+pub fn update_carbon_footprint_report_new_account_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert carbon footprint report
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Successfully update carbon footprint report
+		assert_ok!(Veles::update_carbon_footprint_report(RuntimeOrigin::none(), report_ipfs.clone(), 0));
+
+		// Check if voting has closed
+		let report = CarbonFootprintReports::<Test>::get(report_ipfs.clone()).unwrap();
+
+		assert_eq!(report.voting_active, false);
+
+		// Check if the voting timeout has been removed
+		assert_eq!(VotingTimeouts::<Test>::get(0), None);
+
+		// Check if carbon footprint account has been saved
+		let account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+
+		assert_eq!(account.documentation_ipfses.contains(&report_ipfs), true);
+		assert_eq!(account.carbon_footprint_surplus, 0);
+		assert_eq!(account.carbon_footprint_deficit, 100);
+		assert_eq!(account.creation_date, <mock::Test as pallet::Config>::Time::now());
+	});
+}
+
+#[test]
+pub fn update_carbon_footprint_report_old_account_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert carbon footprint account
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let report_1_ipfs = BoundedString::<IPFSLength>::truncate_from("report_1_ipfs");
+		documentation_ipfses.try_insert(report_1_ipfs.clone()).unwrap();
+
+		let cf_account = CarbonFootprintAccountInfo {
+			documentation_ipfses: documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(50u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(alice(), cf_account);
+
+		// Insert carbon footprint report
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_2_ipfs = BoundedString::<IPFSLength>::truncate_from("report_2_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_2_ipfs.clone(), report_info);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_2_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Successfully update carbon footprint report
+		assert_ok!(Veles::update_carbon_footprint_report(RuntimeOrigin::none(), report_2_ipfs.clone(), 0));
+
+		// Check if voting has closed
+		let report = CarbonFootprintReports::<Test>::get(report_2_ipfs.clone()).unwrap();
+
+		assert_eq!(report.voting_active, false);
+
+		// Check if carbon footprint account has been saved
+		let account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+
+		assert_eq!(account.documentation_ipfses.contains(&report_1_ipfs), true);
+		assert_eq!(account.documentation_ipfses.contains(&report_2_ipfs), true);
+		assert_eq!(account.carbon_footprint_surplus, 0);
+		assert_eq!(account.carbon_footprint_deficit, 50);
+		assert_eq!(account.creation_date, <mock::Test as pallet::Config>::Time::now());
+	});
+}
+
+#[test]
+pub fn update_project_proposal_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert carbon footprint report
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let project_hash = generate_hash(alice());
+
+		let proposal = ProjectProposalInfo {
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			project_hash: project_hash,
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+
+		ProjectProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Successfully update project proposal
+		assert_ok!(Veles::update_project_proposal(RuntimeOrigin::none(), proposal_ipfs.clone(), 0));
+
+		// Check if voting has closed
+		let proposal = ProjectProposals::<Test>::get(proposal_ipfs.clone()).unwrap();
+
+		assert_eq!(proposal.voting_active, false);
+
+		// Check if project has been saved
+		let project = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project.documentation_ipfs, proposal_ipfs);
+		assert_eq!(project.project_owner, alice());
+		assert_eq!(project.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(project.penalty_level, 0);
+		assert_eq!(project.penalty_timeout, BlockNumber::<Test>::from(0u32));
+
+		// Check if project has been indexed by project owner
+		assert_eq!(ProjectsByOwner::<Test>::get(alice()).unwrap().contains(&project_hash), true);
+	});
+}
+
+#[test]
+pub fn update_project_penalty_level_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project
+		let project_hash = generate_hash(alice());
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project_info);
+
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
+
+		PenaltyTimeoutsHashes::<Test>::insert(120, penalty_hashes);
+
+		// Go to the penalty timeout block
+		System::set_block_number(120);
+
+		// Successfully update project penalty level
+		assert_ok!(Veles::update_project_penalty_level(RuntimeOrigin::none(), project_hash));
+
+		// Check if project penalty level has been updated
+		let project = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project.penalty_level, 0);
+		assert_eq!(project.penalty_timeout, 0);
+
+		// Check if the penalty timeout has been removed
+		assert_eq!(PenaltyTimeoutsHashes::<Test>::get(120), None);
+	});
+}
+
+#[test]
+pub fn update_project_penalty_level_non_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project
+		let project_hash = generate_hash(alice());
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 2,
+			penalty_timeout: BlockNumber::<Test>::from(320u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project_info);
+
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
+
+		PenaltyTimeoutsHashes::<Test>::insert(320, penalty_hashes);
+
+		// Go to the penalty timeout block
+		System::set_block_number(320);
+
+		// Successfully update project penalty level
+		assert_ok!(Veles::update_project_penalty_level(RuntimeOrigin::none(), project_hash));
+
+		// Check if project penalty level has been updated
+		let project = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project.penalty_level, 1);
+		assert_eq!(project.penalty_timeout, 446720);
+
+		// Check if the next penalty timeout has been created
+		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(446720).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&project_hash), true);
+		assert_eq!(PenaltyTimeoutsHashes::<Test>::get(320), None);
+	});
+}
+
+#[test]
+pub fn update_validator_penalty_level_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert validator
+		let validator_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator_info);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Go to the penalty timeout block
+		System::set_block_number(120);
+
+		// Successfully update validator penalty level
+		assert_ok!(Veles::update_validator_penalty_level(RuntimeOrigin::none(), alice()));
+
+		// Check if validator penalty level has been updated
+		let validator = Validators::<Test>::get(alice()).unwrap();
+
+		assert_eq!(validator.penalty_level, 0);
+		assert_eq!(validator.penalty_timeout, 0);
+
+		// Check if the penalty timeout has been removed
+		assert_eq!(PenaltyTimeoutsAccounts::<Test>::get(120), None);
+	});
+}
+
+#[test]
+pub fn update_validator_penalty_level_non_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert validator
+		let validator_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 2,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator_info);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Go to the penalty timeout block
+		System::set_block_number(120);
+
+		// Successfully update validator penalty level
+		assert_ok!(Veles::update_validator_penalty_level(RuntimeOrigin::none(), alice()));
+
+		// Check if validator penalty level has been updated
+		let validator = Validators::<Test>::get(alice()).unwrap();
+
+		assert_eq!(validator.penalty_level, 1);
+		assert_eq!(validator.penalty_timeout, 446520);
+
+		// Check if the next penalty timeout has been created
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446520).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+		assert_eq!(PenaltyTimeoutsAccounts::<Test>::get(120), None);
+	});
+}
+
+#[test]
+pub fn update_project_owner_penalty_level_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project owner
+		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Go to the penalty timeout block
+		System::set_block_number(120);
+
+		// Successfully update project owner penalty level
+		assert_ok!(Veles::update_project_owner_penalty_level(RuntimeOrigin::none(), alice()));
+
+		// Check if project owner penalty level has been updated
+		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+
+		assert_eq!(project_owner_info.penalty_level, 0);
+		assert_eq!(project_owner_info.penalty_timeout, 0);
+
+		// Check if the penalty timeout has been removed
+		assert_eq!(PenaltyTimeoutsAccounts::<Test>::get(120), None);
+	});
+}
+
+#[test]
+pub fn update_project_owner_penalty_level_non_zero_penalty_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project owner
+		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 2,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Go to the penalty timeout block
+		System::set_block_number(120);
+
+		// Successfully update project owner penalty level
+		assert_ok!(Veles::update_project_owner_penalty_level(RuntimeOrigin::none(), alice()));
+
+		// Check if project owner penalty level has been updated
+		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+
+		assert_eq!(project_owner_info.penalty_level, 1);
+		assert_eq!(project_owner_info.penalty_timeout, 446520);
+
+		// Check if the next penalty timeout has been created
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446520).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+		assert_eq!(PenaltyTimeoutsAccounts::<Test>::get(120), None);
+	});
+}
+
+#[test]
+pub fn update_carbon_credit_sale_order_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+
+		// Insert carbon credit holdings 
+		let batch_hash = generate_hash(alice());
+
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(20u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: batch_hash,
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: alice(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
+		};
+
+		let sale_hash = generate_hash(charlie());
+
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
+
+		// Go to the sale order timeout block
+		System::set_block_number(10);
+
+		// Successfully update carbon credit sale order
+		assert_ok!(Veles::update_carbon_credit_sale_order(RuntimeOrigin::none(), sale_hash));
+
+		// Check if the carbon credit sale order has been updated
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+
+		assert_eq!(sale_order.status, SaleOrderStatus::Expired);
+
+		// Check if the sale order timeout has been removed
+		assert_eq!(SaleOrderTimeouts::<Test>::get(10), None);
+	
+		// Check if the carbon credit holdings have been updated
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(30u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+	});
+}
+
+#[test]
+pub fn update_carbon_credit_batch_proposal_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project owner
+		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+
+		// Insert project
+		let project_hash = generate_hash(bob());
+
+		let project_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+
+		let project = ProjectInfo {
+			documentation_ipfs: project_documentation_ipfs,
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch proposal
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+
+		let batch_hash = generate_hash(charlie());
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let proposal = CarbonCreditBatchProposalInfo {
+			project_hash,
+			batch_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: 100u32.into(),
+			penalty_repay_price: 5u32.into(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		CarbonCreditBatchProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(0, timeout_events);
+
+		// Successfully update carbon credit batch proposal
+		assert_ok!(Veles::update_carbon_credit_batch_proposal(RuntimeOrigin::none(), proposal_ipfs.clone(), 0));
+
+		// Check if the carbon credit batch proposal has been updated
+		let proposal = CarbonCreditBatchProposals::<Test>::get(proposal_ipfs).unwrap();
+
+		assert_eq!(proposal.voting_active, false);
+
+		// Chech if the carbon credit holdings has been updated
+		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(100u32));
+		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+
+		// Check if carbon credit batch has been indexed by project
+		assert_eq!(BatchesByProject::<Test>::get(project_hash).unwrap().contains(&batch_hash), true);
+	});
+}
+
+#[test]
+pub fn update_beneficiary_split_proposal_ok() {
+	new_test_ext().execute_with(|| {
+		// Go past genesis block so events get deposited
+		run_to_block(1);
+
+		// Insert project
+		let project_hash = generate_hash(bob());
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert beneficiary split proposal
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+
+		let mut stakeholders = StakeholderSharesOf::<Test>::new();
+		stakeholders.try_insert(george(), Permill::from_parts(50_000)).unwrap();
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(bob()).unwrap();
+
+		let proposal = BeneficiarySplitProposalInfo {
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			beneficiary_splits: BeneficiarySplits::<Test>::get(),
+			stakeholders: stakeholders.clone(),
+			votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		BeneficiarySplitProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(proposal_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(1, timeout_events);
+
+		// Successfully update beneficiary split proposal
+		assert_ok!(Veles::update_beneficiary_split_proposal(
+			RuntimeOrigin::none(),
+			proposal_ipfs.clone(),
+			1
+		));
+
+		// Check if the beneficiary split proposal has been updated
+		let proposal = BeneficiarySplitProposals::<Test>::get(proposal_ipfs.clone()).unwrap();
+
+		assert_eq!(proposal.voting_active, false);
+		assert_eq!(VotingTimeouts::<Test>::get(1), None);
+
+		// Check if the project beneficiary splits have been applied
+		let project_splits = ProjectBeneficiarySplits::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project_splits.beneficiary_splits, BeneficiarySplits::<Test>::get());
+		assert_eq!(project_splits.stakeholders, stakeholders);
+
+		System::assert_has_event(
+			Event::<Test>::ProjectBeneficiarySplitsUpdated(project_hash).into(),
+		);
+		System::assert_last_event(
+			Event::<Test>::BeneficiarySplitProposalUpdated(proposal_ipfs).into(),
+		);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_account_project_owner_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project owner
+		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+
+		// Insert complaint for account type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintAccountBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ProjectOwnerComplaint,
+			complaint_for: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for account
+		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project owner has been updated
+		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+
+		assert_eq!(project_owner_info.penalty_level, 1);
+		assert_eq!(project_owner_info.penalty_timeout, 446400);
+
+		// Check if the penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_account_project_owner_non_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project owner
+		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+
+		// Insert complaint for account type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintAccountBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ProjectOwnerComplaint,
+			complaint_for: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for account
+		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project owner has been updated
+		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+
+		assert_eq!(project_owner_info.penalty_level, 2);
+		assert_eq!(project_owner_info.penalty_timeout, 446400);
+
+		// Check if the new penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+
+		// Check if the old penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(120).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), false);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_account_validator_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert validator
+		let validator_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator_info);
+
+		// Insert complaint for account type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintAccountBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ValidatorComplaint,
+			complaint_for: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for account
+		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project owner has been updated
+		let validator_info = Validators::<Test>::get(alice()).unwrap();
+
+		assert_eq!(validator_info.penalty_level, 1);
+		assert_eq!(validator_info.penalty_timeout, 446400);
+
+		// Check if the penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_account_validator_non_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert validator
+		let validator_info = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Validators::<Test>::insert(alice(), validator_info);
+
+		// Insert complaint for account type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintAccountBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ValidatorComplaint,
+			complaint_for: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert penalty timeout
+		let mut penalty_accounts = TimeoutsOf::<Test, AccountIdOf<Test>>::new();
+		penalty_accounts.try_insert(alice()).unwrap();
+
+		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for account
+		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project owner has been updated
+		let validator_info = Validators::<Test>::get(alice()).unwrap();
+
+		assert_eq!(validator_info.penalty_level, 2);
+		assert_eq!(validator_info.penalty_timeout, 446400);
+
+		// Check if the new penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), true);
+
+		// Check if the old penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(120).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&alice()), false);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_hash_project_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project
+		let project_hash = generate_hash(alice());
+
+		let project_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: project_documentation_ipfs,
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project_info);
+	
+		// Insert complaint for hash type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintHashBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ProjectComplaint,
+			complaint_for: project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for hash
+		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForHashes::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project has been updated
+		let project_info = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project_info.penalty_level, 1);
+		assert_eq!(project_info.penalty_timeout, 446400);
+
+		// Check if the penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&project_hash), true);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_hash_project_non_zero_level_ok() {
+	new_test_ext().execute_with(|| {
+		// Insert project
+		let project_hash = generate_hash(alice());
+
+		let project_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: project_documentation_ipfs,
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project_info);
+	
+		// Insert complaint for hash type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(charlie()).unwrap();
+
+		let complaint = ComplaintHashBasedInfo {
+			complaint_proposer: bob(),
+			complaint_type: ComplaintType::ProjectComplaint,
+			complaint_for: project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
+
+		PenaltyTimeoutsHashes::<Test>::insert(120, penalty_hashes);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for hash
+		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check if the complaint has been updated
+		let complaint = ComplaintsForHashes::<Test>::get(complaint_ipfs.clone()).unwrap();
+
+		assert_eq!(complaint.complaint_active, false);
+
+		// Check if the project has been updated
+		let project_info = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project_info.penalty_level, 2);
+		assert_eq!(project_info.penalty_timeout, 446400);
+
+		// Check if the new penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(446400).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&project_hash), true);
+
+		// Check if the old penalty timeouts have updated
+		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(120).unwrap();
+
+		assert_eq!(penalty_timeouts.contains(&project_hash), false);
+	});
+}
+
+#[test]
+pub fn update_complaint_for_hash_carbon_credit_batch_ok() {
+	new_test_ext().execute_with(|| {
+		let project_hash = generate_hash(alice());
+		let batch_hash = generate_hash(bob());
+
+		// Insert project owner
+		let owner_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+
+		let project_owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: owner_documentation_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
+
+		ProjectOwners::<Test>::insert(alice(), project_owner);
+
+		// Insert project
+		let project_documentation =
+			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+
+		let project = ProjectInfo {
+			documentation_ipfs: project_documentation,
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project);
+
+		// Insert carbon credit batch
+		let batch_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation_ipfs,
+			project_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(10000u32),
+			penalty_repay_price: BalanceOf::<Test>::from(2u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
+		};
+
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+
+		// Insert carbon credit holdings (owner)
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(120u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+
+		// Insert carbon footprint account (#1)
+		let mut documentation_ipfses_1 =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let documentation_ipfs_1 = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs_1");
+
+		documentation_ipfses_1.try_insert(documentation_ipfs_1).unwrap();
+
+		let carbon_footprint_account_1 = CarbonFootprintAccountInfo {
+			documentation_ipfses: documentation_ipfses_1,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(50u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(bob(), carbon_footprint_account_1);
+
+		// Insert carbon footprint account (#2)
+		let mut documentation_ipfses_2 =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		let documentation_ipfs_2 = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs_2");
+
+		documentation_ipfses_2.try_insert(documentation_ipfs_2).unwrap();
+
+		let carbon_footprint_account_2 = CarbonFootprintAccountInfo {
+			documentation_ipfses: documentation_ipfses_2,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonFootprintAccounts::<Test>::insert(charlie(), carbon_footprint_account_2);
+
+		// Insert retirement (#1)
+		let retirement_hash_1 = generate_hash(dave());
+
+		let retirement_1 = CarbonCreditRetirementInfo {
+			carbon_footprint_account: bob(),
+			batch_hash: batch_hash,
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			retirement_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonCreditRetirements::<Test>::insert(retirement_hash_1, retirement_1);
+
+		// Insert retirement (#2)
+		let retirement_hash_2 = generate_hash(fred());
+
+		let retirement_2 = CarbonCreditRetirementInfo {
+			carbon_footprint_account: charlie(),
+			batch_hash: batch_hash,
+			credit_amount: BalanceOf::<Test>::from(123u32),
+			retirement_date: <mock::Test as pallet::Config>::Time::now(),
+		};
+
+		CarbonCreditRetirements::<Test>::insert(retirement_hash_2, retirement_2);
+
+		// Insert retirements by batch index
+		let mut retirements = BoundedBTreeSet::<H256, MaxRetirementsPerBatch>::new();
+		retirements.try_insert(retirement_hash_1).unwrap();
+		retirements.try_insert(retirement_hash_2).unwrap();
+
+		RetirementsByBatch::<Test>::insert(batch_hash, retirements);
+
+		// Insert carbon credit holdings (trader #1)
+		let credit_holdings_1 = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, dave(), credit_holdings_1);
+
+		// Insert carbon credit holdings (trader #2)
+		let credit_holdings_2 = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(250u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, fred(), credit_holdings_2);
+
+		// Insert carbon credit holdings (trader #3)
+		let credit_holdings_3 = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(300u32),
+			unavailable_amount: BalanceOf::<Test>::from(50u32),
+		};
+
+		CarbonCreditHoldings::<Test>::insert(batch_hash, george(), credit_holdings_3);
+
+		// Insert complaint for hash type
+		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+
+		let mut votes_for = VotesOf::<Test>::new();
+		votes_for.try_insert(hank()).unwrap();
+
+		let complaint = ComplaintHashBasedInfo {
+			complaint_proposer: ian(),
+			complaint_type: ComplaintType::CarbonCreditBatchComplaint,
+			complaint_for: batch_hash,
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			votes_for: votes_for,
+			votes_against: VotesOf::<Test>::new(),
+			complaint_active: true,
+		};
+
+		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+
+		// Insert complaint timeout
+		let mut complaint_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		complaint_events.try_insert(complaint_ipfs.clone()).unwrap();
+
+		ComplaintTimeouts::<Test>::insert(0, complaint_events);
+
+		// Successfully update complaint for hash
+		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::none(), complaint_ipfs.clone(), 0));
+
+		// Check carbon credit batch
+		let batch_info = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(batch_info.status, CarbonCreditBatchStatus::Redacted);
+
+		// Check if the redaction has been queued
+		assert_eq!(PendingRedactions::<Test>::contains_key(batch_hash), true);
+		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(&alice()), false);
+
+		// Process pending redactions
+		run_to_block(1);
+
+		assert_eq!(PendingRedactions::<Test>::contains_key(batch_hash), false);
+		System::assert_last_event(
+			Event::<Test>::CarbonCreditBatchRedactionProcessed(batch_hash).into(),
+		);
+
+		// Check carbon footprint balances
+		let carbon_footprint_account_1 = CarbonFootprintAccounts::<Test>::get(bob()).unwrap();
+
+		assert_eq!(carbon_footprint_account_1.carbon_footprint_surplus, BalanceOf::<Test>::from(0u32));
+		assert_eq!(carbon_footprint_account_1.carbon_footprint_deficit, BalanceOf::<Test>::from(50u32));
+
+		let carbon_footprint_account_2 = CarbonFootprintAccounts::<Test>::get(charlie()).unwrap();
+
+		assert_eq!(carbon_footprint_account_2.carbon_footprint_surplus, BalanceOf::<Test>::from(0u32));
+		assert_eq!(carbon_footprint_account_2.carbon_footprint_deficit, BalanceOf::<Test>::from(1123u32));
+
+		// Check debts
+		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(&alice()), true);
+
+		let debts = ProjectOwnerDebts::<Test>::get(alice());
+
+		assert_eq!(debts.len(), 6);
+		assert_eq!(debts.contains_key(&bob()), true);
+		assert_eq!(debts.contains_key(&charlie()), true);
+		assert_eq!(debts.contains_key(&dave()), true);
+		assert_eq!(debts.contains_key(&fred()), true);
+		assert_eq!(debts.contains_key(&george()), true);
+		assert_eq!(debts.contains_key(&pallet_id()), true);
+
+		assert_eq!(*debts.get(&bob()).unwrap(), BalanceOf::<Test>::from(200u32));
+		assert_eq!(*debts.get(&charlie()).unwrap(), BalanceOf::<Test>::from(246u32));
+		assert_eq!(*debts.get(&dave()).unwrap(), BalanceOf::<Test>::from(200u32));
+		assert_eq!(*debts.get(&fred()).unwrap(), BalanceOf::<Test>::from(500u32));
+		assert_eq!(*debts.get(&george()).unwrap(), BalanceOf::<Test>::from(700u32));
+		assert_eq!(*debts.get(&pallet_id()).unwrap(), BalanceOf::<Test>::from(260u32));
+	});
+}
+
+#[test]
+pub fn update_carbon_footprint_report_bad_origin() {
+	new_test_ext().execute_with(|| {
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		// Check for BadOrigin error
+		assert_err!(
+			Veles::update_carbon_footprint_report(RuntimeOrigin::signed(alice()), report_ipfs, 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+pub fn update_carbon_footprint_report_update_is_not_due() {
+	new_test_ext().execute_with(|| {
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
+
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
+
+		VotingTimeouts::<Test>::insert(10, timeout_events);
+
+		// Check for UpdateIsNotDue error (timeout block hasn't passed)
+		assert_err!(
+			Veles::update_carbon_footprint_report(RuntimeOrigin::none(), report_ipfs.clone(), 10),
+			Error::<Test>::UpdateIsNotDue
+		);
+
+		// Check for UpdateIsNotDue error (timeout event doesn't exist)
+		assert_err!(
+			Veles::update_carbon_footprint_report(RuntimeOrigin::none(), report_ipfs.clone(), 0),
+			Error::<Test>::UpdateIsNotDue
+		);
+
+		// Check if voting is still active
+		let report = CarbonFootprintReports::<Test>::get(report_ipfs).unwrap();
+
+		assert_eq!(report.voting_active, true);
+	});
+}
+
+#[test]
+pub fn update_project_penalty_level_update_is_not_due() {
+	new_test_ext().execute_with(|| {
+		// Insert project
+		let project_hash = generate_hash(alice());
+
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		};
+
+		Projects::<Test>::insert(project_hash, project_info);
+
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
 
-		// Check for CarbonCreditBatchDoesNotExist error
+		PenaltyTimeoutsHashes::<Test>::insert(120, penalty_hashes);
+
+		// Check for UpdateIsNotDue error
 		assert_err!(
-			Veles::retire_carbon_credits(
-				RuntimeOrigin::signed(alice()),
-				batch_hash,
-				amount_to_retire,
+			Veles::update_project_penalty_level(RuntimeOrigin::none(), project_hash),
+			Error::<Test>::UpdateIsNotDue
+		);
+
+		// Check if the unsigned transaction is rejected
+		assert_eq!(
+			Veles::validate_unsigned(
+				TransactionSource::External,
+				&Call::<Test>::update_project_penalty_level { hash: project_hash },
 			),
-			Error::<Test>::CarbonCreditBatchDoesNotExist
+			InvalidTransaction::Stale.into()
 		);
+
+		// Check if project penalty level is unchanged
+		let project = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project.penalty_level, 1);
+		assert_eq!(project.penalty_timeout, 120);
 	});
 }
 
 #[test]
-fn retire_carbon_credits_carbon_credit_batch_is_not_active() {
+pub fn update_carbon_credit_sale_order_update_is_not_due() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert carbon credit holdings
+		let batch_hash = generate_hash(alice());
 
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let footprint_account_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(0u32),
+			unavailable_amount: BalanceOf::<Test>::from(10u32),
+		};
 
-		documentation_ipfses.insert(footprint_account_ipfs);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
 
-		let carbon_footprint_account = CarbonFootprintAccountInfo {
-			documentation_ipfses,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
+		// Insert sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: batch_hash,
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: alice(),
+			buyer: None,
+			status: SaleOrderStatus::Open,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
 		};
 
-		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
-
-		// Insert carbon credit batch
-		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
-		let project_hash = generate_hash(bob());
+		let sale_hash = generate_hash(charlie());
 
-		let carbon_credit_batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_ipfs,
-			project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(200u32),
-			penalty_repay_price: BalanceOf::<Test>::from(20u32),
-			status: CarbonCreditBatchStatus::Frozen,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
-		};
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		let batch_hash = generate_hash(alice());
+		// Insert sale order timeout
+		let mut sale_events = TimeoutsOf::<Test, H256>::new();
+		sale_events.try_insert(sale_hash).unwrap();
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, carbon_credit_batch);
+		SaleOrderTimeouts::<Test>::insert(10, sale_events);
 
-		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+		// Go to the block before the sale order timeout block
+		System::set_block_number(9);
 
-		// Check for CarbonCreditBatchIsNotActive error
+		// Check for UpdateIsNotDue error (sale order timeout block hasn't passed)
 		assert_err!(
-			Veles::retire_carbon_credits(
-				RuntimeOrigin::signed(alice()),
-				batch_hash,
-				amount_to_retire,
-			),
-			Error::<Test>::CarbonCreditBatchIsNotActive
+			Veles::update_carbon_credit_sale_order(RuntimeOrigin::none(), sale_hash),
+			Error::<Test>::UpdateIsNotDue
 		);
 	});
 }
 
 #[test]
-fn retire_carbon_credits_carbon_credit_holdings_dont_exist() {
+pub fn update_pallet_base_time_ok() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
-
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let footprint_account_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
-
-		documentation_ipfses.insert(footprint_account_ipfs);
+		// Go to block 100
+		System::set_block_number(100);
 
-		let carbon_footprint_account = CarbonFootprintAccountInfo {
-			documentation_ipfses,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-		};
-
-		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+		// Check for UpdateIsNotDue error (block hasn't passed)
+		assert_err!(
+			Veles::update_pallet_base_time(RuntimeOrigin::none(), 101),
+			Error::<Test>::UpdateIsNotDue
+		);
 
-		// Insert carbon credit batch
-		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
-		let project_hash = generate_hash(bob());
+		// Successfully update pallet base time
+		assert_ok!(Veles::update_pallet_base_time(RuntimeOrigin::none(), 100));
 
-		let carbon_credit_batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_ipfs,
-			project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(200u32),
-			penalty_repay_price: BalanceOf::<Test>::from(20u32),
-			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
-		};
+		let pallet_times = PalletTimeValues::<Test>::get();
 
-		let batch_hash = generate_hash(alice());
+		assert_eq!(pallet_times.pallet_base_time, 100);
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, carbon_credit_batch);
+		// Check for UpdateIsNotDue error (a year hasn't passed)
+		assert_err!(
+			Veles::update_pallet_base_time(
+				RuntimeOrigin::none(),
+				100 + pallet_times.number_of_blocks_per_year
+			),
+			Error::<Test>::UpdateIsNotDue
+		);
 
-		let amount_to_retire = BalanceOf::<Test>::from(0u32);
+		// Go to the end of the year
+		System::set_block_number(100 + pallet_times.number_of_blocks_per_year);
 
-		// Check for CarbonCreditHoldingsDontExist error
+		// Check for UpdateIsNotDue error (base time can only move by a year)
 		assert_err!(
-			Veles::retire_carbon_credits(
-				RuntimeOrigin::signed(alice()),
-				batch_hash,
-				amount_to_retire,
-			),
-			Error::<Test>::CarbonCreditHoldingsDontExist
+			Veles::update_pallet_base_time(RuntimeOrigin::none(), 150),
+			Error::<Test>::UpdateIsNotDue
 		);
+
+		// Successfully update pallet base time
+		assert_ok!(Veles::update_pallet_base_time(
+			RuntimeOrigin::none(),
+			100 + pallet_times.number_of_blocks_per_year
+		));
 	});
 }
 
+// Timeout processing tests
+
 #[test]
-fn retire_carbon_credits_not_enought_available_credits() {
+pub fn process_timeouts_ok() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
 
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let footprint_account_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
 
-		documentation_ipfses.insert(footprint_account_ipfs);
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
 
-		let carbon_footprint_account = CarbonFootprintAccountInfo {
-			documentation_ipfses,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
 
-		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+		VotingTimeouts::<Test>::insert(3, timeout_events);
 
-		// Insert carbon credit batch
-		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
-		let project_hash = generate_hash(bob());
+		// Insert project
+		let project_hash = generate_hash(alice());
 
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_ipfs,
-			project_hash,
+		let project_info = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(200u32),
-			penalty_repay_price: BalanceOf::<Test>::from(20u32),
-			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
+			penalty_level: 1,
+			penalty_timeout: BlockNumber::<Test>::from(4u32),
 		};
 
-		let batch_hash = generate_hash(alice());
+		Projects::<Test>::insert(project_hash, project_info);
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+		// Insert penalty timeout
+		let mut penalty_hashes = TimeoutsOf::<Test, H256>::new();
+		penalty_hashes.try_insert(project_hash).unwrap();
 
-		// Insert carbon credit holdings
-		let holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(110u32),
-			unavailable_amount: BalanceOf::<Test>::from(20u32),
-		};
+		PenaltyTimeoutsHashes::<Test>::insert(4, penalty_hashes);
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), holdings);
+		// Process all timeout events up to block 5
+		run_to_block(5);
 
-		let amount_to_retire = BalanceOf::<Test>::from(200u32);
+		// Check if voting has closed
+		let report = CarbonFootprintReports::<Test>::get(report_ipfs).unwrap();
 
-		// Check for NotEnoughtAvailableCredits error
-		assert_err!(
-			Veles::retire_carbon_credits(
-				RuntimeOrigin::signed(alice()),
-				batch_hash,
-				amount_to_retire,
-			),
-			Error::<Test>::NotEnoughtAvailableCredits
-		);
+		assert_eq!(report.voting_active, false);
+		assert_eq!(VotingTimeouts::<Test>::get(3), None);
+
+		// Check if project penalty level has been updated
+		let project = Projects::<Test>::get(project_hash).unwrap();
+
+		assert_eq!(project.penalty_level, 0);
+		assert_eq!(project.penalty_timeout, 0);
+		assert_eq!(PenaltyTimeoutsHashes::<Test>::get(4), None);
+
+		// Check if the base pallet time has been set
+		assert_eq!(PalletTimeValues::<Test>::get().pallet_base_time, 1);
+
+		// Check if the timeouts cursor has moved past the current block
+		assert_eq!(TimeoutsCursor::<Test>::get(), 6);
 	});
 }
 
 #[test]
-fn retire_carbon_credits_ok() {
+pub fn process_timeouts_weight_limit_reached() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
+		};
 
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let footprint_account_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("footprint_account_ipfs");
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
+
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
 
-		documentation_ipfses.insert(footprint_account_ipfs);
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
 
-		let carbon_footprint_account = CarbonFootprintAccountInfo {
-			documentation_ipfses: documentation_ipfses.clone(),
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		VotingTimeouts::<Test>::insert(1, timeout_events);
 
-		CarbonFootprintAccounts::<Test>::insert(alice(), carbon_footprint_account);
+		// Don't allow any weight to be used for processing timeout events
+		MaxTimeoutsWeight::set(Weight::zero());
 
-		// Insert carbon credit batch
-		let batch_ipfs = BoundedString::<IPFSLength>::truncate_from("batch_ipfs");
-		let project_hash = generate_hash(bob());
+		run_to_block(2);
 
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_ipfs,
-			project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(200u32),
-			penalty_repay_price: BalanceOf::<Test>::from(20u32),
-			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
-		};
+		// Check if voting is still active
+		let report = CarbonFootprintReports::<Test>::get(report_ipfs.clone()).unwrap();
 
-		let batch_hash = generate_hash(alice());
+		assert_eq!(report.voting_active, true);
+		assert_eq!(TimeoutsCursor::<Test>::get(), 0);
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+		// Allow timeout events to be processed again
+		MaxTimeoutsWeight::set(Weight::MAX);
 
-		// Insert carbon credit holdings
-		let holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(110u32),
-			unavailable_amount: BalanceOf::<Test>::from(20u32),
-		};
+		run_to_block(3);
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), holdings);
+		// Check if the carried over timeout event has been processed
+		let report = CarbonFootprintReports::<Test>::get(report_ipfs).unwrap();
 
-		let amount_to_retire = BalanceOf::<Test>::from(50u32);
+		assert_eq!(report.voting_active, false);
+		assert_eq!(VotingTimeouts::<Test>::get(1), None);
+		assert_eq!(TimeoutsCursor::<Test>::get(), 4);
+	});
+}
 
-		// Successfully retire carbon credits
-		assert_ok!(Veles::retire_carbon_credits(
-			RuntimeOrigin::signed(alice()),
-			batch_hash,
-			amount_to_retire,
-		));
+#[test]
+pub fn process_timeouts_heavy_timeout_event_processed() {
+	new_test_ext().execute_with(|| {
+		// Insert carbon footprint reports
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		let mut report_ipfses = Vec::new();
 
-		// Check carbon credit holdings
-		let holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		for ipfs in ["report_ipfs_1", "report_ipfs_2"] {
+			let report_info = CarbonFootprintReportInfo {
+				cf_account: alice(),
+				creation_date: <mock::Test as pallet::Config>::Time::now(),
+				carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+				carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+				votes_for: VotesOf::<Test>::new(),
+				votes_against: VotesOf::<Test>::new(),
+				voting_active: true,
+			};
 
-		assert_eq!(holdings.available_amount, BalanceOf::<Test>::from(60u32));
-		assert_eq!(holdings.unavailable_amount, BalanceOf::<Test>::from(20u32));
+			let report_ipfs = BoundedString::<IPFSLength>::truncate_from(ipfs);
 
-		// Check carbon footprint account
-		let carbon_footprint_account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+			CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
+			timeout_events.try_insert(report_ipfs.clone()).unwrap();
+			report_ipfses.push(report_ipfs);
+		}
 
-		assert_eq!(carbon_footprint_account.documentation_ipfses, documentation_ipfses);
-		assert_eq!(
-			carbon_footprint_account.carbon_footprint_surplus,
-			BalanceOf::<Test>::from(0u32)
-		);
-		assert_eq!(
-			carbon_footprint_account.carbon_footprint_deficit,
-			BalanceOf::<Test>::from(950u32)
-		);
-		assert_eq!(
-			carbon_footprint_account.creation_date,
-			<mock::Test as pallet::Config>::Time::now()
-		);
+		// Insert voting timeouts
+		VotingTimeouts::<Test>::insert(1, timeout_events);
 
-		// Check carbon credit retirement info
-		let retirement_hash = generate_hash(alice());
+		// Allow less weight than a single timeout event needs
+		MaxTimeoutsWeight::set(Weight::from_parts(1, 0));
 
-		let retirement = CarbonCreditRetirements::<Test>::get(retirement_hash).unwrap();
+		run_to_block(2);
+
+		// Check if only one timeout event has been processed
+		let closed_reports = report_ipfses
+			.iter()
+			.filter(|ipfs| !CarbonFootprintReports::<Test>::get(ipfs).unwrap().voting_active)
+			.count();
+
+		assert_eq!(closed_reports, 1);
+		assert_eq!(VotingTimeouts::<Test>::get(1).unwrap().len(), 1);
+		assert_eq!(TimeoutsCursor::<Test>::get(), 1);
+
+		run_to_block(3);
 
-		assert_eq!(retirement.carbon_footprint_account, alice());
-		assert_eq!(retirement.batch_hash, batch_hash);
-		assert_eq!(retirement.credit_amount, BalanceOf::<Test>::from(50u32));
-		assert_eq!(retirement.retirement_date, <mock::Test as pallet::Config>::Time::now());
-	});
-}
+		// Check if the remaining timeout event has been processed
+		for ipfs in report_ipfses {
+			assert_eq!(CarbonFootprintReports::<Test>::get(ipfs).unwrap().voting_active, false);
+		}
 
-#[test]
-fn repay_project_owner_debts_project_owner_doesnt_exist() {
-	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		assert_eq!(VotingTimeouts::<Test>::get(1), None);
+		assert_eq!(TimeoutsCursor::<Test>::get(), 4);
 
-		// Check for ProjectOwnerDoesntExist error
-		assert_err!(
-			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
-			Error::<Test>::ProjectOwnerDoesntExist
-		);
+		MaxTimeoutsWeight::set(Weight::MAX);
 	});
 }
 
 #[test]
-fn repay_project_owner_debts_project_owner_doesnt_have_any_debts() {
+pub fn process_timeouts_stale_timeout_events_removed() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		run_to_block(1);
+		// Insert voting timeout for a non existing report
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
 
-		// Insert project owner
-		let owner_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs).unwrap();
 
-		let project_owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation_ipfs.clone(),
-			penalty_level: 0,
-			penalty_timeout: 0,
-		};
+		VotingTimeouts::<Test>::insert(1, timeout_events);
 
-		ProjectOwners::<Test>::insert(alice(), project_owner);
+		run_to_block(1);
 
-		// Check for ProjectOwnerDoesntHaveAnyDebts error
-		assert_err!(
-			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
-			Error::<Test>::ProjectOwnerDoesntHaveAnyDebts
-		);
+		// Check if the stale timeout event has been removed
+		assert_eq!(VotingTimeouts::<Test>::get(1), None);
 	});
 }
 
 #[test]
-fn repay_project_owner_debts_insufficient_funds() {
+pub fn process_timeouts_buy_order_ok() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Insert project owner
-		let owner_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+		// Set a short sales timeout
+		PalletTimeValues::<Test>::mutate(|time_values| time_values.sales_timeout = 3);
 
-		let project_owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation_ipfs.clone(),
+		// Insert trader account and project
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			penalty_level: 0,
 			penalty_timeout: 0,
 		};
 
-		ProjectOwners::<Test>::insert(alice(), project_owner);
+		let project_hash = generate_hash(alice());
 
-		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
+		Projects::<Test>::insert(project_hash, project);
 
-		debts.insert(bob(), BalanceOf::<Test>::from(20u32));
-		debts.insert(charlie(), BalanceOf::<Test>::from(30u32));
+		// Create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
 
-		ProjectOwnerDebts::<Test>::insert(alice(), debts);
+		let buy_hash = generate_hash(charlie());
 
-		// Check for InsufficientFunds error
-		assert_err!(
-			Veles::repay_project_owner_debts(RuntimeOrigin::signed(alice()),),
-			Error::<Test>::InsufficientFunds
+		// Process all timeout events up to block 4
+		run_to_block(4);
+
+		System::assert_last_event(Event::<Test>::CarbonCreditBuyOrderUpdated(buy_hash).into());
+
+		// Check if the buy order has been closed and the buyer funds released
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
+
+		assert_eq!(buy_order.buy_active, false);
+		assert_eq!(BuyOrderTimeouts::<Test>::get(4), None);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()),
+			0
 		);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
 	});
 }
 
 #[test]
-fn repay_project_owner_debts_ok() {
+pub fn process_timeouts_buy_order_release_failed() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		run_to_block(1);
 
-		// Insert project owner
-		let owner_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+		// Set a short sales timeout
+		PalletTimeValues::<Test>::mutate(|time_values| time_values.sales_timeout = 3);
 
-		let project_owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation_ipfs.clone(),
+		// Insert trader account and project
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project"),
+			project_owner: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			penalty_level: 0,
 			penalty_timeout: 0,
 		};
 
-		ProjectOwners::<Test>::insert(charlie(), project_owner);
-
-		// Insert project owner debts
-		let mut debts = BTreeMap::<AccountIdOf<Test>, BalanceOf<Test>>::new();
-
-		debts.insert(alice(), BalanceOf::<Test>::from(20u32));
-		debts.insert(bob(), BalanceOf::<Test>::from(30u32));
-
-		ProjectOwnerDebts::<Test>::insert(charlie(), debts);
-
-		// Check balances before extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 1);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 100);
+		let project_hash = generate_hash(alice());
 
-		// Check debt storage
-		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(charlie()), true);
+		Projects::<Test>::insert(project_hash, project);
 
-		// Successfully pay off project owner debts
-		assert_ok!(Veles::repay_project_owner_debts(RuntimeOrigin::signed(charlie()),));
+		// Create buy order
+		assert_ok!(Veles::create_buy_order(
+			RuntimeOrigin::signed(charlie()),
+			BuyOrderTarget::Project(project_hash),
+			BalanceOf::<Test>::from(5u32),
+			BalanceOf::<Test>::from(10u32),
+		));
 
-		// Check debt storage
-		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(charlie()), false);
+		let buy_hash = generate_hash(charlie());
 
-		// Check balances after extrinsic call
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4950);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(alice()), 21);
-		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(bob()), 130);
-	});
-}
+		// Release part of the held buyer funds (so the buy order funds can't be released)
+		assert_ok!(Balances::release(
+			&HoldReason::BuyOrderEscrow.into(),
+			&charlie(),
+			10,
+			Precision::Exact
+		));
 
-// Utility functions tests
+		// Check for FundsUnavailable error (the cancellation is reverted)
+		assert_err!(
+			Veles::cancel_buy_order(RuntimeOrigin::signed(charlie()), buy_hash),
+			DispatchError::Token(TokenError::FundsUnavailable)
+		);
 
-#[test]
-pub fn has_vote_passed_ok() {
-	new_test_ext().execute_with(|| {
-		// Example 1
-		assert_eq!(Veles::has_vote_passed(6, 4), true);
-		assert_eq!(Veles::has_vote_passed(6, 1), false);
-		assert_eq!(Veles::has_vote_passed(6, 5), true);
+		assert_eq!(CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap().buy_active, true);
 
-		// Example 2
-		let new_pass_ration = ProportionStructure { proportion_part: 10, upper_limit_part: 0 };
-		VotePassRatio::<Test>::set(new_pass_ration);
+		// Process all timeout events up to block 4
+		run_to_block(4);
 
-		assert_eq!(Veles::has_vote_passed(6, 4), true);
-		assert_eq!(Veles::has_vote_passed(6, 5), true);
-		assert_eq!(Veles::has_vote_passed(3, 1), false);
+		System::assert_has_event(
+			Event::<Test>::EscrowReleaseFailed(charlie(), buy_hash, 50).into(),
+		);
+		System::assert_last_event(Event::<Test>::CarbonCreditBuyOrderUpdated(buy_hash).into());
 
-		// // Example 3
-		let new_pass_ration = ProportionStructure { proportion_part: 1, upper_limit_part: 1 };
-		VotePassRatio::<Test>::set(new_pass_ration);
+		// Check if the buy order has been closed (the held buyer funds are left on hold)
+		let buy_order = CarbonCreditBuyOrders::<Test>::get(buy_hash).unwrap();
 
-		assert_eq!(Veles::has_vote_passed(6, 4), false);
-		assert_eq!(Veles::has_vote_passed(6, 6), true);
-		assert_eq!(Veles::has_vote_passed(2, 1), false);
+		assert_eq!(buy_order.buy_active, false);
+		assert_eq!(BuyOrderTimeouts::<Test>::get(4), None);
+		assert_eq!(Balances::balance_on_hold(&HoldReason::BuyOrderEscrow.into(), &charlie()), 40);
 	});
 }
 
-// Offchain worker tests
+// Pruning tests
 
 #[test]
-pub fn update_carbon_footprint_report_new_account_ok() {
+pub fn process_pruning_ok() {
 	new_test_ext().execute_with(|| {
 		// Insert carbon footprint report
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(bob());
-
 		let report_info = CarbonFootprintReportInfo {
 			cf_account: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
 			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
 			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
 			voting_active: true,
 		};
 
@@ -5443,921 +12078,1208 @@ pub fn update_carbon_footprint_report_new_account_ok() {
 
 		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
 
-		// Successfully update carbon footprint report
-		assert_ok!(Veles::update_carbon_footprint_report(RuntimeOrigin::signed(charlie()), report_ipfs.clone()));
+		// Insert used documentation
+		UsedDocumentation::<Test>::insert(
+			report_ipfs.clone(),
+			DocumentationOwner::CarbonFootprintReport(alice()),
+		);
 
-		// Check if voting has closed
-		let report = CarbonFootprintReports::<Test>::get(report_ipfs.clone()).unwrap();
+		// Insert voting timeout
+		let mut timeout_events = TimeoutsOf::<Test, BoundedString<IPFSLength>>::new();
+		timeout_events.try_insert(report_ipfs.clone()).unwrap();
 
-		assert_eq!(report.voting_active, false);
+		VotingTimeouts::<Test>::insert(1, timeout_events);
 
-		// Check if carbon footprint account has been saved
-		let account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+		// Close voting
+		run_to_block(1);
 
-		assert_eq!(account.documentation_ipfses.contains(&report_ipfs), true);
-		assert_eq!(account.carbon_footprint_surplus, 0);
-		assert_eq!(account.carbon_footprint_deficit, 100);
-		assert_eq!(account.creation_date, <mock::Test as pallet::Config>::Time::now());
+		// Check if the finished report has been scheduled for pruning
+		let record = FinishedRecord::CarbonFootprintReport(report_ipfs.clone());
+
+		assert_eq!(PruningTimeouts::<Test>::get(101).unwrap().contains(&record), true);
+
+		// Check if the report is kept during the retention period
+		run_to_block(100);
+		Veles::on_idle(100, Weight::MAX);
+
+		assert_eq!(CarbonFootprintReports::<Test>::contains_key(report_ipfs.clone()), true);
+		assert_eq!(PruningCursor::<Test>::get(), 101);
+
+		// Prune the finished report
+		run_to_block(101);
+		Veles::on_idle(101, Weight::MAX);
+
+		assert_eq!(CarbonFootprintReports::<Test>::contains_key(report_ipfs.clone()), false);
+		assert_eq!(PruningTimeouts::<Test>::get(101), None);
+		assert_eq!(PruningCursor::<Test>::get(), 102);
+		System::assert_last_event(Event::<Test>::FinishedRecordPruned(record).into());
+
+		// Check if the documentation can't be reused
+		assert_eq!(Veles::is_ipfs_available(report_ipfs), false);
 	});
 }
 
+// Fungibles tests
+
 #[test]
-pub fn update_carbon_footprint_report_old_account_ok() {
+fn fungibles_transfer_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert carbon footprint account
-		let mut documentation_ipfses = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let report_1_ipfs = BoundedString::<IPFSLength>::truncate_from("report_1_ipfs");
-		documentation_ipfses.insert(report_1_ipfs.clone());
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		let cf_account = CarbonFootprintAccountInfo {
-			documentation_ipfses: documentation_ipfses,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(50u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation_ipfs,
+			project_hash: generate_hash(bob()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		CarbonFootprintAccounts::<Test>::insert(alice(), cf_account);
+		let batch_hash = generate_hash(alice());
 
-		// Insert carbon footprint report
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(bob());
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let report_info = CarbonFootprintReportInfo {
-			cf_account: alice(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
-			votes_for: BTreeSet::<AccountId>::new(),
-			votes_against: BTreeSet::<AccountId>::new(),
-			voting_active: true,
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
 		};
 
-		let report_2_ipfs = BoundedString::<IPFSLength>::truncate_from("report_2_ipfs");
-
-		CarbonFootprintReports::<Test>::insert(report_2_ipfs.clone(), report_info);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
 
-		// Successfully update carbon footprint report
-		assert_ok!(Veles::update_carbon_footprint_report(RuntimeOrigin::signed(charlie()), report_2_ipfs.clone()));
+		// Put some of the carbon credits in a sales cycle
+		assert_ok!(Veles::create_sale_order(
+			RuntimeOrigin::signed(alice()),
+			batch_hash,
+			BalanceOf::<Test>::from(0u32),
+			BalanceOf::<Test>::from(5u32),
+			None,
+			None,
+			None,
+		));
 
-		// Check if voting has closed
-		let report = CarbonFootprintReports::<Test>::get(report_2_ipfs.clone()).unwrap();
+		// Check balances
+		assert_eq!(<Veles as fungibles::Inspect<_>>::asset_exists(batch_hash), true);
+		assert_eq!(<Veles as fungibles::Inspect<_>>::total_issuance(batch_hash), 40);
+		assert_eq!(<Veles as fungibles::Inspect<_>>::balance(batch_hash, &alice()), 35);
+		assert_eq!(<Veles as fungibles::Inspect<_>>::total_balance(batch_hash, &alice()), 40);
+		assert_eq!(
+			<Veles as fungibles::InspectHold<_>>::balance_on_hold(
+				batch_hash,
+				&CarbonCreditHoldReason::SaleOrder,
+				&alice()
+			),
+			5
+		);
 
-		assert_eq!(report.voting_active, false);
+		// Successfully transfer carbon credits
+		assert_ok!(<Veles as fungibles::Mutate<_>>::transfer(
+			batch_hash,
+			&alice(),
+			&charlie(),
+			BalanceOf::<Test>::from(15u32),
+			Preservation::Expendable,
+		));
 
-		// Check if carbon footprint account has been saved
-		let account = CarbonFootprintAccounts::<Test>::get(alice()).unwrap();
+		let alice_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		let charlie_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, charlie()).unwrap();
 
-		assert_eq!(account.documentation_ipfses.contains(&report_1_ipfs), true);
-		assert_eq!(account.documentation_ipfses.contains(&report_2_ipfs), true);
-		assert_eq!(account.carbon_footprint_surplus, 0);
-		assert_eq!(account.carbon_footprint_deficit, 50);
-		assert_eq!(account.creation_date, <mock::Test as pallet::Config>::Time::now());
+		assert_eq!(alice_holdings.available_amount, BalanceOf::<Test>::from(20u32));
+		assert_eq!(alice_holdings.unavailable_amount, BalanceOf::<Test>::from(5u32));
+		assert_eq!(charlie_holdings.available_amount, BalanceOf::<Test>::from(15u32));
+		assert_eq!(charlie_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
 	});
 }
 
 #[test]
-pub fn update_project_proposal_ok() {
+fn fungibles_issuance_immutable() {
 	new_test_ext().execute_with(|| {
-		// Insert carbon footprint report
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(bob());
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		let project_hash = generate_hash(alice());
+		// Insert trader account
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
 
-		let proposal = ProjectProposalInfo {
-			project_owner: alice(),
+		// Insert carbon credit batch and holdings
+		let batch_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation_ipfs,
+			project_hash: generate_hash(bob()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			project_hash: project_hash,
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountId>::new(),
-			voting_active: true,
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
-
-		ProjectProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
-
-		// Successfully update project proposal
-		assert_ok!(Veles::update_project_proposal(RuntimeOrigin::signed(charlie()), proposal_ipfs.clone()));
-
-		// Check if voting has closed
-		let proposal = ProjectProposals::<Test>::get(proposal_ipfs.clone()).unwrap();
-
-		assert_eq!(proposal.voting_active, false);
-
-		// Check if project has been saved
-		let project = Projects::<Test>::get(project_hash).unwrap();
-
-		assert_eq!(project.documentation_ipfs, proposal_ipfs);
-		assert_eq!(project.project_owner, alice());
-		assert_eq!(project.creation_date, <mock::Test as pallet::Config>::Time::now());
-		assert_eq!(project.penalty_level, 0);
-		assert_eq!(project.penalty_timeout, BlockNumber::<Test>::from(0u32));
-	});
-}
+		let batch_hash = generate_hash(alice());
 
-#[test]
-pub fn update_project_penalty_level_zero_penalty_level_ok() {
-	new_test_ext().execute_with(|| {
-		// Insert project
-		let project_hash = generate_hash(alice());
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		let project_info = ProjectInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
-			project_owner: alice(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		let holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
 		};
 
-		Projects::<Test>::insert(project_hash, project_info);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), holdings.clone());
+
+		// Check for Unavailable error (minting, burning, shelving and restoring)
+		assert_err!(
+			<Veles as fungibles::Mutate<_>>::mint_into(
+				batch_hash,
+				&alice(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			DispatchError::Unavailable
+		);
 
-		// Successfully update project penalty level
-		assert_ok!(Veles::update_project_penalty_level(RuntimeOrigin::signed(alice()), project_hash));
+		assert_err!(
+			<Veles as fungibles::Mutate<_>>::burn_from(
+				batch_hash,
+				&alice(),
+				BalanceOf::<Test>::from(10u32),
+				Precision::Exact,
+				Fortitude::Polite,
+			),
+			DispatchError::Unavailable
+		);
 
-		// Check if project penalty level has been updated
-		let project = Projects::<Test>::get(project_hash).unwrap();
+		assert_err!(
+			<Veles as fungibles::Mutate<_>>::shelve(
+				batch_hash,
+				&alice(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			DispatchError::Unavailable
+		);
 
-		assert_eq!(project.penalty_level, 0);
-		assert_eq!(project.penalty_timeout, 0);
+		assert_err!(
+			<Veles as fungibles::Mutate<_>>::restore(
+				batch_hash,
+				&alice(),
+				BalanceOf::<Test>::from(10u32),
+			),
+			DispatchError::Unavailable
+		);
+
+		// Check if the issuance can't be changed directly
+		<Veles as fungibles::Unbalanced<_>>::set_total_issuance(
+			batch_hash,
+			BalanceOf::<Test>::from(100u32),
+		);
+
+		assert_eq!(<Veles as fungibles::Inspect<_>>::total_issuance(batch_hash), 40);
+		assert_eq!(CarbonCreditHoldings::<Test>::get(batch_hash, alice()), Some(holdings));
 	});
 }
 
 #[test]
-pub fn update_project_penalty_level_non_zero_penalty_level_ok() {
+fn fungibles_transfer_fails() {
 	new_test_ext().execute_with(|| {
-		// Insert project
-		let project_hash = generate_hash(alice());
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		let project_info = ProjectInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
-			project_owner: alice(),
+		// Insert trader accounts
+		TraderAccounts::<Test>::insert(alice(), TraderAccountInfo::default());
+		TraderAccounts::<Test>::insert(charlie(), TraderAccountInfo::default());
+
+		// Insert carbon credit batch
+		let batch_documentation_ipfs =
+			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: batch_documentation_ipfs,
+			project_hash: generate_hash(bob()),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 2,
-			penalty_timeout: BlockNumber::<Test>::from(320u32),
+			credit_amount: BalanceOf::<Test>::from(40u32),
+			penalty_repay_price: BalanceOf::<Test>::from(1u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		Projects::<Test>::insert(project_hash, project_info);
+		let batch_hash = generate_hash(alice());
 
-		// Successfully update project penalty level
-		assert_ok!(Veles::update_project_penalty_level(RuntimeOrigin::signed(alice()), project_hash));
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		// Check if project penalty level has been updated
-		let project = Projects::<Test>::get(project_hash).unwrap();
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(40u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		assert_eq!(project.penalty_level, 1);
-		assert_eq!(project.penalty_timeout, 446400);
-	});
-}
+		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
 
-#[test]
-pub fn update_validator_penalty_level_zero_penalty_level_ok() {
-	new_test_ext().execute_with(|| {
-		// Insert validator
-		let validator_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		let transfer = |batch_hash: H256, dest: AccountId, amount: u32| {
+			<Veles as fungibles::Mutate<_>>::transfer(
+				batch_hash,
+				&alice(),
+				&dest,
+				BalanceOf::<Test>::from(amount),
+				Preservation::Expendable,
+			)
 		};
 
-		Validators::<Test>::insert(alice(), validator_info);
+		// Check for unknown asset error
+		assert_err!(
+			transfer(generate_hash(bob()), charlie(), 10),
+			DispatchError::Token(TokenError::UnknownAsset)
+		);
 
-		// Successfully update validator penalty level
-		assert_ok!(Veles::update_validator_penalty_level(RuntimeOrigin::signed(alice()), alice()));
+		// Check for blocked error (receiver isn't eligible for carbon credit transactions)
+		assert_err!(transfer(batch_hash, bob(), 10), DispatchError::Token(TokenError::Blocked));
 
-		// Check if validator penalty level has been updated
-		let validator = Validators::<Test>::get(alice()).unwrap();
+		// Check for funds unavailable error
+		assert_err!(
+			transfer(batch_hash, charlie(), 50),
+			DispatchError::Token(TokenError::FundsUnavailable)
+		);
 
-		assert_eq!(validator.penalty_level, 0);
-		assert_eq!(validator.penalty_timeout, 0);
-	});
-}
+		// Check for frozen error (carbon credit batch isn't active)
+		CarbonCreditBatches::<Test>::mutate(batch_hash, |batch| {
+			batch.as_mut().unwrap().status = CarbonCreditBatchStatus::Frozen;
+		});
 
-#[test]
-pub fn update_validator_penalty_level_non_zero_penalty_level_ok() {
-	new_test_ext().execute_with(|| {
-		// Insert validator
-		let validator_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 2,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
-		};
+		assert_eq!(
+			<Veles as fungibles::Inspect<_>>::reducible_balance(
+				batch_hash,
+				&alice(),
+				Preservation::Expendable,
+				Fortitude::Polite
+			),
+			0
+		);
+		assert_err!(transfer(batch_hash, charlie(), 10), DispatchError::Token(TokenError::Frozen));
 
-		Validators::<Test>::insert(alice(), validator_info);
+		// Check for frozen error (project owner has standing debts)
+		CarbonCreditBatches::<Test>::mutate(batch_hash, |batch| {
+			batch.as_mut().unwrap().status = CarbonCreditBatchStatus::Active;
+		});
 
-		// Successfully update validator penalty level
-		assert_ok!(Veles::update_validator_penalty_level(RuntimeOrigin::signed(alice()), alice()));
+		ProjectOwners::<Test>::insert(alice(), ProjectValidatorOrProjectOwnerInfo::default());
 
-		// Check if validator penalty level has been updated
-		let validator = Validators::<Test>::get(alice()).unwrap();
+		let mut debts = DebtsOf::<Test>::new();
+		debts.try_insert(bob(), BalanceOf::<Test>::from(10u32)).unwrap();
 
-		assert_eq!(validator.penalty_level, 1);
-		assert_eq!(validator.penalty_timeout, 446400);
+		ProjectOwnerDebts::<Test>::insert(alice(), debts);
+
+		assert_err!(transfer(batch_hash, charlie(), 10), DispatchError::Token(TokenError::Frozen));
+
+		// Check that the carbon credit holdings are unchanged
+		let holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+
+		assert_eq!(holdings.available_amount, BalanceOf::<Test>::from(40u32));
+		assert_eq!(CarbonCreditHoldings::<Test>::contains_key(batch_hash, charlie()), false);
 	});
 }
 
+// Deposit tests
+
 #[test]
-pub fn update_project_owner_penalty_level_zero_penalty_level_ok() {
+fn submit_carbon_footprint_report_deposit_mode_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert project owner
-		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
-		};
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+		// Enable deposit mode
+		DepositMode::set(true);
 
-		// Successfully update project owner penalty level
-		assert_ok!(Veles::update_project_owner_penalty_level(RuntimeOrigin::signed(alice()), alice()));
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 
-		// Check if project owner penalty level has been updated
-		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+		// Successfully submit carbon footprint report
+		assert_ok!(Veles::submit_carbon_footprint_report(
+			RuntimeOrigin::signed(charlie()),
+			documentation_ipfs.clone(),
+			BalanceOf::<Test>::from(0u32),
+			BalanceOf::<Test>::from(10u32),
+		));
 
-		assert_eq!(project_owner_info.penalty_level, 0);
-		assert_eq!(project_owner_info.penalty_timeout, 0);
+		// Check if the fee was held instead of transferred
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4700);
+		assert_eq!(pallet_balances::Pallet::<Test>::reserved_balance(charlie()), 300);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+		assert_eq!(
+			Balances::balance_on_hold(&HoldReason::ProposalDeposit.into(), &charlie()),
+			300
+		);
+
+		// Check if the deposit was stored correctly
+		let deposit = Deposits::<Test>::get(documentation_ipfs.clone()).unwrap();
+
+		assert_eq!(deposit.depositor, charlie());
+		assert_eq!(deposit.amount, 300);
+
+		System::assert_has_event(
+			Event::<Test>::DepositHeld(charlie(), documentation_ipfs, 300).into(),
+		);
 	});
 }
 
 #[test]
-pub fn update_project_owner_penalty_level_non_zero_penalty_level_ok() {
+fn update_carbon_footprint_report_deposit_released() {
 	new_test_ext().execute_with(|| {
-		// Insert project owner
-		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 2,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
-		};
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+		// Enable deposit mode
+		DepositMode::set(true);
 
-		// Successfully update project owner penalty level
-		assert_ok!(Veles::update_project_owner_penalty_level(RuntimeOrigin::signed(alice()), alice()));
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 
-		// Check if project owner penalty level has been updated
-		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+		assert_ok!(Veles::submit_carbon_footprint_report(
+			RuntimeOrigin::signed(charlie()),
+			documentation_ipfs.clone(),
+			BalanceOf::<Test>::from(0u32),
+			BalanceOf::<Test>::from(10u32),
+		));
 
-		assert_eq!(project_owner_info.penalty_level, 1);
-		assert_eq!(project_owner_info.penalty_timeout, 446400);
+		// Go to the voting timeout block
+		let timeout_block = 1 + PalletTimeValues::<Test>::get().voting_timeout;
+		System::set_block_number(timeout_block);
+
+		// Successfully update carbon footprint report
+		assert_ok!(Veles::update_carbon_footprint_report(
+			RuntimeOrigin::none(),
+			documentation_ipfs.clone(),
+			timeout_block
+		));
+
+		// Check if the deposit was returned
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 5000);
+		assert_eq!(pallet_balances::Pallet::<Test>::reserved_balance(charlie()), 0);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 0);
+		assert_eq!(Deposits::<Test>::get(documentation_ipfs.clone()), None);
+
+		System::assert_has_event(
+			Event::<Test>::DepositReleased(charlie(), documentation_ipfs, 300).into(),
+		);
 	});
 }
 
 #[test]
-pub fn update_carbon_credit_sale_order_ok() {
+fn update_carbon_footprint_report_deposit_slashed() {
 	new_test_ext().execute_with(|| {
-		// Insert trader account
-		let mut new_traders = TraderAccounts::<Test>::get();
-		new_traders.insert(alice());
-		TraderAccounts::<Test>::set(new_traders);
-
-		// Insert carbon credit holdings 
-		let batch_hash = generate_hash(alice());
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		let credit_holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(20u32),
-			unavailable_amount: BalanceOf::<Test>::from(15u32),
-		};
+		// Enable deposit mode
+		DepositMode::set(true);
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+		let documentation_ipfs = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs");
 
-		// Insert sale order
-		let sale_order = CarbonCreditSaleOrderInfo {
-			batch_hash: batch_hash,
-			credit_amount: BalanceOf::<Test>::from(10u32),
-			credit_price: BalanceOf::<Test>::from(5u32),
-			seller: alice(),
-			buyer: alice(),
-			sale_active: true,
-			sale_timeout: BlockNumber::<Test>::from(10u32),
-		};
+		assert_ok!(Veles::submit_carbon_footprint_report(
+			RuntimeOrigin::signed(charlie()),
+			documentation_ipfs.clone(),
+			BalanceOf::<Test>::from(0u32),
+			BalanceOf::<Test>::from(10u32),
+		));
 
-		let sale_hash = generate_hash(charlie());
+		// Vote against the report
+		let mut report = CarbonFootprintReports::<Test>::get(documentation_ipfs.clone()).unwrap();
+		report.votes_against.try_insert(bob()).unwrap();
 
-		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
+		CarbonFootprintReports::<Test>::insert(documentation_ipfs.clone(), report);
 
-		// Successfully update carbon credit sale order
-		assert_ok!(Veles::update_carbon_credit_sale_order(RuntimeOrigin::signed(alice()), sale_hash));
+		// Go to the voting timeout block
+		let timeout_block = 1 + PalletTimeValues::<Test>::get().voting_timeout;
+		System::set_block_number(timeout_block);
 
-		// Check if the carbon credit sale order has been updated
-		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+		// Successfully update carbon footprint report
+		assert_ok!(Veles::update_carbon_footprint_report(
+			RuntimeOrigin::none(),
+			documentation_ipfs.clone(),
+			timeout_block
+		));
 
-		assert_eq!(sale_order.sale_active, false);
-	
-		// Check if the carbon credit holdings have been updated
-		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		// Check if the deposit was moved to the pallet
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(charlie()), 4700);
+		assert_eq!(pallet_balances::Pallet::<Test>::reserved_balance(charlie()), 0);
+		assert_eq!(pallet_balances::Pallet::<Test>::free_balance(pallet_id()), 300);
+		assert_eq!(Deposits::<Test>::get(documentation_ipfs.clone()), None);
 
-		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(30u32));
-		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(5u32));
+		System::assert_has_event(
+			Event::<Test>::DepositSlashed(charlie(), documentation_ipfs, 300).into(),
+		);
 	});
 }
 
+
+// Migration tests
+
 #[test]
-pub fn update_carbon_credit_batch_proposal_ok() {
+fn backfill_used_documentation_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert project owner
-		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
+		// Insert validator
+		let validator_ipfs = BoundedString::<IPFSLength>::truncate_from("validator_ipfs");
+
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_ipfs.clone(),
 			penalty_level: 0,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			penalty_timeout: 0,
 		};
 
-		ProjectOwners::<Test>::insert(alice(), project_owner_info);
+		Validators::<Test>::insert(bob(), validator);
 
-		// Insert project
-		let project_hash = generate_hash(bob());
+		// Insert carbon footprint account
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
 
-		let project_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+		let mut documentation_ipfses =
+			BoundedBTreeSet::<BoundedString<IPFSLength>, MaxDocumentsPerCfa>::new();
+		documentation_ipfses.try_insert(report_ipfs.clone()).unwrap();
 
-		let project = ProjectInfo {
-			documentation_ipfs: project_documentation_ipfs,
-			project_owner: alice(),
+		let cf_account = CarbonFootprintAccountInfo {
+			documentation_ipfses,
+			carbon_footprint_surplus: BalanceOf::<Test>::from(50u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 0,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
 		};
 
-		Projects::<Test>::insert(project_hash, project);
+		CarbonFootprintAccounts::<Test>::insert(alice(), cf_account);
 
-		// Insert carbon credit batch proposal
-		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
+		assert_eq!(Veles::is_ipfs_available(validator_ipfs.clone()), true);
+		assert_eq!(Veles::is_ipfs_available(report_ipfs.clone()), true);
 
-		let batch_hash = generate_hash(charlie());
+		// Run the migration
+		migrations::BackfillUsedDocumentation::<Test>::on_runtime_upgrade();
+
+		// Check if the documentation was indexed
+		assert_eq!(
+			Veles::used_documentation(validator_ipfs.clone()),
+			Some(DocumentationOwner::Validator(bob()))
+		);
+		assert_eq!(
+			Veles::used_documentation(report_ipfs.clone()),
+			Some(DocumentationOwner::CarbonFootprintReport(alice()))
+		);
+		assert_eq!(Veles::is_ipfs_available(validator_ipfs), false);
+		assert_eq!(Veles::is_ipfs_available(report_ipfs), false);
+		assert_eq!(migrations::BackfillUsedDocumentation::<Test>::count_missing_entries(), 0);
+	});
+}
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(bob());
+#[test]
+fn backfill_used_documentation_dropped_entries() {
+	new_test_ext().execute_with(|| {
+		// Insert validator and project owner with the same documentation
+		let ipfs = BoundedString::<IPFSLength>::truncate_from("ipfs");
 
-		let proposal = CarbonCreditBatchProposalInfo {
-			project_hash,
-			batch_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: 100u32.into(),
-			penalty_repay_price: 5u32.into(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountId>::new(),
-			voting_active: true,
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
 		};
 
-		CarbonCreditBatchProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
-
-		// Successfully update carbon credit batch proposal
-		assert_ok!(Veles::update_carbon_credit_batch_proposal(RuntimeOrigin::signed(alice()), proposal_ipfs.clone()));
-
-		// Check if the carbon credit batch proposal has been updated
-		let proposal = CarbonCreditBatchProposals::<Test>::get(proposal_ipfs).unwrap();
+		let owner = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		assert_eq!(proposal.voting_active, false);
+		Validators::<Test>::insert(bob(), validator);
+		ProjectOwners::<Test>::insert(alice(), owner);
 
-		// Chech if the carbon credit holdings has been updated
-		let credit_holdings = CarbonCreditHoldings::<Test>::get(batch_hash, alice()).unwrap();
+		// Run the migration
+		migrations::BackfillUsedDocumentation::<Test>::on_runtime_upgrade();
 
-		assert_eq!(credit_holdings.available_amount, BalanceOf::<Test>::from(100u32));
-		assert_eq!(credit_holdings.unavailable_amount, BalanceOf::<Test>::from(0u32));
+		// Check if the documentation was only indexed for the validator
+		assert_eq!(Veles::used_documentation(ipfs), Some(DocumentationOwner::Validator(bob())));
+		assert_eq!(migrations::BackfillUsedDocumentation::<Test>::count_missing_entries(), 1);
 	});
 }
 
 #[test]
-pub fn update_complaint_for_account_project_owner_zero_level_ok() {
+fn backfill_project_and_batch_indexes_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert project owner
-		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 0,
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: bob(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			penalty_level: 0u8,
 			penalty_timeout: BlockNumber::<Test>::from(0u32),
 		};
 
-		ProjectOwners::<Test>::insert(alice(), project_owner_info);
-
-		// Insert complaint for account type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		let project_hash = generate_hash(alice());
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+		Projects::<Test>::insert(project_hash, project);
 
-		let complaint = ComplaintAccountBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ProjectOwnerComplaint,
-			complaint_for: alice(),
+		// Insert carbon credit batch
+		let batch = CarbonCreditBatchInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("batch_ipfs"),
+			project_hash,
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			penalty_repay_price: BalanceOf::<Test>::from(5u32),
+			status: CarbonCreditBatchStatus::Active,
+			validator_benefactors: VotesOf::<Test>::new(),
 		};
 
-		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+		let batch_hash = generate_hash(bob());
 
-		// Successfully update complaint for account
-		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
 
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+		// Insert carbon credit holdings
+		let credit_holdings = CarbonCreditHoldingsInfo {
+			available_amount: BalanceOf::<Test>::from(100u32),
+			unavailable_amount: BalanceOf::<Test>::from(0u32),
+		};
 
-		assert_eq!(complaint.complaint_active, false);
+		CarbonCreditHoldings::<Test>::insert(batch_hash, bob(), credit_holdings);
 
-		// Check if the project owner has been updated
-		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+		// Run the migration
+		migrations::BackfillProjectAndBatchIndexes::<Test>::on_runtime_upgrade();
 
-		assert_eq!(project_owner_info.penalty_level, 1);
-		assert_eq!(project_owner_info.penalty_timeout, 446400);
+		// Check if the indexes were filled in
+		assert_eq!(Veles::projects_by_owner(bob()).unwrap().contains(&project_hash), true);
+		assert_eq!(Veles::batches_by_project(project_hash).unwrap().contains(&batch_hash), true);
+		assert_eq!(Veles::owner_batch_count(&bob()), 1);
+		assert_eq!(migrations::BackfillProjectAndBatchIndexes::<Test>::count_missing_entries(), 0);
 
-		// Check if the penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+		// Freeze all project owner batches
+		Veles::freeze_all_owner_batches(bob());
 
-		assert_eq!(penalty_timeouts.contains(&alice()), true);
+		let batch = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+
+		assert_eq!(batch.status, CarbonCreditBatchStatus::Frozen);
 	});
 }
 
 #[test]
-pub fn update_complaint_for_account_project_owner_non_zero_level_ok() {
+fn backfill_retirements_by_batch_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert project owner
-		let project_owner_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
-		};
-
-		ProjectOwners::<Test>::insert(alice(), project_owner_info);
-
-		// Insert complaint for account type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		let batch_hash = generate_hash(alice());
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+		// Insert carbon credit retirement
+		let retirement_hash = generate_hash(bob());
 
-		let complaint = ComplaintAccountBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ProjectOwnerComplaint,
-			complaint_for: alice(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
+		let retirement = CarbonCreditRetirementInfo {
+			carbon_footprint_account: bob(),
+			batch_hash,
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			retirement_date: <mock::Test as pallet::Config>::Time::now(),
 		};
 
-		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
-
-		// Insert penalty timeout
-		let mut penalty_accounts = BTreeSet::<AccountIdOf<Test>>::new();
-		penalty_accounts.insert(alice());
-
-		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
-
-		// Successfully update complaint for account
-		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
-
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+		CarbonCreditRetirements::<Test>::insert(retirement_hash, retirement);
 
-		assert_eq!(complaint.complaint_active, false);
+		// Run the migration
+		migrations::BackfillRetirementsByBatch::<Test>::on_runtime_upgrade();
 
-		// Check if the project owner has been updated
-		let project_owner_info = ProjectOwners::<Test>::get(alice()).unwrap();
+		// Check if the index was filled in
+		assert_eq!(Veles::retirements_by_batch(batch_hash).unwrap().contains(&retirement_hash), true);
+		assert_eq!(migrations::BackfillRetirementsByBatch::<Test>::count_missing_entries(), 0);
+	});
+}
 
-		assert_eq!(project_owner_info.penalty_level, 2);
-		assert_eq!(project_owner_info.penalty_timeout, 446400);
+#[test]
+fn backfill_retirements_by_batch_dropped_entries() {
+	new_test_ext().execute_with(|| {
+		let batch_hash = generate_hash(alice());
 
-		// Check if the new penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+		// Insert more carbon credit retirements than fit in the index
+		for i in 0..=MaxRetirementsPerBatch::get() {
+			let retirement = CarbonCreditRetirementInfo {
+				carbon_footprint_account: bob(),
+				batch_hash,
+				credit_amount: BalanceOf::<Test>::from(1u32),
+				retirement_date: <mock::Test as pallet::Config>::Time::now(),
+			};
 
-		assert_eq!(penalty_timeouts.contains(&alice()), true);
+			CarbonCreditRetirements::<Test>::insert(H256::from_low_u64_be(i.into()), retirement);
+		}
 
-		// Check if the old penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(120).unwrap();
+		// Run the migration
+		migrations::BackfillRetirementsByBatch::<Test>::on_runtime_upgrade();
 
-		assert_eq!(penalty_timeouts.contains(&alice()), false);
+		// Check if the retirement that doesn't fit was dropped
+		assert_eq!(
+			Veles::retirements_by_batch(batch_hash).unwrap().len() as u32,
+			MaxRetirementsPerBatch::get()
+		);
+		assert_eq!(migrations::BackfillRetirementsByBatch::<Test>::count_missing_entries(), 1);
 	});
 }
 
 #[test]
-pub fn update_complaint_for_account_validator_zero_level_ok() {
+fn backfill_pruning_timeouts_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert validator
-		let validator_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 0,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
+		// Go past genesis block so events get deposited
+		run_to_block(1);
 
-		Validators::<Test>::insert(alice(), validator_info);
+		// Insert closed sale order
+		let sale_order = CarbonCreditSaleOrderInfo {
+			batch_hash: generate_hash(alice()),
+			credit_amount: BalanceOf::<Test>::from(10u32),
+			min_fill_amount: BalanceOf::<Test>::from(1u32),
+			credit_price: BalanceOf::<Test>::from(5u32),
+			seller: alice(),
+			buyer: Some(bob()),
+			status: SaleOrderStatus::Filled,
+			sale_timeout: Some(BlockNumber::<Test>::from(10u32)),
+		};
 
-		// Insert complaint for account type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		let sale_hash = generate_hash(bob());
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+		CarbonCreditSaleOrders::<Test>::insert(sale_hash, sale_order);
 
-		let complaint = ComplaintAccountBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ValidatorComplaint,
-			complaint_for: alice(),
+		// Insert active project proposal
+		let proposal = ProjectProposalInfo {
+			project_owner: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
+			project_hash: generate_hash(alice()),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: true,
 		};
 
-		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+		let proposal_ipfs = BoundedString::<IPFSLength>::truncate_from("proposal_ipfs");
 
-		// Successfully update complaint for account
-		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		ProjectProposals::<Test>::insert(proposal_ipfs.clone(), proposal);
 
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+		// Run the migration
+		migrations::BackfillPruningTimeouts::<Test>::on_runtime_upgrade();
 
-		assert_eq!(complaint.complaint_active, false);
+		// Check if only the finished record was scheduled for pruning
+		let record = FinishedRecord::CarbonCreditSaleOrder(sale_hash);
+		let pruning_events = PruningTimeouts::<Test>::get(101).unwrap();
 
-		// Check if the project owner has been updated
-		let validator_info = Validators::<Test>::get(alice()).unwrap();
+		assert_eq!(pruning_events.len(), 1);
+		assert_eq!(pruning_events.contains(&record), true);
 
-		assert_eq!(validator_info.penalty_level, 1);
-		assert_eq!(validator_info.penalty_timeout, 446400);
+		// Run the migration again at a later block
+		run_to_block(5);
+		migrations::BackfillPruningTimeouts::<Test>::on_runtime_upgrade();
 
-		// Check if the penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+		// Check if the finished record wasn't scheduled twice
+		assert_eq!(PruningTimeouts::<Test>::get(105), None);
 
-		assert_eq!(penalty_timeouts.contains(&alice()), true);
+		// Prune the finished record
+		run_to_block(101);
+		Veles::on_idle(101, Weight::MAX);
+
+		assert_eq!(CarbonCreditSaleOrders::<Test>::contains_key(sale_hash), false);
+		assert_eq!(ProjectProposals::<Test>::contains_key(proposal_ipfs), true);
+		System::assert_last_event(Event::<Test>::FinishedRecordPruned(record).into());
 	});
 }
 
 #[test]
-pub fn update_complaint_for_account_validator_non_zero_level_ok() {
+fn migrate_v0_to_v1_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert validator
-		let validator_info = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("documentation_ipfs"),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
-		};
+		// Start from storage version 0 state
+		StorageVersion::new(0).put::<Veles>();
 
-		Validators::<Test>::insert(alice(), validator_info);
+		// Insert trader and authority accounts into the old account sets
+		migrations::v0::TraderAccounts::<Test>::put(BTreeSet::from([alice(), bob()]));
+		migrations::v0::AuthorityAccounts::<Test>::put(BTreeSet::from([charlie()]));
 
-		// Insert complaint for account type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		// Insert validator
+		let validator_ipfs = BoundedString::<IPFSLength>::truncate_from("validator_ipfs");
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+		let validator = ProjectValidatorOrProjectOwnerInfo {
+			documentation_ipfs: validator_ipfs.clone(),
+			penalty_level: 0,
+			penalty_timeout: 0,
+		};
 
-		let complaint = ComplaintAccountBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ValidatorComplaint,
-			complaint_for: alice(),
+		Validators::<Test>::insert(bob(), validator);
+
+		// Insert project
+		let project = ProjectInfo {
+			documentation_ipfs: BoundedString::<IPFSLength>::truncate_from("project_ipfs"),
+			project_owner: bob(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
+			penalty_level: 0u8,
+			penalty_timeout: BlockNumber::<Test>::from(0u32),
 		};
 
-		ComplaintsForAccounts::<Test>::insert(complaint_ipfs.clone(), complaint);
+		let project_hash = generate_hash(alice());
 
-		// Insert penalty timeout
-		let mut penalty_accounts = BTreeSet::<AccountIdOf<Test>>::new();
-		penalty_accounts.insert(alice());
+		Projects::<Test>::insert(project_hash, project);
 
-		PenaltyTimeoutsAccounts::<Test>::insert(120, penalty_accounts);
+		// Insert carbon credit retirement
+		let batch_hash = generate_hash(bob());
+		let retirement_hash = generate_hash(charlie());
 
-		// Successfully update complaint for account
-		assert_ok!(Veles::update_complaint_for_account(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		let retirement = CarbonCreditRetirementInfo {
+			carbon_footprint_account: bob(),
+			batch_hash,
+			credit_amount: BalanceOf::<Test>::from(100u32),
+			retirement_date: <mock::Test as pallet::Config>::Time::now(),
+		};
 
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForAccounts::<Test>::get(complaint_ipfs.clone()).unwrap();
+		CarbonCreditRetirements::<Test>::insert(retirement_hash, retirement);
 
-		assert_eq!(complaint.complaint_active, false);
+		// Run the migration
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
 
-		// Check if the project owner has been updated
-		let validator_info = Validators::<Test>::get(alice()).unwrap();
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(1));
 
-		assert_eq!(validator_info.penalty_level, 2);
-		assert_eq!(validator_info.penalty_timeout, 446400);
+		// Check if the accounts were moved into the account maps
+		assert_eq!(TraderAccounts::<Test>::contains_key(alice()), true);
+		assert_eq!(TraderAccounts::<Test>::contains_key(bob()), true);
+		assert_eq!(AuthorityAccounts::<Test>::contains_key(charlie()), true);
 
-		// Check if the new penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(446400).unwrap();
+		// Check if the old account sets were removed
+		assert_eq!(migrations::v0::TraderAccounts::<Test>::exists(), false);
+		assert_eq!(migrations::v0::AuthorityAccounts::<Test>::exists(), false);
 
-		assert_eq!(penalty_timeouts.contains(&alice()), true);
+		// Check if the used documentation index was backfilled
+		assert_eq!(
+			Veles::used_documentation(validator_ipfs),
+			Some(DocumentationOwner::Validator(bob()))
+		);
 
-		// Check if the old penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsAccounts::<Test>::get(120).unwrap();
+		// Check if the project owner index was backfilled
+		assert_eq!(Veles::projects_by_owner(bob()).unwrap().contains(&project_hash), true);
 
-		assert_eq!(penalty_timeouts.contains(&alice()), false);
+		// Check if the carbon credit batch retirements index was backfilled
+		assert_eq!(
+			Veles::retirements_by_batch(batch_hash).unwrap().contains(&retirement_hash),
+			true
+		);
 	});
 }
 
 #[test]
-pub fn update_complaint_for_hash_project_zero_level_ok() {
+fn migrate_v0_to_v1_already_migrated() {
 	new_test_ext().execute_with(|| {
-		// Insert project
-		let project_hash = generate_hash(alice());
+		// Set the current storage version
+		StorageVersion::new(1).put::<Veles>();
 
-		let project_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+		// Insert trader accounts into the old account set
+		migrations::v0::TraderAccounts::<Test>::put(BTreeSet::from([alice()]));
 
-		let project_info = ProjectInfo {
-			documentation_ipfs: project_documentation_ipfs,
-			project_owner: alice(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 0,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
-		};
+		// Run the migration
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
 
-		Projects::<Test>::insert(project_hash, project_info);
-	
-		// Insert complaint for hash type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		// Check if the migration was skipped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(1));
+		assert_eq!(TraderAccounts::<Test>::contains_key(alice()), false);
+		assert_eq!(migrations::v0::TraderAccounts::<Test>::exists(), true);
+	});
+}
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+#[test]
+fn migrate_v0_to_v1_seed_timeout_cursors() {
+	new_test_ext().execute_with(|| {
+		// Start from storage version 0 state
+		StorageVersion::new(0).put::<Veles>();
+		System::set_block_number(10);
 
-		let complaint = ComplaintHashBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ProjectComplaint,
-			complaint_for: project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
-		};
+		// Insert pending timeout events
+		let mut timeout_events = TimeoutsOf::<Test, H256>::new();
+		timeout_events.try_insert(generate_hash(alice())).unwrap();
+
+		SaleOrderTimeouts::<Test>::insert(7, timeout_events.clone());
+		BuyOrderTimeouts::<Test>::insert(12, timeout_events);
+
+		// Run the migration
+		migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
 
-		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+		// Check if the timeouts cursor points to the earliest pending timeout events
+		assert_eq!(TimeoutsCursor::<Test>::get(), 7);
 
-		// Successfully update complaint for hash
-		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		// Check if the pruning cursor points to the current block
+		assert_eq!(PruningCursor::<Test>::get(), 10);
+	});
+}
 
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForHashes::<Test>::get(complaint_ipfs.clone()).unwrap();
+#[test]
+fn migrate_v1_to_v2_ok() {
+	new_test_ext().execute_with(|| {
+		// Start from storage version 1 state
+		StorageVersion::new(1).put::<Veles>();
 
-		assert_eq!(complaint.complaint_active, false);
+		// Insert sale order in the old format
+		let sale_hash = generate_hash(alice());
 
-		// Check if the project has been updated
-		let project_info = Projects::<Test>::get(project_hash).unwrap();
+		migrations::v1::CarbonCreditSaleOrders::<Test>::insert(
+			sale_hash,
+			migrations::v1::CarbonCreditSaleOrderInfo {
+				batch_hash: generate_hash(bob()),
+				credit_amount: BalanceOf::<Test>::from(10u32),
+				credit_price: BalanceOf::<Test>::from(5u32),
+				seller: alice(),
+				buyer: charlie(),
+				sale_active: false,
+				sale_timeout: 100,
+			},
+		);
 
-		assert_eq!(project_info.penalty_level, 1);
-		assert_eq!(project_info.penalty_timeout, 446400);
+		// Run the migration
+		migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
 
-		// Check if the penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(446400).unwrap();
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(2));
 
-		assert_eq!(penalty_timeouts.contains(&project_hash), true);
+		// Check if the sale order can only be filled as a whole
+		let sale_order = migrations::v2::CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+
+		assert_eq!(sale_order.batch_hash, generate_hash(bob()));
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(sale_order.min_fill_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(sale_order.seller, alice());
+		assert_eq!(sale_order.buyer, charlie());
+		assert_eq!(sale_order.sale_active, false);
+		assert_eq!(sale_order.sale_timeout, 100);
 	});
 }
 
 #[test]
-pub fn update_complaint_for_hash_project_non_zero_level_ok() {
+fn migrate_v2_to_v3_ok() {
 	new_test_ext().execute_with(|| {
-		// Insert project
-		let project_hash = generate_hash(alice());
-
-		let project_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation_ipfs");
+		// Start from storage version 2 state
+		StorageVersion::new(2).put::<Veles>();
+		System::set_block_number(50);
 
-		let project_info = ProjectInfo {
-			documentation_ipfs: project_documentation_ipfs,
-			project_owner: alice(),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 1,
-			penalty_timeout: BlockNumber::<Test>::from(120u32),
+		// Insert sale orders in the old format (open, filled, expired and cancelled)
+		let old_sale_order = |buyer, sale_active, sale_timeout| {
+			migrations::v2::CarbonCreditSaleOrderInfo {
+				batch_hash: generate_hash(bob()),
+				credit_amount: BalanceOf::<Test>::from(10u32),
+				min_fill_amount: BalanceOf::<Test>::from(1u32),
+				credit_price: BalanceOf::<Test>::from(5u32),
+				seller: alice(),
+				buyer,
+				sale_active,
+				sale_timeout,
+			}
 		};
 
-		Projects::<Test>::insert(project_hash, project_info);
-	
-		// Insert complaint for hash type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		let open_hash = H256::repeat_byte(1);
+		let filled_hash = H256::repeat_byte(2);
+		let expired_hash = H256::repeat_byte(3);
+		let cancelled_hash = H256::repeat_byte(4);
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(charlie());
+		migrations::v2::CarbonCreditSaleOrders::<Test>::insert(
+			open_hash,
+			old_sale_order(alice(), true, 100),
+		);
+		migrations::v2::CarbonCreditSaleOrders::<Test>::insert(
+			filled_hash,
+			old_sale_order(charlie(), false, 100),
+		);
+		migrations::v2::CarbonCreditSaleOrders::<Test>::insert(
+			expired_hash,
+			old_sale_order(alice(), false, 50),
+		);
+		migrations::v2::CarbonCreditSaleOrders::<Test>::insert(
+			cancelled_hash,
+			old_sale_order(alice(), false, 100),
+		);
 
-		let complaint = ComplaintHashBasedInfo {
-			complaint_proposer: bob(),
-			complaint_type: ComplaintType::ProjectComplaint,
-			complaint_for: project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
-		};
+		// Run the migration
+		migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
 
-		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(3));
 
-		// Insert penalty timeout
-		let mut penalty_hashes = BTreeSet::<H256>::new();
-		penalty_hashes.insert(project_hash);
+		// Check the sale order buyers and statuses
+		let open_sale_order =
+			migrations::v3::CarbonCreditSaleOrders::<Test>::get(open_hash).unwrap();
+		assert_eq!(open_sale_order.buyer, None);
+		assert_eq!(open_sale_order.status, SaleOrderStatus::Open);
+		assert_eq!(open_sale_order.min_fill_amount, BalanceOf::<Test>::from(1u32));
 
-		PenaltyTimeoutsHashes::<Test>::insert(120, penalty_hashes);
+		let filled_sale_order =
+			migrations::v3::CarbonCreditSaleOrders::<Test>::get(filled_hash).unwrap();
+		assert_eq!(filled_sale_order.buyer, Some(charlie()));
+		assert_eq!(filled_sale_order.status, SaleOrderStatus::Filled);
 
-		// Successfully update complaint for hash
-		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		let expired_sale_order =
+			migrations::v3::CarbonCreditSaleOrders::<Test>::get(expired_hash).unwrap();
+		assert_eq!(expired_sale_order.buyer, None);
+		assert_eq!(expired_sale_order.status, SaleOrderStatus::Expired);
 
-		// Check if the complaint has been updated
-		let complaint = ComplaintsForHashes::<Test>::get(complaint_ipfs.clone()).unwrap();
+		let cancelled_sale_order =
+			migrations::v3::CarbonCreditSaleOrders::<Test>::get(cancelled_hash).unwrap();
+		assert_eq!(cancelled_sale_order.buyer, None);
+		assert_eq!(cancelled_sale_order.status, SaleOrderStatus::Cancelled);
 
-		assert_eq!(complaint.complaint_active, false);
+		// Remove the sale orders (they aren't backed by any holdings)
+		let _ = migrations::v3::CarbonCreditSaleOrders::<Test>::clear(u32::MAX, None);
+	});
+}
 
-		// Check if the project has been updated
-		let project_info = Projects::<Test>::get(project_hash).unwrap();
+#[test]
+fn migrate_v3_to_v4_ok() {
+	new_test_ext().execute_with(|| {
+		// Start from storage version 3 state
+		StorageVersion::new(3).put::<Veles>();
 
-		assert_eq!(project_info.penalty_level, 2);
-		assert_eq!(project_info.penalty_timeout, 446400);
+		// Insert sale order in the old format
+		let sale_hash = generate_hash(alice());
 
-		// Check if the new penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(446400).unwrap();
+		migrations::v3::CarbonCreditSaleOrders::<Test>::insert(
+			sale_hash,
+			migrations::v3::CarbonCreditSaleOrderInfo {
+				batch_hash: generate_hash(bob()),
+				credit_amount: BalanceOf::<Test>::from(10u32),
+				min_fill_amount: BalanceOf::<Test>::from(1u32),
+				credit_price: BalanceOf::<Test>::from(5u32),
+				seller: alice(),
+				buyer: Some(charlie()),
+				status: SaleOrderStatus::Filled,
+				sale_timeout: 100,
+			},
+		);
 
-		assert_eq!(penalty_timeouts.contains(&project_hash), true);
+		// Run the migration
+		migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
 
-		// Check if the old penalty timeouts have updated
-		let penalty_timeouts = PenaltyTimeoutsHashes::<Test>::get(120).unwrap();
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(4));
 
-		assert_eq!(penalty_timeouts.contains(&project_hash), false);
+		// Check if the sale order kept its timeout
+		let sale_order = CarbonCreditSaleOrders::<Test>::get(sale_hash).unwrap();
+
+		assert_eq!(sale_order.batch_hash, generate_hash(bob()));
+		assert_eq!(sale_order.credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(sale_order.min_fill_amount, BalanceOf::<Test>::from(1u32));
+		assert_eq!(sale_order.credit_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(sale_order.seller, alice());
+		assert_eq!(sale_order.buyer, Some(charlie()));
+		assert_eq!(sale_order.status, SaleOrderStatus::Filled);
+		assert_eq!(sale_order.sale_timeout, Some(100));
 	});
 }
 
 #[test]
-pub fn update_complaint_for_hash_carbon_credit_batch_ok() {
+fn migrate_v4_to_v5_ok() {
 	new_test_ext().execute_with(|| {
-		let project_hash = generate_hash(alice());
-		let batch_hash = generate_hash(bob());
-
-		// Insert project owner
-		let owner_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("owner_documentation_ipfs");
+		// Start from storage version 4 state
+		StorageVersion::new(4).put::<Veles>();
 
-		let project_owner = ProjectValidatorOrProjectOwnerInfo {
-			documentation_ipfs: owner_documentation_ipfs.clone(),
-			penalty_level: 0,
-			penalty_timeout: 0,
-		};
+		// Insert sale order fills in the old format
+		let sale_hash = generate_hash(alice());
 
-		ProjectOwners::<Test>::insert(alice(), project_owner);
+		let mut fills = migrations::v4::SaleOrderFillsOf::<Test>::new();
+		fills
+			.try_push(migrations::v4::SaleOrderFillInfo {
+				buyer: charlie(),
+				credit_amount: BalanceOf::<Test>::from(10u32),
+				credit_price: BalanceOf::<Test>::from(5u32),
+				fill_date: 100,
+			})
+			.unwrap();
 
-		// Insert project
-		let project_documentation =
-			BoundedString::<IPFSLength>::truncate_from("project_documentation");
+		migrations::v4::SaleOrderFills::<Test>::insert(sale_hash, fills);
 
-		let project = ProjectInfo {
-			documentation_ipfs: project_documentation,
-			project_owner: alice(),
+		// Insert finished carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
 			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			penalty_level: 0u8,
-			penalty_timeout: BlockNumber::<Test>::from(0u32),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: false,
 		};
 
-		Projects::<Test>::insert(project_hash, project);
-
-		// Insert carbon credit batch
-		let batch_documentation_ipfs =
-			BoundedString::<IPFSLength>::truncate_from("batch_documentation_ipfs");
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
 
-		let batch = CarbonCreditBatchInfo {
-			documentation_ipfs: batch_documentation_ipfs,
-			project_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			credit_amount: BalanceOf::<Test>::from(10000u32),
-			penalty_repay_price: BalanceOf::<Test>::from(2u32),
-			status: CarbonCreditBatchStatus::Active,
-			validator_benefactors: BTreeSet::<AccountIdOf<Test>>::new(),
-		};
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
 
-		CarbonCreditBatches::<Test>::insert(batch_hash, batch);
+		// Run the migration
+		migrations::v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
 
-		// Insert carbon credit holdings (owner)
-		let credit_holdings = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(120u32),
-			unavailable_amount: BalanceOf::<Test>::from(10u32),
-		};
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(5));
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, alice(), credit_holdings);
+		// Check if the fill was kept without trading fees
+		let fills = SaleOrderFills::<Test>::get(sale_hash).unwrap();
 
-		// Insert carbon footprint account (#1)
-		let mut documentation_ipfses_1 = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let documentation_ipfs_1 = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs_1");
+		assert_eq!(fills.len(), 1);
+		assert_eq!(fills[0].buyer, charlie());
+		assert_eq!(fills[0].credit_amount, BalanceOf::<Test>::from(10u32));
+		assert_eq!(fills[0].credit_price, BalanceOf::<Test>::from(5u32));
+		assert_eq!(fills[0].fill_date, 100);
+		assert_eq!(fills[0].maker_fee, BalanceOf::<Test>::from(0u32));
+		assert_eq!(fills[0].taker_fee, BalanceOf::<Test>::from(0u32));
 
-		documentation_ipfses_1.insert(documentation_ipfs_1);
+		// Check if the finished report was scheduled for pruning
+		let timeout_block = System::block_number() + RetentionPeriod::get();
+		let pruning_events = PruningTimeouts::<Test>::get(timeout_block).unwrap();
 
-		let carbon_footprint_account_1 = CarbonFootprintAccountInfo {
-			documentation_ipfses: documentation_ipfses_1,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(50u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(0u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		assert_eq!(
+			pruning_events.contains(&FinishedRecord::CarbonFootprintReport(report_ipfs)),
+			true
+		);
+	});
+}
 
-		CarbonFootprintAccounts::<Test>::insert(bob(), carbon_footprint_account_1);
+#[test]
+fn migrate_v5_to_v6_ok() {
+	new_test_ext().execute_with(|| {
+		// Start from storage version 5 state
+		StorageVersion::new(5).put::<Veles>();
 
-		// Insert carbon footprint account (#2)
-		let mut documentation_ipfses_2 = BTreeSet::<BoundedString<IPFSLength>>::new();
-		let documentation_ipfs_2 = BoundedString::<IPFSLength>::truncate_from("documentation_ipfs_2");
+		// Insert vote pass ratio, penalty levels and beneficiary splits in the old format
+		migrations::v5::VotePassRatio::<Test>::put(migrations::v5::ProportionStructure {
+			proportion_part: 2,
+			upper_limit_part: 3,
+		});
 
-		documentation_ipfses_2.insert(documentation_ipfs_2);
+		let mut penalty_levels = migrations::v5::PenaltyLevelsOf::<Test>::new();
+		penalty_levels.try_insert(0, 10000).unwrap();
+		penalty_levels.try_insert(1, 16700).unwrap();
+		penalty_levels.try_insert(2, 23000).unwrap();
+		penalty_levels.try_insert(3, 27550).unwrap();
+		penalty_levels.try_insert(4, 32000).unwrap();
 
-		let carbon_footprint_account_2 = CarbonFootprintAccountInfo {
-			documentation_ipfses: documentation_ipfses_2,
-			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
-			carbon_footprint_deficit: BalanceOf::<Test>::from(1000u32),
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		migrations::v5::PenaltyLevels::<Test>::put(penalty_levels);
 
-		CarbonFootprintAccounts::<Test>::insert(charlie(), carbon_footprint_account_2);
+		let mut beneficiary_splits = migrations::v5::BeneficiarySplitsOf::<Test>::new();
+		beneficiary_splits.try_insert(BeneficiarySplitType::PrimarySaleValidators, 4500).unwrap();
+		beneficiary_splits.try_insert(BeneficiarySplitType::SecondarySaleValidators, 3500).unwrap();
+		beneficiary_splits.try_insert(BeneficiarySplitType::SecondarySaleOwner, 1000).unwrap();
 
-		// Insert retirement (#1)
-		let retirement_hash_1 = generate_hash(dave());
+		migrations::v5::BeneficiarySplits::<Test>::put(beneficiary_splits.clone());
 
-		let retirement_1 = CarbonCreditRetirementInfo {
-			carbon_footprint_account: bob(),
-			batch_hash: batch_hash,
-			credit_amount: BalanceOf::<Test>::from(100u32),
-			retirement_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		// Insert project beneficiary splits in the old format
+		let project_hash = generate_hash(alice());
 
-		CarbonCreditRetirements::<Test>::insert(retirement_hash_1, retirement_1);
+		let mut stakeholders = migrations::v5::StakeholderSharesOf::<Test>::new();
+		stakeholders.try_insert(george(), 250).unwrap();
 
-		// Insert retirement (#2)
-		let retirement_hash_2 = generate_hash(fred());
+		migrations::v5::ProjectBeneficiarySplits::<Test>::insert(
+			project_hash,
+			migrations::v5::ProjectBeneficiarySplitsInfo { beneficiary_splits, stakeholders },
+		);
 
-		let retirement_2 = CarbonCreditRetirementInfo {
-			carbon_footprint_account: charlie(),
-			batch_hash: batch_hash,
-			credit_amount: BalanceOf::<Test>::from(123u32),
-			retirement_date: <mock::Test as pallet::Config>::Time::now(),
-		};
+		// Insert trading fees in the old format
+		migrations::v5::TradingFees::<Test>::put(migrations::v5::TradingFeeValues {
+			maker_fee: BalanceOf::<Test>::from(100u32),
+			taker_fee: BalanceOf::<Test>::from(250u32),
+		});
 
-		CarbonCreditRetirements::<Test>::insert(retirement_hash_2, retirement_2);
+		// Run the migration
+		migrations::v6::MigrateV5ToV6::<Test>::on_runtime_upgrade();
 
-		// Insert carbon credit holdings (trader #1)
-		let credit_holdings_1 = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(100u32),
-			unavailable_amount: BalanceOf::<Test>::from(0u32),
-		};
+		// Check if the storage version was bumped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(6));
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, dave(), credit_holdings_1);
+		// Check if the vote pass ratio was converted (rounded up)
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::from_parts(666_666_667));
 
-		// Insert carbon credit holdings (trader #2)
-		let credit_holdings_2 = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(0u32),
-			unavailable_amount: BalanceOf::<Test>::from(250u32),
-		};
+		// Check if the votes needed didn't change (2 out of 4 votes passed in the old format)
+		assert!(Veles::has_vote_passed(3, 2));
+		assert!(!Veles::has_vote_passed(3, 1));
+		assert!(Veles::has_vote_passed(4, 2));
+		assert!(!Veles::has_vote_passed(4, 1));
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, fred(), credit_holdings_2);
+		// Check if the penalty levels were converted (to the share of a payment that is withheld)
+		let penalty_levels = PenaltyLevels::<Test>::get();
 
-		// Insert carbon credit holdings (trader #3)
-		let credit_holdings_3 = CarbonCreditHoldingsInfo {
-			available_amount: BalanceOf::<Test>::from(300u32),
-			unavailable_amount: BalanceOf::<Test>::from(50u32),
-		};
+		assert_eq!(penalty_levels[&0], Perbill::zero());
+		assert_eq!(penalty_levels[&1], Perbill::from_rational(6700u32, 16700u32));
+		assert_eq!(penalty_levels[&2], Perbill::from_rational(13000u32, 23000u32));
+		assert_eq!(penalty_levels[&3], Perbill::from_rational(17550u32, 27550u32));
+		assert_eq!(penalty_levels[&4], Perbill::from_rational(22000u32, 32000u32));
 
-		CarbonCreditHoldings::<Test>::insert(batch_hash, george(), credit_holdings_3);
+		// Check if the beneficiary splits were converted
+		let beneficiary_splits = BeneficiarySplits::<Test>::get();
 
-		// Insert complaint for hash type
-		let complaint_ipfs = BoundedString::<IPFSLength>::truncate_from("complaint_ipfs");
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::PrimarySaleValidators],
+			Permill::from_percent(45)
+		);
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::SecondarySaleValidators],
+			Permill::from_percent(35)
+		);
+		assert_eq!(
+			beneficiary_splits[&BeneficiarySplitType::SecondarySaleOwner],
+			Permill::from_percent(10)
+		);
 
-		let mut votes_for = BTreeSet::<AccountId>::new();
-		votes_for.insert(hank());
+		// Check if the project beneficiary splits were converted
+		let project_splits = ProjectBeneficiarySplits::<Test>::get(project_hash).unwrap();
 
-		let complaint = ComplaintHashBasedInfo {
-			complaint_proposer: ian(),
-			complaint_type: ComplaintType::CarbonCreditBatchComplaint,
-			complaint_for: batch_hash,
-			creation_date: <mock::Test as pallet::Config>::Time::now(),
-			votes_for: votes_for,
-			votes_against: BTreeSet::<AccountIdOf<Test>>::new(),
-			complaint_active: true,
-		};
+		assert_eq!(project_splits.beneficiary_splits, beneficiary_splits);
+		assert_eq!(project_splits.stakeholders[&george()], Permill::from_parts(25_000));
 
-		ComplaintsForHashes::<Test>::insert(complaint_ipfs.clone(), complaint);
+		// Check if the trading fees were converted
+		let trading_fees = TradingFees::<Test>::get();
 
-		// Successfully update complaint for hash
-		assert_ok!(Veles::update_complaint_for_hash(RuntimeOrigin::signed(alice()), complaint_ipfs.clone()));
+		assert_eq!(trading_fees.maker_fee, Permill::from_percent(1));
+		assert_eq!(trading_fees.taker_fee, Permill::from_parts(25_000));
+	});
+}
 
-		// Check carbon credit batch
-		let batch_info = CarbonCreditBatches::<Test>::get(batch_hash).unwrap();
+#[test]
+fn migrate_v5_to_v6_zero_proportion_part_ok() {
+	new_test_ext().execute_with(|| {
+		// Start from storage version 5 state
+		StorageVersion::new(5).put::<Veles>();
 
-		assert_eq!(batch_info.status, CarbonCreditBatchStatus::Redacted);
+		// Insert a vote pass ratio with a zero proportion part (any vote passes)
+		migrations::v5::VotePassRatio::<Test>::put(migrations::v5::ProportionStructure {
+			proportion_part: 0,
+			upper_limit_part: 3,
+		});
 
-		// Check carbon footprint balances
-		let carbon_footprint_account_1 = CarbonFootprintAccounts::<Test>::get(bob()).unwrap();
+		// Run the migration
+		migrations::v6::MigrateV5ToV6::<Test>::on_runtime_upgrade();
 
-		assert_eq!(carbon_footprint_account_1.carbon_footprint_surplus, BalanceOf::<Test>::from(0u32));
-		assert_eq!(carbon_footprint_account_1.carbon_footprint_deficit, BalanceOf::<Test>::from(50u32));
+		// Check if the vote pass ratio was converted to the smallest non-zero ratio
+		assert_eq!(VotePassRatio::<Test>::get(), Perbill::from_parts(1));
 
-		let carbon_footprint_account_2 = CarbonFootprintAccounts::<Test>::get(charlie()).unwrap();
+		// Check if any vote still passes (the votes needed are still zero)
+		assert!(Veles::has_vote_passed(3, 1));
+		assert!(Veles::has_vote_passed(3, 0));
+		assert!(Veles::has_vote_passed(0, 0));
+		assert!(Veles::has_vote_passed(u16::MAX, 0));
+	});
+}
 
-		assert_eq!(carbon_footprint_account_2.carbon_footprint_surplus, BalanceOf::<Test>::from(0u32));
-		assert_eq!(carbon_footprint_account_2.carbon_footprint_deficit, BalanceOf::<Test>::from(1123u32));
+#[test]
+fn migrations_already_migrated() {
+	new_test_ext().execute_with(|| {
+		// Set the current storage version
+		StorageVersion::new(6).put::<Veles>();
 
-		// Check debts
-		assert_eq!(ProjectOwnerDebts::<Test>::contains_key(&alice()), true);
+		// Insert finished carbon footprint report
+		let report_info = CarbonFootprintReportInfo {
+			cf_account: alice(),
+			creation_date: <mock::Test as pallet::Config>::Time::now(),
+			carbon_footprint_surplus: BalanceOf::<Test>::from(0u32),
+			carbon_footprint_deficit: BalanceOf::<Test>::from(100u32),
+			votes_for: VotesOf::<Test>::new(),
+			votes_against: VotesOf::<Test>::new(),
+			voting_active: false,
+		};
 
-		let debts = ProjectOwnerDebts::<Test>::get(alice());
+		let report_ipfs = BoundedString::<IPFSLength>::truncate_from("report_ipfs");
 
-		assert_eq!(debts.len(), 6);
-		assert_eq!(debts.contains_key(&bob()), true);
-		assert_eq!(debts.contains_key(&charlie()), true);
-		assert_eq!(debts.contains_key(&dave()), true);
-		assert_eq!(debts.contains_key(&fred()), true);
-		assert_eq!(debts.contains_key(&george()), true);
-		assert_eq!(debts.contains_key(&pallet_id()), true);
+		CarbonFootprintReports::<Test>::insert(report_ipfs.clone(), report_info);
 
-		assert_eq!(*debts.get(&bob()).unwrap(), BalanceOf::<Test>::from(200u32));
-		assert_eq!(*debts.get(&charlie()).unwrap(), BalanceOf::<Test>::from(246u32));
-		assert_eq!(*debts.get(&dave()).unwrap(), BalanceOf::<Test>::from(200u32));
-		assert_eq!(*debts.get(&fred()).unwrap(), BalanceOf::<Test>::from(500u32));
-		assert_eq!(*debts.get(&george()).unwrap(), BalanceOf::<Test>::from(700u32));
-		assert_eq!(*debts.get(&pallet_id()).unwrap(), BalanceOf::<Test>::from(260u32));
+		// Run all migrations
+		<(
+			migrations::v1::MigrateV0ToV1<Test>,
+			migrations::v2::MigrateV1ToV2<Test>,
+			migrations::v3::MigrateV2ToV3<Test>,
+			migrations::v4::MigrateV3ToV4<Test>,
+			migrations::v5::MigrateV4ToV5<Test>,
+			migrations::v6::MigrateV5ToV6<Test>,
+		) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		// Check if the migrations (and their backfills) were skipped
+		assert_eq!(Veles::on_chain_storage_version(), StorageVersion::new(6));
+		assert_eq!(Veles::used_documentation(report_ipfs), None);
+		assert_eq!(PruningTimeouts::<Test>::iter().count(), 0);
 	});
 }
\ No newline at end of file
@@ -48,8 +48,8 @@ pub trait WeightInfo {
 	fn create_sale_order() -> Weight;
 	fn complete_sale_order() -> Weight;
 	fn close_sale_order() -> Weight;
-	fn open_account_complaint() -> Weight;
-	fn open_hash_complaint() -> Weight;
+	fn open_account_complaint(b: u32, ) -> Weight;
+	fn open_hash_complaint(b: u32, ) -> Weight;
 	fn retire_carbon_credits() -> Weight;
 	fn repay_project_owner_debts() -> Weight;
 	fn update_pallet_base_time() -> Weight;
@@ -57,18 +57,36 @@ pub trait WeightInfo {
 	fn update_project_proposal() -> Weight;
 	fn update_carbon_credit_batch_proposal() -> Weight;
 	fn update_carbon_credit_sale_order() -> Weight;
-	fn update_complaint_for_account() -> Weight;
-	fn update_complaint_for_hash() -> Weight;
+	fn update_complaint_for_account(b: u32, ) -> Weight;
+	fn update_complaint_for_hash(b: u32, ) -> Weight;
 	fn update_project_owner_penalty_level() -> Weight;
 	fn update_validator_penalty_level() -> Weight;
 	fn update_project_penalty_level() -> Weight;
+	fn add_authority() -> Weight;
+	fn remove_authority() -> Weight;
+	fn update_transfer_royalties() -> Weight;
+	fn transfer_carbon_credits() -> Weight;
+	fn create_buy_order() -> Weight;
+	fn cancel_buy_order() -> Weight;
+	fn fill_buy_order() -> Weight;
+	fn update_carbon_credit_buy_order() -> Weight;
+	fn place_limit_order(m: u32, ) -> Weight;
+	fn cancel_limit_order() -> Weight;
+	fn create_auction() -> Weight;
+	fn place_auction_bid() -> Weight;
+	fn buy_auction_lot() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn update_carbon_credit_auction() -> Weight;
+	fn amend_sale_order() -> Weight;
+	fn propose_beneficiary_splits() -> Weight;
+	fn update_beneficiary_split_proposal() -> Weight;
 }
 
 /// Weight functions for `pallet_veles`.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
-	/// Proof: `Veles::AuthorityAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::VotePassRatio` (r:0 w:1)
 	/// Proof: `Veles::VotePassRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn update_vote_pass_ratio() -> Weight {
@@ -82,21 +100,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
-	/// Proof: `Veles::AuthorityAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Veles::VotePassRatio` (r:0 w:1)
-	/// Proof: `Veles::VotePassRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PenaltyLevels` (r:0 w:1)
+	/// Proof: `Veles::PenaltyLevels` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn update_penalty_levels() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
 		//  Estimated: `1657`
-		// Minimum execution time: 10_449_000 picoseconds.
-		Weight::from_parts(10_981_000, 0)
+		// Minimum execution time: 11_214_000 picoseconds.
+		Weight::from_parts(11_695_000, 0)
 			.saturating_add(Weight::from_parts(0, 1657))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
-	/// Proof: `Veles::AuthorityAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::BeneficiarySplits` (r:0 w:1)
 	/// Proof: `Veles::BeneficiarySplits` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn update_beneficiary_splits() -> Weight {
@@ -110,7 +128,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
-	/// Proof: `Veles::AuthorityAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::PalletTimeValues` (r:1 w:1)
 	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn update_time_value() -> Weight {
@@ -124,9 +142,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
-	/// Proof: `Veles::AuthorityAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::PalletFeeValues` (r:1 w:1)
 	/// Proof: `Veles::PalletFeeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:1)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn update_fee_value() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `172`
@@ -134,13 +154,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 11_101_000 picoseconds.
 		Weight::from_parts(11_682_000, 0)
 			.saturating_add(Weight::from_parts(0, 1657))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:1)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Validators` (r:1 w:0)
 	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:1 w:0)
@@ -164,7 +184,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:2 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Validators` (r:2 w:1)
 	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:2 w:0)
@@ -196,7 +216,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:2 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Validators` (r:2 w:0)
 	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:2 w:1)
@@ -226,7 +246,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Validators` (r:2 w:0)
 	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:2 w:0)
@@ -370,7 +390,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:1 w:0)
 	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
@@ -387,32 +407,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::SaleOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditSaleOrders` (r:0 w:1)
 	/// Proof: `Veles::CarbonCreditSaleOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::SaleOrderAllowedBuyers` (r:0 w:1)
+	/// Proof: `Veles::SaleOrderAllowedBuyers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_sale_order() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `437`
 		//  Estimated: `3902`
-		// Minimum execution time: 34_695_000 picoseconds.
-		Weight::from_parts(36_258_000, 0)
+		// Minimum execution time: 39_814_000 picoseconds.
+		Weight::from_parts(41_026_000, 0)
 			.saturating_add(Weight::from_parts(0, 3902))
 			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:4 w:0)
 	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditSaleOrders` (r:1 w:1)
 	/// Proof: `Veles::CarbonCreditSaleOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::SaleOrderAllowedBuyers` (r:1 w:0)
+	/// Proof: `Veles::SaleOrderAllowedBuyers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
 	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:5 w:5)
+	/// Storage: `System::Account` (r:26 w:26)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Veles::Projects` (r:1 w:0)
 	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::BeneficiarySplits` (r:1 w:0)
 	/// Proof: `Veles::BeneficiarySplits` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::PenaltyLevels` (r:1 w:0)
 	/// Proof: `Veles::PenaltyLevels` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Validators` (r:3 w:0)
@@ -421,20 +449,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::SaleOrderTimeouts` (r:1 w:1)
 	/// Proof: `Veles::SaleOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::SaleOrderFills` (r:1 w:1)
+	/// Proof: `Veles::SaleOrderFills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn complete_sale_order() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1231`
-		//  Estimated: `14005`
-		// Minimum execution time: 211_708_000 picoseconds.
-		Weight::from_parts(215_455_000, 0)
-			.saturating_add(Weight::from_parts(0, 14005))
-			.saturating_add(T::DbWeight::get().reads(22))
-			.saturating_add(T::DbWeight::get().writes(9))
+		//  Measured:  `4427`
+		//  Estimated: `68139`
+		// Minimum execution time: 219_372_000 picoseconds.
+		Weight::from_parts(332_487_000, 0)
+			.saturating_add(Weight::from_parts(0, 68139))
+			.saturating_add(T::DbWeight::get().reads(48))
+			.saturating_add(T::DbWeight::get().writes(32))
 	}
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
-	/// Proof: `Veles::TraderAccounts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ProjectOwners` (r:1 w:0)
 	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditSaleOrders` (r:1 w:1)
@@ -479,7 +511,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Veles::Projects` (r:2 w:0)
 	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Veles::CarbonCreditBatches` (r:2 w:1)
+	/// Storage: `Veles::CarbonCreditBatches` (r:100 w:100)
 	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -487,15 +519,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ComplaintTimeouts` (r:1 w:1)
 	/// Proof: `Veles::ComplaintTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn open_account_complaint() -> Weight {
+	/// Storage: `Veles::ProjectsByOwner` (r:1 w:0)
+	/// Proof: `Veles::ProjectsByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BatchesByProject` (r:1 w:0)
+	/// Proof: `Veles::BatchesByProject` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn open_account_complaint(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `862`
 		//  Estimated: `9277`
 		// Minimum execution time: 75_031_000 picoseconds.
 		Weight::from_parts(76_824_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 9277))
 			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 	/// Storage: `Veles::Validators` (r:2 w:0)
 	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -519,7 +561,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Veles::Projects` (r:1 w:0)
 	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Veles::CarbonCreditBatches` (r:2 w:1)
+	/// Storage: `Veles::CarbonCreditBatches` (r:100 w:100)
 	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -527,15 +569,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::ComplaintTimeouts` (r:1 w:1)
 	/// Proof: `Veles::ComplaintTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn open_hash_complaint() -> Weight {
+	/// Storage: `Veles::BatchesByProject` (r:1 w:0)
+	/// Proof: `Veles::BatchesByProject` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn open_hash_complaint(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `862`
 		//  Estimated: `6802`
 		// Minimum execution time: 64_331_000 picoseconds.
 		Weight::from_parts(66_214_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_398_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 6802))
 			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:1)
 	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -671,17 +721,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::PenaltyTimeoutsAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::Projects` (r:1 w:0)
 	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Storage: `Veles::CarbonCreditBatches` (r:100 w:100)
 	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn update_complaint_for_account() -> Weight {
+	/// Storage: `Veles::ProjectsByOwner` (r:1 w:0)
+	/// Proof: `Veles::ProjectsByOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BatchesByProject` (r:1 w:0)
+	/// Proof: `Veles::BatchesByProject` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn update_complaint_for_account(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
 		//  Estimated: `6340`
 		// Minimum execution time: 35_857_000 picoseconds.
 		Weight::from_parts(36_950_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_371_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 6340))
 			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 	/// Storage: `Veles::ComplaintsForHashes` (r:1 w:1)
 	/// Proof: `Veles::ComplaintsForHashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -689,7 +749,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::VotePassRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
 	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:1)
+	/// Storage: `Veles::CarbonCreditBatches` (r:100 w:100)
 	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::CarbonCreditRetirements` (r:3 w:0)
 	/// Proof: `Veles::CarbonCreditRetirements` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -703,15 +763,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Veles::PenaltyTimeoutsHashes` (r:1 w:1)
 	/// Proof: `Veles::PenaltyTimeoutsHashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn update_complaint_for_hash() -> Weight {
+	/// Storage: `Veles::BatchesByProject` (r:1 w:0)
+	/// Proof: `Veles::BatchesByProject` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 100]`.
+	fn update_complaint_for_hash(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1506`
 		//  Estimated: `14871`
 		// Minimum execution time: 88_446_000 picoseconds.
 		Weight::from_parts(89_879_000, 0)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_parts(6_405_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 14871))
 			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 	/// Storage: `Veles::ProjectOwners` (r:1 w:1)
 	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -755,6 +823,450 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Veles::AuthorityAccounts` (r:1 w:1)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 10_512_000 picoseconds.
+		Weight::from_parts(10_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 1657))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Veles::AuthorityAccounts` (r:1 w:1)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_authority() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204`
+		//  Estimated: `1689`
+		// Minimum execution time: 10_873_000 picoseconds.
+		Weight::from_parts(10_873_000, 0)
+			.saturating_add(Weight::from_parts(0, 1689))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Veles::AuthorityAccounts` (r:1 w:0)
+	/// Proof: `Veles::AuthorityAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TransferRoyaltiesEnabled` (r:0 w:1)
+	/// Proof: `Veles::TransferRoyaltiesEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn update_transfer_royalties() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `172`
+		//  Estimated: `1657`
+		// Minimum execution time: 10_402_000 picoseconds.
+		Weight::from_parts(10_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 1657))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Veles::CarbonFootprintAccounts` (r:2 w:0)
+	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:2 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwners` (r:3 w:0)
+	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwnerDebts` (r:1 w:0)
+	/// Proof: `Veles::ProjectOwnerDebts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:3 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:2 w:2)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TransferRoyaltiesEnabled` (r:1 w:0)
+	/// Proof: `Veles::TransferRoyaltiesEnabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:24 w:24)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::BeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::BeneficiarySplits` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PenaltyLevels` (r:1 w:0)
+	/// Proof: `Veles::PenaltyLevels` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Validators` (r:2 w:0)
+	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_carbon_credits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2311`
+		//  Estimated: `63534`
+		// Minimum execution time: 168_391_000 picoseconds.
+		Weight::from_parts(276_153_000, 0)
+			.saturating_add(Weight::from_parts(0, 63534))
+			.saturating_add(T::DbWeight::get().reads(44))
+			.saturating_add(T::DbWeight::get().writes(26))
+	}
+	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
+	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwners` (r:1 w:0)
+	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BuyOrderTimeouts` (r:1 w:1)
+	/// Proof: `Veles::BuyOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::CarbonCreditBuyOrders` (r:0 w:1)
+	/// Proof: `Veles::CarbonCreditBuyOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_buy_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `842`
+		//  Estimated: `4307`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(53_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 4307))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Veles::CarbonCreditBuyOrders` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditBuyOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BuyOrderTimeouts` (r:1 w:1)
+	/// Proof: `Veles::BuyOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_buy_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `713`
+		//  Estimated: `4178`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_586_000, 0)
+			.saturating_add(Weight::from_parts(0, 4178))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Veles::CarbonCreditBuyOrders` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditBuyOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonFootprintAccounts` (r:2 w:0)
+	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:2 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwners` (r:3 w:0)
+	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwnerDebts` (r:1 w:0)
+	/// Proof: `Veles::ProjectOwnerDebts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:3 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:2 w:2)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:25 w:25)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::BeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::BeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PenaltyLevels` (r:1 w:0)
+	/// Proof: `Veles::PenaltyLevels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Validators` (r:2 w:0)
+	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BuyOrderTimeouts` (r:1 w:1)
+	/// Proof: `Veles::BuyOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn fill_buy_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2639`
+		//  Estimated: `63862`
+		// Minimum execution time: 201_436_000 picoseconds.
+		Weight::from_parts(309_842_000, 0)
+			.saturating_add(Weight::from_parts(0, 63862))
+			.saturating_add(T::DbWeight::get().reads(49))
+			.saturating_add(T::DbWeight::get().writes(31))
+	}
+	/// Storage: `Veles::CarbonCreditBuyOrders` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditBuyOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BuyOrderTimeouts` (r:1 w:1)
+	/// Proof: `Veles::BuyOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_carbon_credit_buy_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `652`
+		//  Estimated: `4117`
+		// Minimum execution time: 36_915_000 picoseconds.
+		Weight::from_parts(38_022_000, 0)
+			.saturating_add(Weight::from_parts(0, 4117))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Veles::CarbonFootprintAccounts` (r:1 w:0)
+	/// Proof: `Veles::CarbonFootprintAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwners` (r:2 w:0)
+	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:100 w:100)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::OrderBooks` (r:2 w:2)
+	/// Proof: `Veles::OrderBooks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::LimitOrders` (r:99 w:100)
+	/// Proof: `Veles::LimitOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:418 w:418)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::BeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::BeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PenaltyLevels` (r:1 w:0)
+	/// Proof: `Veles::PenaltyLevels` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Validators` (r:2 w:0)
+	/// Proof: `Veles::Validators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 99]`.
+	fn place_limit_order(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2336 + m * (221 ±0)`
+		//  Estimated: `60974 + m * (5206 ±0)`
+		// Minimum execution time: 61_522_000 picoseconds.
+		Weight::from_parts(166_834_000, 0)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(98_734_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 60974))
+			.saturating_add(T::DbWeight::get().reads(36))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(26))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(m.into()))
+	}
+	/// Storage: `Veles::LimitOrders` (r:1 w:1)
+	/// Proof: `Veles::LimitOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::OrderBooks` (r:1 w:1)
+	/// Proof: `Veles::OrderBooks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn cancel_limit_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `689`
+		//  Estimated: `4154`
+		// Minimum execution time: 34_816_000 picoseconds.
+		Weight::from_parts(35_973_000, 0)
+			.saturating_add(Weight::from_parts(0, 4154))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::AuctionTimeouts` (r:1 w:1)
+	/// Proof: `Veles::AuctionTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditAuctions` (r:0 w:1)
+	/// Proof: `Veles::CarbonCreditAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `706`
+		//  Estimated: `4171`
+		// Minimum execution time: 40_172_000 picoseconds.
+		Weight::from_parts(41_384_000, 0)
+			.saturating_add(Weight::from_parts(0, 4171))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Veles::CarbonCreditAuctions` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::AuctionTimeouts` (r:2 w:2)
+	/// Proof: `Veles::AuctionTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn place_auction_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1089`
+		//  Estimated: `6554`
+		// Minimum execution time: 68_311_000 picoseconds.
+		Weight::from_parts(70_052_000, 0)
+			.saturating_add(Weight::from_parts(0, 6554))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Veles::CarbonCreditAuctions` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:26 w:26)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:2 w:2)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::AuctionTimeouts` (r:1 w:1)
+	/// Proof: `Veles::AuctionTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn buy_auction_lot() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3235`
+		//  Estimated: `67602`
+		// Minimum execution time: 181_905_000 picoseconds.
+		Weight::from_parts(289_066_000, 0)
+			.saturating_add(Weight::from_parts(0, 67602))
+			.saturating_add(T::DbWeight::get().reads(42))
+			.saturating_add(T::DbWeight::get().writes(31))
+	}
+	/// Storage: `Veles::CarbonCreditAuctions` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::AuctionTimeouts` (r:1 w:1)
+	/// Proof: `Veles::AuctionTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `803`
+		//  Estimated: `4268`
+		// Minimum execution time: 31_655_000 picoseconds.
+		Weight::from_parts(32_740_000, 0)
+			.saturating_add(Weight::from_parts(0, 4268))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Veles::CarbonCreditAuctions` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditAuctions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::AuctionTimeouts` (r:1 w:1)
+	/// Proof: `Veles::AuctionTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:1 w:0)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:26 w:26)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:2 w:2)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TradingFees` (r:1 w:0)
+	/// Proof: `Veles::TradingFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_carbon_credit_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3309`
+		//  Estimated: `67676`
+		// Minimum execution time: 190_442_000 picoseconds.
+		Weight::from_parts(297_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 67676))
+			.saturating_add(T::DbWeight::get().reads(41))
+			.saturating_add(T::DbWeight::get().writes(33))
+	}
+	/// Storage: `Veles::CarbonCreditSaleOrders` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditSaleOrders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditBatches` (r:1 w:0)
+	/// Proof: `Veles::CarbonCreditBatches` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::TraderAccounts` (r:1 w:0)
+	/// Proof: `Veles::TraderAccounts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::CarbonCreditHoldings` (r:1 w:1)
+	/// Proof: `Veles::CarbonCreditHoldings` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::SaleOrderTimeouts` (r:2 w:2)
+	/// Proof: `Veles::SaleOrderTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn amend_sale_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `6852`
+		// Minimum execution time: 43_706_000 picoseconds.
+		Weight::from_parts(45_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 6852))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Veles::ProjectOwners` (r:1 w:0)
+	/// Proof: `Veles::ProjectOwners` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectOwnerDebts` (r:1 w:0)
+	/// Proof: `Veles::ProjectOwnerDebts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::UsedDocumentation` (r:1 w:1)
+	/// Proof: `Veles::UsedDocumentation` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PalletFeeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletFeeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PenaltyLevels` (r:1 w:0)
+	/// Proof: `Veles::PenaltyLevels` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::VotingTimeouts` (r:1 w:1)
+	/// Proof: `Veles::VotingTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::BeneficiarySplitProposals` (r:0 w:1)
+	/// Proof: `Veles::BeneficiarySplitProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_beneficiary_splits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1283`
+		//  Estimated: `4748`
+		// Minimum execution time: 51_473_000 picoseconds.
+		Weight::from_parts(53_219_000, 0)
+			.saturating_add(Weight::from_parts(0, 4748))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Veles::BeneficiarySplitProposals` (r:1 w:1)
+	/// Proof: `Veles::BeneficiarySplitProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::VotingTimeouts` (r:1 w:1)
+	/// Proof: `Veles::VotingTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::VotePassRatio` (r:1 w:0)
+	/// Proof: `Veles::VotePassRatio` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::Projects` (r:1 w:0)
+	/// Proof: `Veles::Projects` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PalletTimeValues` (r:1 w:0)
+	/// Proof: `Veles::PalletTimeValues` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::PruningTimeouts` (r:1 w:1)
+	/// Proof: `Veles::PruningTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Veles::ProjectBeneficiarySplits` (r:0 w:1)
+	/// Proof: `Veles::ProjectBeneficiarySplits` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_beneficiary_split_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1392`
+		//  Estimated: `4857`
+		// Minimum execution time: 37_842_000 picoseconds.
+		Weight::from_parts(39_106_000, 0)
+			.saturating_add(Weight::from_parts(0, 4857))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
 
 impl WeightInfo for () {
@@ -766,7 +1278,7 @@ impl WeightInfo for () {
 	}
 
 	fn update_penalty_levels() -> Weight {
-		Weight::from_parts(10_981_000, 0)
+		Weight::from_parts(11_695_000, 0)
 			.saturating_add(Weight::from_parts(0, 1657))
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
@@ -789,8 +1301,8 @@ impl WeightInfo for () {
 	fn update_fee_value() -> Weight {
 		Weight::from_parts(11_682_000, 0)
 			.saturating_add(Weight::from_parts(0, 1657))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 
 	fn register_for_trader_account() -> Weight {
@@ -843,17 +1355,17 @@ impl WeightInfo for () {
 	}
 
 	fn create_sale_order() -> Weight {
-		Weight::from_parts(36_258_000, 0)
+		Weight::from_parts(41_026_000, 0)
 			.saturating_add(Weight::from_parts(0, 3902))
 			.saturating_add(RocksDbWeight::get().reads(9))
-			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 
 	fn complete_sale_order() -> Weight {
-		Weight::from_parts(215_455_000, 0)
-			.saturating_add(Weight::from_parts(0, 14005))
-			.saturating_add(RocksDbWeight::get().reads(22))
-			.saturating_add(RocksDbWeight::get().writes(9))
+		Weight::from_parts(332_487_000, 0)
+			.saturating_add(Weight::from_parts(0, 68139))
+			.saturating_add(RocksDbWeight::get().reads(48))
+			.saturating_add(RocksDbWeight::get().writes(32))
 	}
 
 	fn close_sale_order() -> Weight {
@@ -863,18 +1375,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 
-	fn open_account_complaint() -> Weight {
+	fn open_account_complaint(b: u32, ) -> Weight {
 		Weight::from_parts(76_824_000, 0)
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 9277))
 			.saturating_add(RocksDbWeight::get().reads(23))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 
-	fn open_hash_complaint() -> Weight {
+	fn open_hash_complaint(b: u32, ) -> Weight {
 		Weight::from_parts(66_214_000, 0)
+			.saturating_add(Weight::from_parts(6_398_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 6802))
 			.saturating_add(RocksDbWeight::get().reads(19))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 
 	fn retire_carbon_credits() -> Weight {
@@ -926,18 +1446,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
 
-	fn update_complaint_for_account() -> Weight {
+	fn update_complaint_for_account(b: u32, ) -> Weight {
 		Weight::from_parts(36_950_000, 0)
+			.saturating_add(Weight::from_parts(6_371_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 6340))
 			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 
-	fn update_complaint_for_hash() -> Weight {
+	fn update_complaint_for_hash(b: u32, ) -> Weight {
 		Weight::from_parts(89_879_000, 0)
+			.saturating_add(Weight::from_parts(6_405_000, 0).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 14871))
 			.saturating_add(RocksDbWeight::get().reads(17))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(RocksDbWeight::get().writes(6))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2590).saturating_mul(b.into()))
 	}
 
 	fn update_project_owner_penalty_level() -> Weight {
@@ -960,4 +1488,133 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+
+	fn add_authority() -> Weight {
+		Weight::from_parts(10_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 1657))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn remove_authority() -> Weight {
+		Weight::from_parts(10_873_000, 0)
+			.saturating_add(Weight::from_parts(0, 1689))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn update_transfer_royalties() -> Weight {
+		Weight::from_parts(10_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 1657))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn transfer_carbon_credits() -> Weight {
+		Weight::from_parts(276_153_000, 0)
+			.saturating_add(Weight::from_parts(0, 63534))
+			.saturating_add(RocksDbWeight::get().reads(44))
+			.saturating_add(RocksDbWeight::get().writes(26))
+	}
+
+	fn create_buy_order() -> Weight {
+		Weight::from_parts(53_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 4307))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn cancel_buy_order() -> Weight {
+		Weight::from_parts(42_586_000, 0)
+			.saturating_add(Weight::from_parts(0, 4178))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn fill_buy_order() -> Weight {
+		Weight::from_parts(309_842_000, 0)
+			.saturating_add(Weight::from_parts(0, 63862))
+			.saturating_add(RocksDbWeight::get().reads(49))
+			.saturating_add(RocksDbWeight::get().writes(31))
+	}
+
+	fn update_carbon_credit_buy_order() -> Weight {
+		Weight::from_parts(38_022_000, 0)
+			.saturating_add(Weight::from_parts(0, 4117))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn place_limit_order(m: u32, ) -> Weight {
+		Weight::from_parts(166_834_000, 0)
+			.saturating_add(Weight::from_parts(98_734_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 60974))
+			.saturating_add(RocksDbWeight::get().reads(36))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(26))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(m.into()))
+	}
+
+	fn cancel_limit_order() -> Weight {
+		Weight::from_parts(35_973_000, 0)
+			.saturating_add(Weight::from_parts(0, 4154))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	fn create_auction() -> Weight {
+		Weight::from_parts(41_384_000, 0)
+			.saturating_add(Weight::from_parts(0, 4171))
+			.saturating_add(RocksDbWeight::get().reads(9))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn place_auction_bid() -> Weight {
+		Weight::from_parts(70_052_000, 0)
+			.saturating_add(Weight::from_parts(0, 6554))
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+
+	fn buy_auction_lot() -> Weight {
+		Weight::from_parts(289_066_000, 0)
+			.saturating_add(Weight::from_parts(0, 67602))
+			.saturating_add(RocksDbWeight::get().reads(42))
+			.saturating_add(RocksDbWeight::get().writes(31))
+	}
+
+	fn cancel_auction() -> Weight {
+		Weight::from_parts(32_740_000, 0)
+			.saturating_add(Weight::from_parts(0, 4268))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn update_carbon_credit_auction() -> Weight {
+		Weight::from_parts(297_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 67676))
+			.saturating_add(RocksDbWeight::get().reads(41))
+			.saturating_add(RocksDbWeight::get().writes(33))
+	}
+
+	fn amend_sale_order() -> Weight {
+		Weight::from_parts(45_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 6852))
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	fn propose_beneficiary_splits() -> Weight {
+		Weight::from_parts(53_219_000, 0)
+			.saturating_add(Weight::from_parts(0, 4748))
+			.saturating_add(RocksDbWeight::get().reads(10))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+
+	fn update_beneficiary_split_proposal() -> Weight {
+		Weight::from_parts(39_106_000, 0)
+			.saturating_add(Weight::from_parts(0, 4857))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
 }
\ No newline at end of file
@@ -24,8 +24,8 @@ use frame_support::genesis_builder_helper::{build_config, create_default_config}
 pub use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{
-		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness,
-		StorageInfo,
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOfDiverse, KeyOwnerProofSystem,
+		Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -37,6 +37,7 @@ pub use frame_support::{
 };
 pub use frame_system::offchain::SendTransactionTypes;
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -234,8 +235,8 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
-	type RuntimeFreezeReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 }
 
 parameter_types! {
@@ -264,6 +265,23 @@ parameter_types! {
 	pub OffchainWorkerTxPriority: TransactionPriority =
 		Perbill::from_percent(10) * TransactionPriority::max_value();
 	pub OffchainWorkerTxLongevity: TransactionLongevity = 5;
+	pub MaxTimeoutsWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const MaxValidatorsPerVote: u32 = 100;
+	pub const MaxDocumentsPerCfa: u32 = 100;
+	pub const MaxTimeoutsPerBlock: u32 = 256;
+	pub const MaxDebtsPerProjectOwner: u32 = 1_000;
+	pub const MaxProjectsPerOwner: u32 = 100;
+	pub const MaxBatchesPerProject: u32 = 1_000;
+	pub const MaxRetirementsPerBatch: u32 = 10_000;
+	pub const MaxFillsPerSaleOrder: u32 = 100;
+	pub const MaxAllowedBuyersPerSaleOrder: u32 = 20;
+	pub const MaxOrdersPerBook: u32 = 100;
+	pub const MaxStakeholdersPerProject: u32 = 20;
+	pub const AuctionExtensionPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxTradingFee: Permill = Permill::from_percent(5);
+	pub const TradingFeeBeneficiary: Option<AccountId> = None;
+	pub const RetentionPeriod: BlockNumber = 90 * DAYS;
+	pub const MaxOffchainWorkerTimeoutBlocks: u32 = 100;
 }
 
 /// Configure the Veles pallet
@@ -274,8 +292,31 @@ impl pallet_veles::Config for Runtime {
 	type Time = Timestamp;
 	type UnsignedPriority = OffchainWorkerTxPriority;
 	type UnsignedLongevity = OffchainWorkerTxLongevity;
+	type MaxTimeoutsWeight = MaxTimeoutsWeight;
+	type OffchainWorkerFallback = ConstBool<true>;
+	type MaxOffchainWorkerTimeoutBlocks = MaxOffchainWorkerTimeoutBlocks;
+	type DepositMode = ConstBool<true>;
+	type MaxValidatorsPerVote = MaxValidatorsPerVote;
+	type MaxDocumentsPerCfa = MaxDocumentsPerCfa;
+	type MaxTimeoutsPerBlock = MaxTimeoutsPerBlock;
+	type MaxDebtsPerProjectOwner = MaxDebtsPerProjectOwner;
+	type MaxProjectsPerOwner = MaxProjectsPerOwner;
+	type MaxBatchesPerProject = MaxBatchesPerProject;
+	type MaxRetirementsPerBatch = MaxRetirementsPerBatch;
+	type MaxFillsPerSaleOrder = MaxFillsPerSaleOrder;
+	type MaxAllowedBuyersPerSaleOrder = MaxAllowedBuyersPerSaleOrder;
+	type MaxOrdersPerBook = MaxOrdersPerBook;
+	type MaxStakeholdersPerProject = MaxStakeholdersPerProject;
+	type AuctionExtensionPeriod = AuctionExtensionPeriod;
+	type MaxTradingFee = MaxTradingFee;
+	type TradingFeeBeneficiary = TradingFeeBeneficiary;
+	type RetentionPeriod = RetentionPeriod;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type Currency = Balances;
 	type WeightInfo = pallet_veles::weights::SubstrateWeight<Runtime>;
+	type AdminOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, pallet_veles::EnsureAuthority<Runtime>>;
+	type AuthorityManagerOrigin = EnsureRoot<AccountId>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -342,7 +383,14 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_veles::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_veles::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_veles::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_veles::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_veles::migrations::v5::MigrateV4ToV5<Runtime>,
+	pallet_veles::migrations::v6::MigrateV5ToV6<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =